@@ -2,7 +2,7 @@
 
 use anyhow::{Error, Result, anyhow};
 use git_mile_core::event::Event;
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{TaskId, TaskIdPrefix};
 use git_mile_core::{TaskFilter, TaskSnapshot};
 use git_mile_store_git::{GitStore, GitStoreError};
 use git2::Oid;
@@ -10,8 +10,11 @@ use std::sync::Arc;
 use time::OffsetDateTime;
 use tokio::sync::Mutex;
 
+use crate::ready_work::ReadyWork;
+use crate::rollup::TaskRollup;
 use crate::task_cache::{TaskCache, TaskView};
-use crate::task_log::ordered_events;
+use crate::task_log::{ActivityFeed, ActivityFilter, ordered_events};
+use crate::task_repository::TaskTips;
 
 /// Async storage trait for use with `tokio::sync::Mutex`.
 ///
@@ -54,6 +57,55 @@ pub trait AsyncTaskStore: Send + Sync {
         &self,
         since: time::OffsetDateTime,
     ) -> Result<Vec<TaskId>, Self::Error>;
+
+    /// Load all events for every known task.
+    ///
+    /// See [`crate::task_writer::TaskStore::load_all_events`].
+    ///
+    /// # Errors
+    /// Propagates the first error from the underlying store.
+    async fn load_all_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>, Self::Error> {
+        let mut results = Vec::new();
+        for task in self.list_tasks().await? {
+            let events = self.load_events(task).await?;
+            results.push((task, events));
+        }
+        Ok(results)
+    }
+
+    /// Report the commit each task's history currently ends at, if the store tracks one.
+    ///
+    /// See [`crate::task_writer::TaskStore::list_task_tips`].
+    ///
+    /// # Errors
+    /// Returns a store-specific error when enumerating tips fails.
+    async fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Invalidate cached events for the specified tasks.
+    ///
+    /// # Errors
+    /// Returns a store-specific error when cache invalidation fails.
+    async fn invalidate_cache(&self, _task_ids: &[TaskId]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Resolve a full or abbreviated task id against the stored tasks.
+    ///
+    /// See [`crate::task_writer::TaskStore::resolve_task_id`].
+    ///
+    /// # Errors
+    /// Returns a [`TaskIdResolveError`](git_mile_core::id::TaskIdResolveError) for malformed,
+    /// unknown or ambiguous ids, or the store error when listing tasks fails.
+    async fn resolve_task_id(&self, input: &str) -> Result<TaskId> {
+        let prefix = TaskIdPrefix::parse(input)?;
+        if let Some(id) = prefix.as_full() {
+            return Ok(id);
+        }
+        let tasks = self.list_tasks().await.map_err(Into::into)?;
+        Ok(prefix.resolve(tasks)?)
+    }
 }
 
 impl AsyncTaskStore for Arc<Mutex<GitStore>> {
@@ -122,6 +174,36 @@ impl AsyncTaskStore for Arc<Mutex<GitStore>> {
             .map_err(|e| GitStoreError::Other(format!("Task join error: {e}")))?
             .map_err(GitStoreError::from)
     }
+
+    async fn load_all_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>, Self::Error> {
+        let guard = self.lock().await;
+        // Clone the store to avoid holding the lock during blocking I/O
+        let store = guard.clone();
+        drop(guard);
+
+        tokio::task::spawn_blocking(move || store.load_all_task_events())
+            .await
+            .map_err(|e| GitStoreError::Other(format!("Task join error: {e}")))?
+            .map_err(GitStoreError::from)
+    }
+
+    async fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        let guard = self.lock().await;
+        // Clone the store to avoid holding the lock during blocking I/O
+        let store = guard.clone();
+        drop(guard);
+
+        tokio::task::spawn_blocking(move || store.list_task_tips())
+            .await
+            .map_err(|e| GitStoreError::Other(format!("Task join error: {e}")))?
+            .map(Some)
+            .map_err(GitStoreError::from)
+    }
+
+    async fn invalidate_cache(&self, task_ids: &[TaskId]) -> Result<(), Self::Error> {
+        self.lock().await.invalidate_tasks_cache(task_ids);
+        Ok(())
+    }
 }
 
 /// Async repository that caches task snapshots for MCP integration.
@@ -135,6 +217,7 @@ pub struct AsyncTaskRepository<S> {
 struct CacheState {
     cache: TaskCache,
     last_refresh: Option<OffsetDateTime>,
+    tips: Option<TaskTips>,
 }
 
 impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
@@ -145,11 +228,12 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
             cache: Arc::new(Mutex::new(CacheState {
                 cache: TaskCache::default(),
                 last_refresh: None,
+                tips: None,
             })),
         }
     }
 
-    /// Refresh the cache if stale.
+    /// Refresh the cache if stale, preferring history tips over timestamps when available.
     async fn refresh_if_stale(&self) -> Result<()> {
         enum RefreshPlan {
             Full,
@@ -165,6 +249,7 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
 
         match plan {
             RefreshPlan::Full => {
+                let tips = self.list_tips().await?;
                 let cache = self.load_cache().await?;
                 let mut state = self.cache.lock().await;
                 let latest_ts = cache
@@ -174,8 +259,12 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
                     .unwrap_or(OffsetDateTime::UNIX_EPOCH);
                 state.cache = cache;
                 state.last_refresh = Some(latest_ts);
+                state.tips = tips;
             }
             RefreshPlan::Incremental(last_refresh) => {
+                if let Some(tips) = self.list_tips().await? {
+                    return self.refresh_changed_tips(tips, last_refresh).await;
+                }
                 let modified = self.list_modified_since(last_refresh).await?;
                 if modified.is_empty() {
                     return Ok(());
@@ -196,6 +285,40 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
         Ok(())
     }
 
+    async fn refresh_changed_tips(&self, current: TaskTips, last_refresh: OffsetDateTime) -> Result<()> {
+        let changes = current.changes_since(self.cache.lock().await.tips.as_ref());
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        self.store
+            .invalidate_cache(&changes.changed)
+            .await
+            .map_err(|e| anyhow!("Failed to invalidate cached events: {}", e.into()))?;
+        let updated_views = self.load_task_views(&changes.changed).await?;
+        let latest_seen = updated_views
+            .iter()
+            .filter_map(|view| view.last_updated)
+            .max()
+            .unwrap_or(last_refresh);
+        let mut state = self.cache.lock().await;
+        state.cache.remove_views(&changes.removed);
+        state.cache.upsert_views(updated_views);
+        let previous = state.last_refresh.unwrap_or(last_refresh);
+        state.last_refresh = Some(previous.max(latest_seen));
+        state.tips = Some(current);
+        drop(state);
+        Ok(())
+    }
+
+    async fn list_tips(&self) -> Result<Option<TaskTips>> {
+        self.store
+            .list_task_tips()
+            .await
+            .map(|tips| tips.map(TaskTips::new))
+            .map_err(|e| anyhow!("Failed to list task tips: {}", e.into()))
+    }
+
     async fn list_modified_since(&self, since: OffsetDateTime) -> Result<Vec<TaskId>> {
         self.store
             .list_tasks_modified_since(since)
@@ -230,17 +353,41 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
 
     /// List task snapshots with optional filter.
     ///
+    /// Abbreviated ids in the filter's query are resolved against the cached tasks first.
+    ///
     /// # Errors
-    /// Returns an error if the cache cannot be refreshed or task loading fails.
+    /// Returns an error if the cache cannot be refreshed, task loading fails, or a query id
+    /// prefix is unknown or ambiguous.
     pub async fn list_snapshots(&self, filter: Option<&TaskFilter>) -> Result<Vec<TaskSnapshot>> {
         self.refresh_if_stale().await?;
         let state = self.cache.lock().await;
+        let filter = filter.map(|f| state.cache.resolve_filter(f)).transpose()?;
         Ok(filter.map_or_else(
             || state.cache.snapshots().cloned().collect(),
-            |f| state.cache.filtered_snapshots(f),
+            |f| state.cache.filtered_snapshots(&f),
         ))
     }
 
+    /// Compute the tasks that can be started now. See [`TaskCache::ready_work`].
+    ///
+    /// # Errors
+    /// Returns an error if the cache cannot be refreshed or task loading fails.
+    pub async fn ready_work(&self) -> Result<ReadyWork> {
+        self.refresh_if_stale().await?;
+        let state = self.cache.lock().await;
+        Ok(state.cache.ready_work())
+    }
+
+    /// Subtask rollup of a task. See [`TaskCache::rollup`].
+    ///
+    /// # Errors
+    /// Returns an error if the cache cannot be refreshed or task loading fails.
+    pub async fn rollup(&self, task_id: TaskId) -> Result<Option<TaskRollup>> {
+        self.refresh_if_stale().await?;
+        let state = self.cache.lock().await;
+        Ok(state.cache.rollup(task_id).cloned())
+    }
+
     /// Get a clone of the current cache.
     ///
     /// # Errors
@@ -251,6 +398,14 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
         Ok(state.cache.clone())
     }
 
+    /// Resolve a full or abbreviated task id.
+    ///
+    /// # Errors
+    /// Returns an error if the id is malformed, unknown or ambiguous, or listing tasks fails.
+    pub async fn resolve_task_id(&self, input: &str) -> Result<TaskId> {
+        self.store.resolve_task_id(input).await
+    }
+
     /// Fetch a snapshot for a specific task.
     ///
     /// # Errors
@@ -285,6 +440,20 @@ impl<S: AsyncTaskStore> AsyncTaskRepository<S> {
         Ok(ordered_events(&events))
     }
 
+    /// Merge every task's events into one feed filtered by `filter`.
+    ///
+    /// # Errors
+    /// Returns an error if the filter is invalid or events cannot be loaded.
+    pub async fn activity(&self, filter: &ActivityFilter) -> Result<ActivityFeed> {
+        filter.validate()?;
+        let histories = self
+            .store
+            .load_all_events()
+            .await
+            .map_err(|e| anyhow!("Failed to load task events: {}", e.into()))?;
+        Ok(ActivityFeed::collect(histories, filter))
+    }
+
     /// List children for a task.
     ///
     /// # Errors
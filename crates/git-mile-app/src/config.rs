@@ -1,14 +1,16 @@
 use std::{
-    collections::HashSet,
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashSet},
     fs,
     path::{Path, PathBuf},
 };
 
 use anyhow::{Context, Result, anyhow, bail};
 pub use git_mile_core::StateKind;
+use git_mile_core::{TaskFilter, TaskSnapshot, compare_field_values, rank};
 pub use git_mile_hooks::HooksConfig;
 use git2::Repository;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 const CONFIG_DIR: &str = ".git-mile";
 const CONFIG_FILE: &str = "config.toml";
@@ -20,6 +22,9 @@ pub struct ProjectConfig {
     pub workflow: WorkflowConfig,
     #[serde(default)]
     pub hooks: HooksConfig,
+    /// Named views declared under `[views.<name>]`, keyed by name.
+    #[serde(default)]
+    pub views: BTreeMap<String, SavedView>,
 }
 
 impl ProjectConfig {
@@ -59,9 +64,63 @@ impl ProjectConfig {
         Ok(config)
     }
 
+    /// Look up a saved view by name.
+    ///
+    /// # Errors
+    /// Returns an error listing the configured views when `name` is unknown.
+    pub fn view(&self, name: &str) -> Result<&SavedView> {
+        self.views.get(name).ok_or_else(|| {
+            if self.views.is_empty() {
+                anyhow!("view '{name}' not found: no views are configured")
+            } else {
+                let names = self
+                    .views
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow!("view '{name}' not found. Available views: {names}")
+            }
+        })
+    }
+
     fn validate(&self) -> Result<()> {
         self.workflow.ensure_unique_states()?;
-        self.workflow.ensure_valid_default()
+        self.workflow.ensure_valid_default()?;
+        self.workflow.ensure_valid_transitions()?;
+        self.workflow.ensure_valid_fields()?;
+        self.ensure_valid_views()
+    }
+
+    fn ensure_valid_views(&self) -> Result<()> {
+        for (name, view) in &self.views {
+            if name.trim().is_empty() {
+                bail!("view name must not be empty");
+            }
+            if view.columns.is_empty() {
+                bail!("view '{name}' must list at least one column");
+            }
+            if let Some(field) = view.sort.field()
+                && self.workflow.field(field).is_none()
+            {
+                bail!("view '{name}' sorts by undeclared field '{field}'");
+            }
+            view.filter
+                .validate()
+                .map_err(|err| anyhow!("view '{name}' has an invalid filter: {err}"))?;
+            if !self.workflow.is_restricted() {
+                continue;
+            }
+            if let Some(state) = view
+                .filter
+                .states
+                .iter()
+                .find(|state| self.workflow.find_state(state).is_none())
+            {
+                bail!("view '{name}' references undefined workflow state '{state}'");
+            }
+        }
+        Ok(())
     }
 }
 
@@ -84,6 +143,15 @@ pub struct WorkflowConfig {
     states: Vec<WorkflowState>,
     #[serde(default)]
     default_state: Option<String>,
+    /// Allowed state changes. When empty, any state change is permitted.
+    ///
+    /// The initial state of a new task is not checked; "an unset state" in the rules refers
+    /// to an existing task whose state was never set or was cleared.
+    #[serde(default)]
+    transitions: Vec<WorkflowTransition>,
+    /// Custom task fields declared under `[workflow.fields.<name>]`.
+    #[serde(default)]
+    fields: BTreeMap<String, FieldDefinition>,
 }
 
 impl Default for WorkflowConfig {
@@ -91,6 +159,8 @@ impl Default for WorkflowConfig {
         Self {
             states: Self::builtin_states(),
             default_state: Some("state/todo".into()),
+            transitions: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 }
@@ -102,6 +172,8 @@ impl WorkflowConfig {
         Self {
             states: Vec::new(),
             default_state: None,
+            transitions: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 
@@ -131,6 +203,8 @@ impl WorkflowConfig {
         Self {
             states,
             default_state: None,
+            transitions: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 
@@ -139,7 +213,95 @@ impl WorkflowConfig {
         Self {
             states,
             default_state: default_state.map(str::to_owned),
+            transitions: Vec::new(),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    /// Replace the transition rules.
+    #[must_use]
+    pub fn with_transitions(mut self, transitions: Vec<WorkflowTransition>) -> Self {
+        self.transitions = transitions;
+        self
+    }
+
+    /// Replace the custom field declarations.
+    #[must_use]
+    pub fn with_fields(mut self, fields: BTreeMap<String, FieldDefinition>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Declared custom fields, keyed by name.
+    #[must_use]
+    pub const fn fields(&self) -> &BTreeMap<String, FieldDefinition> {
+        &self.fields
+    }
+
+    /// Look up a custom field declaration.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&FieldDefinition> {
+        self.fields.get(name)
+    }
+
+    /// Check `value` against the declaration of field `name` and return its canonical form.
+    ///
+    /// # Errors
+    /// Returns a [`FieldViolation`] when the field is not declared or the value does not fit its type.
+    pub fn normalize_field(&self, name: &str, value: &str) -> Result<String, FieldViolation> {
+        let definition = self.field(name).ok_or_else(|| FieldViolation::Unknown {
+            name: name.to_owned(),
+        })?;
+        definition
+            .normalize(value)
+            .ok_or_else(|| FieldViolation::InvalidValue {
+                name: name.to_owned(),
+                value: value.to_owned(),
+                expected: definition.hint(),
+            })
+    }
+
+    /// Configured transition rules (empty when transitions are unrestricted).
+    #[must_use]
+    pub fn transitions(&self) -> &[WorkflowTransition] {
+        &self.transitions
+    }
+
+    /// Check a state change against the transition rules.
+    ///
+    /// Staying in the same state is always allowed. Otherwise at least one rule targeting `to`
+    /// must match `from` and have its requirements met by the task's resulting labels and assignees.
+    ///
+    /// # Errors
+    /// Returns the violation of the first matching rule, or [`TransitionViolation::NotAllowed`]
+    /// when no rule matches.
+    pub fn check_transition(
+        &self,
+        from: Option<&str>,
+        to: &str,
+        labels: &BTreeSet<String>,
+        assignees: &BTreeSet<String>,
+    ) -> Result<(), TransitionViolation> {
+        if self.transitions.is_empty() || from == Some(to) {
+            return Ok(());
+        }
+
+        let mut first_violation = None;
+        for rule in self.transitions.iter().filter(|rule| rule.matches(from, to)) {
+            match rule.check_requirements(labels, assignees) {
+                Ok(()) => return Ok(()),
+                Err(violation) => {
+                    first_violation.get_or_insert(violation);
+                }
+            }
         }
+
+        Err(
+            first_violation.unwrap_or_else(|| TransitionViolation::NotAllowed {
+                from: from.map(str::to_owned),
+                to: to.to_owned(),
+            }),
+        )
     }
 
     /// Returns true when states are restricted to a configured set.
@@ -234,6 +396,50 @@ impl WorkflowConfig {
         Ok(())
     }
 
+    fn ensure_valid_transitions(&self) -> Result<()> {
+        for rule in &self.transitions {
+            if rule.to.trim().is_empty() {
+                bail!("workflow transition target must not be empty");
+            }
+            if !self.is_restricted() {
+                continue;
+            }
+            for value in rule.from.iter().chain(std::iter::once(&rule.to)) {
+                if self.find_state(value).is_none() {
+                    bail!("workflow transition references undefined state '{value}'");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn ensure_valid_fields(&self) -> Result<()> {
+        for (name, definition) in &self.fields {
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                bail!("field name '{name}' may only contain letters, digits, '-' and '_'");
+            }
+            if let FieldDefinition::Enum { values } = definition {
+                if values.is_empty() {
+                    bail!("enum field '{name}' must list at least one value");
+                }
+                let mut seen = HashSet::new();
+                for value in values {
+                    if value.trim().is_empty() || value.contains(',') {
+                        bail!("enum field '{name}' has an invalid value '{value}'");
+                    }
+                    if !seen.insert(value.to_ascii_lowercase()) {
+                        bail!("enum field '{name}' lists '{value}' more than once");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Resolve the configured state kind (if any) for the provided workflow state.
     #[must_use]
     pub fn resolve_state_kind(&self, value: Option<&str>) -> Option<StateKind> {
@@ -288,6 +494,425 @@ impl WorkflowState {
     }
 }
 
+/// Allowed state change declared under `[[workflow.transitions]]`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowTransition {
+    #[serde(default)]
+    from: Option<String>,
+    to: String,
+    #[serde(default)]
+    require_assignee: bool,
+    #[serde(default)]
+    require_labels: Vec<String>,
+}
+
+impl WorkflowTransition {
+    /// Allow moving from `from` (or from any state when `None`) to `to`.
+    #[must_use]
+    pub fn new(from: Option<&str>, to: impl Into<String>) -> Self {
+        Self {
+            from: from.map(str::to_owned),
+            to: to.into(),
+            require_assignee: false,
+            require_labels: Vec::new(),
+        }
+    }
+
+    /// Require at least one assignee for this transition.
+    #[must_use]
+    pub const fn require_assignee(mut self) -> Self {
+        self.require_assignee = true;
+        self
+    }
+
+    /// Require the given label for this transition.
+    #[must_use]
+    pub fn require_label(mut self, label: impl Into<String>) -> Self {
+        self.require_labels.push(label.into());
+        self
+    }
+
+    /// Source state, or `None` when the rule applies to any source.
+    #[must_use]
+    pub fn from(&self) -> Option<&str> {
+        self.from.as_deref()
+    }
+
+    /// Target state.
+    #[must_use]
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    fn matches(&self, from: Option<&str>, to: &str) -> bool {
+        self.to == to && self.from.as_deref().is_none_or(|expected| from == Some(expected))
+    }
+
+    fn check_requirements(
+        &self,
+        labels: &BTreeSet<String>,
+        assignees: &BTreeSet<String>,
+    ) -> Result<(), TransitionViolation> {
+        if self.require_assignee && assignees.is_empty() {
+            return Err(TransitionViolation::MissingAssignee { to: self.to.clone() });
+        }
+        if let Some(label) = self.require_labels.iter().find(|label| !labels.contains(*label)) {
+            return Err(TransitionViolation::MissingLabel {
+                to: self.to.clone(),
+                label: label.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Type of a custom task field declared under `[workflow.fields.<name>]`.
+///
+/// ```toml
+/// [workflow.fields.priority]
+/// type = "enum"
+/// values = ["high", "medium", "low"]
+///
+/// [workflow.fields.due]
+/// type = "date"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FieldDefinition {
+    /// One of a fixed list of values, ordered as declared.
+    Enum {
+        /// Allowed values, matched ignoring case.
+        values: Vec<String>,
+    },
+    /// Calendar date written `YYYY-MM-DD`.
+    Date,
+    /// Integer or decimal number.
+    Number,
+    /// Single line of free text.
+    String,
+    /// Person responsible for something, written like an assignee.
+    Actor,
+}
+
+impl FieldDefinition {
+    /// Canonical form of `value`, or `None` when it does not fit this type.
+    ///
+    /// Enum values take the declared spelling, dates are zero-padded and numbers lose
+    /// insignificant zeros, so equal values are stored identically. Numbers with more
+    /// precision than an `f64` holds (e.g. integers above 2^53) are rejected.
+    #[must_use]
+    pub fn normalize(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        if value.is_empty() || value.contains('\n') {
+            return None;
+        }
+        match self {
+            Self::Enum { values } => values
+                .iter()
+                .find(|allowed| allowed.eq_ignore_ascii_case(value))
+                .cloned(),
+            Self::Date => parse_field_date(value).map(|date| {
+                format!(
+                    "{:04}-{:02}-{:02}",
+                    date.year(),
+                    u8::from(date.month()),
+                    date.day()
+                )
+            }),
+            Self::Number => {
+                let number = value.parse::<f64>().ok().filter(|number| number.is_finite())?;
+                let canonical = number.to_string();
+                // Refuse values an `f64` cannot hold exactly rather than store another number.
+                (decimal_digits(value)? == decimal_digits(&canonical)?).then_some(canonical)
+            }
+            Self::String | Self::Actor => Some(value.to_owned()),
+        }
+    }
+
+    /// Short description of accepted values for error messages and editor hints.
+    #[must_use]
+    pub fn hint(&self) -> String {
+        match self {
+            Self::Enum { values } => format!("one of {}", values.join(", ")),
+            Self::Date => "a date like 2025-01-31".to_owned(),
+            Self::Number => "a number".to_owned(),
+            Self::String => "a single line of text".to_owned(),
+            Self::Actor => "an actor name or email".to_owned(),
+        }
+    }
+
+    /// Order two stored values: enums by declaration order, everything else by
+    /// [`compare_field_values`].
+    #[must_use]
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Enum { values } => {
+                let rank = |value: &str| {
+                    values
+                        .iter()
+                        .position(|allowed| allowed.eq_ignore_ascii_case(value))
+                };
+                // Values no longer declared sort after the declared ones.
+                match (rank(a), rank(b)) {
+                    (Some(x), Some(y)) => x.cmp(&y),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => compare_field_values(a, b),
+                }
+            }
+            _ => compare_field_values(a, b),
+        }
+    }
+}
+
+/// Exact value of a decimal literal as `(negative, significant digits, exponent)`, so that
+/// `1.50`, `15e-1` and `1.5` compare equal. Zero is `(false, "", 0)`.
+fn decimal_digits(value: &str) -> Option<(bool, String, i64)> {
+    let (negative, unsigned) = match value.as_bytes().first()? {
+        b'-' => (true, &value[1..]),
+        b'+' => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(pos) => (&unsigned[..pos], unsigned[pos + 1..].parse::<i64>().ok()?),
+        None => (unsigned, 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if (int.is_empty() && frac.is_empty())
+        || !int.bytes().chain(frac.bytes()).all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+    let digits = format!("{int}{frac}");
+    let significant = digits.trim_start_matches('0');
+    let trimmed = significant.trim_end_matches('0');
+    if trimmed.is_empty() {
+        return Some((false, String::new(), 0));
+    }
+    // Each dropped trailing zero moves the decimal point one place.
+    let dropped = i64::try_from(significant.len() - trimmed.len()).ok()?;
+    let fraction = i64::try_from(frac.len()).ok()?;
+    Some((negative, trimmed.to_owned(), exponent - fraction + dropped))
+}
+
+fn parse_field_date(value: &str) -> Option<time::Date> {
+    let mut parts = value.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse::<u8>().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    time::Date::from_calendar_date(year, time::Month::try_from(month).ok()?, day).ok()
+}
+
+/// Reason a custom field value was refused.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FieldViolation {
+    /// The field is not declared in the configuration.
+    #[error("field '{name}' is not declared in the workflow configuration")]
+    Unknown {
+        /// Field name as given
+        name: String,
+    },
+    /// The value does not fit the declared type.
+    #[error("field '{name}' expects {expected}, got '{value}'")]
+    InvalidValue {
+        /// Field name
+        name: String,
+        /// Value as given
+        value: String,
+        /// Description of accepted values
+        expected: String,
+    },
+}
+
+/// Reason a state change was refused by the workflow transition rules.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TransitionViolation {
+    /// No rule allows moving between the two states.
+    #[error("transition from {} to '{to}' is not allowed", describe_source(.from.as_deref()))]
+    NotAllowed {
+        /// Current state (`None` when unset)
+        from: Option<String>,
+        /// Requested state
+        to: String,
+    },
+    /// The transition requires at least one assignee.
+    #[error("moving to '{to}' requires an assignee")]
+    MissingAssignee {
+        /// Requested state
+        to: String,
+    },
+    /// The transition requires a label the task does not carry.
+    #[error("moving to '{to}' requires label '{label}'")]
+    MissingLabel {
+        /// Requested state
+        to: String,
+        /// Missing label
+        label: String,
+    },
+}
+
+fn describe_source(from: Option<&str>) -> String {
+    from.map_or_else(|| "an unset state".to_owned(), |value| format!("'{value}'"))
+}
+
+/// Named filter with presentation settings, declared under `[views.<name>]`.
+///
+/// ```toml
+/// [views.my-open]
+/// description = "My unfinished work"
+/// sort = "updated"
+/// columns = ["id", "state", "title"]
+///
+/// [views.my-open.filter]
+/// assignees = ["alice"]
+/// query = "-kind:done"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedView {
+    /// Short explanation shown when listing views.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Filter applied when the view is selected.
+    #[serde(default)]
+    pub filter: TaskFilter,
+    /// Ordering of the matched tasks.
+    #[serde(default)]
+    pub sort: ViewSort,
+    /// Columns shown in table output.
+    #[serde(default = "ViewColumn::all")]
+    pub columns: Vec<ViewColumn>,
+}
+
+impl Default for SavedView {
+    fn default() -> Self {
+        Self {
+            description: None,
+            filter: TaskFilter::default(),
+            sort: ViewSort::default(),
+            columns: ViewColumn::all(),
+        }
+    }
+}
+
+/// Sort order used by a [`SavedView`].
+///
+/// Custom fields sort with `sort = { field = "priority" }` or `sort = { field-desc = "due" }`;
+/// tasks without a value come last either way.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViewSort {
+    /// Most recently updated first.
+    #[default]
+    Updated,
+    /// Least recently updated first.
+    UpdatedAsc,
+    /// Title, ignoring case.
+    Title,
+    /// Workflow state value.
+    State,
+    /// Manual queue rank, then most recently updated for tasks outside the queue.
+    Rank,
+    /// Custom field, smallest (or first declared enum value) first.
+    Field(String),
+    /// Custom field, largest (or last declared enum value) first.
+    FieldDesc(String),
+}
+
+impl ViewSort {
+    /// Custom field this order sorts by, if any.
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::Field(name) | Self::FieldDesc(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Compare two snapshots, falling back to the task id so the order is stable.
+    ///
+    /// `workflow` supplies the field declarations that order custom field values.
+    #[must_use]
+    pub fn compare(&self, workflow: &WorkflowConfig, a: &TaskSnapshot, b: &TaskSnapshot) -> Ordering {
+        let primary = match self {
+            Self::Updated => b.updated_at().cmp(&a.updated_at()),
+            Self::UpdatedAsc => a.updated_at().cmp(&b.updated_at()),
+            Self::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            Self::State => a.state.cmp(&b.state),
+            Self::Rank => rank::compare(a, b).then_with(|| b.updated_at().cmp(&a.updated_at())),
+            Self::Field(name) | Self::FieldDesc(name) => {
+                let compare = |x: &str, y: &str| {
+                    let ordering = workflow
+                        .field(name)
+                        .map_or_else(|| compare_field_values(x, y), |field| field.compare(x, y));
+                    if matches!(self, Self::FieldDesc(_)) {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                };
+                match (a.fields.get(name), b.fields.get(name)) {
+                    (Some(x), Some(y)) => compare(x, y),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            }
+        };
+        primary.then_with(|| a.id.cmp(&b.id))
+    }
+
+    /// Sort snapshots in place.
+    pub fn sort(&self, workflow: &WorkflowConfig, tasks: &mut [TaskSnapshot]) {
+        tasks.sort_by(|a, b| self.compare(workflow, a, b));
+    }
+}
+
+/// Column shown in table output of a [`SavedView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViewColumn {
+    /// Task identifier.
+    Id,
+    /// Workflow state.
+    State,
+    /// Title.
+    Title,
+    /// Labels.
+    Labels,
+    /// Assignees.
+    Assignees,
+    /// Last update timestamp.
+    Updated,
+}
+
+impl ViewColumn {
+    /// Every column, in the default table order.
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Id,
+            Self::State,
+            Self::Title,
+            Self::Labels,
+            Self::Assignees,
+            Self::Updated,
+        ]
+    }
+
+    /// Table header text.
+    #[must_use]
+    pub const fn header(self) -> &'static str {
+        match self {
+            Self::Id => "ID",
+            Self::State => "State",
+            Self::Title => "Title",
+            Self::Labels => "Labels",
+            Self::Assignees => "Assignees",
+            Self::Updated => "Updated",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -427,4 +1052,276 @@ mod tests {
         assert!(workflow.resolve_state_kind(Some("state/unknown")).is_none());
         assert!(workflow.resolve_state_kind(None).is_none());
     }
+
+    #[test]
+    fn load_config_with_transitions() -> Result<()> {
+        let dir = tempdir()?;
+        let cfg_dir = dir.path().join(CONFIG_DIR);
+        fs::create_dir_all(&cfg_dir)?;
+        fs::write(
+            cfg_dir.join(CONFIG_FILE),
+            r#"
+[workflow]
+states = [{ value = "state/todo" }, { value = "state/in-review" }, { value = "state/done" }]
+
+[[workflow.transitions]]
+from = "state/todo"
+to = "state/in-review"
+require_assignee = true
+
+[[workflow.transitions]]
+from = "state/in-review"
+to = "state/done"
+require_labels = ["approved"]
+
+[[workflow.transitions]]
+to = "state/todo"
+"#,
+        )?;
+
+        let workflow = ProjectConfig::from_workdir(dir.path())?.workflow;
+        assert_eq!(workflow.transitions().len(), 3);
+
+        let none = BTreeSet::new();
+        let alice = BTreeSet::from(["alice".to_owned()]);
+        let approved = BTreeSet::from(["approved".to_owned()]);
+        assert_eq!(
+            workflow.check_transition(Some("state/todo"), "state/done", &none, &alice),
+            Err(TransitionViolation::NotAllowed {
+                from: Some("state/todo".into()),
+                to: "state/done".into(),
+            })
+        );
+        assert_eq!(
+            workflow.check_transition(Some("state/todo"), "state/in-review", &none, &none),
+            Err(TransitionViolation::MissingAssignee {
+                to: "state/in-review".into()
+            })
+        );
+        assert!(
+            workflow
+                .check_transition(Some("state/todo"), "state/in-review", &none, &alice)
+                .is_ok()
+        );
+        assert!(matches!(
+            workflow.check_transition(Some("state/in-review"), "state/done", &none, &alice),
+            Err(TransitionViolation::MissingLabel { .. })
+        ));
+        assert!(
+            workflow
+                .check_transition(Some("state/in-review"), "state/done", &approved, &none)
+                .is_ok()
+        );
+        assert!(
+            workflow
+                .check_transition(None, "state/todo", &none, &none)
+                .is_ok()
+        );
+        assert!(
+            workflow
+                .check_transition(Some("state/done"), "state/done", &none, &none)
+                .is_ok()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn transitions_must_reference_defined_states() -> Result<()> {
+        let dir = tempdir()?;
+        let cfg_dir = dir.path().join(CONFIG_DIR);
+        fs::create_dir_all(&cfg_dir)?;
+        fs::write(
+            cfg_dir.join(CONFIG_FILE),
+            "[workflow]\nstates = [{ value = \"state/todo\" }]\n\n[[workflow.transitions]]\nto = \"state/done\"\n",
+        )?;
+
+        let Err(err) = ProjectConfig::from_workdir(dir.path()) else {
+            panic!("undefined transition state should error");
+        };
+        assert!(err.to_string().contains("undefined state 'state/done'"));
+        Ok(())
+    }
+
+    #[test]
+    fn load_config_with_views() -> Result<()> {
+        let dir = tempdir()?;
+        let cfg_dir = dir.path().join(CONFIG_DIR);
+        fs::create_dir_all(&cfg_dir)?;
+        fs::write(
+            cfg_dir.join(CONFIG_FILE),
+            "[views.my-open]\ndescription = \"Mine\"\nsort = \"title\"\ncolumns = [\"id\", \"title\"]\n\n\
+             [views.my-open.filter]\nstates = [\"state/todo\"]\nassignees = [\"alice\"]\nquery = \"-label:wontfix\"\n\n\
+             [views.everything]\n",
+        )?;
+
+        let cfg = ProjectConfig::from_workdir(dir.path())?;
+        assert_eq!(cfg.views.keys().collect::<Vec<_>>(), ["everything", "my-open"]);
+        let view = cfg.view("my-open")?;
+        assert_eq!(view.description.as_deref(), Some("Mine"));
+        assert_eq!(view.sort, ViewSort::Title);
+        assert_eq!(view.columns, [ViewColumn::Id, ViewColumn::Title]);
+        assert!(view.filter.states.contains("state/todo"));
+        assert!(view.filter.query.is_some());
+        assert_eq!(cfg.view("everything")?, &SavedView::default());
+
+        let Err(err) = cfg.view("missing") else {
+            panic!("unknown view should error");
+        };
+        assert!(err.to_string().contains("everything, my-open"));
+        Ok(())
+    }
+
+    #[test]
+    fn views_must_reference_defined_states() -> Result<()> {
+        let dir = tempdir()?;
+        let cfg_dir = dir.path().join(CONFIG_DIR);
+        fs::create_dir_all(&cfg_dir)?;
+        fs::write(
+            cfg_dir.join(CONFIG_FILE),
+            "[views.stale.filter]\nstates = [\"state/archived\"]\n",
+        )?;
+
+        let Err(err) = ProjectConfig::from_workdir(dir.path()) else {
+            panic!("undefined view state should error");
+        };
+        assert!(
+            err.to_string()
+                .contains("view 'stale' references undefined workflow state 'state/archived'")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn view_sort_orders_snapshots() {
+        let mut tasks: Vec<TaskSnapshot> = ["beta", "Alpha", "gamma"]
+            .into_iter()
+            .map(|title| {
+                let mut snapshot = TaskSnapshot::default();
+                snapshot.title = title.into();
+                snapshot
+            })
+            .collect();
+        ViewSort::Title.sort(&WorkflowConfig::unrestricted(), &mut tasks);
+        let titles: Vec<_> = tasks.iter().map(|task| task.title.as_str()).collect();
+        assert_eq!(titles, ["Alpha", "beta", "gamma"]);
+
+        for (task, rank) in tasks.iter_mut().zip([None, Some("k"), Some("F")]) {
+            task.rank = rank.map(str::to_owned);
+        }
+        ViewSort::Rank.sort(&WorkflowConfig::unrestricted(), &mut tasks);
+        let titles: Vec<_> = tasks.iter().map(|task| task.title.as_str()).collect();
+        assert_eq!(titles, ["gamma", "beta", "Alpha"]);
+    }
+
+    #[test]
+    fn load_config_with_fields() -> Result<()> {
+        let dir = tempdir()?;
+        let cfg_dir = dir.path().join(CONFIG_DIR);
+        fs::create_dir_all(&cfg_dir)?;
+        fs::write(
+            cfg_dir.join(CONFIG_FILE),
+            "[workflow.fields.priority]\ntype = \"enum\"\nvalues = [\"high\", \"medium\", \"low\"]\n\n\
+             [workflow.fields.due]\ntype = \"date\"\n\n\
+             [workflow.fields.estimate]\ntype = \"number\"\n\n\
+             [views.urgent]\nsort = { field = \"priority\" }\n",
+        )?;
+
+        let cfg = ProjectConfig::from_workdir(dir.path())?;
+        let workflow = &cfg.workflow;
+        assert_eq!(workflow.fields().len(), 3);
+        assert_eq!(
+            workflow.normalize_field("priority", "HIGH"),
+            Ok("high".to_owned())
+        );
+        assert_eq!(
+            workflow.normalize_field("due", "2025-1-5"),
+            Ok("2025-01-05".to_owned())
+        );
+        assert_eq!(workflow.normalize_field("estimate", "3.50"), Ok("3.5".to_owned()));
+        assert_eq!(
+            workflow.normalize_field("estimate", "1.5e3"),
+            Ok("1500".to_owned())
+        );
+        assert_eq!(
+            workflow.normalize_field("estimate", "-0.010"),
+            Ok("-0.01".to_owned())
+        );
+        assert_eq!(
+            workflow.normalize_field("estimate", "9007199254740992"),
+            Ok("9007199254740992".to_owned())
+        );
+        // Past 2^53 an f64 would silently store a different integer.
+        assert!(
+            workflow
+                .normalize_field("estimate", "12345678901234567890")
+                .is_err()
+        );
+        assert!(workflow.normalize_field("estimate", "9007199254740993").is_err());
+        assert!(matches!(
+            workflow.normalize_field("priority", "urgent"),
+            Err(FieldViolation::InvalidValue { expected, .. }) if expected == "one of high, medium, low"
+        ));
+        assert!(workflow.normalize_field("due", "2025-02-30").is_err());
+        assert!(matches!(
+            workflow.normalize_field("owner", "alice"),
+            Err(FieldViolation::Unknown { .. })
+        ));
+        assert_eq!(cfg.view("urgent")?.sort, ViewSort::Field("priority".into()));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_field_declarations_are_rejected() -> Result<()> {
+        let dir = tempdir()?;
+        let cfg_dir = dir.path().join(CONFIG_DIR);
+        fs::create_dir_all(&cfg_dir)?;
+        for (contents, expected) in [
+            (
+                "[workflow.fields.priority]\ntype = \"enum\"\nvalues = []\n",
+                "must list at least one value",
+            ),
+            (
+                "[workflow.fields.\"due date\"]\ntype = \"date\"\n",
+                "may only contain",
+            ),
+            (
+                "[views.by-due]\nsort = { field-desc = \"due\" }\n",
+                "undeclared field 'due'",
+            ),
+        ] {
+            fs::write(cfg_dir.join(CONFIG_FILE), contents)?;
+            let Err(err) = ProjectConfig::from_workdir(dir.path()) else {
+                panic!("invalid field config should error: {contents}");
+            };
+            assert!(format!("{err:#}").contains(expected), "{err:#}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn view_sort_orders_custom_fields_with_missing_values_last() {
+        let workflow = WorkflowConfig::unrestricted().with_fields(BTreeMap::from([(
+            "priority".to_owned(),
+            FieldDefinition::Enum {
+                values: vec!["high".into(), "medium".into(), "low".into()],
+            },
+        )]));
+        let mut tasks: Vec<TaskSnapshot> = [Some("low"), None, Some("high"), Some("medium")]
+            .into_iter()
+            .map(|priority| {
+                let mut snapshot = TaskSnapshot::default();
+                snapshot.title = priority.unwrap_or("none").into();
+                if let Some(priority) = priority {
+                    snapshot.fields.insert("priority".into(), priority.into());
+                }
+                snapshot
+            })
+            .collect();
+        let titles = |tasks: &[TaskSnapshot]| tasks.iter().map(|task| task.title.clone()).collect::<Vec<_>>();
+
+        ViewSort::Field("priority".into()).sort(&workflow, &mut tasks);
+        assert_eq!(titles(&tasks), ["high", "medium", "low", "none"]);
+        ViewSort::FieldDesc("priority".into()).sort(&workflow, &mut tasks);
+        assert_eq!(titles(&tasks), ["low", "medium", "high", "none"]);
+    }
 }
@@ -1,11 +1,16 @@
+use std::collections::BTreeMap;
 use std::fmt::{self, Display};
 
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{MilestoneId, TaskId};
 use git_mile_core::{
-    FilterValidationError, StateKind, TaskFilter, TaskFilterBuilder as CoreTaskFilterBuilder, UpdatedFilter,
+    FilterValidationError, QueryParseError, StateKind, TaskFilter,
+    TaskFilterBuilder as CoreTaskFilterBuilder, TaskQuery, UpdatedFilter, parse_age,
 };
 use thiserror::Error;
-use time::{OffsetDateTime, UtcOffset, format_description::well_known::Rfc3339};
+use time::format_description::{self, well_known::Rfc3339};
+use time::{Date, OffsetDateTime, UtcOffset};
+
+use crate::config::{FieldViolation, WorkflowConfig};
 
 /// Error type returned while constructing task filters from user-facing inputs.
 #[derive(Debug, Error)]
@@ -20,6 +25,16 @@ pub enum FilterBuildError {
     },
     #[error("{message}")]
     InvalidTextQuery { message: String },
+    #[error("invalid query: {source}")]
+    InvalidQuery {
+        #[source]
+        source: QueryParseError,
+    },
+    #[error("invalid field filter: {source}")]
+    InvalidField {
+        #[source]
+        source: FieldViolation,
+    },
 }
 
 /// Result alias for filter construction helpers.
@@ -38,6 +53,11 @@ pub struct TaskFilterBuilder {
     text: Option<String>,
     updated_since: Option<OffsetDateTime>,
     updated_until: Option<OffsetDateTime>,
+    include_archived: bool,
+    query: Option<TaskQuery>,
+    fields: BTreeMap<String, String>,
+    milestones: Vec<MilestoneId>,
+    open_checklist: bool,
 }
 
 impl TaskFilterBuilder {
@@ -82,6 +102,13 @@ impl TaskFilterBuilder {
         self
     }
 
+    /// Add milestone filters (logical OR).
+    #[must_use]
+    pub fn with_milestones(mut self, milestones: &[MilestoneId]) -> Self {
+        self.milestones.extend(milestones.iter().copied());
+        self
+    }
+
     /// Configure state kind include/exclude clauses.
     ///
     /// # Errors
@@ -128,6 +155,51 @@ impl TaskFilterBuilder {
         self
     }
 
+    /// Configure the query expression (whitespace-only inputs clear it).
+    ///
+    /// # Errors
+    /// Returns an error if the query does not parse.
+    pub fn with_query(mut self, query: Option<&str>) -> FilterBuildResult<Self> {
+        self.query = match query.map(str::trim) {
+            Some(raw) if !raw.is_empty() => {
+                Some(TaskQuery::parse(raw).map_err(|source| FilterBuildError::InvalidQuery { source })?)
+            }
+            _ => None,
+        };
+        Ok(self)
+    }
+
+    /// Require custom fields to hold the given values.
+    ///
+    /// Values are compared with the canonical values stored on tasks, so pass them through
+    /// [`normalize_field_filters`] first.
+    #[must_use]
+    pub fn with_fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        self.fields.extend(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned())),
+        );
+        self
+    }
+
+    /// Only match tasks whose description checklist still has unticked items.
+    #[must_use]
+    pub const fn with_open_checklist(mut self, open: bool) -> Self {
+        self.open_checklist = open;
+        self
+    }
+
+    /// Control whether archived tasks are included in the results.
+    #[must_use]
+    pub const fn with_archived(mut self, include: bool) -> Self {
+        self.include_archived = include;
+        self
+    }
+
     /// Build the final [`TaskFilter`].
     ///
     /// # Errors
@@ -139,13 +211,24 @@ impl TaskFilterBuilder {
             .assignees(self.assignees)
             .parents(self.parents)
             .children(self.children)
+            .milestones(self.milestones)
             .include_state_kinds(self.include_state_kinds)
-            .exclude_state_kinds(self.exclude_state_kinds);
+            .exclude_state_kinds(self.exclude_state_kinds)
+            .include_archived(self.include_archived)
+            .open_checklist(self.open_checklist);
 
         if let Some(text) = self.text {
             builder = builder.text(text);
         }
 
+        if let Some(query) = self.query {
+            builder = builder.query(query);
+        }
+
+        for (name, value) in self.fields {
+            builder = builder.field(name, value);
+        }
+
         if self.updated_since.is_some() || self.updated_until.is_some() {
             builder = builder.updated(UpdatedFilter {
                 since: self.updated_since,
@@ -190,6 +273,22 @@ pub fn parse_timestamp(s: &str) -> Result<OffsetDateTime, time::error::Parse> {
     OffsetDateTime::parse(s.trim(), &Rfc3339)
 }
 
+/// Parse a point in time given as an RFC3339 timestamp, a `YYYY-MM-DD` date
+/// (midnight UTC), or an age such as `1d` counted back from `now`.
+#[must_use]
+pub fn parse_time_bound(s: &str, now: OffsetDateTime) -> Option<OffsetDateTime> {
+    let trimmed = s.trim();
+    if let Some(age) = parse_age(trimmed) {
+        return now.checked_sub(age);
+    }
+    if let Ok(format) = format_description::parse("[year]-[month]-[day]")
+        && let Ok(day) = Date::parse(trimmed, &format)
+    {
+        return Some(day.midnight().assume_utc());
+    }
+    parse_timestamp(trimmed).ok().map(normalize_timestamp)
+}
+
 /// Normalize timestamps to UTC to avoid offset mismatches across interfaces.
 #[must_use]
 pub const fn normalize_timestamp(dt: OffsetDateTime) -> OffsetDateTime {
@@ -212,6 +311,33 @@ fn parse_optional_timestamp(
     Ok(Some(normalize_timestamp(parsed)))
 }
 
+/// Check custom field filters against `workflow` and return their canonical values.
+///
+/// Stored field values are normalized on write (e.g. dates and enum options), so raw
+/// filter input would otherwise miss matching tasks.
+///
+/// # Errors
+/// Returns [`FilterBuildError::InvalidField`] when a field is not declared or a value does
+/// not fit its type.
+pub fn normalize_field_filters<I>(
+    workflow: &WorkflowConfig,
+    fields: I,
+) -> FilterBuildResult<Vec<(String, String)>>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    fields
+        .into_iter()
+        .map(|(name, value)| {
+            let name = name.trim().to_owned();
+            let value = workflow
+                .normalize_field(&name, value.trim())
+                .map_err(|source| FilterBuildError::InvalidField { source })?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
 impl FilterBuildError {
     /// Convert the error into a message that is friendly for end-users.
     #[must_use]
@@ -222,6 +348,8 @@ impl FilterBuildError {
                 format!("{field} の時刻フォーマットが不正です (RFC3339 必須)")
             }
             Self::InvalidTextQuery { message } => format!("テキストフィルターが不正です: {message}"),
+            Self::InvalidQuery { source } => format!("クエリが不正です: {source}"),
+            Self::InvalidField { source } => format!("フィールドの指定が不正です: {source}"),
         }
     }
 }
@@ -252,6 +380,7 @@ impl Display for TaskFilterBuilder {
             .field("text", &self.text)
             .field("updated_since", &self.updated_since)
             .field("updated_until", &self.updated_until)
+            .field("query", &self.query.as_ref().map(TaskQuery::as_str))
             .finish()
     }
 }
@@ -360,4 +489,62 @@ mod tests {
         assert_eq!(updated.since, Some(datetime("2025-01-01T00:00:00Z")));
         assert_eq!(updated.until, Some(datetime("2025-01-02T00:00:00Z")));
     }
+
+    #[test]
+    fn field_filters_are_normalized_and_must_be_declared() {
+        let workflow = WorkflowConfig::unrestricted().with_fields(BTreeMap::from([
+            ("due".to_owned(), crate::config::FieldDefinition::Date),
+            ("estimate".to_owned(), crate::config::FieldDefinition::Number),
+        ]));
+        let fields = ok(
+            normalize_field_filters(
+                &workflow,
+                [
+                    (" due ".to_owned(), "2025-1-5".to_owned()),
+                    ("estimate".to_owned(), "3.50".to_owned()),
+                ],
+            ),
+            "declared fields",
+        );
+        assert_eq!(
+            fields,
+            [
+                ("due".to_owned(), "2025-01-05".to_owned()),
+                ("estimate".to_owned(), "3.5".to_owned())
+            ]
+        );
+
+        let unknown = normalize_field_filters(&workflow, [("priority".to_owned(), "high".to_owned())]);
+        assert!(matches!(
+            unknown,
+            Err(FilterBuildError::InvalidField {
+                source: FieldViolation::Unknown { .. }
+            })
+        ));
+        let invalid = normalize_field_filters(&workflow, [("due".to_owned(), "soon".to_owned())]);
+        assert!(matches!(
+            invalid,
+            Err(FilterBuildError::InvalidField {
+                source: FieldViolation::InvalidValue { .. }
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_time_bound_accepts_ages_dates_and_timestamps() {
+        let now = datetime("2025-03-10T12:00:00Z");
+        assert_eq!(
+            parse_time_bound("1d", now),
+            Some(datetime("2025-03-09T12:00:00Z"))
+        );
+        assert_eq!(
+            parse_time_bound("2025-03-01", now),
+            Some(datetime("2025-03-01T00:00:00Z"))
+        );
+        assert_eq!(
+            parse_time_bound("2025-03-01T09:00:00+09:00", now),
+            Some(datetime("2025-03-01T00:00:00Z"))
+        );
+        assert_eq!(parse_time_bound("yesterday", now), None);
+    }
 }
@@ -9,12 +9,18 @@ pub mod actor;
 pub mod async_store;
 pub mod config;
 pub mod filter_util;
+pub mod milestone;
+pub mod ready_work;
+pub mod rollup;
 pub mod service;
 pub mod task_cache;
+pub mod task_history;
 pub mod task_log;
 pub mod task_patch;
 pub mod task_repository;
+pub mod task_revert;
 pub mod task_writer;
+mod view_index;
 
 // Re-exports for convenience
 pub use actor::{
@@ -22,14 +28,29 @@ pub use actor::{
     actor_from_git_config, actor_from_params_or_default, default_actor,
 };
 pub use async_store::{AsyncTaskRepository, AsyncTaskStore};
-pub use config::{HooksConfig, ProjectConfig, StateKind, WorkflowConfig, WorkflowState};
-pub use filter_util::{FilterBuildError, TaskFilterBuilder, normalize_timestamp, parse_timestamp};
+pub use config::{
+    FieldDefinition, FieldViolation, HooksConfig, ProjectConfig, SavedView, StateKind, TransitionViolation,
+    ViewColumn, ViewSort, WorkflowConfig, WorkflowState, WorkflowTransition,
+};
+pub use filter_util::{
+    FilterBuildError, TaskFilterBuilder, normalize_field_filters, normalize_timestamp, parse_time_bound,
+    parse_timestamp,
+};
+pub use milestone::{
+    CreateMilestoneInput, MilestoneService, MilestoneStore, MilestoneUpdate, MilestoneView, normalize_due,
+};
+pub use ready_work::ReadyWork;
+pub use rollup::{BlockedDescendant, ESTIMATE_FIELD, TaskRollup};
 pub use service::{CommentInput, CommentOutput, CreateTaskInput, CreateTaskOutput, ParentLink, TaskService};
 pub use task_cache::{TaskCache, TaskComment, TaskView};
-pub use task_log::ordered_events;
-pub use task_patch::{DescriptionPatch, SetDiff, StatePatch, TaskEditData, TaskPatch, TaskUpdate, diff_sets};
+pub use task_history::{BlameEntry, BlameField, HistoryPoint, blame, events_until};
+pub use task_log::{ActivityFeed, ActivityFilter, ordered_events};
+pub use task_patch::{
+    DescriptionPatch, MilestonePatch, SetDiff, StatePatch, TaskEditData, TaskPatch, TaskUpdate, diff_sets,
+};
 pub use task_repository::TaskRepository;
+pub use task_revert::{Revert, compensation, latest_event_by};
 pub use task_writer::{
-    CommentRequest, CreateTaskRequest, CreateTaskResult, ParentLinkResult, TaskStore, TaskWriteError,
-    TaskWriteResult, TaskWriter,
+    BulkUpdateOutcome, CommentRequest, CreateTaskRequest, CreateTaskResult, ParentLinkResult, QueuePosition,
+    TaskStore, TaskWriteError, TaskWriteResult, TaskWriter,
 };
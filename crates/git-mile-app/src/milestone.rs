@@ -0,0 +1,450 @@
+//! Milestone storage and mutations shared by CLI and MCP surfaces.
+
+use anyhow::{Context, Error, Result, anyhow, bail};
+use git_mile_core::StateKindCounts;
+use git_mile_core::TaskSnapshot;
+use git_mile_core::event::Actor;
+use git_mile_core::id::{MIN_TASK_ID_PREFIX, MilestoneId};
+use git_mile_core::milestone::{self, MilestoneEvent, MilestoneEventKind, MilestoneSnapshot};
+use git_mile_store_git::GitStore;
+use git2::Oid;
+use serde::Serialize;
+use std::str::FromStr;
+use tokio::sync::MutexGuard;
+
+use crate::config::FieldDefinition;
+
+/// Storage abstraction for milestone histories, kept apart from [`TaskStore`](crate::TaskStore).
+pub trait MilestoneStore {
+    /// Error type bubbled up from the backing store.
+    type Error: Into<Error>;
+
+    /// Append events for one milestone as a single unit.
+    ///
+    /// # Errors
+    /// Returns a store-specific error when persisting the events fails.
+    fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid, Self::Error>;
+
+    /// Load every event for the given milestone.
+    ///
+    /// # Errors
+    /// Returns a store-specific error when the milestone cannot be read.
+    fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>, Self::Error>;
+
+    /// Enumerate all known milestone identifiers.
+    ///
+    /// # Errors
+    /// Returns a store-specific error when listing fails.
+    fn list_milestones(&self) -> Result<Vec<MilestoneId>, Self::Error>;
+}
+
+impl MilestoneStore for GitStore {
+    type Error = Error;
+
+    fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid, Self::Error> {
+        Self::append_milestone_events(self, events)
+    }
+
+    fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>, Self::Error> {
+        Self::load_milestone_events(self, milestone)
+    }
+
+    fn list_milestones(&self) -> Result<Vec<MilestoneId>, Self::Error> {
+        Self::list_milestones(self)
+    }
+}
+
+impl<S> MilestoneStore for &S
+where
+    S: MilestoneStore + ?Sized,
+{
+    type Error = S::Error;
+
+    fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid, Self::Error> {
+        (*self).append_milestone_events(events)
+    }
+
+    fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>, Self::Error> {
+        (*self).load_milestone_events(milestone)
+    }
+
+    fn list_milestones(&self) -> Result<Vec<MilestoneId>, Self::Error> {
+        (*self).list_milestones()
+    }
+}
+
+impl MilestoneStore for MutexGuard<'_, GitStore> {
+    type Error = Error;
+
+    fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid, Self::Error> {
+        (**self).append_milestone_events(events)
+    }
+
+    fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>, Self::Error> {
+        (**self).load_milestone_events(milestone)
+    }
+
+    fn list_milestones(&self) -> Result<Vec<MilestoneId>, Self::Error> {
+        (**self).list_milestones()
+    }
+}
+
+impl<S> MilestoneStore for std::sync::Arc<S>
+where
+    S: MilestoneStore + ?Sized,
+{
+    type Error = S::Error;
+
+    fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid, Self::Error> {
+        (**self).append_milestone_events(events)
+    }
+
+    fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>, Self::Error> {
+        (**self).load_milestone_events(milestone)
+    }
+
+    fn list_milestones(&self) -> Result<Vec<MilestoneId>, Self::Error> {
+        (**self).list_milestones()
+    }
+}
+
+/// Input for creating a milestone.
+#[derive(Debug, Clone)]
+pub struct CreateMilestoneInput {
+    /// Human-readable title.
+    pub title: String,
+    /// Target date; any `YYYY-MM-DD` spelling accepted by date fields.
+    pub due: Option<String>,
+    /// Optional description in Markdown.
+    pub description: Option<String>,
+    /// Actor recorded on the event.
+    pub actor: Actor,
+}
+
+/// Changes applied to an existing milestone; `None` leaves a property untouched.
+#[derive(Debug, Clone, Default)]
+pub struct MilestoneUpdate {
+    /// New title.
+    pub title: Option<String>,
+    /// New target date, or `Some(None)` to clear it.
+    pub due: Option<Option<String>>,
+    /// New description, or `Some(None)` to clear it.
+    pub description: Option<Option<String>>,
+    /// Close (`true`) or reopen (`false`) the milestone.
+    pub closed: Option<bool>,
+}
+
+/// A milestone together with the progress of its member tasks.
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneView {
+    /// Materialized milestone.
+    #[serde(flatten)]
+    pub milestone: MilestoneSnapshot,
+    /// Member task counts by state kind.
+    pub progress: StateKindCounts,
+    /// Share of member tasks that are done, in percent.
+    pub percent_done: usize,
+}
+
+impl MilestoneView {
+    /// Attach progress computed from `tasks` to `milestone`.
+    pub fn new<'a>(milestone: MilestoneSnapshot, tasks: impl IntoIterator<Item = &'a TaskSnapshot>) -> Self {
+        let progress = milestone::progress(milestone.id, tasks);
+        Self {
+            milestone,
+            percent_done: progress.percent_done(),
+            progress,
+        }
+    }
+}
+
+/// Canonical `YYYY-MM-DD` form of a milestone target date.
+///
+/// # Errors
+/// Returns an error when `raw` is not a calendar date.
+pub fn normalize_due(raw: &str) -> Result<String> {
+    FieldDefinition::Date
+        .normalize(raw)
+        .ok_or_else(|| anyhow!("Invalid target date '{raw}': expected YYYY-MM-DD"))
+}
+
+/// Reads and writes milestones through a [`MilestoneStore`].
+pub struct MilestoneService<S> {
+    store: S,
+}
+
+impl<S: MilestoneStore> MilestoneService<S> {
+    /// Wrap `store`.
+    pub const fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// Record a new milestone.
+    ///
+    /// # Errors
+    /// Returns an error when the title is blank, the date is invalid, or storage fails.
+    pub fn create(&self, input: CreateMilestoneInput) -> Result<MilestoneSnapshot> {
+        let title = input.title.trim();
+        if title.is_empty() {
+            bail!("Milestone title must not be empty");
+        }
+        let due = input.due.as_deref().map(normalize_due).transpose()?;
+        let id = MilestoneId::new();
+        let mut event = MilestoneEvent::new(
+            id,
+            &input.actor,
+            MilestoneEventKind::MilestoneCreated {
+                title: title.to_owned(),
+                due,
+                description: input.description.filter(|text| !text.trim().is_empty()),
+            },
+        );
+        event.lamport = 1;
+        self.store
+            .append_milestone_events(std::slice::from_ref(&event))
+            .map_err(Into::into)?;
+        Ok(MilestoneSnapshot::replay(&[event]))
+    }
+
+    /// Apply `update` to a milestone, recording only what actually changes.
+    ///
+    /// # Errors
+    /// Returns an error when the milestone is missing, a value is invalid, or storage fails.
+    pub fn update(
+        &self,
+        id: MilestoneId,
+        update: MilestoneUpdate,
+        actor: &Actor,
+    ) -> Result<MilestoneSnapshot> {
+        let mut history = self.events(id)?;
+        let current = MilestoneSnapshot::replay(&history);
+
+        let mut kinds = Vec::new();
+        if let Some(title) = update.title {
+            let title = title.trim();
+            if title.is_empty() {
+                bail!("Milestone title must not be empty");
+            }
+            if title != current.title {
+                kinds.push(MilestoneEventKind::MilestoneTitleSet {
+                    title: title.to_owned(),
+                });
+            }
+        }
+        if let Some(due) = update.due {
+            let due = due.as_deref().map(normalize_due).transpose()?;
+            if due != current.due {
+                kinds.push(MilestoneEventKind::MilestoneDueSet { due });
+            }
+        }
+        if let Some(description) = update.description {
+            let description = description.filter(|text| !text.trim().is_empty());
+            if description.as_deref().unwrap_or_default() != current.description {
+                kinds.push(MilestoneEventKind::MilestoneDescriptionSet { description });
+            }
+        }
+        match update.closed {
+            Some(true) if !current.closed => kinds.push(MilestoneEventKind::MilestoneClosed),
+            Some(false) if current.closed => kinds.push(MilestoneEventKind::MilestoneReopened),
+            _ => {}
+        }
+        if kinds.is_empty() {
+            return Ok(current);
+        }
+
+        let lamport = history.iter().map(|event| event.lamport).max().unwrap_or(0) + 1;
+        let mut events: Vec<MilestoneEvent> = kinds
+            .into_iter()
+            .map(|kind| MilestoneEvent::new(id, actor, kind))
+            .collect();
+        let ts = events[0].ts;
+        for event in &mut events {
+            event.lamport = lamport;
+            event.ts = ts;
+        }
+        self.store.append_milestone_events(&events).map_err(Into::into)?;
+        history.extend(events);
+        Ok(MilestoneSnapshot::replay(&history))
+    }
+
+    /// Materialize one milestone.
+    ///
+    /// # Errors
+    /// Returns an error when the milestone is missing or cannot be read.
+    pub fn get(&self, id: MilestoneId) -> Result<MilestoneSnapshot> {
+        Ok(MilestoneSnapshot::replay(&self.events(id)?))
+    }
+
+    /// Materialize every milestone, soonest target date first.
+    ///
+    /// # Errors
+    /// Returns an error when listing or reading milestones fails.
+    pub fn list(&self) -> Result<Vec<MilestoneSnapshot>> {
+        let mut milestones = self
+            .store
+            .list_milestones()
+            .map_err(Into::into)?
+            .into_iter()
+            .map(|id| self.get(id))
+            .collect::<Result<Vec<_>>>()?;
+        milestones.sort_by(MilestoneSnapshot::schedule_order);
+        Ok(milestones)
+    }
+
+    /// Resolve a full or abbreviated milestone id against the stored milestones.
+    ///
+    /// # Errors
+    /// Returns an error when no milestone, or more than one, matches `input`.
+    pub fn resolve(&self, input: &str) -> Result<MilestoneId> {
+        let input = input.trim();
+        let known = self.store.list_milestones().map_err(Into::into)?;
+        if let Ok(id) = MilestoneId::from_str(input) {
+            if known.contains(&id) {
+                return Ok(id);
+            }
+            bail!("Milestone not found: {id}");
+        }
+        if input.chars().filter(|ch| *ch != '-').count() < MIN_TASK_ID_PREFIX {
+            bail!(
+                "Milestone id prefix '{input}' is too short (use at least {MIN_TASK_ID_PREFIX} hex digits)"
+            );
+        }
+        let matches: Vec<MilestoneId> = known.into_iter().filter(|id| id.matches_prefix(input)).collect();
+        match matches.as_slice() {
+            [] => bail!("No milestone matches id prefix '{input}'"),
+            [id] => Ok(*id),
+            _ => bail!(
+                "Milestone id prefix '{input}' is ambiguous; it matches {}",
+                matches
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    fn events(&self, id: MilestoneId) -> Result<Vec<MilestoneEvent>> {
+        self.store
+            .load_milestone_events(id)
+            .map_err(Into::into)
+            .with_context(|| format!("Milestone not found: {id}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    #[derive(Default)]
+    struct MemoryStore {
+        histories: RefCell<BTreeMap<MilestoneId, Vec<MilestoneEvent>>>,
+    }
+
+    impl MilestoneStore for MemoryStore {
+        type Error = Error;
+
+        fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid> {
+            for event in events {
+                self.histories
+                    .borrow_mut()
+                    .entry(event.milestone)
+                    .or_default()
+                    .push(event.clone());
+            }
+            Ok(Oid::zero())
+        }
+
+        fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>> {
+            self.histories
+                .borrow()
+                .get(&milestone)
+                .cloned()
+                .ok_or_else(|| anyhow!("missing milestone {milestone}"))
+        }
+
+        fn list_milestones(&self) -> Result<Vec<MilestoneId>> {
+            Ok(self.histories.borrow().keys().copied().collect())
+        }
+    }
+
+    fn actor() -> Actor {
+        Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        }
+    }
+
+    fn create(service: &MilestoneService<MemoryStore>, title: &str, due: Option<&str>) -> MilestoneSnapshot {
+        service
+            .create(CreateMilestoneInput {
+                title: title.into(),
+                due: due.map(Into::into),
+                description: None,
+                actor: actor(),
+            })
+            .unwrap_or_else(|e| panic!("create milestone: {e}"))
+    }
+
+    #[test]
+    fn create_update_and_list_milestones() {
+        let service = MilestoneService::new(MemoryStore::default());
+        let later = create(&service, "v2.0", None);
+        let sooner = create(&service, "v1.0", Some("2025-6-30"));
+        assert_eq!(sooner.due.as_deref(), Some("2025-06-30"));
+
+        let updated = service
+            .update(
+                sooner.id,
+                MilestoneUpdate {
+                    title: Some("v1.0".into()),
+                    closed: Some(true),
+                    ..MilestoneUpdate::default()
+                },
+                &actor(),
+            )
+            .unwrap_or_else(|e| panic!("update milestone: {e}"));
+        assert!(updated.closed);
+        assert_eq!(
+            service.store.histories.borrow()[&sooner.id].len(),
+            2,
+            "unchanged title is not recorded"
+        );
+
+        let listed: Vec<MilestoneId> = service
+            .list()
+            .unwrap_or_else(|e| panic!("list milestones: {e}"))
+            .iter()
+            .map(|milestone| milestone.id)
+            .collect();
+        assert_eq!(listed, vec![sooner.id, later.id]);
+
+        assert!(
+            service
+                .create(CreateMilestoneInput {
+                    title: "bad".into(),
+                    due: Some("next week".into()),
+                    description: None,
+                    actor: actor(),
+                })
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn resolve_accepts_unique_prefixes() {
+        let service = MilestoneService::new(MemoryStore::default());
+        let milestone = create(&service, "v1.0", None);
+
+        assert_eq!(
+            service.resolve(&milestone.id.to_string()).ok(),
+            Some(milestone.id)
+        );
+        let prefix = milestone.id.0.simple().to_string();
+        assert_eq!(service.resolve(&prefix[..20]).ok(), Some(milestone.id));
+        assert!(service.resolve(&MilestoneId::new().to_string()).is_err());
+        assert!(service.resolve("01").is_err());
+    }
+}
@@ -0,0 +1,274 @@
+//! Dependency-aware "what can I start now" query over the task cache.
+
+use std::collections::{BTreeSet, HashMap};
+
+use git_mile_core::id::TaskId;
+use git_mile_core::{RelationKind, StateKind, TaskSnapshot};
+use serde::Serialize;
+
+use crate::task_cache::TaskCache;
+
+/// Tasks that can be started right now, plus any dependency cycles found on the way.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReadyWork {
+    /// Open tasks whose blockers and children are all done or archived, in last-updated order.
+    pub ready: Vec<TaskSnapshot>,
+    /// Dependency cycles among open tasks. Each chain lists the tasks in waiting order; the last entry
+    /// waits on the first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cycles: Vec<Vec<TaskId>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+impl TaskCache {
+    /// Compute the tasks that are ready to start.
+    ///
+    /// A task is ready when it is neither done nor archived and every task blocking it, as well as
+    /// every child task, has reached [`StateKind::Done`] or been archived. Blockers come from both `blocked-by` on the
+    /// task and `blocks` on other tasks, so one-sided relations written by older clients still count.
+    /// Dependencies that are not in the cache are ignored.
+    #[must_use]
+    pub fn ready_work(&self) -> ReadyWork {
+        let dependencies = self.open_dependencies();
+
+        let ready = self
+            .tasks
+            .iter()
+            .map(|view| &view.snapshot)
+            .filter(|snapshot| is_open(snapshot))
+            .filter(|snapshot| dependencies.get(&snapshot.id).is_none_or(BTreeSet::is_empty))
+            .cloned()
+            .collect();
+
+        ReadyWork {
+            ready,
+            cycles: find_cycles(&dependencies),
+        }
+    }
+
+    /// Map each open task to the dependencies it is still waiting on.
+    fn open_dependencies(&self) -> HashMap<TaskId, BTreeSet<TaskId>> {
+        let mut blockers: HashMap<TaskId, BTreeSet<TaskId>> = HashMap::new();
+        for view in &self.tasks {
+            let snapshot = &view.snapshot;
+            if let Some(targets) = snapshot.relates.get(RelationKind::BlockedBy.as_str()) {
+                blockers.entry(snapshot.id).or_default().extend(targets);
+            }
+            if let Some(targets) = snapshot.relates.get(RelationKind::Blocks.as_str()) {
+                for target in targets {
+                    blockers.entry(*target).or_default().insert(snapshot.id);
+                }
+            }
+        }
+
+        self.tasks
+            .iter()
+            .map(|view| &view.snapshot)
+            .filter(|snapshot| is_open(snapshot))
+            .map(|snapshot| {
+                let pending = blockers
+                    .remove(&snapshot.id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(self.children_of(snapshot.id))
+                    .filter(|dependency| *dependency != snapshot.id)
+                    .filter(|dependency| self.is_unfinished(*dependency))
+                    .collect();
+                (snapshot.id, pending)
+            })
+            .collect()
+    }
+
+    fn is_unfinished(&self, task: TaskId) -> bool {
+        self.task_index
+            .get(&task)
+            .and_then(|&idx| self.tasks.get(idx))
+            .is_some_and(|view| is_open(&view.snapshot))
+    }
+}
+
+fn is_open(snapshot: &TaskSnapshot) -> bool {
+    !snapshot.archived && snapshot.state_kind != Some(StateKind::Done)
+}
+
+/// Depth-first search over the waiting graph, returning every cycle closed by a back edge.
+///
+/// The search keeps its own stack so arbitrarily long dependency chains cannot overflow
+/// the thread's stack.
+fn find_cycles(dependencies: &HashMap<TaskId, BTreeSet<TaskId>>) -> Vec<Vec<TaskId>> {
+    let edges = |task: TaskId| dependencies.get(&task).into_iter().flatten();
+
+    let mut roots: Vec<TaskId> = dependencies.keys().copied().collect();
+    roots.sort_unstable();
+
+    let mut state = HashMap::new();
+    let mut cycles = Vec::new();
+    for root in roots {
+        if state.contains_key(&root) {
+            continue;
+        }
+        // `path[i]` is the task whose remaining edges are `pending[i]`.
+        state.insert(root, Visit::InProgress);
+        let mut path = vec![root];
+        let mut pending = vec![edges(root)];
+        while let Some(next) = pending.last_mut().map(Iterator::next) {
+            let Some(&next) = next else {
+                pending.pop();
+                if let Some(task) = path.pop() {
+                    state.insert(task, Visit::Done);
+                }
+                continue;
+            };
+            match state.get(&next) {
+                Some(Visit::InProgress) => {
+                    if let Some(start) = path.iter().position(|id| *id == next) {
+                        cycles.push(path[start..].to_vec());
+                    }
+                }
+                Some(Visit::Done) => {}
+                None => {
+                    state.insert(next, Visit::InProgress);
+                    path.push(next);
+                    pending.push(edges(next));
+                }
+            }
+        }
+    }
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task_cache::TaskView;
+    use git_mile_core::event::{Actor, Event, EventKind};
+
+    fn actor() -> Actor {
+        Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        }
+    }
+
+    fn task(id: TaskId, state_kind: Option<StateKind>, extra: Vec<EventKind>) -> TaskView {
+        let mut events = vec![Event::new(
+            id,
+            &actor(),
+            EventKind::TaskCreated {
+                title: id.to_string(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind,
+            },
+        )];
+        events.extend(extra.into_iter().map(|kind| Event::new(id, &actor(), kind)));
+        TaskView::from_events(&events)
+    }
+
+    fn blocked_by(target: TaskId) -> EventKind {
+        EventKind::RelationAdded {
+            kind: RelationKind::BlockedBy.as_str().into(),
+            target,
+        }
+    }
+
+    fn ready_ids(work: &ReadyWork) -> BTreeSet<TaskId> {
+        work.ready.iter().map(|snapshot| snapshot.id).collect()
+    }
+
+    #[test]
+    fn blockers_and_children_gate_readiness() {
+        let [done, open_blocker, waiting, unblocked, parent, child] = [(); 6].map(|()| TaskId::new());
+        let cache = TaskCache::from_views(vec![
+            task(done, Some(StateKind::Done), vec![]),
+            task(open_blocker, None, vec![]),
+            task(waiting, None, vec![blocked_by(open_blocker)]),
+            task(unblocked, None, vec![blocked_by(done)]),
+            task(parent, None, vec![]),
+            task(child, None, vec![EventKind::ChildLinked { parent, child }]),
+        ]);
+
+        let work = cache.ready_work();
+        assert_eq!(ready_ids(&work), BTreeSet::from([open_blocker, unblocked, child]));
+        assert!(work.cycles.is_empty());
+    }
+
+    #[test]
+    fn archived_dependencies_no_longer_block() {
+        let [blocker, waiting, parent, child] = [(); 4].map(|()| TaskId::new());
+        let cache = TaskCache::from_views(vec![
+            task(blocker, None, vec![EventKind::TaskArchived]),
+            task(waiting, None, vec![blocked_by(blocker)]),
+            task(parent, None, vec![]),
+            task(
+                child,
+                None,
+                vec![EventKind::ChildLinked { parent, child }, EventKind::TaskArchived],
+            ),
+        ]);
+
+        assert_eq!(ready_ids(&cache.ready_work()), BTreeSet::from([waiting, parent]));
+    }
+
+    #[test]
+    fn one_sided_blocks_relation_is_honoured() {
+        let [source, target] = [(); 2].map(|()| TaskId::new());
+        let cache = TaskCache::from_views(vec![
+            task(
+                source,
+                None,
+                vec![EventKind::RelationAdded {
+                    kind: RelationKind::Blocks.as_str().into(),
+                    target,
+                }],
+            ),
+            task(target, None, vec![]),
+        ]);
+
+        assert_eq!(ready_ids(&cache.ready_work()), BTreeSet::from([source]));
+    }
+
+    #[test]
+    fn reports_cycle_chain() {
+        let [a, b, c] = [(); 3].map(|()| TaskId::new());
+        let cache = TaskCache::from_views(vec![
+            task(a, None, vec![blocked_by(b)]),
+            task(b, None, vec![blocked_by(c)]),
+            task(c, None, vec![blocked_by(a)]),
+        ]);
+
+        let work = cache.ready_work();
+        assert!(work.ready.is_empty());
+        assert_eq!(work.cycles.len(), 1);
+        let cycle = &work.cycles[0];
+        assert_eq!(
+            cycle.iter().copied().collect::<BTreeSet<_>>(),
+            BTreeSet::from([a, b, c])
+        );
+        for (idx, id) in cycle.iter().enumerate() {
+            let next = cycle[(idx + 1) % cycle.len()];
+            assert!(cache.open_dependencies()[id].contains(&next));
+        }
+    }
+
+    #[test]
+    fn long_dependency_chain_does_not_overflow_the_stack() {
+        let chain: Vec<TaskId> = (0..100_000).map(|_| TaskId::new()).collect();
+        let mut dependencies: HashMap<TaskId, BTreeSet<TaskId>> = chain
+            .windows(2)
+            .map(|pair| (pair[0], BTreeSet::from([pair[1]])))
+            .collect();
+        dependencies.insert(chain[chain.len() - 1], BTreeSet::from([chain[0]]));
+
+        let cycles = find_cycles(&dependencies);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), chain.len());
+    }
+}
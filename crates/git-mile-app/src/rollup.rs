@@ -0,0 +1,206 @@
+//! Progress of parent tasks rolled up from their subtasks.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use git_mile_core::id::TaskId;
+use git_mile_core::{StateKind, StateKindCounts, TaskSnapshot};
+use serde::Serialize;
+
+use crate::task_cache::TaskCache;
+
+/// Custom field whose numeric values are summed across children.
+pub const ESTIMATE_FIELD: &str = "estimate";
+
+/// Aggregate view of a parent task's subtasks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TaskRollup {
+    /// Direct, non-archived children by state kind.
+    pub children: StateKindCounts,
+    /// Share of those children that are done, in percent.
+    pub percent_done: usize,
+    /// Sum of the children's numeric `estimate` field, when at least one child has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// Blocked descendant furthest down the subtask tree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deepest_blocked: Option<BlockedDescendant>,
+}
+
+/// A blocked task somewhere below a parent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BlockedDescendant {
+    /// The blocked task.
+    pub task_id: TaskId,
+    /// Its title, for display without another lookup.
+    pub title: String,
+    /// Levels below the parent; direct children are at depth 1.
+    pub depth: usize,
+}
+
+impl TaskCache {
+    /// Rollup of `task`'s subtasks, or `None` when it has no non-archived children.
+    #[must_use]
+    pub fn rollup(&self, task: TaskId) -> Option<&TaskRollup> {
+        self.rollups.get(&task)
+    }
+
+    /// Compute the rollup of every task that has non-archived children.
+    pub(crate) fn compute_rollups(&self) -> HashMap<TaskId, TaskRollup> {
+        self.children_index
+            .iter()
+            .filter_map(|(&parent, children)| {
+                let children: Vec<&TaskSnapshot> = children
+                    .iter()
+                    .filter_map(|child| self.snapshot(*child))
+                    .filter(|child| !child.archived)
+                    .collect();
+                if children.is_empty() {
+                    return None;
+                }
+                let mut counts = StateKindCounts::default();
+                let mut estimate = None;
+                for child in &children {
+                    counts.record(child.state_kind);
+                    if let Some(value) = child_estimate(child) {
+                        *estimate.get_or_insert(0.0) += value;
+                    }
+                }
+                Some((
+                    parent,
+                    TaskRollup {
+                        children: counts,
+                        percent_done: counts.percent_done(),
+                        estimate,
+                        deepest_blocked: self.deepest_blocked(parent),
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Breadth-first walk below `root`, keeping the first blocked task found at the greatest depth.
+    fn deepest_blocked(&self, root: TaskId) -> Option<BlockedDescendant> {
+        let mut queue = VecDeque::from([(root, 0)]);
+        let mut visited = HashSet::from([root]);
+        let mut deepest: Option<BlockedDescendant> = None;
+        while let Some((task, depth)) = queue.pop_front() {
+            for child in self.children_index.get(&task).into_iter().flatten() {
+                if !visited.insert(*child) {
+                    continue;
+                }
+                let Some(snapshot) = self.snapshot(*child).filter(|snapshot| !snapshot.archived) else {
+                    continue;
+                };
+                if snapshot.state_kind == Some(StateKind::Blocked)
+                    && deepest.as_ref().is_none_or(|found| found.depth <= depth)
+                {
+                    deepest = Some(BlockedDescendant {
+                        task_id: snapshot.id,
+                        title: snapshot.title.clone(),
+                        depth: depth + 1,
+                    });
+                }
+                queue.push_back((*child, depth + 1));
+            }
+        }
+        deepest
+    }
+
+    fn snapshot(&self, task: TaskId) -> Option<&TaskSnapshot> {
+        self.task_index
+            .get(&task)
+            .and_then(|&idx| self.tasks.get(idx))
+            .map(|view| &view.snapshot)
+    }
+}
+
+fn child_estimate(child: &TaskSnapshot) -> Option<f64> {
+    child.fields.get(ESTIMATE_FIELD)?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task_cache::TaskView;
+    use git_mile_core::event::{Actor, Event, EventKind};
+
+    fn actor() -> Actor {
+        Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        }
+    }
+
+    fn task(
+        title: &str,
+        kind: Option<StateKind>,
+        parent: Option<TaskId>,
+        estimate: Option<&str>,
+    ) -> TaskView {
+        let id = TaskId::new();
+        let mut events = vec![Event::new(
+            id,
+            &actor(),
+            EventKind::TaskCreated {
+                title: title.into(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                description: None,
+                state: kind.map(|kind| format!("state/{}", kind.as_str())),
+                state_kind: kind,
+            },
+        )];
+        if let Some(parent) = parent {
+            events.push(Event::new(
+                id,
+                &actor(),
+                EventKind::ChildLinked { parent, child: id },
+            ));
+        }
+        if let Some(value) = estimate {
+            events.push(Event::new(
+                id,
+                &actor(),
+                EventKind::FieldSet {
+                    name: ESTIMATE_FIELD.into(),
+                    value: value.into(),
+                },
+            ));
+        }
+        TaskView::from_events(&events)
+    }
+
+    #[test]
+    fn rollup_counts_children_sums_estimates_and_finds_deepest_blocker() {
+        let epic = task("epic", None, None, None);
+        let epic_id = epic.snapshot.id;
+        let done = task("done", Some(StateKind::Done), Some(epic_id), Some("3"));
+        let story = task("story", Some(StateKind::InProgress), Some(epic_id), Some("2.5"));
+        let shallow = task("shallow", Some(StateKind::Blocked), Some(epic_id), None);
+        let story_id = story.snapshot.id;
+        let deep = task("deep", Some(StateKind::Blocked), Some(story_id), Some("100"));
+        let deep_id = deep.snapshot.id;
+        let cache = TaskCache::from_views(vec![epic, done, story, shallow, deep]);
+
+        let rollup = cache
+            .rollup(epic_id)
+            .unwrap_or_else(|| panic!("epic has children"));
+        assert_eq!(rollup.children.total, 3);
+        assert_eq!(rollup.children.done, 1);
+        assert_eq!(rollup.children.in_progress, 1);
+        assert_eq!(rollup.children.blocked, 1);
+        assert_eq!(rollup.percent_done, 33);
+        assert_eq!(rollup.estimate, Some(5.5));
+        let blocked = rollup
+            .deepest_blocked
+            .as_ref()
+            .unwrap_or_else(|| panic!("a descendant is blocked"));
+        assert_eq!((blocked.task_id, blocked.depth), (deep_id, 2));
+
+        let story = cache
+            .rollup(story_id)
+            .unwrap_or_else(|| panic!("story has a child"));
+        assert_eq!(story.estimate, Some(100.0));
+        assert!(cache.rollup(deep_id).is_none());
+    }
+}
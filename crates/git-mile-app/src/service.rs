@@ -1,14 +1,21 @@
 use anyhow::{Result, anyhow};
-use git_mile_core::TaskSnapshot;
 use git_mile_core::event::{Actor, Event};
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{EventId, TaskId, TaskIdAbbreviations};
+use git_mile_core::{RelationKind, TaskSnapshot};
 use git2::Oid;
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use crate::config::{HooksConfig, WorkflowConfig};
-use crate::task_log::ordered_events;
-use crate::task_writer::{CommentRequest, CreateTaskRequest, TaskStore, TaskWriter};
+use crate::milestone::{MilestoneService, MilestoneStore};
+use crate::task_log::{ActivityFeed, ActivityFilter, ordered_events};
+use crate::task_patch::{TaskEditData, TaskPatch, TaskUpdate};
+use crate::task_revert::latest_event_by;
+use crate::task_writer::{
+    BulkUpdateOutcome, CommentRequest, CreateTaskRequest, QueuePosition, TaskStore, TaskWriteResult,
+    TaskWriter,
+};
 
 /// Service façade that encapsulates all task-related side effects.
 pub struct TaskService<S> {
@@ -34,6 +41,13 @@ impl<S> TaskService<S> {
     }
 }
 
+impl<S: MilestoneStore> TaskService<S> {
+    /// Milestone operations over the same store as the tasks.
+    pub const fn milestones(&self) -> MilestoneService<&S> {
+        MilestoneService::new(self.store())
+    }
+}
+
 impl<S: TaskStore> TaskService<S> {
     /// Create a task and optionally link parents.
     ///
@@ -47,6 +61,7 @@ impl<S: TaskStore> TaskService<S> {
             assignees,
             description,
             parents,
+            fields,
             actor,
         } = input;
 
@@ -57,6 +72,7 @@ impl<S: TaskStore> TaskService<S> {
             assignees,
             description,
             parents,
+            fields,
             actor,
         };
         let result = self.writer.create_task(request)?;
@@ -102,6 +118,200 @@ impl<S: TaskStore> TaskService<S> {
         Ok(CommentOutput { task, oid })
     }
 
+    /// Delete a comment, hiding its body from materialized views.
+    ///
+    /// # Errors
+    /// Returns an error if the comment is missing or the event append fails.
+    pub fn delete_comment(&self, task: TaskId, comment: EventId, actor: &Actor) -> Result<Oid> {
+        let result = self.writer.delete_comment(task, comment, actor)?;
+        result
+            .events
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("TaskWriter returned no events for delete_comment"))
+    }
+
+    /// Add or withdraw a reaction on a comment on behalf of `actor`.
+    ///
+    /// # Errors
+    /// Returns an error if the comment is missing, the reaction is invalid, or the append fails.
+    pub fn react_to_comment(
+        &self,
+        task: TaskId,
+        comment: EventId,
+        reaction: &str,
+        remove: bool,
+        actor: &Actor,
+    ) -> Result<Oid> {
+        let result = self
+            .writer
+            .react_to_comment(task, comment, reaction, remove, actor)?;
+        result
+            .events
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("TaskWriter returned no events for react_to_comment"))
+    }
+
+    /// Archive or restore the specified task, returning the appended event OID.
+    ///
+    /// # Errors
+    /// Returns an error if the task is missing or the event append fails.
+    pub fn set_archived(&self, task: TaskId, archived: bool, actor: &Actor) -> Result<Oid> {
+        let result = self.writer.set_archived(task, archived, actor)?;
+        result
+            .events
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("TaskWriter returned no events for set_archived"))
+    }
+
+    /// Add or remove a relation between `task` and each target, returning every appended event OID.
+    ///
+    /// # Errors
+    /// Returns an error if a task is missing, a target equals `task`, or the event append fails.
+    pub fn set_relation(
+        &self,
+        task: TaskId,
+        kind: RelationKind,
+        targets: &[TaskId],
+        link: bool,
+        actor: &Actor,
+    ) -> Result<Vec<Oid>> {
+        let result = if link {
+            self.writer.link_relation(task, kind, targets, actor)?
+        } else {
+            self.writer.unlink_relation(task, kind, targets, actor)?
+        };
+        Ok(result.events)
+    }
+
+    /// Apply a field-level patch to the task as a single batch of events.
+    ///
+    /// # Errors
+    /// Returns an error if the task is missing, validation fails, or the append fails.
+    pub fn update(&self, task: TaskId, update: TaskUpdate, actor: &Actor) -> Result<TaskWriteResult> {
+        Ok(self.writer.update_task(task, update, actor)?)
+    }
+
+    /// Apply `update` to every task in `tasks`, reporting each task's outcome.
+    pub fn bulk_update(
+        &self,
+        tasks: &[TaskSnapshot],
+        update: &TaskUpdate,
+        actor: &Actor,
+    ) -> Vec<BulkUpdateOutcome> {
+        self.writer.bulk_update(tasks, update, actor)
+    }
+
+    /// Replace the editable fields of the task with `data`, writing only what changed.
+    ///
+    /// # Errors
+    /// Returns an error if the task is missing, validation fails, or the append fails.
+    pub fn edit(&self, task: TaskId, data: TaskEditData, actor: &Actor) -> Result<TaskWriteResult> {
+        let snapshot = self.materialize(task)?;
+        let update = TaskPatch::from_snapshot(&snapshot, data).into_task_update();
+        self.update(task, update, actor)
+    }
+
+    /// Set or clear (`None`) the workflow state of the task.
+    ///
+    /// # Errors
+    /// Returns an error if the task is missing, the state is not allowed, or the append fails.
+    pub fn set_state(&self, task: TaskId, state: Option<String>, actor: &Actor) -> Result<TaskWriteResult> {
+        Ok(self.writer.set_state(task, state, actor)?)
+    }
+
+    /// Tick or untick checklist item `index` in the task description.
+    ///
+    /// # Errors
+    /// Returns an error if the task or the item is missing, or the append fails.
+    pub fn toggle_checklist_item(
+        &self,
+        task: TaskId,
+        index: usize,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult> {
+        Ok(self.writer.toggle_checklist_item(task, index, actor)?)
+    }
+
+    /// Move `task` within the manual queue, ranking whatever has to join the queue with it.
+    ///
+    /// # Errors
+    /// Returns an error if a task is missing or an event append fails.
+    pub fn move_task(
+        &self,
+        task: TaskId,
+        position: QueuePosition,
+        actor: &Actor,
+    ) -> Result<Vec<TaskWriteResult>> {
+        Ok(self.writer.move_task(task, position, actor)?)
+    }
+
+    /// Link or unlink `task` as a child of each parent.
+    ///
+    /// # Errors
+    /// Returns an error if a task is missing or the event append fails.
+    pub fn set_parents(
+        &self,
+        task: TaskId,
+        parents: &[TaskId],
+        link: bool,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult> {
+        let result = if link {
+            self.writer.link_parents(task, parents, actor)?
+        } else {
+            self.writer.unlink_parents(task, parents, actor)?
+        };
+        Ok(result)
+    }
+
+    /// Undo `event` with its compensating change, locating its task when `task` is `None`.
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be found or reverted, or the compensating write fails.
+    pub fn revert(&self, event: EventId, task: Option<TaskId>, actor: &Actor) -> Result<TaskWriteResult> {
+        let task = match task {
+            Some(task) => task,
+            None => self
+                .store()
+                .load_all_events()
+                .map_err(Into::into)?
+                .into_iter()
+                .find(|(_, events)| events.iter().any(|candidate| candidate.id == event))
+                .map(|(task, _)| task)
+                .ok_or_else(|| anyhow!("Event {event} not found in any task"))?,
+        };
+        Ok(self.writer.revert_event(task, event, actor)?)
+    }
+
+    /// Most recent event authored by `actor` in any task.
+    ///
+    /// # Errors
+    /// Returns an error if event loading fails.
+    pub fn last_event_by(&self, actor: &Actor) -> Result<Option<Event>> {
+        let histories = self.store().load_all_events().map_err(Into::into)?;
+        Ok(latest_event_by(&histories, actor).cloned())
+    }
+
+    /// Resolve a full or abbreviated task id against the stored tasks.
+    ///
+    /// # Errors
+    /// Returns an error if the id is malformed, unknown or ambiguous, or listing tasks fails.
+    pub fn resolve_task_id(&self, input: &str) -> Result<TaskId> {
+        self.store().resolve_task_id(input)
+    }
+
+    /// Shortest unique id prefixes across every stored task.
+    ///
+    /// # Errors
+    /// Returns an error if listing tasks fails.
+    pub fn id_abbreviations(&self) -> Result<TaskIdAbbreviations> {
+        let tasks = self.store().list_tasks().map_err(Into::into)?;
+        Ok(TaskIdAbbreviations::new(tasks))
+    }
+
     /// Build a [`TaskSnapshot`] for the given task by replaying events.
     ///
     /// # Errors
@@ -111,6 +321,16 @@ impl<S: TaskStore> TaskService<S> {
         Ok(TaskSnapshot::replay(&events))
     }
 
+    /// Merge every task's events into one feed filtered by `filter`.
+    ///
+    /// # Errors
+    /// Returns an error if the filter is invalid or event loading fails.
+    pub fn activity(&self, filter: &ActivityFilter) -> Result<ActivityFeed> {
+        filter.validate()?;
+        let histories = self.store().load_all_events().map_err(Into::into)?;
+        Ok(ActivityFeed::collect(histories, filter))
+    }
+
     /// Return ordered raw events for the given task.
     ///
     /// # Errors
@@ -128,6 +348,7 @@ pub struct CreateTaskInput {
     pub assignees: Vec<String>,
     pub description: Option<String>,
     pub parents: Vec<TaskId>,
+    pub fields: BTreeMap<String, String>,
     pub actor: Actor,
 }
 
@@ -156,7 +377,12 @@ pub struct CommentOutput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{WorkflowConfig, WorkflowState};
+    use crate::config::{
+        FieldDefinition, FieldViolation, TransitionViolation, WorkflowConfig, WorkflowState,
+        WorkflowTransition,
+    };
+    use crate::task_writer::{SetDiff, StatePatch, TaskUpdate, TaskWriteError};
+    use anyhow::Context;
     use git_mile_core::TaskFilter;
     use git_mile_core::event::{Actor, Event, EventKind};
     use std::collections::{HashMap, HashSet};
@@ -330,6 +556,7 @@ mod tests {
             assignees: vec![],
             description: None,
             parents: vec![],
+            fields: BTreeMap::new(),
             actor: sample_actor(),
         })?;
         let parent = parent_output.task;
@@ -342,6 +569,7 @@ mod tests {
             assignees: vec!["dev".into()],
             description: Some("desc".into()),
             parents: vec![parent],
+            fields: BTreeMap::new(),
             actor: sample_actor(),
         })?;
 
@@ -395,6 +623,7 @@ mod tests {
             assignees: vec![],
             description: None,
             parents: vec![],
+            fields: BTreeMap::new(),
             actor: sample_actor(),
         }) else {
             panic!("expected state validation error");
@@ -424,6 +653,7 @@ mod tests {
             assignees: vec![],
             description: None,
             parents: vec![],
+            fields: BTreeMap::new(),
             actor: sample_actor(),
         })?;
 
@@ -440,6 +670,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn custom_fields_are_normalized_and_validated() -> Result<()> {
+        let store = MockStore::default();
+        let workflow = WorkflowConfig::unrestricted().with_fields(BTreeMap::from([
+            (
+                "priority".to_owned(),
+                FieldDefinition::Enum {
+                    values: vec!["high".into(), "low".into()],
+                },
+            ),
+            ("due".to_owned(), FieldDefinition::Date),
+        ]));
+        let service = TaskService::new(
+            store.clone(),
+            workflow,
+            HooksConfig::default(),
+            PathBuf::from("/tmp/.git-mile"),
+        );
+        let actor = sample_actor();
+        let input = |fields: &[(&str, &str)]| CreateTaskInput {
+            title: "task".into(),
+            state: None,
+            labels: vec![],
+            assignees: vec![],
+            description: None,
+            parents: vec![],
+            fields: fields
+                .iter()
+                .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+                .collect(),
+            actor: actor.clone(),
+        };
+
+        let task = service
+            .create_with_parents(input(&[("priority", "HIGH"), ("due", "2025-3-1")]))?
+            .task;
+        let snapshot = service.materialize(task)?;
+        assert_eq!(snapshot.fields.get("priority").map(String::as_str), Some("high"));
+        assert_eq!(snapshot.fields.get("due").map(String::as_str), Some("2025-03-01"));
+        assert!(matches!(store.appended()[1].kind, EventKind::FieldSet { .. }));
+
+        let unknown = service.create_with_parents(input(&[("severity", "1")]));
+        assert!(matches!(
+            unknown.map_err(anyhow::Error::downcast::<TaskWriteError>),
+            Err(Ok(TaskWriteError::InvalidField(FieldViolation::Unknown { .. })))
+        ));
+
+        let invalid = service.update(
+            task,
+            TaskUpdate {
+                fields: BTreeMap::from([("priority".to_owned(), Some("urgent".to_owned()))]),
+                ..TaskUpdate::default()
+            },
+            &actor,
+        );
+        assert!(invalid.is_err_and(|err| err.to_string().contains("one of high, low")));
+
+        service.update(
+            task,
+            TaskUpdate {
+                fields: BTreeMap::from([("due".to_owned(), None)]),
+                ..TaskUpdate::default()
+            },
+            &actor,
+        )?;
+        assert!(!service.materialize(task)?.fields.contains_key("due"));
+        Ok(())
+    }
+
     #[test]
     fn create_task_errors_when_parent_missing() {
         let (service, _repository, store) = service_with_store();
@@ -453,6 +752,7 @@ mod tests {
             assignees: vec![],
             description: None,
             parents: vec![parent],
+            fields: BTreeMap::new(),
             actor: sample_actor(),
         });
 
@@ -471,6 +771,7 @@ mod tests {
             assignees: vec![],
             description: None,
             parents: vec![],
+            fields: BTreeMap::new(),
             actor: sample_actor(),
         })?;
         let task = create_output.task;
@@ -496,6 +797,493 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn comment_deletion_and_reactions_require_live_comment() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let actor = sample_actor();
+        let task = service
+            .create_with_parents(CreateTaskInput {
+                title: "task for reactions".into(),
+                state: None,
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                parents: vec![],
+                fields: BTreeMap::new(),
+                actor: actor.clone(),
+            })?
+            .task;
+        service.add_comment(CommentInput {
+            task,
+            message: "oops".into(),
+            actor: actor.clone(),
+        })?;
+        let comment = match &store.appended()[1].kind {
+            EventKind::CommentAdded { comment_id, .. } => *comment_id,
+            other => panic!("unexpected event kind: {other:?}"),
+        };
+
+        service.react_to_comment(task, comment, "+1", false, &actor)?;
+        assert!(
+            service
+                .react_to_comment(task, comment, "  ", false, &actor)
+                .is_err()
+        );
+        assert!(service.delete_comment(task, EventId::new(), &actor).is_err());
+
+        service.delete_comment(task, comment, &actor)?;
+        assert!(service.delete_comment(task, comment, &actor).is_err());
+        assert!(
+            service
+                .react_to_comment(task, comment, "+1", true, &actor)
+                .is_err()
+        );
+
+        let events = store.appended();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[2].kind, EventKind::CommentReactionAdded { .. }));
+        assert!(matches!(events[3].kind, EventKind::CommentDeleted { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn toggle_checklist_item_rewrites_description() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let actor = sample_actor();
+        let task = service
+            .create_with_parents(CreateTaskInput {
+                title: "release".into(),
+                state: None,
+                labels: vec![],
+                assignees: vec![],
+                description: Some("Steps:\n- [ ] tag\n- [x] changelog\n".into()),
+                parents: vec![],
+                fields: BTreeMap::new(),
+                actor: actor.clone(),
+            })?
+            .task;
+
+        service.toggle_checklist_item(task, 0, &actor)?;
+        let snapshot = service.materialize(task)?;
+        assert_eq!(snapshot.description, "Steps:\n- [x] tag\n- [x] changelog\n");
+        assert_eq!((snapshot.checklist.done, snapshot.checklist.total), (2, 2));
+
+        let Err(err) = service.toggle_checklist_item(task, 2, &actor) else {
+            panic!("only two items exist");
+        };
+        assert!(err.to_string().contains("no checklist item 2"));
+        assert_eq!(store.appended().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn move_task_orders_the_queue_by_rank() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let actor = sample_actor();
+        let create = |title: &str| -> Result<TaskId> {
+            Ok(service
+                .create_with_parents(CreateTaskInput {
+                    title: title.into(),
+                    state: None,
+                    labels: vec![],
+                    assignees: vec![],
+                    description: None,
+                    parents: vec![],
+                    fields: BTreeMap::new(),
+                    actor: actor.clone(),
+                })?
+                .task)
+        };
+        let (first, second, third) = (create("first")?, create("second")?, create("third")?);
+        store.set_list(vec![first, second, third]);
+
+        assert_eq!(service.move_task(third, QueuePosition::Top, &actor)?.len(), 1);
+        assert_eq!(
+            service
+                .move_task(first, QueuePosition::After(third), &actor)?
+                .len(),
+            1
+        );
+        service.move_task(second, QueuePosition::Before(first), &actor)?;
+        let rank = |task| -> Result<String> {
+            service
+                .materialize(task)?
+                .rank
+                .ok_or_else(|| anyhow!("{task} must be ranked"))
+        };
+        assert!(rank(third)? < rank(second)? && rank(second)? < rank(first)?);
+
+        let appended = store.appended().len();
+        assert!(
+            service
+                .move_task(first, QueuePosition::After(first), &actor)?
+                .is_empty()
+        );
+        let Err(err) = service.move_task(first, QueuePosition::Before(TaskId::new()), &actor) else {
+            panic!("anchor task does not exist");
+        };
+        assert!(err.to_string().contains("not found"));
+        assert_eq!(store.appended().len(), appended);
+        Ok(())
+    }
+
+    #[test]
+    fn relations_record_inverse_on_target() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let actor = sample_actor();
+        let create = |title: &str| {
+            service.create_with_parents(CreateTaskInput {
+                title: title.into(),
+                state: None,
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                parents: vec![],
+                fields: BTreeMap::new(),
+                actor: actor.clone(),
+            })
+        };
+        let blocker = create("blocker")?.task;
+        let dependent = create("dependent")?.task;
+
+        let oids = service.set_relation(blocker, RelationKind::Blocks, &[dependent], true, &actor)?;
+        assert_eq!(oids.len(), 2);
+        assert!(
+            service
+                .set_relation(blocker, RelationKind::RelatesTo, &[blocker], true, &actor)
+                .is_err()
+        );
+        assert!(
+            service
+                .set_relation(blocker, RelationKind::Blocks, &[TaskId::new()], true, &actor)
+                .is_err()
+        );
+        let appended = store.appended().len();
+        assert!(
+            service
+                .set_relation(blocker, RelationKind::Blocks, &[dependent, blocker], true, &actor)
+                .is_err()
+        );
+        assert_eq!(
+            store.appended().len(),
+            appended,
+            "no target may be linked when one is invalid"
+        );
+        let oids = service.set_relation(blocker, RelationKind::Duplicates, &[dependent], true, &actor)?;
+        assert_eq!(oids.len(), 1);
+
+        let events = store.appended();
+        match (&events[2].kind, &events[3].kind) {
+            (
+                EventKind::RelationAdded { kind, target },
+                EventKind::RelationAdded {
+                    kind: inverse,
+                    target: inverse_target,
+                },
+            ) => {
+                assert_eq!((kind.as_str(), *target), ("blocks", dependent));
+                assert_eq!((inverse.as_str(), *inverse_target), ("blocked-by", blocker));
+                assert_eq!(events[3].task, dependent);
+            }
+            other => panic!("unexpected event kinds: {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn edit_writes_only_changed_fields_and_parents_toggle() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let actor = sample_actor();
+        let create = |title: &str| {
+            service.create_with_parents(CreateTaskInput {
+                title: title.into(),
+                state: None,
+                labels: vec!["keep".into()],
+                assignees: vec![],
+                description: Some("same".into()),
+                parents: vec![],
+                fields: BTreeMap::new(),
+                actor: actor.clone(),
+            })
+        };
+        let parent = create("parent")?.task;
+        let task = create("child")?.task;
+
+        let result = service.edit(
+            task,
+            TaskEditData::new(
+                "renamed".into(),
+                None,
+                vec!["keep".into(), "new".into()],
+                vec![],
+                Some("same".into()),
+            ),
+            &actor,
+        )?;
+        assert_eq!(result.events.len(), 1, "one batch commit for the whole edit");
+        let kinds: Vec<_> = store.appended()[2..].iter().map(|ev| ev.kind.clone()).collect();
+        assert_eq!(kinds.len(), 2);
+        assert!(matches!(&kinds[0], EventKind::TaskTitleSet { title } if title == "renamed"));
+        assert!(matches!(&kinds[1], EventKind::LabelsAdded { labels } if labels == &["new".to_owned()]));
+
+        let unchanged = service.edit(
+            task,
+            TaskEditData::new(
+                "renamed".into(),
+                None,
+                vec!["keep".into(), "new".into()],
+                vec![],
+                Some("same".into()),
+            ),
+            &actor,
+        )?;
+        assert!(unchanged.events.is_empty());
+
+        assert_eq!(
+            service.set_parents(task, &[parent], true, &actor)?.events.len(),
+            2
+        );
+        assert!(service.materialize(task)?.parents.contains(&parent));
+        service.set_parents(task, &[parent], false, &actor)?;
+        assert!(service.materialize(task)?.parents.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn revert_writes_compensating_events_through_the_writer() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let actor = sample_actor();
+        let task = service
+            .create_with_parents(CreateTaskInput {
+                title: "original".into(),
+                state: Some("state/todo".into()),
+                labels: vec!["keep".into()],
+                assignees: vec![],
+                description: None,
+                parents: vec![],
+                fields: BTreeMap::new(),
+                actor: actor.clone(),
+            })?
+            .task;
+        store.set_list(vec![task]);
+        service.update(
+            task,
+            TaskUpdate {
+                title: Some("typo".into()),
+                labels: SetDiff {
+                    added: vec!["keep".into(), "oops".into()],
+                    removed: vec![],
+                },
+                ..TaskUpdate::default()
+            },
+            &actor,
+        )?;
+        let events = service.event_log(task)?;
+        let retitle = events
+            .iter()
+            .find(|event| matches!(event.kind, EventKind::TaskTitleSet { .. }))
+            .map(|event| event.id)
+            .context("title event")?;
+        let labels = events
+            .iter()
+            .find(|event| matches!(event.kind, EventKind::LabelsAdded { .. }))
+            .map(|event| event.id)
+            .context("label event")?;
+
+        // The title and label events were written in one commit and are undone together, in one commit.
+        let reverted = service.revert(retitle, None, &actor)?;
+        assert_eq!(reverted.events.len(), 1);
+        let compensations: Vec<Event> = service
+            .event_log(task)?
+            .into_iter()
+            .filter(|event| event.reverts == Some(retitle))
+            .collect();
+        assert_eq!(compensations.len(), 2);
+        assert!(
+            compensations
+                .iter()
+                .all(|event| event.lamport == compensations[0].lamport)
+        );
+        let snapshot = service.materialize(task)?;
+        assert_eq!(snapshot.title, "original");
+        assert_eq!(snapshot.labels.iter().collect::<Vec<_>>(), ["keep"]);
+        // Undo skips the compensation and the reverted commit.
+        assert!(
+            matches!(service.last_event_by(&actor)?, Some(event) if matches!(event.kind, EventKind::TaskCreated { .. }))
+        );
+        // Reverting again would overwrite the compensation, so it is refused.
+        assert!(matches!(
+            service.revert(labels, Some(task), &actor),
+            Err(e) if e.to_string().contains("changed the same field afterwards")
+        ));
+
+        let created = events[0].id;
+        assert!(matches!(
+            service.writer.revert_event(task, created, &actor),
+            Err(TaskWriteError::NotRevertible { .. })
+        ));
+        assert!(service.revert(EventId::new(), None, &actor).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn state_changes_follow_workflow_transitions() -> Result<()> {
+        let store = MockStore::default();
+        let workflow = WorkflowConfig::from_states(vec![
+            WorkflowState::new("state/todo"),
+            WorkflowState::new("state/in-review"),
+            WorkflowState::new("state/done"),
+        ])
+        .with_transitions(vec![
+            WorkflowTransition::new(Some("state/todo"), "state/in-review").require_assignee(),
+            WorkflowTransition::new(Some("state/in-review"), "state/done"),
+        ]);
+        let service = TaskService::new(
+            std::sync::Arc::new(store),
+            workflow,
+            HooksConfig::default(),
+            PathBuf::from("/tmp/.git-mile"),
+        );
+        let actor = sample_actor();
+        let task = service
+            .create_with_parents(CreateTaskInput {
+                title: "reviewed work".into(),
+                state: Some("state/todo".into()),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                parents: vec![],
+                fields: BTreeMap::new(),
+                actor: actor.clone(),
+            })?
+            .task;
+
+        let skipped = service.writer.set_state(task, Some("state/done".into()), &actor);
+        assert!(matches!(
+            skipped,
+            Err(TaskWriteError::Transition {
+                violation: TransitionViolation::NotAllowed { .. },
+                ..
+            })
+        ));
+        let unassigned = service
+            .writer
+            .set_state(task, Some("state/in-review".into()), &actor);
+        assert!(matches!(
+            unassigned,
+            Err(TaskWriteError::Transition {
+                violation: TransitionViolation::MissingAssignee { .. },
+                ..
+            })
+        ));
+
+        service.writer.update_task(
+            task,
+            TaskUpdate {
+                state: Some(StatePatch::Set {
+                    state: "state/in-review".into(),
+                }),
+                assignees: SetDiff {
+                    added: vec!["alice".into()],
+                    removed: vec![],
+                },
+                ..TaskUpdate::default()
+            },
+            &actor,
+        )?;
+        service
+            .writer
+            .set_state(task, Some("state/done".into()), &actor)?;
+
+        // Creation is exempt: no rule leads from an unset state to `state/done`.
+        let created = service.create_with_parents(CreateTaskInput {
+            title: "imported as done".into(),
+            state: Some("state/done".into()),
+            labels: vec![],
+            assignees: vec![],
+            description: None,
+            parents: vec![],
+            fields: BTreeMap::new(),
+            actor,
+        })?;
+        assert_eq!(
+            service.materialize(created.task)?.state.as_deref(),
+            Some("state/done")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bulk_update_reports_each_task_without_stopping() -> Result<()> {
+        let workflow = WorkflowConfig::from_states(vec![
+            WorkflowState::new("state/todo"),
+            WorkflowState::new("state/in-review"),
+            WorkflowState::new("state/done"),
+        ])
+        .with_transitions(vec![
+            WorkflowTransition::new(Some("state/todo"), "state/in-review"),
+            WorkflowTransition::new(Some("state/in-review"), "state/done"),
+        ]);
+        let service = TaskService::new(
+            std::sync::Arc::new(MockStore::default()),
+            workflow,
+            HooksConfig::default(),
+            PathBuf::from("/tmp/.git-mile"),
+        );
+        let actor = sample_actor();
+        let create = |state: &str| -> Result<TaskSnapshot> {
+            let task = service
+                .create_with_parents(CreateTaskInput {
+                    title: state.into(),
+                    state: Some(state.into()),
+                    labels: vec![],
+                    assignees: vec![],
+                    description: None,
+                    parents: vec![],
+                    fields: BTreeMap::new(),
+                    actor: actor.clone(),
+                })?
+                .task;
+            service.materialize(task)
+        };
+        let tasks = vec![
+            create("state/in-review")?,
+            create("state/done")?,
+            create("state/todo")?,
+        ];
+
+        let outcomes = service.bulk_update(
+            &tasks,
+            &TaskUpdate {
+                state: Some(StatePatch::Set {
+                    state: "state/done".into(),
+                }),
+                ..TaskUpdate::default()
+            },
+            &actor,
+        );
+        assert!(
+            matches!(&outcomes[0], BulkUpdateOutcome::Updated { task, events } if *task == tasks[0].id && events.len() == 1)
+        );
+        assert!(matches!(&outcomes[1], BulkUpdateOutcome::Unchanged { task } if *task == tasks[1].id));
+        assert!(matches!(
+            &outcomes[2],
+            BulkUpdateOutcome::Failed {
+                error: TaskWriteError::Transition { .. },
+                ..
+            }
+        ));
+        assert_eq!(
+            service.materialize(tasks[0].id)?.state.as_deref(),
+            Some("state/done")
+        );
+        assert_eq!(
+            service.materialize(tasks[2].id)?.state.as_deref(),
+            Some("state/todo")
+        );
+        Ok(())
+    }
+
     #[test]
     fn list_snapshots_applies_filters() -> Result<()> {
         let (_service, repository, store) = service_with_store();
@@ -1,16 +1,19 @@
 //! Shared task snapshot cache utilities reused by CLI/TUI/MCP.
 
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+use crate::rollup::TaskRollup;
 use crate::task_writer::TaskStore;
+use crate::view_index;
 use git_mile_core::event::{Actor, Event, EventKind};
-use git_mile_core::id::{EventId, TaskId};
-use git_mile_core::{OrderedEvents, TaskFilter, TaskSnapshot};
+use git_mile_core::id::{EventId, TaskId, TaskIdResolveError};
+use git_mile_core::{OrderedEvents, TaskFilter, TaskSnapshot, rank};
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 /// Actor-written comment on a task.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskComment {
     /// Unique identifier for the comment event.
     pub id: EventId,
@@ -19,19 +22,26 @@ pub struct TaskComment {
     /// Comment body in Markdown.
     pub body: String,
     /// Timestamp when the comment was created.
+    #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     /// Timestamp when the comment was last updated.
+    #[serde(with = "time::serde::rfc3339::option")]
     pub updated_at: Option<OffsetDateTime>,
+    /// Whether the comment has been deleted. Deleted comments keep their slot but expose no body.
+    pub deleted: bool,
+    /// Reactions keyed by reaction token, listing the reacting actor emails.
+    pub reactions: BTreeMap<String, BTreeSet<String>>,
 }
 
 /// Materialized view combining snapshot + comments.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskView {
     /// Current snapshot derived from the CRDT.
     pub snapshot: TaskSnapshot,
     /// Chronological comment history.
     pub comments: Vec<TaskComment>,
     /// Timestamp of the most recent event.
+    #[serde(with = "time::serde::rfc3339::option")]
     pub last_updated: Option<OffsetDateTime>,
 }
 
@@ -54,6 +64,8 @@ impl TaskView {
                         body: body_md.clone(),
                         created_at: ev.ts,
                         updated_at: None,
+                        deleted: false,
+                        reactions: BTreeMap::new(),
                     };
                     comment_index.insert(*comment_id, comments.len());
                     comments.push(entry);
@@ -70,6 +82,15 @@ impl TaskView {
             }
         }
 
+        for comment in &mut comments {
+            if snapshot.deleted_comments.contains(&comment.id) {
+                comment.deleted = true;
+                comment.body.clear();
+            } else if let Some(reactions) = snapshot.comment_reactions.get(&comment.id) {
+                comment.reactions.clone_from(reactions);
+            }
+        }
+
         let last_updated = ordered.latest().map(|ev| ev.ts);
 
         Self {
@@ -91,11 +112,16 @@ pub struct TaskCache {
     pub parents_index: HashMap<TaskId, Vec<TaskId>>,
     /// Cached child relationships.
     pub children_index: HashMap<TaskId, Vec<TaskId>>,
+    /// Subtask rollups of every task with non-archived children.
+    pub rollups: HashMap<TaskId, TaskRollup>,
 }
 
 impl TaskCache {
     /// Load every task snapshot from the store and build indexes.
     ///
+    /// Views are served from the store's [view index](TaskStore::view_index_path)
+    /// when it has one, so only tasks whose ref moved are replayed.
+    ///
     /// # Errors
     ///
     /// Propagates store-specific read failures.
@@ -103,13 +129,7 @@ impl TaskCache {
     where
         S: TaskStore,
     {
-        let mut views = Vec::new();
-
-        for (_, events) in store.load_all_events()? {
-            views.push(TaskView::from_events(&events));
-        }
-
-        Ok(Self::from_views(views))
+        Ok(Self::from_views(view_index::load_views(store)?))
     }
 
     /// Create a `TaskCache` from pre-built `TaskViews`.
@@ -122,6 +142,7 @@ impl TaskCache {
             task_index: HashMap::new(),
             parents_index: HashMap::new(),
             children_index: HashMap::new(),
+            rollups: HashMap::new(),
         };
         cache.rebuild_indexes();
         cache
@@ -131,13 +152,14 @@ impl TaskCache {
         views.sort_by(Self::compare_views);
     }
 
+    /// Queued tasks first in rank order, then the rest by most recent update.
     fn compare_views(a: &TaskView, b: &TaskView) -> Ordering {
-        match (a.last_updated, b.last_updated) {
+        rank::compare(&a.snapshot, &b.snapshot).then_with(|| match (a.last_updated, b.last_updated) {
             (Some(a_ts), Some(b_ts)) => b_ts.cmp(&a_ts),
             (Some(_), None) => Ordering::Less,
             (None, Some(_)) => Ordering::Greater,
             (None, None) => a.snapshot.id.cmp(&b.snapshot.id),
-        }
+        })
     }
 
     /// Upsert task views, reusing cached state when possible.
@@ -158,6 +180,15 @@ impl TaskCache {
         self.rebuild_indexes();
     }
 
+    /// Drop the views of tasks that no longer exist in the store.
+    pub fn remove_views(&mut self, task_ids: &[TaskId]) {
+        let before = self.tasks.len();
+        self.tasks.retain(|view| !task_ids.contains(&view.snapshot.id));
+        if self.tasks.len() != before {
+            self.rebuild_indexes();
+        }
+    }
+
     fn rebuild_indexes(&mut self) {
         self.task_index.clear();
         self.parents_index.clear();
@@ -178,6 +209,7 @@ impl TaskCache {
             self.children_index.entry(view.snapshot.id).or_default();
             self.parents_index.insert(view.snapshot.id, parents);
         }
+        self.rollups = self.compute_rollups();
     }
 
     /// Iterate over cached snapshots in last-updated order.
@@ -195,6 +227,15 @@ impl TaskCache {
             .collect()
     }
 
+    /// Resolve abbreviated task ids in `filter` against the cached tasks.
+    ///
+    /// # Errors
+    /// Returns [`TaskIdResolveError`] when a prefix matches no cached task or more than one.
+    pub fn resolve_filter(&self, filter: &TaskFilter) -> Result<TaskFilter, TaskIdResolveError> {
+        let known: Vec<TaskId> = self.task_index.keys().copied().collect();
+        filter.resolve_task_ids(&known)
+    }
+
     /// Fetch a full [`TaskView`] for a task id.
     #[must_use]
     pub fn view(&self, task_id: TaskId) -> Option<TaskView> {
@@ -330,6 +371,35 @@ mod tests {
         assert_eq!(ids, vec![second, third, first]);
     }
 
+    #[test]
+    fn load_puts_ranked_tasks_first_with_ties_broken_by_id() {
+        let unranked = fixed_task_id(4);
+        let tied_low = fixed_task_id(5);
+        let tied_high = fixed_task_id(6);
+        let front = fixed_task_id(7);
+        let ranked = |task: TaskId, secs: i64, rank: &str| {
+            let mut ev = Event::new(
+                task,
+                &actor(),
+                EventKind::TaskRankSet {
+                    rank: Some(rank.into()),
+                },
+            );
+            ev.ts = ts(secs);
+            vec![created(task, secs, "task"), ev]
+        };
+
+        let store = MockStore::default()
+            .with_task(unranked, vec![created(unranked, 50, "newest")])
+            .with_task(tied_high, ranked(tied_high, 10, "k"))
+            .with_task(tied_low, ranked(tied_low, 20, "k"))
+            .with_task(front, ranked(front, 5, "F"));
+
+        let cache = TaskCache::load(&store).unwrap_or_else(|err| panic!("must load cache: {err}"));
+        let ids: Vec<TaskId> = cache.snapshots().map(|view| view.id).collect();
+        assert_eq!(ids, vec![front, tied_low, tied_high, unranked]);
+    }
+
     #[test]
     fn indexes_track_parent_child_relationships() {
         let parent = fixed_task_id(10);
@@ -389,6 +459,54 @@ mod tests {
         assert_eq!(view.snapshot.title, "single");
     }
 
+    #[test]
+    fn view_hides_deleted_comment_bodies_and_collects_reactions() {
+        let task = fixed_task_id(33);
+        let kept = EventId::new();
+        let removed = EventId::new();
+        let events = vec![
+            created(task, 1, "comments"),
+            Event::new(
+                task,
+                &actor(),
+                EventKind::CommentAdded {
+                    comment_id: kept,
+                    body_md: "keep me".into(),
+                },
+            ),
+            Event::new(
+                task,
+                &actor(),
+                EventKind::CommentAdded {
+                    comment_id: removed,
+                    body_md: "secret token".into(),
+                },
+            ),
+            Event::new(
+                task,
+                &actor(),
+                EventKind::CommentReactionAdded {
+                    comment_id: kept,
+                    reaction: "+1".into(),
+                },
+            ),
+            Event::new(task, &actor(), EventKind::CommentDeleted { comment_id: removed }),
+        ];
+
+        let view = TaskView::from_events(&events);
+        assert_eq!(view.comments.len(), 2);
+        let kept_comment = view.comments.iter().find(|c| c.id == kept).expect("kept comment");
+        assert!(!kept_comment.deleted);
+        assert!(kept_comment.reactions["+1"].contains("tester@example.invalid"));
+        let removed_comment = view
+            .comments
+            .iter()
+            .find(|c| c.id == removed)
+            .expect("deleted comment");
+        assert!(removed_comment.deleted);
+        assert!(removed_comment.body.is_empty());
+    }
+
     #[test]
     fn upsert_views_updates_existing_entries() {
         let first = fixed_task_id(40);
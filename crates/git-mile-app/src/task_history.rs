@@ -0,0 +1,282 @@
+//! Point-in-time replay and per-field attribution of task histories.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow, bail};
+use git_mile_core::TaskSnapshot;
+use git_mile_core::event::{Actor, Event, EventKind};
+use git_mile_core::id::EventId;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::filter_util::{normalize_timestamp, parse_timestamp};
+use crate::task_log::ordered_events;
+
+/// Where to stop replaying a task history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryPoint {
+    /// Keep events recorded at or before this instant.
+    At(OffsetDateTime),
+    /// Keep events up to and including this event, in replay order.
+    Event(EventId),
+}
+
+impl FromStr for HistoryPoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if let Ok(at) = parse_timestamp(trimmed) {
+            return Ok(Self::At(normalize_timestamp(at)));
+        }
+        EventId::from_str(trimmed).map(Self::Event).map_err(|_| {
+            anyhow!("Invalid point in history '{s}': expected an RFC3339 timestamp or an event id")
+        })
+    }
+}
+
+/// Ordered prefix of `events` that had happened at `point`.
+///
+/// # Errors
+/// Returns an error when `point` names an event that is not part of `events`.
+pub fn events_until(events: &[Event], point: HistoryPoint) -> Result<Vec<Event>> {
+    let ordered = ordered_events(events);
+    match point {
+        HistoryPoint::At(at) => Ok(ordered.into_iter().filter(|event| event.ts <= at).collect()),
+        HistoryPoint::Event(id) => {
+            let Some(end) = ordered.iter().position(|event| event.id == id) else {
+                bail!("Event {id} is not part of this task's history");
+            };
+            Ok(ordered.into_iter().take(end + 1).collect())
+        }
+    }
+}
+
+/// Task field whose last change [`blame`] reports.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(tag = "field", content = "value", rename_all = "snake_case")]
+pub enum BlameField {
+    /// The task title.
+    Title,
+    /// The workflow state, including clearing it.
+    State,
+    /// The Markdown description, including clearing it.
+    Description,
+    /// One label currently on the task.
+    Label(String),
+    /// One assignee currently on the task.
+    Assignee(String),
+    /// One custom field currently set on the task.
+    Field(String),
+    /// The milestone, including removing the task from it.
+    Milestone,
+    /// The position in the manual queue.
+    Rank,
+}
+
+impl fmt::Display for BlameField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Title => f.write_str("title"),
+            Self::State => f.write_str("state"),
+            Self::Description => f.write_str("description"),
+            Self::Label(label) => write!(f, "label {label}"),
+            Self::Assignee(assignee) => write!(f, "assignee {assignee}"),
+            Self::Field(name) => write!(f, "field {name}"),
+            Self::Milestone => f.write_str("milestone"),
+            Self::Rank => f.write_str("rank"),
+        }
+    }
+}
+
+/// The event that last set a field.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlameEntry {
+    /// Field the event set.
+    #[serde(flatten)]
+    pub field: BlameField,
+    /// Identifier of the responsible event.
+    pub event_id: EventId,
+    /// Actor who recorded the event.
+    pub actor: Actor,
+    /// When the event was recorded.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+}
+
+/// For every field of the current snapshot, the event that last set it.
+///
+/// Labels, assignees and custom fields are only reported while they are still on the task.
+#[must_use]
+pub fn blame(events: &[Event]) -> Vec<BlameEntry> {
+    let ordered = ordered_events(events);
+    let mut last: BTreeMap<BlameField, &Event> = BTreeMap::new();
+    for event in &ordered {
+        let mut set = |field| {
+            last.insert(field, event);
+        };
+        match &event.kind {
+            EventKind::TaskCreated {
+                labels,
+                assignees,
+                description,
+                state,
+                ..
+            } => {
+                set(BlameField::Title);
+                if state.is_some() {
+                    set(BlameField::State);
+                }
+                if description.is_some() {
+                    set(BlameField::Description);
+                }
+                labels.iter().cloned().map(BlameField::Label).for_each(&mut set);
+                assignees
+                    .iter()
+                    .cloned()
+                    .map(BlameField::Assignee)
+                    .for_each(&mut set);
+            }
+            EventKind::TaskTitleSet { .. } => set(BlameField::Title),
+            EventKind::TaskStateSet { .. } | EventKind::TaskStateCleared => set(BlameField::State),
+            EventKind::TaskDescriptionSet { .. } => set(BlameField::Description),
+            EventKind::LabelsAdded { labels } => {
+                labels.iter().cloned().map(BlameField::Label).for_each(&mut set);
+            }
+            EventKind::AssigneesAdded { assignees } => {
+                assignees
+                    .iter()
+                    .cloned()
+                    .map(BlameField::Assignee)
+                    .for_each(&mut set);
+            }
+            EventKind::FieldSet { name, .. } => set(BlameField::Field(name.clone())),
+            EventKind::MilestoneSet { .. } | EventKind::MilestoneCleared => set(BlameField::Milestone),
+            EventKind::TaskRankSet { .. } => set(BlameField::Rank),
+            _ => {}
+        }
+    }
+
+    let snapshot = TaskSnapshot::replay(events);
+    last.into_iter()
+        .filter(|(field, _)| match field {
+            BlameField::Label(label) => snapshot.labels.contains(label),
+            BlameField::Assignee(assignee) => snapshot.assignees.contains(assignee),
+            BlameField::Field(name) => snapshot.fields.contains_key(name),
+            _ => true,
+        })
+        .map(|(field, event)| BlameEntry {
+            field,
+            event_id: event.id,
+            actor: event.actor.clone(),
+            ts: event.ts,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git_mile_core::id::TaskId;
+    use time::Duration;
+
+    fn actor(name: &str) -> Actor {
+        Actor {
+            name: name.into(),
+            email: format!("{name}@example.invalid"),
+        }
+    }
+
+    fn history() -> (Vec<Event>, [Event; 4]) {
+        let task = TaskId::new();
+        let mut created = Event::new(
+            task,
+            &actor("alice"),
+            EventKind::TaskCreated {
+                title: "Ship it".into(),
+                labels: vec!["bug".into(), "ui".into()],
+                assignees: vec![],
+                description: None,
+                state: Some("state/todo".into()),
+                state_kind: None,
+            },
+        );
+        created.lamport = 1;
+        let mut relabel = Event::new(
+            task,
+            &actor("bob"),
+            EventKind::LabelsRemoved {
+                labels: vec!["ui".into()],
+            },
+        );
+        relabel.lamport = 2;
+        relabel.ts = created.ts + Duration::seconds(10);
+        let mut closed = Event::new(
+            task,
+            &actor("carol"),
+            EventKind::TaskStateSet {
+                state: "state/done".into(),
+                state_kind: None,
+            },
+        );
+        closed.lamport = 3;
+        closed.ts = created.ts + Duration::seconds(20);
+        let mut retitled = Event::new(
+            task,
+            &actor("bob"),
+            EventKind::TaskTitleSet {
+                title: "Shipped".into(),
+            },
+        );
+        retitled.lamport = 4;
+        retitled.ts = created.ts + Duration::seconds(30);
+        let events = vec![retitled.clone(), closed.clone(), created.clone(), relabel.clone()];
+        (events, [created, relabel, closed, retitled])
+    }
+
+    #[test]
+    fn events_until_stops_at_timestamp_or_event() {
+        let (events, [created, relabel, closed, _]) = history();
+
+        let by_time = events_until(&events, HistoryPoint::At(relabel.ts)).unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(
+            by_time.iter().map(|e| e.id).collect::<Vec<_>>(),
+            vec![created.id, relabel.id]
+        );
+
+        let by_event =
+            events_until(&events, HistoryPoint::Event(closed.id)).unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(
+            TaskSnapshot::replay(&by_event).state.as_deref(),
+            Some("state/done")
+        );
+        assert_eq!(TaskSnapshot::replay(&by_event).title, "Ship it");
+
+        assert!(events_until(&events, HistoryPoint::Event(EventId::new())).is_err());
+        assert!("not-a-point".parse::<HistoryPoint>().is_err());
+        assert_eq!(
+            closed.id.to_string().parse::<HistoryPoint>().ok(),
+            Some(HistoryPoint::Event(closed.id))
+        );
+    }
+
+    #[test]
+    fn blame_reports_last_writer_of_each_current_field() {
+        let (events, [created, _, closed, retitled]) = history();
+
+        let entries = blame(&events);
+        let lookup = |field: BlameField| {
+            entries
+                .iter()
+                .find(|entry| entry.field == field)
+                .map(|entry| entry.event_id)
+        };
+        assert_eq!(lookup(BlameField::Title), Some(retitled.id));
+        assert_eq!(lookup(BlameField::State), Some(closed.id));
+        assert_eq!(lookup(BlameField::Label("bug".into())), Some(created.id));
+        assert_eq!(lookup(BlameField::Label("ui".into())), None);
+        assert_eq!(lookup(BlameField::Description), None);
+    }
+}
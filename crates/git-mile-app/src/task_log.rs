@@ -1,7 +1,12 @@
 //! Utility helpers for task event logs shared across interfaces.
 
-use git_mile_core::OrderedEvents;
-use git_mile_core::event::Event;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use anyhow::{Result, bail};
+use git_mile_core::event::{Event, EventKind};
+use git_mile_core::id::{EventId, TaskId};
+use git_mile_core::{OrderedEvents, TaskSnapshot};
+use time::OffsetDateTime;
 
 /// Return events ordered by lamport clock, timestamp, and event id.
 #[must_use]
@@ -9,11 +14,116 @@ pub fn ordered_events(events: &[Event]) -> Vec<Event> {
     OrderedEvents::from(events).iter().cloned().collect()
 }
 
+/// Criteria selecting events for the repository-wide activity feed.
+///
+/// Empty criteria match every event.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFilter {
+    /// Keep events at or after this instant.
+    pub since: Option<OffsetDateTime>,
+    /// Keep events at or before this instant.
+    pub until: Option<OffsetDateTime>,
+    /// Keep events whose actor name or email equals one of these (case-insensitive).
+    pub actors: BTreeSet<String>,
+    /// Keep events whose `type` tag is listed, see [`EventKind::TYPE_NAMES`].
+    pub kinds: BTreeSet<String>,
+}
+
+impl ActivityFilter {
+    /// Reject unknown event kinds and inverted time ranges.
+    ///
+    /// # Errors
+    /// Returns a user-facing error describing the first invalid criterion.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(kind) = self
+            .kinds
+            .iter()
+            .find(|kind| !EventKind::TYPE_NAMES.contains(&kind.as_str()))
+        {
+            bail!(
+                "Unknown event kind '{kind}'. Expected one of: {}",
+                EventKind::TYPE_NAMES.join(", ")
+            );
+        }
+        if let (Some(since), Some(until)) = (self.since, self.until)
+            && since > until
+        {
+            bail!("'since' must not be later than 'until'");
+        }
+        Ok(())
+    }
+
+    /// Whether `event` satisfies every criterion.
+    #[must_use]
+    pub fn matches(&self, event: &Event) -> bool {
+        self.since.is_none_or(|since| event.ts >= since)
+            && self.until.is_none_or(|until| event.ts <= until)
+            && (self.actors.is_empty()
+                || self.actors.iter().any(|actor| {
+                    actor.eq_ignore_ascii_case(&event.actor.name)
+                        || actor.eq_ignore_ascii_case(&event.actor.email)
+                }))
+            && (self.kinds.is_empty() || self.kinds.contains(event.kind.type_name()))
+    }
+}
+
+/// Events from every task merged into one ordered feed.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFeed {
+    /// Matching events ordered like [`ordered_events`].
+    pub events: Vec<Event>,
+    /// Current title of every task that appears in [`events`](Self::events).
+    pub titles: HashMap<TaskId, String>,
+}
+
+impl ActivityFeed {
+    /// Merge per-task histories into a feed of the events `filter` selects.
+    ///
+    /// Bodies of comments that were deleted later are cleared, as in
+    /// [`TaskView`](crate::TaskView), so the feed never resurfaces them.
+    #[must_use]
+    pub fn collect(histories: Vec<(TaskId, Vec<Event>)>, filter: &ActivityFilter) -> Self {
+        let mut titles = HashMap::new();
+        let mut deleted: HashSet<EventId> = HashSet::new();
+        let mut selected = Vec::new();
+        for (task, events) in histories {
+            deleted.extend(events.iter().filter_map(|event| match &event.kind {
+                EventKind::CommentDeleted { comment_id } => Some(*comment_id),
+                _ => None,
+            }));
+            let before = selected.len();
+            selected.extend(events.iter().filter(|event| filter.matches(event)).cloned());
+            if selected.len() > before {
+                titles.insert(task, TaskSnapshot::replay(&events).title);
+            }
+        }
+
+        for event in &mut selected {
+            if let EventKind::CommentAdded { comment_id, body_md }
+            | EventKind::CommentUpdated { comment_id, body_md } = &mut event.kind
+                && deleted.contains(comment_id)
+            {
+                body_md.clear();
+            }
+        }
+
+        Self {
+            events: ordered_events(&selected),
+            titles,
+        }
+    }
+
+    /// Title of `task`, or an empty string when it is not part of the feed.
+    #[must_use]
+    pub fn title(&self, task: TaskId) -> &str {
+        self.titles.get(&task).map_or("", String::as_str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use git_mile_core::event::{Actor, EventKind};
-    use git_mile_core::id::TaskId;
+    use git_mile_core::event::Actor;
     use time::Duration;
 
     #[test]
@@ -58,4 +168,80 @@ mod tests {
 
         assert_eq!(vec![second.id, third.id, first.id], ids);
     }
+
+    #[test]
+    fn activity_feed_merges_tasks_and_applies_filter() {
+        let alice = Actor {
+            name: "alice".into(),
+            email: "alice@example.invalid".into(),
+        };
+        let bob = Actor {
+            name: "bob".into(),
+            email: "bob@example.invalid".into(),
+        };
+        let created = |task, title: &str| {
+            Event::new(
+                task,
+                &alice,
+                EventKind::TaskCreated {
+                    title: title.into(),
+                    labels: vec![],
+                    assignees: vec![],
+                    description: None,
+                    state: None,
+                    state_kind: None,
+                },
+            )
+        };
+        let first = TaskId::new();
+        let second = TaskId::new();
+        let old = {
+            let mut event = created(first, "first");
+            event.ts -= Duration::days(3);
+            event
+        };
+        let comment_id = EventId::new();
+        let mut comment = Event::new(
+            second,
+            &bob,
+            EventKind::CommentAdded {
+                comment_id,
+                body_md: "secret".into(),
+            },
+        );
+        comment.lamport = 1;
+        let mut retract = Event::new(second, &alice, EventKind::CommentDeleted { comment_id });
+        retract.lamport = 2;
+        let histories = vec![
+            (first, vec![old]),
+            (second, vec![created(second, "second"), comment, retract]),
+        ];
+
+        let filter = ActivityFilter {
+            since: Some(OffsetDateTime::now_utc() - Duration::days(1)),
+            actors: BTreeSet::from(["BOB@example.invalid".to_owned()]),
+            ..ActivityFilter::default()
+        };
+        let feed = ActivityFeed::collect(histories, &filter);
+
+        assert_eq!(feed.events.len(), 1);
+        assert!(
+            matches!(&feed.events[0].kind, EventKind::CommentAdded { body_md, .. } if body_md.is_empty())
+        );
+        assert_eq!(feed.title(second), "second");
+        assert_eq!(feed.title(first), "");
+    }
+
+    #[test]
+    fn activity_filter_rejects_unknown_kinds() {
+        let filter = ActivityFilter {
+            kinds: BTreeSet::from(["stateChanged".to_owned()]),
+            ..ActivityFilter::default()
+        };
+        let err = filter
+            .validate()
+            .err()
+            .unwrap_or_else(|| panic!("unknown kind must fail"));
+        assert!(err.to_string().contains("taskStateSet"));
+    }
 }
@@ -1,6 +1,7 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use git_mile_core::TaskSnapshot;
+use git_mile_core::id::MilestoneId;
 
 /// Difference between two sets.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -19,6 +20,27 @@ impl<T> SetDiff<T> {
     }
 }
 
+impl<T: Ord + Clone> SetDiff<T> {
+    /// Keep only additions missing from `current` and removals present in it.
+    #[must_use]
+    pub fn narrowed_to(&self, current: &BTreeSet<T>) -> Self {
+        Self {
+            added: self
+                .added
+                .iter()
+                .filter(|value| !current.contains(*value))
+                .cloned()
+                .collect(),
+            removed: self
+                .removed
+                .iter()
+                .filter(|value| current.contains(*value))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
 /// Compute differences between two sets.
 #[must_use]
 pub fn diff_sets<T: Ord + Clone>(current: &BTreeSet<T>, desired: &BTreeSet<T>) -> SetDiff<T> {
@@ -52,6 +74,18 @@ pub enum DescriptionPatch {
     Clear,
 }
 
+/// Patch for the milestone a task is planned for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MilestonePatch {
+    /// Move the task to the milestone.
+    Set {
+        /// Milestone identifier.
+        milestone: MilestoneId,
+    },
+    /// Remove the task from its milestone.
+    Clear,
+}
+
 /// Aggregate task update payload shared by frontends.
 #[derive(Debug, Clone, Default)]
 pub struct TaskUpdate {
@@ -65,17 +99,64 @@ pub struct TaskUpdate {
     pub labels: SetDiff<String>,
     /// Assignee diffs.
     pub assignees: SetDiff<String>,
+    /// Custom field assignments; `None` clears the field.
+    pub fields: BTreeMap<String, Option<String>>,
+    /// Patch applied to the milestone.
+    pub milestone: Option<MilestonePatch>,
+    /// New queue rank; `Some(None)` drops the task from the queue.
+    pub rank: Option<Option<String>>,
 }
 
 impl TaskUpdate {
     /// Returns true when the update would not emit any events.
     #[must_use]
-    pub const fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.title.is_none()
             && self.state.is_none()
             && self.description.is_none()
             && self.labels.is_empty()
             && self.assignees.is_empty()
+            && self.fields.is_empty()
+            && self.milestone.is_none()
+            && self.rank.is_none()
+    }
+
+    /// Drop the parts of the update that `snapshot` already satisfies.
+    ///
+    /// Bulk edits apply one update to many tasks; narrowing it per task keeps
+    /// tasks that already match from receiving redundant events.
+    #[must_use]
+    pub fn narrowed_to(&self, snapshot: &TaskSnapshot) -> Self {
+        let state = match &self.state {
+            Some(StatePatch::Set { state }) if snapshot.state.as_ref() == Some(state) => None,
+            Some(StatePatch::Clear) if snapshot.state.is_none() => None,
+            other => other.clone(),
+        };
+        let description = match &self.description {
+            Some(DescriptionPatch::Set { description }) if *description == snapshot.description => None,
+            Some(DescriptionPatch::Clear) if snapshot.description.is_empty() => None,
+            other => other.clone(),
+        };
+        let milestone = match &self.milestone {
+            Some(MilestonePatch::Set { milestone }) if snapshot.milestone == Some(*milestone) => None,
+            Some(MilestonePatch::Clear) if snapshot.milestone.is_none() => None,
+            other => other.clone(),
+        };
+        Self {
+            title: self.title.clone().filter(|title| *title != snapshot.title),
+            state,
+            description,
+            labels: self.labels.narrowed_to(&snapshot.labels),
+            assignees: self.assignees.narrowed_to(&snapshot.assignees),
+            fields: self
+                .fields
+                .iter()
+                .filter(|(name, value)| snapshot.fields.get(*name) != value.as_ref())
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            milestone,
+            rank: self.rank.clone().filter(|rank| *rank != snapshot.rank),
+        }
     }
 }
 
@@ -92,6 +173,8 @@ pub struct TaskEditData {
     pub assignees: Vec<String>,
     /// Desired description body (`None` leaves unchanged, `Some("")` clears).
     pub description: Option<String>,
+    /// Desired custom field values (`None` leaves fields unchanged; missing names are cleared).
+    pub fields: Option<BTreeMap<String, String>>,
 }
 
 #[allow(clippy::missing_const_for_fn)]
@@ -111,8 +194,16 @@ impl TaskEditData {
             labels,
             assignees,
             description,
+            fields: None,
         }
     }
+
+    /// Also replace the custom fields with `fields`.
+    #[must_use]
+    pub fn with_fields(mut self, fields: BTreeMap<String, String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
 
 /// Diff between a snapshot and target fields.
@@ -128,6 +219,8 @@ pub struct TaskPatch {
     pub labels: SetDiff<String>,
     /// Assignee additions/removals.
     pub assignees: SetDiff<String>,
+    /// Custom field assignments (`None` clears).
+    pub fields: BTreeMap<String, Option<String>>,
 }
 
 #[allow(clippy::missing_const_for_fn)]
@@ -141,6 +234,7 @@ impl TaskPatch {
             labels,
             assignees,
             description,
+            fields,
         } = data;
 
         let mut patch = Self::default();
@@ -179,6 +273,21 @@ impl TaskPatch {
             },
         );
 
+        if let Some(fields) = fields {
+            patch.fields = snapshot
+                .fields
+                .keys()
+                .filter(|name| !fields.contains_key(*name))
+                .map(|name| (name.clone(), None))
+                .collect();
+            patch.fields.extend(
+                fields
+                    .into_iter()
+                    .filter(|(name, value)| snapshot.fields.get(name) != Some(value))
+                    .map(|(name, value)| (name, Some(value))),
+            );
+        }
+
         patch
     }
 
@@ -190,6 +299,7 @@ impl TaskPatch {
             && self.description.is_none()
             && self.labels.is_empty()
             && self.assignees.is_empty()
+            && self.fields.is_empty()
     }
 
     /// Convert the patch into a [`TaskUpdate`] consumable by [`TaskWriter`](crate::task_writer::TaskWriter).
@@ -201,6 +311,9 @@ impl TaskPatch {
             description: self.description,
             labels: self.labels,
             assignees: self.assignees,
+            fields: self.fields,
+            milestone: None,
+            rank: None,
         }
     }
 }
@@ -295,6 +408,29 @@ mod tests {
         assert_eq!(patch.assignees.added, vec!["bob"]);
     }
 
+    #[test]
+    fn patch_sets_changed_fields_and_clears_missing_ones() {
+        let mut snapshot = snapshot_with("Title", Some("state/todo"), "");
+        snapshot.fields.insert("priority".into(), "high".into());
+        snapshot.fields.insert("due".into(), "2025-01-31".into());
+
+        let unchanged = TaskPatch::from_snapshot(&snapshot, default_data());
+        assert!(unchanged.fields.is_empty());
+
+        let data = default_data().with_fields(BTreeMap::from([
+            ("priority".to_owned(), "high".to_owned()),
+            ("estimate".to_owned(), "3".to_owned()),
+        ]));
+        let patch = TaskPatch::from_snapshot(&snapshot, data);
+        assert_eq!(
+            patch.fields,
+            BTreeMap::from([
+                ("due".to_owned(), None),
+                ("estimate".to_owned(), Some("3".to_owned())),
+            ])
+        );
+    }
+
     #[test]
     fn diff_sets_detects_added_and_removed_items() {
         let current = BTreeSet::from(["a".to_string(), "b".to_string()]);
@@ -304,4 +440,51 @@ mod tests {
         assert_eq!(diff.added, vec!["c"]);
         assert_eq!(diff.removed, vec!["a"]);
     }
+
+    #[test]
+    fn update_narrows_to_fields_the_snapshot_does_not_match() {
+        let mut snapshot = snapshot_with("Title", Some("state/todo"), "");
+        snapshot.labels.insert("a".into());
+        snapshot.fields.insert("priority".into(), "high".into());
+
+        let update = TaskUpdate {
+            title: Some("Title".into()),
+            state: Some(StatePatch::Set {
+                state: "state/todo".into(),
+            }),
+            description: Some(DescriptionPatch::Clear),
+            labels: SetDiff {
+                added: vec!["a".into(), "b".into()],
+                removed: vec!["a".into(), "c".into()],
+            },
+            assignees: SetDiff::default(),
+            fields: BTreeMap::from([
+                ("priority".to_owned(), Some("high".to_owned())),
+                ("due".to_owned(), None),
+                ("estimate".to_owned(), Some("3".to_owned())),
+            ]),
+            milestone: Some(MilestonePatch::Clear),
+            rank: Some(None),
+        };
+        let narrowed = update.narrowed_to(&snapshot);
+        assert_eq!(
+            narrowed.fields,
+            BTreeMap::from([("estimate".to_owned(), Some("3".to_owned()))])
+        );
+        assert!(narrowed.title.is_none());
+        assert!(narrowed.state.is_none());
+        assert!(narrowed.description.is_none());
+        assert!(narrowed.milestone.is_none());
+        assert!(narrowed.rank.is_none());
+        assert_eq!(narrowed.labels.added, vec!["b"]);
+        assert_eq!(narrowed.labels.removed, vec!["a"]);
+
+        let already_done = TaskUpdate {
+            state: Some(StatePatch::Set {
+                state: "state/todo".into(),
+            }),
+            ..TaskUpdate::default()
+        };
+        assert!(already_done.narrowed_to(&snapshot).is_empty());
+    }
 }
@@ -1,10 +1,14 @@
 //! Task repository with caching for efficient snapshot access.
 
 use anyhow::{Context, Result, anyhow};
-use git_mile_core::{TaskFilter, TaskSnapshot, event::Event, id::TaskId};
+use git_mile_core::id::{TaskId, TaskIdAbbreviations};
+use git_mile_core::{TaskFilter, TaskSnapshot, event::Event};
+use git2::Oid;
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use time::OffsetDateTime;
 
+use crate::ready_work::ReadyWork;
 use crate::task_cache::{TaskCache, TaskView};
 use crate::task_log::ordered_events;
 use crate::task_writer::TaskStore;
@@ -18,6 +22,51 @@ pub struct TaskRepository<S> {
 struct CacheState {
     cache: TaskCache,
     last_refresh: Option<OffsetDateTime>,
+    tips: Option<TaskTips>,
+}
+
+/// History tips observed at the last refresh, keyed by task.
+#[derive(Debug, Default)]
+pub(crate) struct TaskTips(HashMap<TaskId, Oid>);
+
+/// Tasks whose tip differs between two [`TaskTips`] observations.
+#[derive(Debug, Default)]
+pub(crate) struct TipChanges {
+    /// Tasks that are new or whose tip moved.
+    pub(crate) changed: Vec<TaskId>,
+    /// Tasks that no longer exist.
+    pub(crate) removed: Vec<TaskId>,
+}
+
+impl TaskTips {
+    pub(crate) fn new(tips: Vec<(TaskId, Oid)>) -> Self {
+        Self(tips.into_iter().collect())
+    }
+
+    /// Compare against an earlier observation; `None` treats every task as changed.
+    pub(crate) fn changes_since(&self, previous: Option<&Self>) -> TipChanges {
+        let empty = HashMap::new();
+        let previous = previous.map_or(&empty, |tips| &tips.0);
+        TipChanges {
+            changed: self
+                .0
+                .iter()
+                .filter(|(task, tip)| previous.get(task) != Some(tip))
+                .map(|(task, _)| *task)
+                .collect(),
+            removed: previous
+                .keys()
+                .filter(|task| !self.0.contains_key(task))
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+impl TipChanges {
+    pub(crate) const fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty()
+    }
 }
 
 impl<S: TaskStore> TaskRepository<S> {
@@ -28,12 +77,18 @@ impl<S: TaskStore> TaskRepository<S> {
             cache: Arc::new(RwLock::new(CacheState {
                 cache: TaskCache::default(),
                 last_refresh: None,
+                tips: None,
             })),
         }
     }
 
     /// Refresh the cache if it's stale.
     ///
+    /// Stores that report history tips are diffed against the tips seen at the
+    /// previous refresh, so pulled, merged and deleted tasks are picked up even
+    /// when their events are older than anything cached. Other stores fall back
+    /// to [`TaskStore::list_tasks_modified_since`].
+    ///
     /// # Errors
     /// Returns an error if loading tasks from the store fails.
     pub fn refresh_if_stale(&self) -> Result<()> {
@@ -51,6 +106,11 @@ impl<S: TaskStore> TaskRepository<S> {
 
         match plan {
             RefreshPlan::Full => {
+                let tips = self
+                    .store
+                    .list_task_tips()
+                    .map_err(Into::into)?
+                    .map(TaskTips::new);
                 let cache = TaskCache::load(&*self.store).map_err(Into::into)?;
                 let mut state = self.cache.write().map_err(|_| anyhow!("Failed to lock cache"))?;
                 let latest_ts = cache
@@ -60,8 +120,12 @@ impl<S: TaskStore> TaskRepository<S> {
                     .unwrap_or(OffsetDateTime::UNIX_EPOCH);
                 state.cache = cache;
                 state.last_refresh = Some(latest_ts);
+                state.tips = tips;
             }
             RefreshPlan::Incremental(last_refresh) => {
+                if let Some(tips) = self.store.list_task_tips().map_err(Into::into)? {
+                    return self.refresh_changed_tips(TaskTips::new(tips), last_refresh);
+                }
                 let modified = self
                     .store
                     .list_tasks_modified_since(last_refresh)
@@ -88,19 +152,32 @@ impl<S: TaskStore> TaskRepository<S> {
 
     /// List all task snapshots, optionally filtered.
     ///
+    /// Abbreviated ids in the filter's query are resolved against the cached tasks first.
+    ///
     /// # Errors
-    /// Returns an error if refreshing the cache fails.
+    /// Returns an error if refreshing the cache fails or a query id prefix is unknown or ambiguous.
     pub fn list_snapshots(&self, filter: Option<&TaskFilter>) -> Result<Vec<TaskSnapshot>> {
         self.refresh_if_stale()?;
 
         let state = self.cache.read().map_err(|_| anyhow!("Failed to lock cache"))?;
+        let filter = filter.map(|f| state.cache.resolve_filter(f)).transpose()?;
 
         Ok(filter.map_or_else(
             || state.cache.snapshots().cloned().collect(),
-            |f| state.cache.filtered_snapshots(f),
+            |f| state.cache.filtered_snapshots(&f),
         ))
     }
 
+    /// Compute the tasks that can be started now. See [`TaskCache::ready_work`].
+    ///
+    /// # Errors
+    /// Returns an error if refreshing the cache fails.
+    pub fn ready_work(&self) -> Result<ReadyWork> {
+        self.refresh_if_stale()?;
+        let state = self.cache.read().map_err(|_| anyhow!("Failed to lock cache"))?;
+        Ok(state.cache.ready_work())
+    }
+
     /// Get a single task snapshot by ID.
     ///
     /// # Errors
@@ -168,6 +245,23 @@ impl<S: TaskStore> TaskRepository<S> {
         Ok(state.cache.parents_of(task_id))
     }
 
+    /// Resolve a full or abbreviated task id.
+    ///
+    /// # Errors
+    /// Returns an error if the id is malformed, unknown or ambiguous, or listing tasks fails.
+    pub fn resolve_task_id(&self, input: &str) -> Result<TaskId> {
+        self.store.resolve_task_id(input)
+    }
+
+    /// Shortest unique id prefixes for the tasks loaded by the last refresh.
+    ///
+    /// # Errors
+    /// Returns an error if the cache lock cannot be acquired.
+    pub fn id_abbreviations(&self) -> Result<TaskIdAbbreviations> {
+        let state = self.cache.read().map_err(|_| anyhow!("Failed to lock cache"))?;
+        Ok(TaskIdAbbreviations::new(state.cache.task_index.keys().copied()))
+    }
+
     /// Clear the cache, forcing a full reload on next access.
     ///
     /// # Errors
@@ -177,6 +271,7 @@ impl<S: TaskStore> TaskRepository<S> {
 
         state.cache = TaskCache::default();
         state.last_refresh = None;
+        state.tips = None;
         drop(state);
         Ok(())
     }
@@ -193,6 +288,34 @@ impl<S: TaskStore> TaskRepository<S> {
         Ok(state.cache.clone())
     }
 
+    fn refresh_changed_tips(&self, current: TaskTips, last_refresh: OffsetDateTime) -> Result<()> {
+        let changes = {
+            let state = self.cache.read().map_err(|_| anyhow!("Failed to lock cache"))?;
+            current.changes_since(state.tips.as_ref())
+        };
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        self.store
+            .invalidate_cache(&changes.changed)
+            .map_err(Into::into)?;
+        let updated_views = self.load_task_views(&changes.changed)?;
+        let latest_seen = updated_views
+            .iter()
+            .filter_map(|view| view.last_updated)
+            .max()
+            .unwrap_or(last_refresh);
+        let mut state = self.cache.write().map_err(|_| anyhow!("Failed to lock cache"))?;
+        state.cache.remove_views(&changes.removed);
+        state.cache.upsert_views(updated_views);
+        let previous = state.last_refresh.unwrap_or(last_refresh);
+        state.last_refresh = Some(previous.max(latest_seen));
+        state.tips = Some(current);
+        drop(state);
+        Ok(())
+    }
+
     fn load_task_views(&self, task_ids: &[TaskId]) -> Result<Vec<TaskView>> {
         let batches = self
             .store
@@ -283,6 +406,121 @@ mod tests {
         assert!(repo.cache.read().expect("read cache").last_refresh.is_some());
     }
 
+    fn tester() -> Actor {
+        Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        }
+    }
+
+    fn init_with_origin(root: &std::path::Path, name: &str, remote: &std::path::Path) -> std::path::PathBuf {
+        let path = root.join(name);
+        let repo = git2::Repository::init(&path).expect("init git repo");
+        repo.remote("origin", &format!("file://{}", remote.display()))
+            .expect("add remote");
+        path
+    }
+
+    /// Commit an event the way another clone would have recorded it at `event.ts`.
+    fn append_backdated(repo_path: &std::path::Path, event: &Event) {
+        let repo = git2::Repository::open(repo_path).expect("open repo");
+        let refname = format!("refs/git-mile/tasks/{}", event.task);
+        let time = git2::Time::new(event.ts.unix_timestamp(), 0);
+        let sig = git2::Signature::new(&event.actor.name, &event.actor.email, &time).expect("signature");
+        let mut index = repo.index().expect("index");
+        index.clear().expect("clear index");
+        let tree = repo
+            .find_tree(index.write_tree().expect("write tree"))
+            .expect("find tree");
+        let parent = repo
+            .find_reference(&refname)
+            .ok()
+            .and_then(|reference| reference.peel_to_commit().ok());
+        let parents: Vec<_> = parent.iter().collect();
+        let body = serde_json::to_string_pretty(event).expect("serialize event");
+        let message = format!("git-mile-event: {}\n\n{body}", event.id);
+        repo.commit(Some(&refname), &sig, &sig, &message, &tree, &parents)
+            .expect("commit event");
+    }
+
+    #[test]
+    fn refresh_detects_pulled_refs_with_old_timestamps() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let remote = temp_dir.path().join("remote.git");
+        git2::Repository::init_bare(&remote).expect("init remote");
+        let local = init_with_origin(temp_dir.path(), "local", &remote);
+        let peer = init_with_origin(temp_dir.path(), "peer", &remote);
+
+        let shared = TaskId::new();
+        let local_store = GitStore::open(&local).expect("open local store");
+        local_store
+            .append_event(&Event::new(
+                shared,
+                &tester(),
+                EventKind::TaskCreated {
+                    title: "shared".into(),
+                    labels: vec![],
+                    assignees: vec![],
+                    description: None,
+                    state: None,
+                    state_kind: None,
+                },
+            ))
+            .expect("append local event");
+        local_store.push_refs("origin", false).expect("push shared task");
+        GitStore::open(&peer)
+            .expect("open peer store")
+            .pull_refs("origin")
+            .expect("peer pulls shared task");
+
+        let repo = TaskRepository::new(Arc::new(local_store));
+        assert_eq!(repo.list_snapshots(None).expect("initial load").len(), 1);
+
+        let old_task = TaskId::new();
+        append_backdated(&peer, &mock_event(old_task, "old", 1_000_000));
+        let mut renamed = Event::new(
+            shared,
+            &tester(),
+            EventKind::TaskTitleSet {
+                title: "renamed offline".into(),
+            },
+        );
+        renamed.ts = OffsetDateTime::from_unix_timestamp(1_000_001).expect("valid timestamp");
+        renamed.lamport = 2;
+        append_backdated(&peer, &renamed);
+        let peer_store = GitStore::open(&peer).expect("open peer store");
+        peer_store.push_refs("origin", false).expect("peer pushes");
+
+        let summary = GitStore::open(&local)
+            .expect("open second local store")
+            .pull_refs("origin")
+            .expect("local pulls");
+        assert_eq!((summary.created, summary.fast_forwarded), (1, 1));
+
+        let titles: HashMap<_, _> = repo
+            .list_snapshots(None)
+            .expect("refresh after pull")
+            .into_iter()
+            .map(|snapshot| (snapshot.id, snapshot.title))
+            .collect();
+        assert_eq!(titles.get(&old_task).map(String::as_str), Some("old"));
+        assert_eq!(titles.get(&shared).map(String::as_str), Some("renamed offline"));
+
+        git2::Repository::open(&local)
+            .expect("open local repo")
+            .find_reference(&format!("refs/git-mile/tasks/{old_task}"))
+            .expect("pulled ref")
+            .delete()
+            .expect("delete ref");
+        let ids: Vec<_> = repo
+            .list_snapshots(None)
+            .expect("refresh after delete")
+            .into_iter()
+            .map(|snapshot| snapshot.id)
+            .collect();
+        assert_eq!(ids, vec![shared]);
+    }
+
     #[derive(Default)]
     struct MockStore {
         inner: Mutex<MockStoreInner>,
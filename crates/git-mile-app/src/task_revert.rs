@@ -0,0 +1,453 @@
+//! Compensating changes that undo a recorded event.
+
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Range;
+
+use git_mile_core::event::{Actor, Event, EventKind};
+use git_mile_core::id::{EventId, TaskId};
+use git_mile_core::{RelationKind, TaskSnapshot};
+use time::OffsetDateTime;
+
+use crate::task_patch::{DescriptionPatch, MilestonePatch, SetDiff, StatePatch, TaskUpdate};
+
+/// Writer operation that reverses the effect of one event.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum Revert {
+    /// The event changed nothing, so there is nothing to undo.
+    Noop,
+    /// Restore fields through a regular task update.
+    Update(TaskUpdate),
+    /// Link (`link == true`) or unlink `child` under `parent`.
+    Parent {
+        /// Parent side of the link.
+        parent: TaskId,
+        /// Child side of the link.
+        child: TaskId,
+        /// Whether the link must be re-created.
+        link: bool,
+    },
+    /// Add (`link == true`) or remove a relation from the reverted task to `target`.
+    Relation {
+        /// Relation kind as seen from the reverted task.
+        kind: RelationKind,
+        /// Other end of the relation.
+        target: TaskId,
+        /// Whether the relation must be re-created.
+        link: bool,
+    },
+    /// Archive (`true`) or restore (`false`) the task.
+    Archive(bool),
+    /// Retract a comment.
+    DeleteComment(EventId),
+}
+
+impl Revert {
+    /// Combine the undo of two events written together; `None` when they cannot form one write.
+    fn merge(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Self::Noop, other) | (other, Self::Noop) => Some(other),
+            (Self::Update(first), Self::Update(second)) => Some(Self::Update(merge_updates(first, second))),
+            _ => None,
+        }
+    }
+}
+
+/// Union of two updates touching different fields.
+fn merge_updates(mut first: TaskUpdate, second: TaskUpdate) -> TaskUpdate {
+    first.title = second.title.or(first.title);
+    first.state = second.state.or(first.state);
+    first.description = second.description.or(first.description);
+    first.labels.added.extend(second.labels.added);
+    first.labels.removed.extend(second.labels.removed);
+    first.assignees.added.extend(second.assignees.added);
+    first.assignees.removed.extend(second.assignees.removed);
+    first.fields.extend(second.fields);
+    first.milestone = second.milestone.or(first.milestone);
+    first.rank = second.rank.or(first.rank);
+    first
+}
+
+/// Operation undoing `kind`, given the task as it was right before the event.
+///
+/// Only the part of the event that actually changed the task is reversed: reverting
+/// `LabelsAdded` keeps labels that were already present. Returns `None` for events that
+/// have no inverse, such as task creation, comment edits and deletions, and reactions
+/// (which belong to the reacting actor).
+///
+/// Fields are restored to their value before the event regardless of what happened since,
+/// so check [`overwritten_by`] first to avoid discarding later changes.
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub fn compensation(before: &TaskSnapshot, kind: &EventKind) -> Option<Revert> {
+    let update = |update: TaskUpdate| {
+        Some(if update.is_empty() {
+            Revert::Noop
+        } else {
+            Revert::Update(update)
+        })
+    };
+    match kind {
+        EventKind::TaskTitleSet { title } if *title == before.title => Some(Revert::Noop),
+        EventKind::TaskTitleSet { .. } => update(TaskUpdate {
+            title: Some(before.title.clone()),
+            ..TaskUpdate::default()
+        }),
+        EventKind::TaskStateSet { state, .. } if before.state.as_ref() == Some(state) => Some(Revert::Noop),
+        EventKind::TaskStateCleared if before.state.is_none() => Some(Revert::Noop),
+        EventKind::TaskStateSet { .. } | EventKind::TaskStateCleared => update(TaskUpdate {
+            state: Some(
+                before
+                    .state
+                    .clone()
+                    .map_or(StatePatch::Clear, |state| StatePatch::Set { state }),
+            ),
+            ..TaskUpdate::default()
+        }),
+        EventKind::TaskDescriptionSet { description }
+            if description.as_deref().unwrap_or_default() == before.description =>
+        {
+            Some(Revert::Noop)
+        }
+        EventKind::TaskDescriptionSet { .. } => update(TaskUpdate {
+            description: Some(if before.description.is_empty() {
+                DescriptionPatch::Clear
+            } else {
+                DescriptionPatch::Set {
+                    description: before.description.clone(),
+                }
+            }),
+            ..TaskUpdate::default()
+        }),
+        EventKind::LabelsAdded { labels } => update(TaskUpdate {
+            labels: SetDiff {
+                added: Vec::new(),
+                removed: changed(labels, |label| !before.labels.contains(label)),
+            },
+            ..TaskUpdate::default()
+        }),
+        EventKind::LabelsRemoved { labels } => update(TaskUpdate {
+            labels: SetDiff {
+                added: changed(labels, |label| before.labels.contains(label)),
+                removed: Vec::new(),
+            },
+            ..TaskUpdate::default()
+        }),
+        EventKind::AssigneesAdded { assignees } => update(TaskUpdate {
+            assignees: SetDiff {
+                added: Vec::new(),
+                removed: changed(assignees, |assignee| !before.assignees.contains(assignee)),
+            },
+            ..TaskUpdate::default()
+        }),
+        EventKind::AssigneesRemoved { assignees } => update(TaskUpdate {
+            assignees: SetDiff {
+                added: changed(assignees, |assignee| before.assignees.contains(assignee)),
+                removed: Vec::new(),
+            },
+            ..TaskUpdate::default()
+        }),
+        EventKind::ChildLinked { parent, child } | EventKind::ChildUnlinked { parent, child } => {
+            let link = matches!(kind, EventKind::ChildUnlinked { .. });
+            let was_linked = before.parents.contains(parent) || before.children.contains(child);
+            Some(if was_linked == link {
+                Revert::Parent {
+                    parent: *parent,
+                    child: *child,
+                    link,
+                }
+            } else {
+                Revert::Noop
+            })
+        }
+        EventKind::RelationAdded { kind: key, target } | EventKind::RelationRemoved { kind: key, target } => {
+            let relation = key.parse::<RelationKind>().ok()?;
+            let link = matches!(kind, EventKind::RelationRemoved { .. });
+            let was_related = before
+                .relates
+                .get(key)
+                .is_some_and(|targets| targets.contains(target));
+            Some(if was_related == link {
+                Revert::Relation {
+                    kind: relation,
+                    target: *target,
+                    link,
+                }
+            } else {
+                Revert::Noop
+            })
+        }
+        EventKind::TaskArchived | EventKind::TaskUnarchived => {
+            let archived = matches!(kind, EventKind::TaskArchived);
+            Some(if before.archived == archived {
+                Revert::Noop
+            } else {
+                Revert::Archive(before.archived)
+            })
+        }
+        EventKind::FieldSet { name, value } if before.fields.get(name) == Some(value) => Some(Revert::Noop),
+        EventKind::FieldCleared { name } if !before.fields.contains_key(name) => Some(Revert::Noop),
+        EventKind::FieldSet { name, .. } | EventKind::FieldCleared { name } => update(TaskUpdate {
+            fields: BTreeMap::from([(name.clone(), before.fields.get(name).cloned())]),
+            ..TaskUpdate::default()
+        }),
+        EventKind::MilestoneSet { milestone } if before.milestone == Some(*milestone) => Some(Revert::Noop),
+        EventKind::MilestoneCleared if before.milestone.is_none() => Some(Revert::Noop),
+        EventKind::MilestoneSet { .. } | EventKind::MilestoneCleared => update(TaskUpdate {
+            milestone: Some(before.milestone.map_or(MilestonePatch::Clear, |milestone| {
+                MilestonePatch::Set { milestone }
+            })),
+            ..TaskUpdate::default()
+        }),
+        EventKind::TaskRankSet { rank } if *rank == before.rank => Some(Revert::Noop),
+        EventKind::TaskRankSet { .. } => update(TaskUpdate {
+            rank: Some(before.rank.clone()),
+            ..TaskUpdate::default()
+        }),
+        EventKind::CommentAdded { comment_id, .. } => Some(Revert::DeleteComment(*comment_id)),
+        EventKind::TaskCreated { .. }
+        | EventKind::CommentUpdated { .. }
+        | EventKind::CommentDeleted { .. }
+        | EventKind::CommentReactionAdded { .. }
+        | EventKind::CommentReactionRemoved { .. } => None,
+    }
+}
+
+/// Operation undoing every event of `batch` (one commit), given the task right before it.
+///
+/// Multi-field updates are written as one commit, so they are undone together as one
+/// [`TaskUpdate`].
+///
+/// # Errors
+/// Returns the first event that has no inverse or cannot be undone together with the others.
+pub fn batch_compensation<'a>(before: &TaskSnapshot, batch: &'a [Event]) -> Result<Revert, &'a Event> {
+    let mut revert = Revert::Noop;
+    for event in batch {
+        revert = compensation(before, &event.kind)
+            .and_then(|next| revert.merge(next))
+            .ok_or(event)?;
+    }
+    Ok(revert)
+}
+
+/// Position range of the events written in the same commit as `history[idx]`.
+///
+/// `history` must be in [`ordered_events`](crate::ordered_events) order. Events of one commit
+/// share the task, Lamport stamp and timestamp, so they sit next to each other.
+#[must_use]
+pub fn batch_range(history: &[Event], idx: usize) -> Range<usize> {
+    let Some(target) = history.get(idx) else {
+        return idx..idx;
+    };
+    let key = batch_key(target);
+    let start = history[..idx]
+        .iter()
+        .rposition(|event| batch_key(event) != key)
+        .map_or(0, |pos| pos + 1);
+    let end = history[idx..]
+        .iter()
+        .position(|event| batch_key(event) != key)
+        .map_or(history.len(), |pos| idx + pos);
+    start..end
+}
+
+/// First event of `later` that changed part of the task `batch` also changed.
+///
+/// [`compensation`] restores the value from before `batch`, so reverting it while such an
+/// event exists would silently discard that later change.
+#[must_use]
+pub fn overwritten_by<'a>(batch: &[Event], later: &'a [Event]) -> Option<&'a Event> {
+    let changed: Vec<Touched> = batch.iter().flat_map(|event| touched(&event.kind)).collect();
+    later
+        .iter()
+        .find(|event| touched(&event.kind).iter().any(|part| changed.contains(part)))
+}
+
+/// Part of a task an event changes, for [`overwritten_by`].
+#[derive(Debug, PartialEq, Eq)]
+enum Touched {
+    Title,
+    State,
+    Description,
+    Label(String),
+    Assignee(String),
+    Parent(TaskId, TaskId),
+    Relation(String, TaskId),
+    Archived,
+    Field(String),
+    Milestone,
+    Rank,
+    Comment(EventId),
+}
+
+fn touched(kind: &EventKind) -> Vec<Touched> {
+    match kind {
+        EventKind::TaskTitleSet { .. } => vec![Touched::Title],
+        EventKind::TaskStateSet { .. } | EventKind::TaskStateCleared => vec![Touched::State],
+        EventKind::TaskDescriptionSet { .. } => vec![Touched::Description],
+        EventKind::LabelsAdded { labels } | EventKind::LabelsRemoved { labels } => {
+            labels.iter().cloned().map(Touched::Label).collect()
+        }
+        EventKind::AssigneesAdded { assignees } | EventKind::AssigneesRemoved { assignees } => {
+            assignees.iter().cloned().map(Touched::Assignee).collect()
+        }
+        EventKind::ChildLinked { parent, child } | EventKind::ChildUnlinked { parent, child } => {
+            vec![Touched::Parent(*parent, *child)]
+        }
+        EventKind::RelationAdded { kind, target } | EventKind::RelationRemoved { kind, target } => {
+            vec![Touched::Relation(kind.clone(), *target)]
+        }
+        EventKind::TaskArchived | EventKind::TaskUnarchived => vec![Touched::Archived],
+        EventKind::FieldSet { name, .. } | EventKind::FieldCleared { name } => {
+            vec![Touched::Field(name.clone())]
+        }
+        EventKind::MilestoneSet { .. } | EventKind::MilestoneCleared => vec![Touched::Milestone],
+        EventKind::TaskRankSet { .. } => vec![Touched::Rank],
+        EventKind::CommentAdded { comment_id, .. }
+        | EventKind::CommentUpdated { comment_id, .. }
+        | EventKind::CommentDeleted { comment_id }
+        | EventKind::CommentReactionAdded { comment_id, .. }
+        | EventKind::CommentReactionRemoved { comment_id, .. } => vec![Touched::Comment(*comment_id)],
+        EventKind::TaskCreated { .. } => Vec::new(),
+    }
+}
+
+/// Identity of the commit an event was written in.
+const fn batch_key(event: &Event) -> (TaskId, u64, OffsetDateTime) {
+    (event.task, event.lamport, event.ts)
+}
+
+/// Values of an event payload that satisfy `changed`, without duplicates.
+fn changed(values: &[String], changed: impl Fn(&String) -> bool) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for value in values {
+        if changed(value) && !out.contains(value) {
+            out.push(value.clone());
+        }
+    }
+    out
+}
+
+/// Most recent event `actor` authored across `histories`, matched by e-mail address.
+///
+/// Compensation events and commits that have already been reverted are skipped, so
+/// repeated undos walk back through the actor's changes instead of redoing them.
+#[must_use]
+pub fn latest_event_by<'a>(histories: &'a [(TaskId, Vec<Event>)], actor: &Actor) -> Option<&'a Event> {
+    let events = || histories.iter().flat_map(|(_, events)| events);
+    let reverted_ids: HashSet<EventId> = events().filter_map(|event| event.reverts).collect();
+    let reverted: HashSet<_> = events()
+        .filter(|event| reverted_ids.contains(&event.id))
+        .map(batch_key)
+        .collect();
+    events()
+        .filter(|event| event.actor.email.eq_ignore_ascii_case(&actor.email))
+        .filter(|event| event.reverts.is_none() && !reverted.contains(&batch_key(event)))
+        .max_by_key(|event| (event.ts, event.lamport, event.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn before() -> TaskSnapshot {
+        let mut snapshot = TaskSnapshot::default();
+        snapshot.title = "Old title".into();
+        snapshot.state = Some("state/todo".into());
+        snapshot.labels = BTreeSet::from(["keep".to_owned()]);
+        snapshot
+    }
+
+    #[test]
+    fn compensation_restores_prior_field_values() {
+        let before = before();
+        let title = compensation(&before, &EventKind::TaskTitleSet { title: "New".into() });
+        assert!(matches!(title, Some(Revert::Update(TaskUpdate { title: Some(t), .. })) if t == "Old title"));
+
+        let state = compensation(
+            &before,
+            &EventKind::TaskStateSet {
+                state: "state/done".into(),
+                state_kind: None,
+            },
+        );
+        assert!(matches!(
+            state,
+            Some(Revert::Update(TaskUpdate { state: Some(StatePatch::Set { state }), .. })) if state == "state/todo"
+        ));
+
+        let cleared = compensation(&TaskSnapshot::default(), &EventKind::TaskStateCleared);
+        assert!(matches!(cleared, Some(Revert::Noop)));
+
+        let Some(Revert::Update(labels)) = compensation(
+            &before,
+            &EventKind::LabelsAdded {
+                labels: vec!["keep".into(), "new".into(), "new".into()],
+            },
+        ) else {
+            panic!("expected a label update");
+        };
+        assert!(labels.labels.added.is_empty());
+        assert_eq!(labels.labels.removed, vec!["new".to_owned()]);
+
+        let Some(Revert::Update(field)) = compensation(
+            &before,
+            &EventKind::FieldSet {
+                name: "priority".into(),
+                value: "high".into(),
+            },
+        ) else {
+            panic!("expected a field update");
+        };
+        assert_eq!(field.fields, BTreeMap::from([("priority".to_owned(), None)]));
+
+        let Some(Revert::Update(milestone)) = compensation(
+            &before,
+            &EventKind::MilestoneSet {
+                milestone: git_mile_core::id::MilestoneId::new(),
+            },
+        ) else {
+            panic!("expected a milestone update");
+        };
+        assert_eq!(milestone.milestone, Some(MilestonePatch::Clear));
+    }
+
+    #[test]
+    fn compensation_inverts_links_and_skips_irreversible_events() {
+        let before = before();
+        let parent = TaskId::new();
+        let child = TaskId::new();
+        assert!(matches!(
+            compensation(&before, &EventKind::ChildLinked { parent, child }),
+            Some(Revert::Parent { link: false, .. })
+        ));
+        assert!(matches!(
+            compensation(&before, &EventKind::ChildUnlinked { parent, child }),
+            Some(Revert::Noop)
+        ));
+        assert!(matches!(
+            compensation(
+                &before,
+                &EventKind::RelationAdded {
+                    kind: "blocks".into(),
+                    target: parent,
+                }
+            ),
+            Some(Revert::Relation {
+                kind: RelationKind::Blocks,
+                link: false,
+                ..
+            })
+        ));
+        assert!(
+            compensation(
+                &before,
+                &EventKind::CommentDeleted {
+                    comment_id: EventId::new()
+                }
+            )
+            .is_none()
+        );
+    }
+}
@@ -2,17 +2,29 @@
 
 use anyhow::Error;
 use git_mile_core::event::{Actor, Event, EventKind};
-use git_mile_core::id::{EventId, TaskId};
+use git_mile_core::id::{EventId, TaskId, TaskIdPrefix};
+use git_mile_core::{RelationKind, TaskSnapshot};
+use git_mile_core::{checklist, rank};
 use git_mile_hooks::{HookContext, HookExecutor, HookKind, HooksConfig};
-use git_mile_store_git::GitStore;
+use git_mile_store_git::{GitStore, GitStoreError};
 use git2::Oid;
-use std::collections::BTreeMap;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
+use std::time::Duration;
 use tokio::sync::MutexGuard;
 
-use crate::config::WorkflowConfig;
+use crate::config::{FieldViolation, TransitionViolation, ViewSort, WorkflowConfig};
+use crate::task_log::ordered_events;
+use crate::task_revert::{Revert, batch_compensation, batch_range, overwritten_by};
+use crate::view_index;
 
-pub use crate::task_patch::{DescriptionPatch, SetDiff, StatePatch, TaskUpdate, diff_sets};
+pub use crate::task_patch::{DescriptionPatch, MilestonePatch, SetDiff, StatePatch, TaskUpdate, diff_sets};
+
+/// Attempts made to append events before giving up on a contended task ref.
+const MAX_APPEND_ATTEMPTS: u32 = 10;
+/// Base delay between append attempts; grows linearly with each retry.
+const APPEND_RETRY_BACKOFF_MS: u64 = 5;
 
 /// Minimal storage abstraction required by [`TaskWriter`].
 pub trait TaskStore {
@@ -31,6 +43,22 @@ pub trait TaskStore {
     /// Returns a store-specific error when persisting the event fails.
     fn append_event(&self, event: &Event) -> Result<Oid, Self::Error>;
 
+    /// Append several events for one task as a single unit.
+    ///
+    /// The default implementation appends the events one by one and returns the
+    /// id of the last write; stores that can persist a batch atomically should
+    /// override it.
+    ///
+    /// # Errors
+    /// Returns a store-specific error when persisting the events fails.
+    fn append_events(&self, events: &[Event]) -> Result<Oid, Self::Error> {
+        let mut last = Oid::zero();
+        for event in events {
+            last = self.append_event(event)?;
+        }
+        Ok(last)
+    }
+
     /// Load every event for the given task.
     ///
     /// # Errors
@@ -49,6 +77,19 @@ pub trait TaskStore {
     /// Returns a store-specific error when the query fails.
     fn list_tasks_modified_since(&self, since: time::OffsetDateTime) -> Result<Vec<TaskId>, Self::Error>;
 
+    /// Report the commit each task's history currently ends at, if the store tracks one.
+    ///
+    /// [`TaskRepository`](crate::TaskRepository) compares tips between refreshes so that
+    /// pulled, merged or deleted tasks are noticed regardless of their event timestamps.
+    /// The default returns `None`, which falls back to
+    /// [`list_tasks_modified_since`](Self::list_tasks_modified_since).
+    ///
+    /// # Errors
+    /// Returns a store-specific error when enumerating tips fails.
+    fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        Ok(None)
+    }
+
     /// Load events for the provided task ids in a single call.
     ///
     /// The default implementation iterates over [`load_events`](Self::load_events)
@@ -82,6 +123,15 @@ pub trait TaskStore {
         Ok(results)
     }
 
+    /// Location of the on-disk cache of materialized task views, if the store keeps one.
+    ///
+    /// [`TaskCache::load`](crate::TaskCache::load) reuses cached views whose ref tip
+    /// (from [`list_task_tips`](Self::list_task_tips)) is unchanged. The default
+    /// returns `None`, which replays every task on each load.
+    fn view_index_path(&self) -> Option<PathBuf> {
+        None
+    }
+
     /// Invalidate cached events for the specified tasks.
     ///
     /// This is useful when external processes may have modified tasks and the cache
@@ -93,6 +143,22 @@ pub trait TaskStore {
     fn invalidate_cache(&self, _task_ids: &[TaskId]) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    /// Resolve a full or abbreviated task id against the stored tasks.
+    ///
+    /// Full ids are returned without consulting the store; a prefix must match exactly one task.
+    ///
+    /// # Errors
+    /// Returns a [`TaskIdResolveError`](git_mile_core::id::TaskIdResolveError) for malformed,
+    /// unknown or ambiguous ids, or the store error when listing tasks fails.
+    fn resolve_task_id(&self, input: &str) -> Result<TaskId, Error> {
+        let prefix = TaskIdPrefix::parse(input)?;
+        if let Some(id) = prefix.as_full() {
+            return Ok(id);
+        }
+        let tasks = self.list_tasks().map_err(Into::into)?;
+        Ok(prefix.resolve(tasks)?)
+    }
 }
 
 /// High-level service that validates inputs and emits task events.
@@ -133,6 +199,8 @@ impl<S> TaskWriter<S> {
 struct LamportTracker<'a, S> {
     store: &'a S,
     cache: BTreeMap<TaskId, u64>,
+    /// Event being undone, recorded on every event appended through this tracker.
+    reverts: Option<EventId>,
 }
 
 impl<'a, S> LamportTracker<'a, S>
@@ -144,6 +212,15 @@ where
         Self {
             store,
             cache: BTreeMap::new(),
+            reverts: None,
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn reverting(store: &'a S, event: EventId) -> Self {
+        Self {
+            reverts: Some(event),
+            ..Self::new(store)
         }
     }
 
@@ -152,6 +229,20 @@ where
         Ok(())
     }
 
+    /// Give `events` (all for one task) a fresh shared stamp after the task's history moved
+    /// underneath us, discarding the clock cached for that task.
+    fn restamp(&mut self, events: &mut [Event]) -> Result<(), TaskWriteError> {
+        let Some(task) = events.first().map(|event| event.task) else {
+            return Ok(());
+        };
+        self.cache.remove(&task);
+        let lamport = self.next(task)?;
+        for event in events {
+            event.lamport = lamport;
+        }
+        Ok(())
+    }
+
     fn next(&mut self, task: TaskId) -> Result<u64, TaskWriteError> {
         if let Some(value) = self.cache.get_mut(&task) {
             *value += 1;
@@ -194,6 +285,22 @@ impl<S> TaskWriter<S> {
             .map_err(|_| TaskWriteError::InvalidState(state.unwrap_or("<none>").to_owned()))
     }
 
+    /// Event setting or clearing a custom field, with the value checked against its declaration.
+    ///
+    /// Clearing is allowed for any name so fields dropped from the configuration can be removed.
+    fn field_event_kind(&self, name: String, value: Option<&str>) -> Result<EventKind, TaskWriteError> {
+        Ok(match value {
+            Some(value) => EventKind::FieldSet {
+                value: self
+                    .workflow
+                    .normalize_field(&name, value)
+                    .map_err(TaskWriteError::InvalidField)?,
+                name,
+            },
+            None => EventKind::FieldCleared { name },
+        })
+    }
+
     fn ensure_task_exists(&self, task: TaskId) -> Result<(), TaskWriteError>
     where
         S: TaskStore,
@@ -205,32 +312,118 @@ impl<S> TaskWriter<S> {
         }
     }
 
-    /// Execute a pre-hook and reject the operation if it fails
-    fn execute_pre_hook(&self, kind: HookKind, event: &Event) -> Result<(), TaskWriteError>
+    /// Ensure the comment was added to the task and has not been deleted since.
+    fn ensure_comment_exists(&self, task: TaskId, comment: EventId) -> Result<(), TaskWriteError>
+    where
+        S: TaskStore,
+    {
+        self.ensure_task_exists(task)?;
+        let events = self.store.load_events(task).map_err(Self::store_error)?;
+        let added = events.iter().any(
+            |ev| matches!(&ev.kind, EventKind::CommentAdded { comment_id, .. } if *comment_id == comment),
+        );
+        let deleted = events
+            .iter()
+            .any(|ev| matches!(&ev.kind, EventKind::CommentDeleted { comment_id } if *comment_id == comment));
+        if added && !deleted {
+            Ok(())
+        } else {
+            Err(TaskWriteError::MissingComment { task, comment })
+        }
+    }
+
+    /// Check a state change against the workflow transition rules, judging requirements on the
+    /// labels and assignees the task will have once the pending update is applied.
+    fn check_transition(
+        &self,
+        task: TaskId,
+        to: &str,
+        labels: &SetDiff<String>,
+        assignees: &SetDiff<String>,
+    ) -> Result<(), TaskWriteError>
+    where
+        S: TaskStore,
+    {
+        if self.workflow.transitions().is_empty() {
+            return Ok(());
+        }
+        let events = self.store.load_events(task).map_err(Self::store_error)?;
+        let snapshot = TaskSnapshot::replay(&events);
+        let apply = |current: BTreeSet<String>, diff: &SetDiff<String>| {
+            let mut next = current;
+            next.extend(diff.added.iter().cloned());
+            for removed in &diff.removed {
+                next.remove(removed);
+            }
+            next
+        };
+        self.workflow
+            .check_transition(
+                snapshot.state.as_deref(),
+                to,
+                &apply(snapshot.labels, labels),
+                &apply(snapshot.assignees, assignees),
+            )
+            .map_err(|violation| TaskWriteError::Transition { task, violation })
+    }
+
+    /// Execute a pre-hook once for the events of one commit and reject the operation if it fails.
+    ///
+    /// Returns the events that should continue through the pipeline: either the
+    /// originals or the validated replacements printed by the hook. A hook run for
+    /// a single event prints one event; a hook run for a batch prints an array
+    /// holding one event per batch event.
+    fn execute_pre_hook(&self, kind: HookKind, events: Vec<Event>) -> Result<Vec<Event>, TaskWriteError>
     where
         S: TaskStore,
     {
+        let Some(context) = HookContext::batch(&events) else {
+            return Ok(events);
+        };
         let executor = HookExecutor::new(self.hooks_config.clone(), self.base_dir.clone());
-        let context = HookContext {
-            event: event.clone(),
-            data: None,
+        let invalid = |reason: String| TaskWriteError::InvalidHookEvent {
+            hook: kind.script_name().to_owned(),
+            reason,
         };
 
         match executor.execute(kind, &context) {
             Ok(result) => {
-                if result.is_success() {
-                    Ok(())
-                } else {
-                    Err(TaskWriteError::HookRejected {
+                if !result.is_success() {
+                    return Err(TaskWriteError::HookRejected {
                         hook: kind.script_name().to_owned(),
                         exit_code: result.exit_code,
                         stderr: result.stderr,
-                    })
+                    });
                 }
+                let modified = if context.events.is_empty() {
+                    result
+                        .modified_event()
+                        .map(|event| event.map(|event| vec![event]))
+                        .map_err(|err| invalid(format!("stdout is not an event: {err}")))?
+                } else {
+                    result
+                        .modified_events()
+                        .map_err(|err| invalid(format!("stdout is not an array of events: {err}")))?
+                };
+                let Some(modified) = modified else {
+                    return Ok(events);
+                };
+                if modified.len() != events.len() {
+                    return Err(invalid(format!(
+                        "expected {} events, got {}",
+                        events.len(),
+                        modified.len()
+                    )));
+                }
+                events
+                    .into_iter()
+                    .zip(modified)
+                    .map(|(original, modified)| self.apply_hook_modification(kind, original, modified))
+                    .collect()
             }
             Err(git_mile_hooks::HookError::NotFound(_)) => {
                 // Hook script not found - this is not an error, just skip
-                Ok(())
+                Ok(events)
             }
             Err(e) => Err(TaskWriteError::HookFailed {
                 hook: kind.script_name().to_owned(),
@@ -239,16 +432,132 @@ impl<S> TaskWriter<S> {
         }
     }
 
-    /// Execute a post-hook (errors are logged but don't fail the operation)
-    fn execute_post_hook(&self, kind: HookKind, event: &Event)
+    /// Validate an event printed by a pre-hook and merge it into the original.
+    ///
+    /// Only the event kind is taken from the hook output; the id, Lamport clock,
+    /// timestamp, and actor assigned by the writer are preserved.
+    fn apply_hook_modification(
+        &self,
+        kind: HookKind,
+        original: Event,
+        modified: Event,
+    ) -> Result<Event, TaskWriteError>
     where
         S: TaskStore,
     {
-        let executor = HookExecutor::new(self.hooks_config.clone(), self.base_dir.clone());
-        let context = HookContext {
-            event: event.clone(),
-            data: None,
+        let invalid = |reason: String| TaskWriteError::InvalidHookEvent {
+            hook: kind.script_name().to_owned(),
+            reason,
+        };
+
+        if modified.task != original.task {
+            return Err(invalid(format!(
+                "task id changed from {} to {}",
+                original.task, modified.task
+            )));
+        }
+        if std::mem::discriminant(&modified.kind) != std::mem::discriminant(&original.kind) {
+            return Err(invalid(format!(
+                "event kind changed from {} to {}",
+                original.kind.type_name(),
+                modified.kind.type_name()
+            )));
+        }
+
+        if let (Some(comment_id), Some(modified_id)) =
+            (comment_target(&original.kind), comment_target(&modified.kind))
+            && comment_id != modified_id
+        {
+            return Err(invalid(format!(
+                "comment id changed from {comment_id} to {modified_id}"
+            )));
+        }
+
+        let mut kind_value = modified.kind;
+        let state_slot = match &mut kind_value {
+            EventKind::TaskCreated {
+                state, state_kind, ..
+            } => Some((state.clone(), state_kind)),
+            EventKind::TaskStateSet { state, state_kind } => Some((Some(state.clone()), state_kind)),
+            _ => None,
         };
+        if let Some((state, state_kind)) = state_slot {
+            if self.workflow.validate_state(state.as_deref()).is_err() {
+                return Err(invalid(format!(
+                    "workflow state '{}' is not allowed",
+                    state.as_deref().unwrap_or("<none>")
+                )));
+            }
+            // Keep the kind consistent with the (possibly rewritten) state.
+            *state_kind = self.workflow.resolve_state_kind(state.as_deref());
+        }
+        if let EventKind::FieldSet { name, value } = &mut kind_value {
+            *value = self
+                .workflow
+                .normalize_field(name, value)
+                .map_err(|violation| invalid(violation.to_string()))?;
+        }
+
+        Ok(Event {
+            kind: kind_value,
+            ..original
+        })
+    }
+
+    /// Check states rewritten by pre-hooks against the workflow transition rules.
+    ///
+    /// `rewritten_by` names, per event, the hook that changed its target state. Label and
+    /// assignee changes in the same batch count toward the rule requirements, exactly as for
+    /// a state change requested directly.
+    fn check_hook_transitions(
+        &self,
+        events: &[Event],
+        rewritten_by: &[Option<HookKind>],
+    ) -> Result<(), TaskWriteError>
+    where
+        S: TaskStore,
+    {
+        for (event, hook) in events.iter().zip(rewritten_by) {
+            let (Some(hook), Some(state)) = (hook, state_set(event)) else {
+                continue;
+            };
+            let mut labels = SetDiff::default();
+            let mut assignees = SetDiff::default();
+            for pending in events.iter().filter(|pending| pending.task == event.task) {
+                match &pending.kind {
+                    EventKind::LabelsAdded { labels: added } => {
+                        labels.added.extend(added.iter().cloned());
+                    }
+                    EventKind::LabelsRemoved { labels: removed } => {
+                        labels.removed.extend(removed.iter().cloned());
+                    }
+                    EventKind::AssigneesAdded { assignees: added } => {
+                        assignees.added.extend(added.iter().cloned());
+                    }
+                    EventKind::AssigneesRemoved { assignees: removed } => {
+                        assignees.removed.extend(removed.iter().cloned());
+                    }
+                    _ => {}
+                }
+            }
+            self.check_transition(event.task, state, &labels, &assignees)
+                .map_err(|err| TaskWriteError::InvalidHookEvent {
+                    hook: hook.script_name().to_owned(),
+                    reason: err.to_string(),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Execute a post-hook once for the events of one commit (errors are logged but don't fail the operation)
+    fn execute_post_hook(&self, kind: HookKind, events: &[Event])
+    where
+        S: TaskStore,
+    {
+        let Some(context) = HookContext::batch(events) else {
+            return;
+        };
+        let executor = HookExecutor::new(self.hooks_config.clone(), self.base_dir.clone());
 
         // Post-hooks should not fail the operation, so we ignore errors
         let _ = executor.execute(kind, &context);
@@ -256,20 +565,14 @@ impl<S> TaskWriter<S> {
 
     /// Append an event to the store with hook execution
     ///
-    /// This method wraps the store's `append_event` and ensures that `PreEvent` and `PostEvent`
-    /// hooks are executed for all events, along with any specific hooks.
-    ///
-    /// # Hook Execution Order
-    /// 1. `PreEvent` (global)
-    /// 2. Specific pre-hook (if provided)
-    /// 3. Store `append_event`
-    /// 4. Specific post-hook (if provided)
-    /// 5. `PostEvent` (global)
+    /// This is [`append_batch_with_hooks`](Self::append_batch_with_hooks) for a
+    /// single event.
     ///
     /// # Errors
     /// Returns [`TaskWriteError`] if `PreEvent`, specific pre-hook, or store operation fails.
     fn append_event_with_hooks(
         &self,
+        lamports: &mut LamportTracker<'_, S>,
         event: &Event,
         specific_pre_hook: Option<HookKind>,
         specific_post_hook: Option<HookKind>,
@@ -277,29 +580,191 @@ impl<S> TaskWriter<S> {
     where
         S: TaskStore,
     {
-        // 1. PreEvent (global)
-        self.execute_pre_hook(HookKind::PreEvent, event)?;
+        let hooked = HookedEvent {
+            event: event.clone(),
+            pre_hook: specific_pre_hook,
+            post_hook: specific_post_hook,
+        };
+        self.append_batch_with_hooks(lamports, vec![hooked])
+    }
 
-        // 2. Specific pre-hook (e.g., PreTaskUpdate)
-        if let Some(hook_kind) = specific_pre_hook {
-            self.execute_pre_hook(hook_kind, event)?;
-        }
+    /// Append several events for one task as a single commit with hook execution.
+    ///
+    /// Every hook runs once per commit, receiving all the batch events it applies to.
+    ///
+    /// # Hook Execution Order
+    /// 1. `PreEvent` (global) with every event
+    /// 2. Each specific pre-hook with the events that requested it
+    /// 3. Store append as one commit
+    /// 4. Each specific post-hook with the events that requested it
+    /// 5. `PostEvent` (global) with every event
+    ///
+    /// Every pre-hook runs before anything is persisted, so one rejection aborts the
+    /// whole batch, and post-hooks only run once the commit exists.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] if any pre-hook or the store operation fails.
+    fn append_batch_with_hooks(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        batch: Vec<HookedEvent>,
+    ) -> Result<Oid, TaskWriteError>
+    where
+        S: TaskStore,
+    {
+        let prepared = self.prepare_batch(lamports, batch)?;
+        self.commit_batch(lamports, prepared)
+    }
 
-        // 3. Persist to store
-        let oid = self.store.append_event(event).map_err(Self::store_error)?;
+    /// Run the pre-hooks of a batch (steps 1 and 2 of [`Self::append_batch_with_hooks`])
+    /// without persisting anything.
+    ///
+    /// Operations spanning several tasks prepare every batch before committing the first,
+    /// so a rejection on any task leaves all of them untouched.
+    fn prepare_batch(
+        &self,
+        lamports: &LamportTracker<'_, S>,
+        batch: Vec<HookedEvent>,
+    ) -> Result<PreparedBatch, TaskWriteError>
+    where
+        S: TaskStore,
+    {
+        let (mut events, hooks): (Vec<Event>, Vec<_>) = batch
+            .into_iter()
+            .map(|hooked| (hooked.event, (hooked.pre_hook, hooked.post_hook)))
+            .unzip();
+        if let Some(reverts) = lamports.reverts {
+            for event in &mut events {
+                event.reverts = Some(reverts);
+            }
+        }
 
-        // 4. Specific post-hook
-        if let Some(hook_kind) = specific_post_hook {
-            self.execute_post_hook(hook_kind, event);
+        // Hook that last moved each event to another state, so the result can be checked
+        // against the transition rules once every pre-hook has run.
+        let mut state_rewrites = vec![None; events.len()];
+        let original_states: Vec<Option<String>> =
+            events.iter().map(|e| state_set(e).map(str::to_owned)).collect();
+        let mut events = self.execute_pre_hook(HookKind::PreEvent, events)?;
+        for (idx, event) in events.iter().enumerate() {
+            if state_set(event) != original_states[idx].as_deref() {
+                state_rewrites[idx] = Some(HookKind::PreEvent);
+            }
+        }
+        for (hook_kind, positions) in group_by_hook(hooks.iter().map(|(pre, _)| *pre)) {
+            let selected = positions.iter().map(|&idx| events[idx].clone()).collect();
+            let replaced = self.execute_pre_hook(hook_kind, selected)?;
+            for (idx, event) in positions.into_iter().zip(replaced) {
+                if state_set(&event) != state_set(&events[idx]) {
+                    state_rewrites[idx] = Some(hook_kind);
+                }
+                events[idx] = event;
+            }
         }
+        self.check_hook_transitions(&events, &state_rewrites)?;
 
-        // 5. PostEvent (global)
-        self.execute_post_hook(HookKind::PostEvent, event);
+        Ok(PreparedBatch {
+            events,
+            post_hooks: hooks.into_iter().map(|(_, post)| post).collect(),
+        })
+    }
+
+    /// Persist a prepared batch as one commit and run its post-hooks (steps 3 to 5 of
+    /// [`Self::append_batch_with_hooks`]).
+    fn commit_batch(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        prepared: PreparedBatch,
+    ) -> Result<Oid, TaskWriteError>
+    where
+        S: TaskStore,
+    {
+        let PreparedBatch {
+            mut events,
+            post_hooks,
+        } = prepared;
+        let oid = self.append_with_retry(lamports, &mut events)?;
+
+        for (hook_kind, positions) in group_by_hook(post_hooks.into_iter()) {
+            let selected: Vec<Event> = positions.iter().map(|&idx| events[idx].clone()).collect();
+            self.execute_post_hook(hook_kind, &selected);
+        }
+        self.execute_post_hook(HookKind::PostEvent, &events);
 
         Ok(oid)
     }
 }
 
+/// Target state of a `TaskStateSet` event.
+fn state_set(event: &Event) -> Option<&str> {
+    match &event.kind {
+        EventKind::TaskStateSet { state, .. } => Some(state),
+        _ => None,
+    }
+}
+
+/// Group batch positions by the specific hook they requested, in order of first use.
+fn group_by_hook(hooks: impl Iterator<Item = Option<HookKind>>) -> Vec<(HookKind, Vec<usize>)> {
+    let mut groups: Vec<(HookKind, Vec<usize>)> = Vec::new();
+    for (idx, hook) in hooks.enumerate() {
+        let Some(hook) = hook else {
+            continue;
+        };
+        match groups.iter_mut().find(|(kind, _)| *kind == hook) {
+            Some((_, positions)) => positions.push(idx),
+            None => groups.push((hook, vec![idx])),
+        }
+    }
+    groups
+}
+
+impl<S> TaskWriter<S>
+where
+    S: TaskStore,
+{
+    /// Persist `events` (all for one task) as one commit, retrying when another writer moved
+    /// the task ref between reading its tip and updating it.
+    ///
+    /// Each retry re-reads the task history, restamps the events with a Lamport clock above
+    /// everything now on the ref, and lets the store commit on top of the new tip.
+    fn append_with_retry(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        events: &mut [Event],
+    ) -> Result<Oid, TaskWriteError> {
+        let mut attempt = 1;
+        loop {
+            let err: Error = match self.store.append_events(events) {
+                Ok(oid) => return Ok(oid),
+                Err(err) => err.into(),
+            };
+            let raced = err
+                .downcast_ref::<GitStoreError>()
+                .is_some_and(|err| matches!(err, GitStoreError::RefConflict(_)));
+            if !raced || attempt >= MAX_APPEND_ATTEMPTS {
+                return Err(TaskWriteError::Store(err));
+            }
+            std::thread::sleep(Duration::from_millis(
+                APPEND_RETRY_BACKOFF_MS * u64::from(attempt),
+            ));
+            lamports.restamp(events)?;
+            attempt += 1;
+        }
+    }
+}
+
+/// Event queued for [`TaskWriter::append_batch_with_hooks`] with its specific hooks.
+struct HookedEvent {
+    event: Event,
+    pre_hook: Option<HookKind>,
+    post_hook: Option<HookKind>,
+}
+
+/// Batch whose pre-hooks have run, waiting to be committed.
+struct PreparedBatch {
+    events: Vec<Event>,
+    post_hooks: Vec<Option<HookKind>>,
+}
+
 impl<S> TaskWriter<S>
 where
     S: TaskStore,
@@ -316,6 +781,7 @@ where
             assignees,
             description,
             parents,
+            fields,
             actor,
         } = request;
 
@@ -324,6 +790,10 @@ where
         }
         self.validate_state(state.as_deref())?;
         let state_kind = self.workflow.resolve_state_kind(state.as_deref());
+        let field_kinds = fields
+            .into_iter()
+            .map(|(name, value)| self.field_event_kind(name, Some(&value)))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let task = TaskId::new();
         let mut events = Vec::new();
@@ -344,11 +814,26 @@ where
         );
         lamports.assign(&mut created_event)?;
 
-        let created_oid = self.append_event_with_hooks(
-            &created_event,
-            Some(HookKind::PreTaskCreate),
-            Some(HookKind::PostTaskCreate),
-        )?;
+        // Initial field values land in the creation commit, sharing its stamp.
+        let (lamport, ts) = (created_event.lamport, created_event.ts);
+        let field_events = field_kinds.into_iter().map(|kind| {
+            let mut event = Event::new(task, &actor, kind);
+            event.lamport = lamport;
+            event.ts = ts;
+            HookedEvent {
+                event,
+                pre_hook: Some(HookKind::PreTaskUpdate),
+                post_hook: Some(HookKind::PostTaskUpdate),
+            }
+        });
+        let batch = std::iter::once(HookedEvent {
+            event: created_event,
+            pre_hook: Some(HookKind::PreTaskCreate),
+            post_hook: Some(HookKind::PostTaskCreate),
+        })
+        .chain(field_events)
+        .collect();
+        let created_oid = self.append_batch_with_hooks(&mut lamports, batch)?;
         events.push(created_oid);
 
         for parent in parents {
@@ -358,6 +843,7 @@ where
             let mut child_event = Event::new(task, &actor, EventKind::ChildLinked { parent, child: task });
             lamports.assign(&mut child_event)?;
             let child_oid = self.append_event_with_hooks(
+                &mut lamports,
                 &child_event,
                 Some(HookKind::PreRelationChange),
                 Some(HookKind::PostRelationChange),
@@ -367,6 +853,7 @@ where
             let mut parent_event = Event::new(parent, &actor, EventKind::ChildLinked { parent, child: task });
             lamports.assign(&mut parent_event)?;
             let parent_oid = self.append_event_with_hooks(
+                &mut lamports,
                 &parent_event,
                 Some(HookKind::PreRelationChange),
                 Some(HookKind::PostRelationChange),
@@ -388,14 +875,27 @@ where
 
     /// Apply a patch to an existing task.
     ///
+    /// All resulting events share one Lamport stamp and are written as a single commit,
+    /// so a rejecting pre-hook leaves the task untouched.
+    ///
     /// # Errors
     /// Returns [`TaskWriteError`] when the task is missing, validation fails, or storage errors occur.
-    #[allow(clippy::too_many_lines)]
     pub fn update_task(
         &self,
         task: TaskId,
         patch: TaskUpdate,
         actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.update_task_with(&mut LamportTracker::new(&self.store), task, patch, actor)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn update_task_with(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        patch: TaskUpdate,
+        actor: &Actor,
     ) -> Result<TaskWriteResult, TaskWriteError> {
         self.ensure_task_exists(task)?;
 
@@ -405,10 +905,12 @@ where
             description,
             labels,
             assignees,
+            fields,
+            milestone,
+            rank,
         } = patch;
 
         let mut events = Vec::new();
-        let mut lamports = LamportTracker::new(&self.store);
 
         if let Some(title) = title {
             events.push(Event::new(task, actor, EventKind::TaskTitleSet { title }));
@@ -418,6 +920,7 @@ where
             match state_patch {
                 StatePatch::Set { state } => {
                     self.validate_state(Some(&state))?;
+                    self.check_transition(task, &state, &labels, &assignees)?;
                     let state_kind = self.workflow.resolve_state_kind(Some(&state));
                     events.push(Event::new(
                         task,
@@ -496,9 +999,36 @@ where
             ));
         }
 
-        let mut oids = Vec::new();
+        for (name, value) in fields {
+            let kind = self.field_event_kind(name, value.as_deref())?;
+            events.push(Event::new(task, actor, kind));
+        }
+
+        if let Some(milestone_patch) = milestone {
+            let kind = match milestone_patch {
+                MilestonePatch::Set { milestone } => EventKind::MilestoneSet { milestone },
+                MilestonePatch::Clear => EventKind::MilestoneCleared,
+            };
+            events.push(Event::new(task, actor, kind));
+        }
+
+        if let Some(rank) = rank {
+            events.push(Event::new(task, actor, EventKind::TaskRankSet { rank }));
+        }
+
+        let Some(ts) = events.first().map(|event| event.ts) else {
+            return Ok(TaskWriteResult {
+                task,
+                events: Vec::new(),
+                comment_id: None,
+            });
+        };
+        let lamport = lamports.next(task)?;
+
+        let mut batch = Vec::with_capacity(events.len());
         for mut event in events {
-            lamports.assign(&mut event)?;
+            event.lamport = lamport;
+            event.ts = ts;
 
             // Determine specific hooks based on event kind
             let (pre_hook, post_hook) = match &event.kind {
@@ -511,24 +1041,63 @@ where
                 | EventKind::LabelsAdded { .. }
                 | EventKind::LabelsRemoved { .. }
                 | EventKind::AssigneesAdded { .. }
-                | EventKind::AssigneesRemoved { .. } => {
+                | EventKind::AssigneesRemoved { .. }
+                | EventKind::FieldSet { .. }
+                | EventKind::FieldCleared { .. }
+                | EventKind::MilestoneSet { .. }
+                | EventKind::MilestoneCleared
+                | EventKind::TaskRankSet { .. } => {
                     // Other task update events get task-update hooks
                     (Some(HookKind::PreTaskUpdate), Some(HookKind::PostTaskUpdate))
                 }
                 _ => (None, None),
             };
 
-            let oid = self.append_event_with_hooks(&event, pre_hook, post_hook)?;
-            oids.push(oid);
+            batch.push(HookedEvent {
+                event,
+                pre_hook,
+                post_hook,
+            });
         }
 
+        let oid = self.append_batch_with_hooks(lamports, batch)?;
         Ok(TaskWriteResult {
             task,
-            events: oids,
+            events: vec![oid],
             comment_id: None,
         })
     }
 
+    /// Apply one update to each of `tasks`, narrowing it to what each task still lacks.
+    ///
+    /// Every task is written as its own batch, so a hook or transition rule
+    /// rejecting one task is reported in its outcome and does not stop the rest.
+    pub fn bulk_update(
+        &self,
+        tasks: &[TaskSnapshot],
+        update: &TaskUpdate,
+        actor: &Actor,
+    ) -> Vec<BulkUpdateOutcome> {
+        tasks
+            .iter()
+            .map(|snapshot| {
+                let task = snapshot.id;
+                let patch = update.narrowed_to(snapshot);
+                if patch.is_empty() {
+                    return BulkUpdateOutcome::Unchanged { task };
+                }
+                match self.update_task(task, patch, actor) {
+                    Ok(result) if result.events.is_empty() => BulkUpdateOutcome::Unchanged { task },
+                    Ok(result) => BulkUpdateOutcome::Updated {
+                        task,
+                        events: result.events,
+                    },
+                    Err(error) => BulkUpdateOutcome::Failed { task, error },
+                }
+            })
+            .collect()
+    }
+
     /// Only mutate the workflow state of a task.
     ///
     /// # Errors
@@ -549,6 +1118,88 @@ where
         self.update_task(task, patch, actor)
     }
 
+    /// Tick or untick checklist item `index` by rewriting the task description.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when the task or the item is missing, or storage fails.
+    pub fn toggle_checklist_item(
+        &self,
+        task: TaskId,
+        index: usize,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.ensure_task_exists(task)?;
+        let events = self.store.load_events(task).map_err(Self::store_error)?;
+        let snapshot = TaskSnapshot::replay(&events);
+        let description = checklist::toggle(&snapshot.description, index)
+            .ok_or(TaskWriteError::MissingChecklistItem { task, index })?;
+        let patch = TaskUpdate {
+            description: Some(DescriptionPatch::Set { description }),
+            ..TaskUpdate::default()
+        };
+        self.update_task(task, patch, actor)
+    }
+
+    /// Move `task` to `position` in the manual queue, i.e. the [`ViewSort::Rank`] order of every task.
+    ///
+    /// Tasks outside the queue that end up in front of `task` join it as well, each with its
+    /// own write; `task` itself is written last.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when `task` or the anchor task is missing, or storage fails.
+    pub fn move_task(
+        &self,
+        task: TaskId,
+        position: QueuePosition,
+        actor: &Actor,
+    ) -> Result<Vec<TaskWriteResult>, TaskWriteError> {
+        self.ensure_task_exists(task)?;
+        let anchor = match position {
+            QueuePosition::Top => None,
+            QueuePosition::Before(anchor) | QueuePosition::After(anchor) => Some(anchor),
+        };
+        if anchor == Some(task) {
+            return Ok(Vec::new());
+        }
+
+        let mut snapshots: Vec<TaskSnapshot> = self
+            .store
+            .load_all_events()
+            .map_err(Self::store_error)?
+            .iter()
+            .map(|(_, events)| TaskSnapshot::replay(events))
+            .collect();
+        ViewSort::Rank.sort(&self.workflow, &mut snapshots);
+        let order: Vec<&TaskSnapshot> = snapshots.iter().collect();
+        let index_of = |id: TaskId| {
+            order
+                .iter()
+                .position(|snapshot| snapshot.id == id)
+                .ok_or(TaskWriteError::MissingTask(id))
+        };
+        let from = index_of(task)?;
+        let to = match anchor {
+            None => 0,
+            Some(anchor) => {
+                // Taking `task` out shifts everything behind it one slot forward.
+                let index = index_of(anchor)?;
+                let index = if index > from { index - 1 } else { index };
+                index + usize::from(matches!(position, QueuePosition::After(_)))
+            }
+        };
+
+        rank::plan_move(&order, from, to)
+            .into_iter()
+            .map(|(id, rank)| {
+                let patch = TaskUpdate {
+                    rank: Some(Some(rank)),
+                    ..TaskUpdate::default()
+                };
+                self.update_task(id, patch, actor)
+            })
+            .collect()
+    }
+
     /// Append a Markdown comment to the task.
     ///
     /// # Errors
@@ -566,6 +1217,7 @@ where
         let mut event = Event::new(task, &actor, EventKind::CommentAdded { comment_id, body_md });
         lamports.assign(&mut event)?;
         let oid = self.append_event_with_hooks(
+            &mut lamports,
             &event,
             Some(HookKind::PreCommentAdd),
             Some(HookKind::PostCommentAdd),
@@ -578,6 +1230,120 @@ where
         })
     }
 
+    /// Delete a comment so its body is hidden from materialized views.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when the comment is missing or events cannot be persisted.
+    pub fn delete_comment(
+        &self,
+        task: TaskId,
+        comment_id: EventId,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.delete_comment_with(&mut LamportTracker::new(&self.store), task, comment_id, actor)
+    }
+
+    fn delete_comment_with(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        comment_id: EventId,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.ensure_comment_exists(task, comment_id)?;
+        self.append_comment_event(lamports, task, actor, EventKind::CommentDeleted { comment_id })
+    }
+
+    /// Add or withdraw the actor's reaction on a comment.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when the comment is missing, the reaction is invalid, or
+    /// persistence fails.
+    pub fn react_to_comment(
+        &self,
+        task: TaskId,
+        comment_id: EventId,
+        reaction: &str,
+        remove: bool,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        let reaction = reaction.trim();
+        if reaction.is_empty() || reaction.chars().any(char::is_whitespace) {
+            return Err(TaskWriteError::InvalidReaction(reaction.to_owned()));
+        }
+        self.ensure_comment_exists(task, comment_id)?;
+
+        let reaction = reaction.to_owned();
+        let kind = if remove {
+            EventKind::CommentReactionRemoved { comment_id, reaction }
+        } else {
+            EventKind::CommentReactionAdded { comment_id, reaction }
+        };
+        self.append_comment_event(&mut LamportTracker::new(&self.store), task, actor, kind)
+    }
+
+    fn append_comment_event(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        actor: &Actor,
+        kind: EventKind,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        let comment_id = comment_target(&kind);
+        let mut event = Event::new(task, actor, kind);
+        lamports.assign(&mut event)?;
+        let oid = self.append_event_with_hooks(lamports, &event, None, None)?;
+
+        Ok(TaskWriteResult {
+            task,
+            events: vec![oid],
+            comment_id,
+        })
+    }
+
+    /// Archive or restore a task.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when the task is missing or events cannot be persisted.
+    pub fn set_archived(
+        &self,
+        task: TaskId,
+        archived: bool,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.set_archived_with(&mut LamportTracker::new(&self.store), task, archived, actor)
+    }
+
+    fn set_archived_with(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        archived: bool,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.ensure_task_exists(task)?;
+
+        let kind = if archived {
+            EventKind::TaskArchived
+        } else {
+            EventKind::TaskUnarchived
+        };
+        let mut event = Event::new(task, actor, kind);
+        lamports.assign(&mut event)?;
+        let oid = self.append_event_with_hooks(
+            lamports,
+            &event,
+            Some(HookKind::PreTaskUpdate),
+            Some(HookKind::PostTaskUpdate),
+        )?;
+
+        Ok(TaskWriteResult {
+            task,
+            events: vec![oid],
+            comment_id: None,
+        })
+    }
+
     /// Link new parents to the task.
     ///
     /// # Errors
@@ -587,11 +1353,20 @@ where
         task: TaskId,
         parents: &[TaskId],
         actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.link_parents_with(&mut LamportTracker::new(&self.store), task, parents, actor)
+    }
+
+    fn link_parents_with(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        parents: &[TaskId],
+        actor: &Actor,
     ) -> Result<TaskWriteResult, TaskWriteError> {
         self.ensure_task_exists(task)?;
 
         let mut events = Vec::new();
-        let mut lamports = LamportTracker::new(&self.store);
         for parent in parents {
             match self.ensure_task_exists(*parent) {
                 Ok(()) => {}
@@ -609,6 +1384,7 @@ where
             );
             lamports.assign(&mut child_event)?;
             events.push(self.append_event_with_hooks(
+                lamports,
                 &child_event,
                 Some(HookKind::PreRelationChange),
                 Some(HookKind::PostRelationChange),
@@ -624,6 +1400,7 @@ where
             );
             lamports.assign(&mut parent_event)?;
             events.push(self.append_event_with_hooks(
+                lamports,
                 &parent_event,
                 Some(HookKind::PreRelationChange),
                 Some(HookKind::PostRelationChange),
@@ -637,6 +1414,136 @@ where
         })
     }
 
+    /// Relate the task to each target, recording the inverse relation on the target when one exists.
+    ///
+    /// The task's edges share one commit and each inverse gets its own on the target. Every
+    /// pre-hook runs before the first commit, so a rejection leaves no one-sided link.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when either task is missing, a target is the task itself, or
+    /// persistence fails.
+    pub fn link_relation(
+        &self,
+        task: TaskId,
+        kind: RelationKind,
+        targets: &[TaskId],
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.change_relation(
+            &mut LamportTracker::new(&self.store),
+            task,
+            kind,
+            targets,
+            actor,
+            true,
+        )
+    }
+
+    /// Remove relations of the given kind, along with their inverse on each target.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when the task is missing or persistence fails.
+    pub fn unlink_relation(
+        &self,
+        task: TaskId,
+        kind: RelationKind,
+        targets: &[TaskId],
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.change_relation(
+            &mut LamportTracker::new(&self.store),
+            task,
+            kind,
+            targets,
+            actor,
+            false,
+        )
+    }
+
+    fn change_relation(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        kind: RelationKind,
+        targets: &[TaskId],
+        actor: &Actor,
+        link: bool,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.ensure_task_exists(task)?;
+
+        let relation_event = |on: TaskId, kind: RelationKind, target: TaskId| {
+            let kind = kind.as_str().to_owned();
+            let event_kind = if link {
+                EventKind::RelationAdded { kind, target }
+            } else {
+                EventKind::RelationRemoved { kind, target }
+            };
+            Event::new(on, actor, event_kind)
+        };
+
+        // Check every target up front so a bad one cannot leave the others half linked.
+        for target in targets {
+            if *target == task {
+                return Err(TaskWriteError::SelfRelation(task));
+            }
+            if link {
+                self.ensure_task_exists(*target)?;
+            }
+        }
+
+        let hooked = |event: Event| HookedEvent {
+            event,
+            pre_hook: Some(HookKind::PreRelationChange),
+            post_hook: Some(HookKind::PostRelationChange),
+        };
+        let forward: Vec<Event> = targets
+            .iter()
+            .map(|target| relation_event(task, kind, *target))
+            .collect();
+        let Some(ts) = forward.first().map(|event| event.ts) else {
+            return Ok(TaskWriteResult {
+                task,
+                events: Vec::new(),
+                comment_id: None,
+            });
+        };
+        let lamport = lamports.next(task)?;
+        let mut batches = vec![
+            forward
+                .into_iter()
+                .map(|mut event| {
+                    event.lamport = lamport;
+                    event.ts = ts;
+                    hooked(event)
+                })
+                .collect::<Vec<_>>(),
+        ];
+        if let Some(inverse) = kind.inverse() {
+            for target in targets {
+                let mut inverse_event = relation_event(*target, inverse, task);
+                lamports.assign(&mut inverse_event)?;
+                batches.push(vec![hooked(inverse_event)]);
+            }
+        }
+
+        // Run every pre-hook before the first commit so a rejected edge cannot leave its
+        // mirror behind.
+        let prepared = batches
+            .into_iter()
+            .map(|batch| self.prepare_batch(lamports, batch))
+            .collect::<Result<Vec<_>, _>>()?;
+        let events = prepared
+            .into_iter()
+            .map(|batch| self.commit_batch(lamports, batch))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TaskWriteResult {
+            task,
+            events,
+            comment_id: None,
+        })
+    }
+
     /// Remove existing parent links from the task.
     ///
     /// # Errors
@@ -646,11 +1553,20 @@ where
         task: TaskId,
         parents: &[TaskId],
         actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.unlink_parents_with(&mut LamportTracker::new(&self.store), task, parents, actor)
+    }
+
+    fn unlink_parents_with(
+        &self,
+        lamports: &mut LamportTracker<'_, S>,
+        task: TaskId,
+        parents: &[TaskId],
+        actor: &Actor,
     ) -> Result<TaskWriteResult, TaskWriteError> {
         self.ensure_task_exists(task)?;
 
         let mut events = Vec::new();
-        let mut lamports = LamportTracker::new(&self.store);
         for parent in parents {
             let mut child_event = Event::new(
                 task,
@@ -662,6 +1578,7 @@ where
             );
             lamports.assign(&mut child_event)?;
             events.push(self.append_event_with_hooks(
+                lamports,
                 &child_event,
                 Some(HookKind::PreRelationChange),
                 Some(HookKind::PostRelationChange),
@@ -677,6 +1594,7 @@ where
             );
             lamports.assign(&mut parent_event)?;
             events.push(self.append_event_with_hooks(
+                lamports,
                 &parent_event,
                 Some(HookKind::PreRelationChange),
                 Some(HookKind::PostRelationChange),
@@ -689,6 +1607,69 @@ where
             comment_id: None,
         })
     }
+
+    /// Undo `event` on `task` by appending the compensating change.
+    ///
+    /// Events written in the same commit as `event` are undone with it, in one commit. The
+    /// inverse restores the values from right before that commit and is written through the
+    /// regular operations above, so workflow validation, transition rules and hooks apply
+    /// exactly as if the change had been made by hand. Every compensating event records
+    /// `event` in [`Event::reverts`]. A commit that changed nothing yields a result without
+    /// events.
+    ///
+    /// # Errors
+    /// Returns [`TaskWriteError`] when the event is not part of the task, has no inverse, a
+    /// later event changed the same part of the task (restoring the old value would discard
+    /// it), or the compensating write is rejected.
+    pub fn revert_event(
+        &self,
+        task: TaskId,
+        event: EventId,
+        actor: &Actor,
+    ) -> Result<TaskWriteResult, TaskWriteError> {
+        self.ensure_task_exists(task)?;
+        let events = ordered_events(&self.store.load_events(task).map_err(Self::store_error)?);
+        let Some(idx) = events.iter().position(|candidate| candidate.id == event) else {
+            return Err(TaskWriteError::MissingEvent { task, event });
+        };
+        let batch = batch_range(&events, idx);
+        if let Some(later) = overwritten_by(&events[batch.clone()], &events[batch.end..]) {
+            return Err(TaskWriteError::RevertConflict {
+                event,
+                later: later.id,
+            });
+        }
+        let before = TaskSnapshot::replay(&events[..batch.start]);
+        let revert =
+            batch_compensation(&before, &events[batch]).map_err(|failed| TaskWriteError::NotRevertible {
+                event: failed.id,
+                kind: failed.kind.type_name(),
+            })?;
+
+        let mut lamports = LamportTracker::reverting(&self.store, event);
+        match revert {
+            Revert::Noop => Ok(TaskWriteResult {
+                task,
+                events: Vec::new(),
+                comment_id: None,
+            }),
+            Revert::Update(update) => self.update_task_with(&mut lamports, task, update, actor),
+            Revert::Parent { parent, child, link } => {
+                if link {
+                    self.link_parents_with(&mut lamports, child, &[parent], actor)
+                } else {
+                    self.unlink_parents_with(&mut lamports, child, &[parent], actor)
+                }
+            }
+            Revert::Relation { kind, target, link } => {
+                self.change_relation(&mut lamports, task, kind, &[target], actor, link)
+            }
+            Revert::Archive(archived) => self.set_archived_with(&mut lamports, task, archived, actor),
+            Revert::DeleteComment(comment_id) => {
+                self.delete_comment_with(&mut lamports, task, comment_id, actor)
+            }
+        }
+    }
 }
 
 /// Payload used when creating a task.
@@ -706,10 +1687,23 @@ pub struct CreateTaskRequest {
     pub description: Option<String>,
     /// Parent tasks to link.
     pub parents: Vec<TaskId>,
+    /// Initial custom field values, validated against the workflow configuration.
+    pub fields: BTreeMap<String, String>,
     /// Actor who authored the request.
     pub actor: Actor,
 }
 
+/// Target slot of [`TaskWriter::move_task`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueuePosition {
+    /// Front of the queue.
+    Top,
+    /// Directly before the given task.
+    Before(TaskId),
+    /// Directly after the given task.
+    After(TaskId),
+}
+
 /// Comment body payload.
 #[derive(Debug, Clone)]
 pub struct CommentRequest {
@@ -731,16 +1725,66 @@ pub struct CreateTaskResult {
 }
 
 /// Result returned for update/comment/link operations.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TaskWriteResult {
     /// Identifier of the mutated task.
     pub task: TaskId,
     /// Event object IDs created during the operation.
+    #[serde(serialize_with = "serialize_oids")]
     pub events: Vec<Oid>,
     /// Comment identifier (when applicable).
     pub comment_id: Option<EventId>,
 }
 
+/// Per-task result of [`TaskWriter::bulk_update`].
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BulkUpdateOutcome {
+    /// Events were written to the task.
+    Updated {
+        /// Updated task.
+        task: TaskId,
+        /// Event object IDs created for the task.
+        #[serde(serialize_with = "serialize_oids")]
+        events: Vec<Oid>,
+    },
+    /// The task already matched the update.
+    Unchanged {
+        /// Untouched task.
+        task: TaskId,
+    },
+    /// Validation, a hook or the store rejected the update for this task.
+    Failed {
+        /// Task left unchanged.
+        task: TaskId,
+        /// Why the update was rejected.
+        #[serde(serialize_with = "serialize_display")]
+        error: TaskWriteError,
+    },
+}
+
+impl BulkUpdateOutcome {
+    /// Task the outcome refers to.
+    #[must_use]
+    pub const fn task(&self) -> TaskId {
+        match self {
+            Self::Updated { task, .. } | Self::Unchanged { task } | Self::Failed { task, .. } => *task,
+        }
+    }
+}
+
+fn serialize_display<T: std::fmt::Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Serialize commit OIDs as hex strings, the form `git` itself prints.
+fn serialize_oids<S: Serializer>(oids: &[Oid], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(oids.iter().map(Oid::to_string))
+}
+
 /// Parent link metadata emitted during task creation/linking.
 #[derive(Debug, Clone)]
 pub struct ParentLinkResult {
@@ -786,6 +1830,84 @@ pub enum TaskWriteError {
         /// Error message
         error: String,
     },
+    /// Hook printed a modified event that cannot replace the original.
+    #[error("hook '{hook}' returned an invalid event: {reason}")]
+    InvalidHookEvent {
+        /// Hook name
+        hook: String,
+        /// Why the modified event was refused
+        reason: String,
+    },
+    /// Target comment does not exist or has been deleted.
+    #[error("comment {comment} not found in task {task}")]
+    MissingComment {
+        /// Task the comment was looked up in
+        task: TaskId,
+        /// Comment identifier
+        comment: EventId,
+    },
+    /// Reaction token is empty or contains whitespace.
+    #[error("reaction '{0}' is not allowed")]
+    InvalidReaction(String),
+    /// A task cannot be related to itself.
+    #[error("task {0} cannot be related to itself")]
+    SelfRelation(TaskId),
+    /// Target event is not part of the task's history.
+    #[error("event {event} not found in task {task}")]
+    MissingEvent {
+        /// Task the event was looked up in
+        task: TaskId,
+        /// Event identifier
+        event: EventId,
+    },
+    /// The description has no checklist item at this position.
+    #[error("task {task} has no checklist item {index}")]
+    MissingChecklistItem {
+        /// Task whose description was searched
+        task: TaskId,
+        /// Zero-based item position
+        index: usize,
+    },
+    /// The event has no compensating change.
+    #[error("event {event} ({kind}) cannot be reverted")]
+    NotRevertible {
+        /// Event identifier
+        event: EventId,
+        /// Serialized type of the event
+        kind: &'static str,
+    },
+    /// Undoing the event would overwrite a later change to the same part of the task.
+    #[error("event {event} cannot be reverted: event {later} changed the same field afterwards")]
+    RevertConflict {
+        /// Event that was asked to be reverted
+        event: EventId,
+        /// Later event whose change would be lost
+        later: EventId,
+    },
+    /// A custom field is undeclared or its value does not fit the declared type.
+    #[error(transparent)]
+    InvalidField(FieldViolation),
+    /// Workflow transition rules refused the state change.
+    #[error("task {task}: {violation}")]
+    Transition {
+        /// Task whose state was being changed
+        task: TaskId,
+        /// Rule that was not satisfied
+        #[source]
+        violation: TransitionViolation,
+    },
+}
+
+/// Comment targeted by a comment event, if any.
+const fn comment_target(kind: &EventKind) -> Option<EventId> {
+    match kind {
+        EventKind::CommentAdded { comment_id, .. }
+        | EventKind::CommentUpdated { comment_id, .. }
+        | EventKind::CommentDeleted { comment_id }
+        | EventKind::CommentReactionAdded { comment_id, .. }
+        | EventKind::CommentReactionRemoved { comment_id, .. } => Some(*comment_id),
+        _ => None,
+    }
 }
 
 impl TaskStore for GitStore {
@@ -799,6 +1921,10 @@ impl TaskStore for GitStore {
         Self::append_event(self, event)
     }
 
+    fn append_events(&self, events: &[Event]) -> Result<Oid, Self::Error> {
+        Self::append_events(self, events)
+    }
+
     fn load_events(&self, task: TaskId) -> Result<Vec<Event>, Self::Error> {
         Self::load_events(self, task)
     }
@@ -811,10 +1937,18 @@ impl TaskStore for GitStore {
         Self::list_tasks_modified_since(self, since)
     }
 
+    fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        Self::list_task_tips(self).map(Some)
+    }
+
     fn load_all_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>, Self::Error> {
         Self::load_all_task_events(self)
     }
 
+    fn view_index_path(&self) -> Option<PathBuf> {
+        Some(view_index::path(self.git_dir()))
+    }
+
     fn invalidate_cache(&self, task_ids: &[TaskId]) -> Result<(), Self::Error> {
         self.invalidate_tasks_cache(task_ids);
         Ok(())
@@ -835,6 +1969,10 @@ where
         (*self).append_event(event)
     }
 
+    fn append_events(&self, events: &[Event]) -> Result<Oid, Self::Error> {
+        (*self).append_events(events)
+    }
+
     fn load_events(&self, task: TaskId) -> Result<Vec<Event>, Self::Error> {
         (*self).load_events(task)
     }
@@ -847,9 +1985,17 @@ where
         (*self).list_tasks_modified_since(since)
     }
 
+    fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        (*self).list_task_tips()
+    }
+
     fn load_all_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>, Self::Error> {
         (*self).load_all_events()
     }
+
+    fn invalidate_cache(&self, task_ids: &[TaskId]) -> Result<(), Self::Error> {
+        (*self).invalidate_cache(task_ids)
+    }
 }
 
 impl TaskStore for MutexGuard<'_, GitStore> {
@@ -863,6 +2009,10 @@ impl TaskStore for MutexGuard<'_, GitStore> {
         GitStore::append_event(self, event)
     }
 
+    fn append_events(&self, events: &[Event]) -> Result<Oid, Self::Error> {
+        GitStore::append_events(self, events)
+    }
+
     fn load_events(&self, task: TaskId) -> Result<Vec<Event>, Self::Error> {
         GitStore::load_events(self, task)
     }
@@ -875,9 +2025,18 @@ impl TaskStore for MutexGuard<'_, GitStore> {
         GitStore::list_tasks_modified_since(self, since)
     }
 
+    fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        GitStore::list_task_tips(self).map(Some)
+    }
+
     fn load_all_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>, Self::Error> {
         GitStore::load_all_events(self)
     }
+
+    fn invalidate_cache(&self, task_ids: &[TaskId]) -> Result<(), Self::Error> {
+        self.invalidate_tasks_cache(task_ids);
+        Ok(())
+    }
 }
 
 impl<S> TaskStore for std::sync::Arc<S>
@@ -894,6 +2053,10 @@ where
         (**self).append_event(event)
     }
 
+    fn append_events(&self, events: &[Event]) -> Result<Oid, Self::Error> {
+        (**self).append_events(events)
+    }
+
     fn load_events(&self, task: TaskId) -> Result<Vec<Event>, Self::Error> {
         (**self).load_events(task)
     }
@@ -906,7 +2069,15 @@ where
         (**self).list_tasks_modified_since(since)
     }
 
+    fn list_task_tips(&self) -> Result<Option<Vec<(TaskId, Oid)>>, Self::Error> {
+        (**self).list_task_tips()
+    }
+
     fn load_all_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>, Self::Error> {
         (**self).load_all_events()
     }
+
+    fn invalidate_cache(&self, task_ids: &[TaskId]) -> Result<(), Self::Error> {
+        (**self).invalidate_cache(task_ids)
+    }
 }
@@ -0,0 +1,171 @@
+//! Persistent on-disk cache of materialized task views.
+//!
+//! Replaying every task history into a [`TaskView`] is the remaining start-up
+//! cost once the store serves decoded events from its own cache. This index
+//! keeps the replayed views keyed by the ref tip they were built from, so a
+//! later load only replays tasks whose ref moved. It uses the same
+//! [`TipIndex`] format as the store's event index, in a file of its own.
+//!
+//! The file is purely a cache: a missing, unreadable, or outdated index is
+//! discarded and rebuilt from the store.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use git_mile_core::id::TaskId;
+use git_mile_store_git::{TipIndex, index_path};
+
+use crate::task_cache::TaskView;
+use crate::task_writer::TaskStore;
+
+/// Bump whenever the layout of [`TaskView`] or the way events replay into it changes.
+const INDEX_SCHEMA_VERSION: u32 = 2;
+/// File name of the view index next to the store's event index.
+const INDEX_FILE: &str = "views.json";
+
+/// Materialized views keyed by task id, each tagged with the ref tip it reflects.
+type ViewIndex = TipIndex<TaskView>;
+
+/// Location of the view index for a repository whose Git directory is `git_dir`.
+pub fn path(git_dir: &Path) -> PathBuf {
+    index_path(git_dir, INDEX_FILE)
+}
+
+/// Materialize a view of every task in `store`.
+///
+/// Stores without a [view index](TaskStore::view_index_path) or ref tips replay
+/// every history. Otherwise cached views are reused for unchanged tips, only the
+/// moved tasks are loaded and replayed, and the refreshed index is written back.
+/// Failing to persist the index is ignored because it is only a cache.
+pub fn load_views<S: TaskStore>(store: &S) -> Result<Vec<TaskView>, S::Error> {
+    let (Some(index_path), Some(tips)) = (store.view_index_path(), store.list_task_tips()?) else {
+        return Ok(store
+            .load_all_events()?
+            .iter()
+            .map(|(_, events)| TaskView::from_events(events))
+            .collect());
+    };
+
+    let mut previous = ViewIndex::load(&index_path, INDEX_SCHEMA_VERSION);
+    let mut index = ViewIndex::new(INDEX_SCHEMA_VERSION);
+    let mut stale = BTreeMap::new();
+    for (task, tip) in tips {
+        match previous.take(task, tip) {
+            Some(view) => index.insert(task, tip, view),
+            None => {
+                stale.insert(task, tip);
+            }
+        }
+    }
+
+    let stale_ids: Vec<TaskId> = stale.keys().copied().collect();
+    for (task, events) in store.load_events_for_tasks(&stale_ids)? {
+        if let Some(&tip) = stale.get(&task) {
+            index.insert(task, tip, TaskView::from_events(&events));
+        }
+    }
+
+    if !stale.is_empty() || !previous.is_empty() {
+        let _ = index.save(&index_path);
+    }
+    Ok(index.into_values().map(|(_, view)| view).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task_cache::TaskCache;
+    use anyhow::{Context, Result};
+    use git_mile_core::event::{Actor, Event, EventKind};
+    use git_mile_store_git::GitStore;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn actor() -> Actor {
+        Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        }
+    }
+
+    fn create_task(store: &GitStore, title: &str) -> Result<TaskId> {
+        let task = TaskId::new();
+        store.append_event(&Event::new(
+            task,
+            &actor(),
+            EventKind::TaskCreated {
+                title: title.into(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        ))?;
+        Ok(task)
+    }
+
+    fn title_of(cache: &TaskCache, task: TaskId) -> Option<String> {
+        cache
+            .task_index
+            .get(&task)
+            .and_then(|&idx| cache.tasks.get(idx))
+            .map(|view| view.snapshot.title.clone())
+    }
+
+    #[test]
+    fn cached_views_are_reused_until_the_ref_moves() -> Result<()> {
+        let dir = TempDir::new()?;
+        git2::Repository::init(dir.path())?;
+        let store = GitStore::open(dir.path())?;
+        let task = create_task(&store, "original")?;
+        let index_path = path(store.git_dir());
+
+        TaskCache::load(&store)?;
+        let mut index = ViewIndex::load(&index_path, INDEX_SCHEMA_VERSION);
+        assert_eq!(index.len(), 1);
+
+        // A view served from the index is returned as stored, without replaying events.
+        let tip = store
+            .list_task_tips()?
+            .into_iter()
+            .find_map(|(id, tip)| (id == task).then_some(tip))
+            .context("task has a ref tip")?;
+        let mut view = index.take(task, tip).context("view is cached at the tip")?;
+        view.snapshot.title = "from index".into();
+        index.insert(task, tip, view);
+        index.save(&index_path)?;
+        assert_eq!(
+            title_of(&TaskCache::load(&store)?, task).as_deref(),
+            Some("from index")
+        );
+
+        store.append_event(&Event::new(
+            task,
+            &actor(),
+            EventKind::TaskTitleSet {
+                title: "renamed".into(),
+            },
+        ))?;
+        assert_eq!(
+            title_of(&TaskCache::load(&store)?, task).as_deref(),
+            Some("renamed")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn corrupt_index_is_rebuilt() -> Result<()> {
+        let dir = TempDir::new()?;
+        git2::Repository::init(dir.path())?;
+        let store = GitStore::open(dir.path())?;
+        let task = create_task(&store, "kept")?;
+        let index_path = path(store.git_dir());
+        fs::create_dir_all(index_path.parent().context("index has a parent")?)?;
+        fs::write(&index_path, b"{ not json")?;
+
+        assert_eq!(title_of(&TaskCache::load(&store)?, task).as_deref(), Some("kept"));
+        assert_eq!(ViewIndex::load(&index_path, INDEX_SCHEMA_VERSION).len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Stress test for concurrent writers appending to the same task ref.
+
+#![allow(clippy::expect_used)]
+
+use git_mile_app::config::ProjectConfig;
+use git_mile_app::task_writer::{CreateTaskRequest, SetDiff, TaskUpdate, TaskWriter};
+use git_mile_core::TaskSnapshot;
+use git_mile_core::event::Actor;
+use git_mile_hooks::HooksConfig;
+use git_mile_store_git::GitStore;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::thread;
+use tempfile::TempDir;
+
+const WRITERS: usize = 4;
+const UPDATES_PER_WRITER: usize = 10;
+
+fn writer(repo: &Path) -> TaskWriter<GitStore> {
+    TaskWriter::new(
+        GitStore::open(repo).expect("open git store"),
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        repo.to_path_buf(),
+    )
+}
+
+fn actor(name: &str) -> Actor {
+    Actor {
+        name: name.to_owned(),
+        email: format!("{name}@example.invalid"),
+    }
+}
+
+#[test]
+fn concurrent_updates_to_one_task_lose_no_events() {
+    let temp = TempDir::with_prefix("git-mile-concurrent-").expect("create temp dir");
+    git2::Repository::init(temp.path()).expect("init git repo");
+
+    let task = writer(temp.path())
+        .create_task(CreateTaskRequest {
+            title: "Contended".to_owned(),
+            state: None,
+            labels: vec![],
+            assignees: vec![],
+            description: None,
+            parents: vec![],
+            fields: BTreeMap::new(),
+            actor: actor("creator"),
+        })
+        .expect("create task")
+        .task;
+
+    thread::scope(|scope| {
+        for writer_idx in 0..WRITERS {
+            let repo = temp.path();
+            scope.spawn(move || {
+                let writer = writer(repo);
+                let actor = actor(&format!("writer-{writer_idx}"));
+                for update_idx in 0..UPDATES_PER_WRITER {
+                    let update = TaskUpdate {
+                        labels: SetDiff {
+                            added: vec![format!("w{writer_idx}/u{update_idx}")],
+                            removed: vec![],
+                        },
+                        ..TaskUpdate::default()
+                    };
+                    writer
+                        .update_task(task, update, &actor)
+                        .expect("update must survive contention");
+                }
+            });
+        }
+    });
+
+    let events = GitStore::open(temp.path())
+        .expect("open git store")
+        .load_events(task)
+        .expect("load events");
+    assert_eq!(events.len(), 1 + WRITERS * UPDATES_PER_WRITER);
+
+    let labels = TaskSnapshot::replay(&events).labels;
+    for writer_idx in 0..WRITERS {
+        for update_idx in 0..UPDATES_PER_WRITER {
+            assert!(labels.contains(&format!("w{writer_idx}/u{update_idx}")));
+        }
+    }
+}
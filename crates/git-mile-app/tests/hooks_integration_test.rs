@@ -10,11 +10,15 @@
     clippy::field_reassign_with_default
 )]
 
-use git_mile_app::config::ProjectConfig;
-use git_mile_app::task_writer::{CommentRequest, CreateTaskRequest, TaskUpdate, TaskWriter};
+use git_mile_app::config::{ProjectConfig, WorkflowTransition};
+use git_mile_app::task_writer::{
+    CommentRequest, CreateTaskRequest, SetDiff, StatePatch, TaskUpdate, TaskWriteError, TaskWriter,
+};
 use git_mile_core::event::Actor;
+use git_mile_core::{RelationKind, TaskSnapshot};
 use git_mile_hooks::HooksConfig;
 use git_mile_store_git::GitStore;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -82,6 +86,70 @@ exit 1
     }
 }
 
+/// Test helper: Create a hook that prints the incoming event rewritten by `sed_script`
+fn create_rewriting_hook(hooks_dir: &std::path::Path, hook_name: &str, sed_script: &str) {
+    let hook_path = hooks_dir.join(hook_name);
+    let script = format!(
+        r#"#!/bin/sh
+sed -e 's/^{{"event"://' -e 's/}}$//' -e '{sed_script}'
+exit 0
+"#
+    );
+
+    fs::write(&hook_path, script).expect("write hook script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&hook_path).expect("get metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&hook_path, perms).expect("set executable");
+    }
+}
+
+/// Test helper: Create a hook that prints `output` on stdout and succeeds
+fn create_printing_hook(hooks_dir: &std::path::Path, hook_name: &str, output: &str) {
+    let hook_path = hooks_dir.join(hook_name);
+    let script = format!(
+        r"#!/bin/sh
+printf '%s' '{output}'
+exit 0
+"
+    );
+
+    fs::write(&hook_path, script).expect("write hook script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&hook_path).expect("get metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&hook_path, perms).expect("set executable");
+    }
+}
+
+/// Test helper: Create a hook that saves its stdin to `capture_path` and succeeds
+fn create_capturing_hook(hooks_dir: &std::path::Path, hook_name: &str, capture_path: &std::path::Path) {
+    let hook_path = hooks_dir.join(hook_name);
+    let script = format!(
+        r#"#!/bin/sh
+cat > "{}"
+exit 0
+"#,
+        capture_path.display()
+    );
+
+    fs::write(&hook_path, script).expect("write hook script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&hook_path).expect("get metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&hook_path, perms).expect("set executable");
+    }
+}
+
 fn test_actor() -> Actor {
     Actor {
         name: "Test User".to_owned(),
@@ -118,6 +186,7 @@ fn test_task_create_executes_pre_and_post_hooks() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -159,6 +228,7 @@ fn test_pre_task_create_hook_can_reject_operation() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -189,6 +259,7 @@ fn test_task_update_hooks_execute_for_title_change() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -246,6 +317,7 @@ fn test_state_change_hooks_execute() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -295,6 +367,7 @@ fn test_comment_hooks_execute() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -349,6 +422,7 @@ fn test_relation_hooks_execute_on_parent_link() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -362,6 +436,7 @@ fn test_relation_hooks_execute_on_parent_link() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -397,6 +472,54 @@ fn test_relation_hooks_execute_on_parent_link() {
     assert_eq!(post_event_count, 2, "PostEvent should execute twice");
 }
 
+#[test]
+#[cfg(unix)]
+fn test_rejected_inverse_relation_leaves_no_one_sided_link() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let upstream = writer
+        .create_task(simple_request("Upstream"))
+        .expect("create upstream")
+        .task;
+    let downstream = writer
+        .create_task(simple_request("Downstream"))
+        .expect("create downstream")
+        .task;
+
+    // Accept the forward `blocks` edge but reject its `blocked-by` mirror.
+    let hook_path = hooks_dir.join("pre-relation-change");
+    fs::write(
+        &hook_path,
+        "#!/bin/sh\nif grep -q blocked-by; then echo 'no mirrors' >&2; exit 1; fi\nexit 0\n",
+    )
+    .expect("write hook script");
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&hook_path, fs::Permissions::from_mode(0o755)).expect("set executable");
+    }
+
+    let err = writer
+        .link_relation(upstream, RelationKind::Blocks, &[downstream], &test_actor())
+        .expect_err("rejected mirror should fail the link");
+    assert!(
+        matches!(err, TaskWriteError::HookRejected { ref hook, .. } if hook == "pre-relation-change"),
+        "unexpected error: {err}"
+    );
+
+    for task in [upstream, downstream] {
+        let events = writer.store().load_events(task).expect("load events");
+        assert!(
+            TaskSnapshot::replay(&events).relates.is_empty(),
+            "no edge may be committed when a pre-hook rejects"
+        );
+    }
+}
+
 #[test]
 #[cfg(unix)]
 fn test_pre_event_can_reject_any_operation() {
@@ -421,6 +544,7 @@ fn test_pre_event_can_reject_any_operation() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -456,6 +580,7 @@ fn test_hook_execution_order_is_correct() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -493,6 +618,7 @@ fn test_hooks_skip_when_script_not_found() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -527,6 +653,7 @@ fn test_hooks_skip_when_disabled() {
         assignees: vec![],
         description: None,
         parents: vec![],
+        fields: BTreeMap::new(),
         actor: test_actor(),
     };
 
@@ -536,3 +663,405 @@ fn test_hooks_skip_when_disabled() {
         "Operations should succeed when hooks are disabled"
     );
 }
+
+fn simple_request(title: &str) -> CreateTaskRequest {
+    CreateTaskRequest {
+        title: title.to_owned(),
+        state: None,
+        labels: vec![],
+        assignees: vec![],
+        description: None,
+        parents: vec![],
+        fields: BTreeMap::new(),
+        actor: test_actor(),
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_modified_event_is_persisted() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    create_rewriting_hook(
+        &hooks_dir,
+        "pre-task-create",
+        r#"s/"title":"[^"]*"/"title":"Normalized"/; s/"labels":\[\]/"labels":["triage"]/"#,
+    );
+
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+
+    let result = writer
+        .create_task(simple_request("  needs normalizing "))
+        .expect("create task");
+
+    let events = writer.store().load_events(result.task).expect("load events");
+    assert_eq!(events.len(), 1);
+    let snapshot = TaskSnapshot::replay(&events);
+    assert_eq!(snapshot.title, "Normalized");
+    assert!(snapshot.labels.contains("triage"));
+    assert_eq!(snapshot.state.as_deref(), Some("state/todo"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_modified_event_flows_into_next_hook() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    create_rewriting_hook(&hooks_dir, "pre-event", r#"s/"title":"[^"]*"/"title":"First"/"#);
+    create_rewriting_hook(&hooks_dir, "pre-task-create", "s/First/Second/");
+
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+
+    let result = writer
+        .create_task(simple_request("Original"))
+        .expect("create task");
+    let events = writer.store().load_events(result.task).expect("load events");
+    assert_eq!(TaskSnapshot::replay(&events).title, "Second");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_cannot_change_task_id() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    create_rewriting_hook(
+        &hooks_dir,
+        "pre-task-create",
+        r#"s/"task":"[^"]*"/"task":"01890a5d-ac96-774b-bcce-b302099a8057"/"#,
+    );
+
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+
+    let err = writer
+        .create_task(simple_request("Test Task"))
+        .expect_err("task id rewrite should be refused");
+    match err {
+        TaskWriteError::InvalidHookEvent { hook, reason } => {
+            assert_eq!(hook, "pre-task-create");
+            assert!(reason.contains("task id"), "unexpected reason: {reason}");
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    assert!(writer.store().list_tasks().expect("list tasks").is_empty());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_cannot_change_event_kind() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    create_rewriting_hook(
+        &hooks_dir,
+        "pre-task-update",
+        r#"s/"type":"taskTitleSet","title":"[^"]*"/"type":"taskStateCleared"/"#,
+    );
+
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    let mut update = TaskUpdate::default();
+    update.title = Some("Renamed".to_owned());
+    let err = writer
+        .update_task(task, update, &test_actor())
+        .expect_err("kind rewrite should be refused");
+    assert!(
+        matches!(err, TaskWriteError::InvalidHookEvent { ref reason, .. } if reason.contains("event kind")),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_cannot_set_unknown_state() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    create_rewriting_hook(
+        &hooks_dir,
+        "pre-state-change",
+        r#"s/"state":"[^"]*"/"state":"state\/bogus"/"#,
+    );
+
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    let err = writer
+        .set_state(task, Some("state/done".to_owned()), &test_actor())
+        .expect_err("unknown state should be refused");
+    assert!(
+        matches!(err, TaskWriteError::InvalidHookEvent { ref reason, .. } if reason.contains("state/bogus")),
+        "unexpected error: {err}"
+    );
+
+    let events = writer.store().load_events(task).expect("load events");
+    assert_eq!(TaskSnapshot::replay(&events).state.as_deref(), Some("state/todo"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_cannot_bypass_workflow_transitions() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    create_rewriting_hook(
+        &hooks_dir,
+        "pre-state-change",
+        r#"s/"state":"[^"]*"/"state":"state\/done"/"#,
+    );
+
+    let workflow = ProjectConfig::default().workflow.with_transitions(vec![
+        WorkflowTransition::new(Some("state/todo"), "state/in-progress"),
+        WorkflowTransition::new(Some("state/in-progress"), "state/done"),
+    ]);
+    let writer = TaskWriter::new(
+        store,
+        workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    // todo -> in-progress is allowed, but the hook turns it into todo -> done.
+    let err = writer
+        .set_state(task, Some("state/in-progress".to_owned()), &test_actor())
+        .expect_err("forbidden transition should be refused");
+    assert!(
+        matches!(err, TaskWriteError::InvalidHookEvent { ref hook, ref reason } if hook == "pre-state-change" && reason.contains("state/done")),
+        "unexpected error: {err}"
+    );
+
+    let events = writer.store().load_events(task).expect("load events");
+    assert_eq!(TaskSnapshot::replay(&events).state.as_deref(), Some("state/todo"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_printing_non_event_output_is_refused() {
+    for output in ["looks good to me", r#"{"ok":true}"#] {
+        let (_temp, store, hooks_dir) = setup_test_repo();
+        create_printing_hook(&hooks_dir, "pre-task-create", output);
+
+        let writer = TaskWriter::new(
+            store,
+            ProjectConfig::default().workflow,
+            HooksConfig::default(),
+            hooks_dir.parent().unwrap().to_path_buf(),
+        );
+
+        let err = writer
+            .create_task(simple_request("Test Task"))
+            .expect_err("non-event stdout should be refused");
+        match err {
+            TaskWriteError::InvalidHookEvent { hook, reason } => {
+                assert_eq!(hook, "pre-task-create");
+                assert!(reason.contains("not an event"), "unexpected reason: {reason}");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(writer.store().list_tasks().expect("list tasks").is_empty());
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_multi_field_update_is_one_commit_with_shared_lamport() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    let update = TaskUpdate {
+        title: Some("Renamed".to_owned()),
+        state: Some(StatePatch::Set {
+            state: "state/done".to_owned(),
+        }),
+        labels: SetDiff {
+            added: vec!["type/bug".to_owned()],
+            removed: vec![],
+        },
+        assignees: SetDiff {
+            added: vec!["alice".to_owned()],
+            removed: vec![],
+        },
+        ..TaskUpdate::default()
+    };
+    let result = writer
+        .update_task(task, update, &test_actor())
+        .expect("update task");
+    assert_eq!(result.events.len(), 1, "update must be a single commit");
+
+    let events = writer.store().load_events(task).expect("load events");
+    assert_eq!(events.len(), 5);
+    let batch = &events[1..];
+    assert!(batch.iter().all(|event| event.lamport == batch[0].lamport));
+    assert!(batch[0].lamport > events[0].lamport);
+
+    let snapshot = TaskSnapshot::replay(&events);
+    assert_eq!(snapshot.title, "Renamed");
+    assert_eq!(snapshot.state.as_deref(), Some("state/done"));
+    assert!(snapshot.labels.contains("type/bug"));
+    assert!(snapshot.assignees.contains("alice"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_batch_runs_each_hook_once_with_every_event() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    let log_file = hooks_dir.join("execution.log");
+    let capture_file = hooks_dir.join("pre-event.json");
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    create_capturing_hook(&hooks_dir, "pre-event", &capture_file);
+    create_logging_hook(&hooks_dir, "pre-task-update", &log_file);
+    create_logging_hook(&hooks_dir, "pre-state-change", &log_file);
+    create_logging_hook(&hooks_dir, "post-event", &log_file);
+
+    let update = TaskUpdate {
+        title: Some("Renamed".to_owned()),
+        state: Some(StatePatch::Set {
+            state: "state/done".to_owned(),
+        }),
+        labels: SetDiff {
+            added: vec!["type/bug".to_owned()],
+            removed: vec![],
+        },
+        ..TaskUpdate::default()
+    };
+    writer
+        .update_task(task, update, &test_actor())
+        .expect("update task");
+
+    let log = fs::read_to_string(&log_file).expect("read log");
+    assert_eq!(
+        log.lines().collect::<Vec<_>>(),
+        ["pre-task-update", "pre-state-change", "post-event"]
+    );
+
+    let payload: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&capture_file).expect("read capture")).expect("parse");
+    let events = payload["events"].as_array().expect("batch payload lists events");
+    assert_eq!(events.len(), 3);
+    assert_eq!(payload["event"], events[0]);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pre_hook_rewrites_batch_with_event_array() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    // Print the `events` array of the payload with every title rewritten.
+    create_rewriting_hook(
+        &hooks_dir,
+        "pre-task-update",
+        "s/^.*\"events\"://; s/Renamed/Rewritten/",
+    );
+
+    let update = TaskUpdate {
+        title: Some("Renamed".to_owned()),
+        labels: SetDiff {
+            added: vec!["type/bug".to_owned()],
+            removed: vec![],
+        },
+        ..TaskUpdate::default()
+    };
+    writer
+        .update_task(task, update, &test_actor())
+        .expect("update task");
+
+    let events = writer.store().load_events(task).expect("load events");
+    let snapshot = TaskSnapshot::replay(&events);
+    assert_eq!(snapshot.title, "Rewritten");
+    assert!(snapshot.labels.contains("type/bug"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_rejected_pre_hook_leaves_batch_unapplied() {
+    let (_temp, store, hooks_dir) = setup_test_repo();
+    let log_file = hooks_dir.join("execution.log");
+    let writer = TaskWriter::new(
+        store,
+        ProjectConfig::default().workflow,
+        HooksConfig::default(),
+        hooks_dir.parent().unwrap().to_path_buf(),
+    );
+    let task = writer
+        .create_task(simple_request("Test Task"))
+        .expect("create task")
+        .task;
+
+    create_logging_hook(&hooks_dir, "post-event", &log_file);
+    create_failing_hook(&hooks_dir, "pre-state-change");
+
+    let update = TaskUpdate {
+        title: Some("Renamed".to_owned()),
+        state: Some(StatePatch::Set {
+            state: "state/done".to_owned(),
+        }),
+        ..TaskUpdate::default()
+    };
+    let err = writer
+        .update_task(task, update, &test_actor())
+        .expect_err("state hook should reject the whole update");
+    assert!(
+        matches!(err, TaskWriteError::HookRejected { .. }),
+        "unexpected error: {err}"
+    );
+
+    let events = writer.store().load_events(task).expect("load events");
+    assert_eq!(events.len(), 1, "no event of the batch may be persisted");
+    assert_eq!(TaskSnapshot::replay(&events).title, "Test Task");
+    assert!(!log_file.exists(), "post-hooks must not run for a rejected batch");
+}
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+/// One `- [ ]` / `- [x]` item found in a Markdown description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    /// Zero-based position among the checklist items of the description.
+    pub index: usize,
+    /// Zero-based line number of the item within the description.
+    pub line: usize,
+    /// Whether the box is ticked.
+    pub checked: bool,
+    /// Item text after the checkbox.
+    pub text: String,
+}
+
+/// Done/total counts of the checklist items in a description.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistCounts {
+    /// Ticked items.
+    pub done: usize,
+    /// All items.
+    pub total: usize,
+}
+
+impl ChecklistCounts {
+    /// Count the checklist items of `description`.
+    #[must_use]
+    pub fn of(description: &str) -> Self {
+        let mut counts = Self::default();
+        for (_, checked, _) in scan(description) {
+            counts.total += 1;
+            counts.done += usize::from(checked);
+        }
+        counts
+    }
+
+    /// Whether the description has no checklist at all.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// Items that are not ticked yet.
+    #[must_use]
+    pub const fn open(&self) -> usize {
+        self.total - self.done
+    }
+}
+
+/// Parse the checklist items of a Markdown description, skipping fenced code blocks.
+#[must_use]
+pub fn parse(description: &str) -> Vec<ChecklistItem> {
+    scan(description)
+        .enumerate()
+        .map(|(index, (line, checked, text))| ChecklistItem {
+            index,
+            line,
+            checked,
+            text: text.to_owned(),
+        })
+        .collect()
+}
+
+/// Flip the checkbox of item `index`, leaving every other byte of `description` untouched.
+///
+/// Returns `None` when the description has fewer than `index + 1` items.
+#[must_use]
+pub fn toggle(description: &str, index: usize) -> Option<String> {
+    let (target, _, _) = scan(description).nth(index)?;
+    let mut toggled = String::with_capacity(description.len());
+    for (number, line) in description.split_inclusive('\n').enumerate() {
+        if number == target {
+            let (marker_at, checked) = checkbox(line)?;
+            toggled.push_str(&line[..marker_at]);
+            toggled.push(if checked { ' ' } else { 'x' });
+            toggled.push_str(&line[marker_at + 1..]);
+        } else {
+            toggled.push_str(line);
+        }
+    }
+    Some(toggled)
+}
+
+/// Yield `(line number, checked, text)` for each checklist line outside code fences.
+fn scan(description: &str) -> impl Iterator<Item = (usize, bool, &str)> {
+    let mut fence: Option<&str> = None;
+    description
+        .split_inclusive('\n')
+        .enumerate()
+        .filter_map(move |(number, line)| {
+            let trimmed = line.trim_start();
+            if let Some(open) = fence {
+                if trimmed.starts_with(open) {
+                    fence = None;
+                }
+                return None;
+            }
+            if let Some(marker) = ["```", "~~~"]
+                .into_iter()
+                .find(|marker| trimmed.starts_with(marker))
+            {
+                fence = Some(marker);
+                return None;
+            }
+            let (marker_at, checked) = checkbox(line)?;
+            Some((number, checked, line[marker_at + 2..].trim()))
+        })
+}
+
+/// Byte offset of the checkbox mark (the character between `[` and `]`) and whether it is ticked.
+fn checkbox(line: &str) -> Option<(usize, bool)> {
+    let body = line.trim_start();
+    let indent = line.len() - body.len();
+    let after_bullet = if let Some(rest) = body.strip_prefix(['-', '*', '+']) {
+        rest
+    } else {
+        let digits = body.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        body[digits..].strip_prefix(['.', ')'])?
+    };
+    let after_space = after_bullet.trim_start_matches([' ', '\t']);
+    if after_space.len() == after_bullet.len() {
+        return None;
+    }
+    let rest = after_space.strip_prefix('[')?;
+    let checked = match rest.as_bytes() {
+        [b' ', b']', ..] => false,
+        [b'x' | b'X', b']', ..] => true,
+        _ => return None,
+    };
+    if !rest[2..].is_empty() && !rest[2..].starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some((indent + (body.len() - rest.len()), checked))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = "Release prep\n\n- [ ] bump version\n* [x] write changelog\n  1. [X] nested tag\n```\n- [ ] not an item\n```\n- [] malformed\n-[ ] no space\n+ [ ]\n";
+
+    #[test]
+    fn parse_finds_items_outside_code_fences() {
+        let items = parse(DESCRIPTION);
+        let summary: Vec<(usize, usize, bool, &str)> = items
+            .iter()
+            .map(|item| (item.index, item.line, item.checked, item.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, 2, false, "bump version"),
+                (1, 3, true, "write changelog"),
+                (2, 4, true, "nested tag"),
+                (3, 10, false, ""),
+            ]
+        );
+        assert_eq!(
+            ChecklistCounts::of(DESCRIPTION),
+            ChecklistCounts { done: 2, total: 4 }
+        );
+        assert_eq!(ChecklistCounts::of(DESCRIPTION).open(), 2);
+        assert!(ChecklistCounts::of("no boxes here").is_empty());
+    }
+
+    #[test]
+    fn toggle_flips_only_the_requested_box() {
+        let toggled = toggle(DESCRIPTION, 0).unwrap_or_else(|| panic!("item 0 exists"));
+        assert_eq!(toggled, DESCRIPTION.replacen("- [ ] bump", "- [x] bump", 1));
+
+        let toggled = toggle(&toggled, 2).unwrap_or_else(|| panic!("item 2 exists"));
+        assert!(toggled.contains("  1. [ ] nested tag\n"));
+        assert_eq!(
+            ChecklistCounts::of(&toggled),
+            ChecklistCounts { done: 2, total: 4 }
+        );
+
+        assert_eq!(toggle("- [ ] no newline", 0).as_deref(), Some("- [x] no newline"));
+        assert!(toggle(DESCRIPTION, 4).is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::id::{EventId, TaskId};
+use crate::id::{EventId, MilestoneId, TaskId};
 use crate::state::StateKind;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
@@ -31,6 +31,9 @@ pub struct Event {
     pub task: TaskId,
     /// Domain-specific payload.
     pub kind: EventKind,
+    /// Event this one undoes, set on the compensating events written by a revert.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverts: Option<EventId>,
 }
 
 /// Event kinds (extend as needed).
@@ -67,6 +70,10 @@ pub enum EventKind {
     },
     /// The workflow state is cleared.
     TaskStateCleared,
+    /// The task is archived and hidden from default listings.
+    TaskArchived,
+    /// A previously archived task is restored.
+    TaskUnarchived,
     /// The task title is overwritten.
     TaskTitleSet {
         /// New task title.
@@ -112,6 +119,25 @@ pub enum EventKind {
         /// New comment body in Markdown.
         body_md: String,
     },
+    /// A comment is retracted; its body is hidden from materialized views.
+    CommentDeleted {
+        /// Identifier for the comment event to delete.
+        comment_id: EventId,
+    },
+    /// The authoring actor reacts to a comment.
+    CommentReactionAdded {
+        /// Identifier for the comment event being reacted to.
+        comment_id: EventId,
+        /// Reaction token (e.g. an emoji or short word).
+        reaction: String,
+    },
+    /// The authoring actor withdraws a reaction from a comment.
+    CommentReactionRemoved {
+        /// Identifier for the comment event the reaction belongs to.
+        comment_id: EventId,
+        /// Reaction token to withdraw.
+        reaction: String,
+    },
     /// A parent-child relationship is established.
     ChildLinked {
         /// Parent task identifier.
@@ -140,6 +166,77 @@ pub enum EventKind {
         /// Target task identifier.
         target: TaskId,
     },
+    /// A custom field is assigned a value (last writer wins).
+    FieldSet {
+        /// Field name as declared in the project configuration.
+        name: String,
+        /// Normalized value.
+        value: String,
+    },
+    /// A custom field is unset.
+    FieldCleared {
+        /// Field name as declared in the project configuration.
+        name: String,
+    },
+    /// The task is planned for a milestone (last writer wins).
+    MilestoneSet {
+        /// Milestone identifier.
+        milestone: MilestoneId,
+    },
+    /// The task is removed from its milestone.
+    MilestoneCleared,
+    /// The task's position in the manual queue changes (last writer wins).
+    TaskRankSet {
+        /// Fractional index from [`crate::rank`]; `None` drops the task from the queue.
+        rank: Option<String>,
+    },
+}
+
+/// Define [`EventKind::TYPE_NAMES`] and [`EventKind::type_name`] from one list, so the
+/// exhaustive `match` refuses to compile until a new variant is given its tag.
+macro_rules! event_type_names {
+    ($($variant:ident => $name:literal),+ $(,)?) => {
+        impl EventKind {
+            /// Serialized `type` tag of every event kind, in declaration order.
+            pub const TYPE_NAMES: &'static [&'static str] = &[$($name),+];
+
+            /// Serialized `type` tag of this kind, e.g. `taskStateSet`.
+            #[must_use]
+            pub const fn type_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant { .. } => $name,)+
+                }
+            }
+        }
+    };
+}
+
+event_type_names! {
+    TaskCreated => "taskCreated",
+    TaskStateSet => "taskStateSet",
+    TaskStateCleared => "taskStateCleared",
+    TaskArchived => "taskArchived",
+    TaskUnarchived => "taskUnarchived",
+    TaskTitleSet => "taskTitleSet",
+    TaskDescriptionSet => "taskDescriptionSet",
+    LabelsAdded => "labelsAdded",
+    LabelsRemoved => "labelsRemoved",
+    AssigneesAdded => "assigneesAdded",
+    AssigneesRemoved => "assigneesRemoved",
+    CommentAdded => "commentAdded",
+    CommentUpdated => "commentUpdated",
+    CommentDeleted => "commentDeleted",
+    CommentReactionAdded => "commentReactionAdded",
+    CommentReactionRemoved => "commentReactionRemoved",
+    ChildLinked => "childLinked",
+    ChildUnlinked => "childUnlinked",
+    RelationAdded => "relationAdded",
+    RelationRemoved => "relationRemoved",
+    FieldSet => "fieldSet",
+    FieldCleared => "fieldCleared",
+    MilestoneSet => "milestoneSet",
+    MilestoneCleared => "milestoneCleared",
+    TaskRankSet => "taskRankSet",
 }
 
 impl Event {
@@ -154,6 +251,7 @@ impl Event {
             actor: actor.clone(),
             task,
             kind,
+            reverts: None,
         }
     }
 }
@@ -190,6 +288,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn type_name_matches_serialized_tag() {
+        let kinds = [
+            EventKind::TaskStateCleared,
+            EventKind::TaskTitleSet { title: "t".into() },
+            EventKind::CommentDeleted {
+                comment_id: EventId::new(),
+            },
+            EventKind::RelationRemoved {
+                kind: "blocks".into(),
+                target: TaskId::new(),
+            },
+            EventKind::FieldCleared {
+                name: "priority".into(),
+            },
+            EventKind::MilestoneSet {
+                milestone: MilestoneId::new(),
+            },
+            EventKind::TaskRankSet {
+                rank: Some("V".into()),
+            },
+        ];
+        for kind in kinds {
+            let value = serde_json::to_value(&kind).unwrap_or_else(|err| panic!("must encode kind: {err}"));
+            assert_eq!(value["type"], kind.type_name());
+            assert!(EventKind::TYPE_NAMES.contains(&kind.type_name()));
+        }
+        let unique: std::collections::BTreeSet<_> = EventKind::TYPE_NAMES.iter().collect();
+        assert_eq!(
+            unique.len(),
+            EventKind::TYPE_NAMES.len(),
+            "type tags must be distinct"
+        );
+    }
+
     #[test]
     fn task_created_defaults_roundtrip() {
         let json = json!({
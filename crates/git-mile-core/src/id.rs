@@ -1,7 +1,16 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 use std::{fmt, str::FromStr};
+use thiserror::Error;
 use uuid::Uuid;
 
+/// Number of hex digits in a task id.
+const TASK_ID_HEX_LEN: usize = 32;
+/// Fewest hex digits accepted when resolving an abbreviated task id.
+pub const MIN_TASK_ID_PREFIX: usize = 4;
+/// Fewest hex digits shown when abbreviating a task id (the first UUID group).
+pub const MIN_TASK_ID_ABBREV: usize = 8;
+
 /// Identifier of a task (UUID v7).
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct TaskId(pub Uuid);
@@ -15,6 +24,182 @@ impl TaskId {
     }
 }
 
+impl TaskId {
+    /// Lowercase hex digits of the id without separators.
+    fn hex(self) -> String {
+        self.0.simple().to_string()
+    }
+
+    /// Render the id truncated to its first `hex_len` hex digits, keeping UUID separators.
+    #[must_use]
+    pub fn abbreviated(self, hex_len: usize) -> String {
+        let full = self.to_string();
+        let mut digits = 0;
+        let end = full
+            .char_indices()
+            .find(|&(_, ch)| {
+                if ch != '-' {
+                    digits += 1;
+                }
+                digits > hex_len
+            })
+            .map_or(full.len(), |(idx, _)| idx);
+        full[..end].trim_end_matches('-').to_owned()
+    }
+}
+
+/// Why a user-supplied (possibly abbreviated) task id could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TaskIdResolveError {
+    /// The input contains characters that cannot appear in a task id.
+    #[error("invalid task id '{0}'")]
+    Invalid(String),
+    /// The prefix is too short to be accepted.
+    #[error("task id prefix '{0}' is too short (use at least {MIN_TASK_ID_PREFIX} hex digits)")]
+    TooShort(String),
+    /// No known task starts with the prefix.
+    #[error("no task matches id prefix '{0}'")]
+    NotFound(String),
+    /// More than one task starts with the prefix.
+    #[error(
+        "task id prefix '{prefix}' is ambiguous; it matches {}",
+        candidates.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    Ambiguous {
+        /// Prefix as typed by the user.
+        prefix: String,
+        /// Every task id starting with the prefix.
+        candidates: Vec<TaskId>,
+    },
+}
+
+/// A full or abbreviated task id as typed on the command line or passed to a tool.
+///
+/// Prefixes are matched against the hex digits of task ids, so both
+/// `0192f3a1-7b` and `0192f3a17b` select the same task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskIdPrefix {
+    input: String,
+    hex: String,
+}
+
+impl TaskIdPrefix {
+    /// Validate `input` as a task id prefix.
+    ///
+    /// # Errors
+    /// Returns [`TaskIdResolveError`] when the input is not hex or shorter than [`MIN_TASK_ID_PREFIX`].
+    pub fn parse(input: &str) -> Result<Self, TaskIdResolveError> {
+        let input = input.trim();
+        if let Ok(id) = TaskId::from_str(input) {
+            return Ok(Self {
+                input: input.to_owned(),
+                hex: id.hex(),
+            });
+        }
+        let hex: String = input
+            .chars()
+            .filter(|ch| *ch != '-')
+            .map(|ch| ch.to_ascii_lowercase())
+            .collect();
+        if hex.len() > TASK_ID_HEX_LEN || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+            return Err(TaskIdResolveError::Invalid(input.to_owned()));
+        }
+        if hex.len() < MIN_TASK_ID_PREFIX {
+            return Err(TaskIdResolveError::TooShort(input.to_owned()));
+        }
+        Ok(Self {
+            input: input.to_owned(),
+            hex,
+        })
+    }
+
+    /// The task id itself when the prefix spells out every digit.
+    #[must_use]
+    pub fn as_full(&self) -> Option<TaskId> {
+        (self.hex.len() == TASK_ID_HEX_LEN)
+            .then(|| Uuid::parse_str(&self.hex).ok().map(TaskId))
+            .flatten()
+    }
+
+    /// Whether `id` starts with this prefix.
+    #[must_use]
+    pub fn matches(&self, id: TaskId) -> bool {
+        id.hex().starts_with(&self.hex)
+    }
+
+    /// Pick the single candidate matching this prefix.
+    ///
+    /// A full id resolves to itself without consulting `candidates`.
+    ///
+    /// # Errors
+    /// Returns [`TaskIdResolveError::NotFound`] or [`TaskIdResolveError::Ambiguous`]
+    /// when zero or several candidates match.
+    pub fn resolve(
+        &self,
+        candidates: impl IntoIterator<Item = TaskId>,
+    ) -> Result<TaskId, TaskIdResolveError> {
+        if let Some(id) = self.as_full() {
+            return Ok(id);
+        }
+        let mut matches: Vec<TaskId> = candidates.into_iter().filter(|id| self.matches(*id)).collect();
+        matches.sort_unstable();
+        matches.dedup();
+        match matches.as_slice() {
+            [] => Err(TaskIdResolveError::NotFound(self.input.clone())),
+            [id] => Ok(*id),
+            _ => Err(TaskIdResolveError::Ambiguous {
+                prefix: self.input.clone(),
+                candidates: matches,
+            }),
+        }
+    }
+}
+
+impl From<TaskId> for TaskIdPrefix {
+    fn from(id: TaskId) -> Self {
+        Self {
+            input: id.to_string(),
+            hex: id.hex(),
+        }
+    }
+}
+
+/// Shortest unique prefix of every id in a set of tasks, like `git log --abbrev-commit`.
+#[derive(Debug, Clone, Default)]
+pub struct TaskIdAbbreviations {
+    lengths: HashMap<TaskId, usize>,
+}
+
+impl TaskIdAbbreviations {
+    /// Compute abbreviations that stay unique within `ids`.
+    pub fn new(ids: impl IntoIterator<Item = TaskId>) -> Self {
+        let mut hexes: Vec<(String, TaskId)> = ids.into_iter().map(|id| (id.hex(), id)).collect();
+        hexes.sort_unstable();
+        hexes.dedup();
+
+        let common = |a: &str, b: &str| a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+        let lengths = hexes
+            .iter()
+            .enumerate()
+            .map(|(idx, (hex, id))| {
+                let prev = idx.checked_sub(1).map_or(0, |prev| common(&hexes[prev].0, hex));
+                let next = hexes.get(idx + 1).map_or(0, |(next, _)| common(next, hex));
+                let len = (prev.max(next) + 1).clamp(MIN_TASK_ID_ABBREV, TASK_ID_HEX_LEN);
+                (*id, len)
+            })
+            .collect();
+        Self { lengths }
+    }
+
+    /// Render `id` abbreviated, or in full when it was not part of the set.
+    #[must_use]
+    pub fn abbreviate(&self, id: TaskId) -> String {
+        self.lengths
+            .get(&id)
+            .map_or_else(|| id.to_string(), |&len| id.abbreviated(len))
+    }
+}
+
 impl fmt::Display for TaskId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -94,6 +279,62 @@ impl<'de> Deserialize<'de> for EventId {
     }
 }
 
+/// Identifier of a milestone (UUID v7).
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct MilestoneId(pub Uuid);
+
+impl MilestoneId {
+    #[must_use]
+    /// Generate a fresh milestone identifier.
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Whether the hex digits of the id start with `prefix` (separators and case ignored).
+    #[must_use]
+    pub fn matches_prefix(self, prefix: &str) -> bool {
+        let hex: String = prefix
+            .chars()
+            .filter(|ch| *ch != '-')
+            .map(|ch| ch.to_ascii_lowercase())
+            .collect();
+        !hex.is_empty() && self.0.simple().to_string().starts_with(&hex)
+    }
+}
+
+impl fmt::Display for MilestoneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for MilestoneId {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+}
+
+impl Serialize for MilestoneId {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MilestoneId {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(d)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +361,64 @@ mod tests {
         assert_eq!(parsed.0, uuid);
     }
 
+    fn task_id(raw: &str) -> TaskId {
+        raw.parse()
+            .unwrap_or_else(|err| panic!("must parse task id: {err}"))
+    }
+
+    #[test]
+    fn prefixes_resolve_unique_matches_and_report_ambiguity() {
+        let a = task_id("0192f3a1-7b00-7000-8000-000000000001");
+        let b = task_id("0192f3a1-7c00-7000-8000-000000000002");
+        let candidates = [a, b];
+        let resolve = |input: &str| TaskIdPrefix::parse(input).and_then(|prefix| prefix.resolve(candidates));
+
+        assert_eq!(resolve("0192f3a1-7b"), Ok(a));
+        assert_eq!(resolve("0192F3A17C"), Ok(b));
+        assert_eq!(resolve(&a.to_string()), Ok(a));
+        assert_eq!(
+            resolve("0192f3a1"),
+            Err(TaskIdResolveError::Ambiguous {
+                prefix: "0192f3a1".into(),
+                candidates: vec![a, b],
+            })
+        );
+        assert_eq!(resolve("ffff"), Err(TaskIdResolveError::NotFound("ffff".into())));
+        assert_eq!(resolve("019"), Err(TaskIdResolveError::TooShort("019".into())));
+        assert_eq!(
+            resolve("not-hex"),
+            Err(TaskIdResolveError::Invalid("not-hex".into()))
+        );
+        let unknown = TaskId::new();
+        assert_eq!(
+            resolve(&unknown.to_string()),
+            Ok(unknown),
+            "full ids skip the lookup"
+        );
+    }
+
+    #[test]
+    fn abbreviations_grow_only_where_ids_collide() {
+        let a = task_id("0192f3a1-7b00-7000-8000-000000000001");
+        let b = task_id("0192f3a1-7c00-7000-8000-000000000002");
+        let c = task_id("0193aaaa-0000-7000-8000-000000000003");
+        let abbrev = TaskIdAbbreviations::new([a, b, c]);
+
+        assert_eq!(abbrev.abbreviate(a), "0192f3a1-7b");
+        assert_eq!(abbrev.abbreviate(b), "0192f3a1-7c");
+        assert_eq!(abbrev.abbreviate(c), "0193aaaa");
+        let outsider = TaskId::new();
+        assert_eq!(abbrev.abbreviate(outsider), outsider.to_string());
+    }
+
+    #[test]
+    fn milestone_ids_match_hex_prefixes() {
+        let id = MilestoneId(Uuid::parse_str("0192f3a1-7b00-7000-8000-000000000001").unwrap_or_default());
+        assert!(id.matches_prefix("0192F3A1-7b"));
+        assert!(!id.matches_prefix("0192f3a2"));
+        assert!(!id.matches_prefix(""));
+    }
+
     #[test]
     fn event_id_roundtrip() {
         let uuid = Uuid::now_v7();
@@ -2,17 +2,28 @@
 
 //! Domain types & replay logic for git-mile events.
 
+/// Markdown checklist items in task descriptions.
+pub mod checklist;
 /// Event payload definitions.
 pub mod event;
 /// Identifier types.
 pub mod id;
+/// Milestone events and their materialized view.
+pub mod milestone;
+mod query;
+/// Fractional indexes ordering the manual task queue.
+pub mod rank;
+mod relation;
 mod state;
 mod text_matcher;
 
-pub use state::StateKind;
+pub use query::{QUERY_FIELDS, QueryParseError, TaskQuery, compare_field_values, parse_age};
+pub use relation::{ParseRelationKindError, RelationKind};
+pub use state::{StateKind, StateKindCounts};
 
+use crate::checklist::ChecklistCounts;
 use crate::event::{Event, EventKind};
-use crate::id::{EventId, TaskId};
+use crate::id::{EventId, MilestoneId, TaskId, TaskIdResolveError};
 use crate::text_matcher::TextMatcher;
 use crdts::CmRDT;
 use crdts::lwwreg::LWWReg;
@@ -89,6 +100,27 @@ pub struct TaskSnapshot {
     pub relates: BTreeMap<String, BTreeSet<TaskId>>,
     /// RFC 3339 timestamp of the most recent event.
     pub updated_rfc3339: Option<String>,
+    /// Whether the task has been archived.
+    #[serde(default)]
+    pub archived: bool,
+    /// Comments that have been deleted.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub deleted_comments: BTreeSet<EventId>,
+    /// Reactions per comment, keyed by reaction token and listing reacting actor emails.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub comment_reactions: BTreeMap<EventId, BTreeMap<String, BTreeSet<String>>>,
+    /// Custom field values keyed by field name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+    /// Milestone the task is planned for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<MilestoneId>,
+    /// Done/total counts of the `- [ ]` checklist in the description.
+    #[serde(default, skip_serializing_if = "ChecklistCounts::is_empty")]
+    pub checklist: ChecklistCounts,
+    /// Position in the manual queue; see [`rank`] for how ranks order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<String>,
     #[serde(skip)]
     #[serde(default)]
     crdt: TaskCrdt,
@@ -109,6 +141,13 @@ impl Default for TaskSnapshot {
             parents: BTreeSet::new(),
             relates: BTreeMap::new(),
             updated_rfc3339: None,
+            archived: false,
+            deleted_comments: BTreeSet::new(),
+            comment_reactions: BTreeMap::new(),
+            fields: BTreeMap::new(),
+            milestone: None,
+            checklist: ChecklistCounts::default(),
+            rank: None,
             crdt,
         };
         snap.sync_from_crdt();
@@ -161,7 +200,10 @@ impl TaskSnapshot {
         self.title = self.crdt.title.val.clone();
         self.state = self.crdt.state.val.clone();
         self.state_kind = self.crdt.state_kind.val;
-        self.description = self.crdt.description.val.clone();
+        if self.description != self.crdt.description.val {
+            self.description = self.crdt.description.val.clone();
+            self.checklist = ChecklistCounts::of(&self.description);
+        }
         self.labels = orswot_to_set(&self.crdt.labels);
         self.assignees = orswot_to_set(&self.crdt.assignees);
         self.children = orswot_to_set(&self.crdt.children);
@@ -174,6 +216,30 @@ impl TaskSnapshot {
             .filter(|(_, members)| !members.is_empty())
             .collect();
         self.updated_rfc3339 = self.crdt.updated.and_then(EventStamp::into_rfc3339);
+        self.archived = self.crdt.archived.val;
+        self.deleted_comments.clone_from(&self.crdt.deleted_comments);
+        self.comment_reactions = self
+            .crdt
+            .comment_reactions
+            .iter()
+            .filter(|(comment_id, _)| !self.crdt.deleted_comments.contains(comment_id))
+            .map(|(comment_id, set)| {
+                let mut grouped: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+                for (reaction, actor) in orswot_to_set(set) {
+                    grouped.entry(reaction).or_default().insert(actor);
+                }
+                (*comment_id, grouped)
+            })
+            .filter(|(_, grouped)| !grouped.is_empty())
+            .collect();
+        self.fields = self
+            .crdt
+            .fields
+            .iter()
+            .filter_map(|(name, value)| Some((name.clone(), value.val.clone()?)))
+            .collect();
+        self.milestone = self.crdt.milestone.val;
+        self.rank.clone_from(&self.crdt.rank.val);
     }
 }
 
@@ -204,6 +270,21 @@ pub struct TaskFilter {
     /// Timestamp range filter.
     #[serde(default)]
     pub updated: Option<UpdatedFilter>,
+    /// Include archived tasks (hidden by default).
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Parsed query expression, combined with the other criteria using AND.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<TaskQuery>,
+    /// Require each custom field to hold the given value (case-insensitive).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+    /// Match tasks planned for any of these milestones.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub milestones: BTreeSet<MilestoneId>,
+    /// Only match tasks whose description checklist has unticked items.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub open_checklist: bool,
 }
 
 /// Minimum number of characters required for text filters after trimming.
@@ -363,6 +444,51 @@ impl TaskFilterBuilder {
         self
     }
 
+    /// Control whether archived tasks are matched.
+    #[must_use]
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.filter.include_archived = include;
+        self
+    }
+
+    /// Configure the query expression.
+    #[must_use]
+    pub fn query(mut self, query: TaskQuery) -> Self {
+        self.filter.query = Some(query);
+        self
+    }
+
+    /// Remove the query expression.
+    #[must_use]
+    pub fn clear_query(mut self) -> Self {
+        self.filter.query = None;
+        self
+    }
+
+    /// Require a custom field to hold `value`.
+    #[must_use]
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filter.fields.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add milestone filters (logical OR).
+    #[must_use]
+    pub fn milestones<I>(mut self, milestones: I) -> Self
+    where
+        I: IntoIterator<Item = MilestoneId>,
+    {
+        self.filter.milestones.extend(milestones);
+        self
+    }
+
+    /// Control whether only tasks with open checklist items are matched.
+    #[must_use]
+    pub fn open_checklist(mut self, open: bool) -> Self {
+        self.filter.open_checklist = open;
+        self
+    }
+
     /// Return the composed filter.
     #[must_use]
     pub fn build(self) -> TaskFilter {
@@ -382,9 +508,31 @@ impl TaskFilterBuilder {
 }
 
 impl TaskFilter {
+    /// Resolve abbreviated task ids in the query against `known` task ids.
+    ///
+    /// See [`TaskQuery::resolve_task_ids`].
+    ///
+    /// # Errors
+    /// Returns [`TaskIdResolveError`] when a prefix matches no known task or more than one.
+    pub fn resolve_task_ids(&self, known: &[TaskId]) -> Result<Self, TaskIdResolveError> {
+        let mut resolved = self.clone();
+        if let Some(query) = &self.query {
+            resolved.query = Some(query.resolve_task_ids(known)?);
+        }
+        Ok(resolved)
+    }
+
     /// Check whether the provided snapshot satisfies this filter.
     #[must_use]
     pub fn matches(&self, task: &TaskSnapshot) -> bool {
+        // A query that asks about archival decides for itself whether archived tasks match.
+        if task.archived
+            && !self.include_archived
+            && !self.query.as_ref().is_some_and(TaskQuery::mentions_archived)
+        {
+            return false;
+        }
+
         if !self.states.is_empty()
             && !task
                 .state
@@ -433,13 +581,46 @@ impl TaskFilter {
             return false;
         }
 
+        if self.query.as_ref().is_some_and(|query| !query.matches(task)) {
+            return false;
+        }
+
+        if !self.fields.iter().all(|(name, expected)| {
+            task.fields
+                .get(name)
+                .is_some_and(|actual| actual.eq_ignore_ascii_case(expected))
+        }) {
+            return false;
+        }
+
+        if !self.milestones.is_empty()
+            && !task
+                .milestone
+                .is_some_and(|milestone| self.milestones.contains(&milestone))
+        {
+            return false;
+        }
+
+        if self.open_checklist && task.checklist.open() == 0 {
+            return false;
+        }
+
         true
     }
 
     /// Determine whether this filter has any active criteria.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.states.is_empty()
+        !self.has_criteria() && !self.include_archived && self.query.is_none()
+    }
+
+    /// Determine whether this filter narrows down the set of tasks.
+    ///
+    /// Unlike [`Self::is_empty`], `include_archived` is ignored because it only widens the
+    /// selection, and a blank query counts as no query.
+    #[must_use]
+    pub fn has_criteria(&self) -> bool {
+        !(self.states.is_empty()
             && self.state_kinds.is_empty()
             && self.labels.is_empty()
             && self.assignees.is_empty()
@@ -447,6 +628,13 @@ impl TaskFilter {
             && self.children.is_empty()
             && self.text.as_deref().is_none_or(|needle| needle.trim().is_empty())
             && self.updated.as_ref().is_none_or(UpdatedFilter::is_empty)
+            && self
+                .query
+                .as_ref()
+                .is_none_or(|query| query.as_str().trim().is_empty())
+            && self.fields.is_empty()
+            && self.milestones.is_empty()
+            && !self.open_checklist)
     }
 
     /// Validate filter invariants (e.g. text length bounds).
@@ -559,6 +747,12 @@ struct TaskCrdt {
     children: Orswot<TaskId, EventId>,
     parents: Orswot<TaskId, EventId>,
     relations: BTreeMap<String, Orswot<TaskId, EventId>>,
+    archived: LWWReg<bool, EventStamp>,
+    deleted_comments: BTreeSet<EventId>,
+    comment_reactions: BTreeMap<EventId, Orswot<(String, String), EventId>>,
+    fields: BTreeMap<String, LWWReg<Option<String>, EventStamp>>,
+    milestone: LWWReg<Option<MilestoneId>, EventStamp>,
+    rank: LWWReg<Option<String>, EventStamp>,
     updated: Option<EventStamp>,
 }
 
@@ -603,6 +797,12 @@ impl TaskCrdt {
             EventKind::TaskStateCleared => {
                 self.apply_task_state_cleared(stamp);
             }
+            EventKind::TaskArchived => {
+                self.archived.update(true, stamp);
+            }
+            EventKind::TaskUnarchived => {
+                self.archived.update(false, stamp);
+            }
             EventKind::TaskTitleSet { title } => {
                 self.apply_task_title_set(stamp, title);
             }
@@ -624,6 +824,18 @@ impl TaskCrdt {
             EventKind::CommentAdded { .. } | EventKind::CommentUpdated { .. } => {
                 // Snapshot ignores comment bodies; updated timestamp handled above.
             }
+            EventKind::CommentDeleted { comment_id } => {
+                self.deleted_comments.insert(*comment_id);
+            }
+            EventKind::CommentReactionAdded { comment_id, reaction } => {
+                let entry = self.comment_reactions.entry(*comment_id).or_default();
+                add_single(entry, (reaction.clone(), ev.actor.email.clone()), ev.id);
+            }
+            EventKind::CommentReactionRemoved { comment_id, reaction } => {
+                if let Some(entry) = self.comment_reactions.get_mut(comment_id) {
+                    remove_all(entry, std::iter::once((reaction.clone(), ev.actor.email.clone())));
+                }
+            }
             EventKind::ChildLinked { parent, child } => {
                 self.apply_child_linked(ev.task, *parent, *child, ev.id);
             }
@@ -636,6 +848,21 @@ impl TaskCrdt {
             EventKind::RelationRemoved { kind, target } => {
                 self.apply_relation_removed(kind, *target);
             }
+            EventKind::FieldSet { name, value } => {
+                self.apply_field(stamp, name, Some(value.clone()));
+            }
+            EventKind::FieldCleared { name } => {
+                self.apply_field(stamp, name, None);
+            }
+            EventKind::MilestoneSet { milestone } => {
+                self.milestone.update(Some(*milestone), stamp);
+            }
+            EventKind::MilestoneCleared => {
+                self.milestone.update(None, stamp);
+            }
+            EventKind::TaskRankSet { rank } => {
+                self.rank.update(rank.clone(), stamp);
+            }
         }
     }
 
@@ -704,6 +931,13 @@ impl TaskCrdt {
         }
     }
 
+    fn apply_field(&mut self, stamp: EventStamp, name: &str, value: Option<String>) {
+        self.fields
+            .entry(name.to_owned())
+            .or_default()
+            .update(value, stamp);
+    }
+
     fn apply_relation_added(&mut self, kind: &str, target: TaskId, event_id: EventId) {
         let entry = self.relations.entry(kind.to_owned()).or_default();
         add_single(entry, target, event_id);
@@ -1330,6 +1564,112 @@ mod tests {
         assert_eq!(snapshot.title, "New");
     }
 
+    #[test]
+    fn archive_events_follow_last_write_wins() {
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+
+        let mut archived = Event::new(task, &actor, EventKind::TaskArchived);
+        archived.lamport = 2;
+        let mut unarchived = Event::new(task, &actor, EventKind::TaskUnarchived);
+        unarchived.lamport = 1;
+
+        let mut snapshot = TaskSnapshot::default();
+        assert!(!snapshot.archived);
+        snapshot.apply(&archived);
+        snapshot.apply(&unarchived);
+        assert!(snapshot.archived, "older unarchive must not win");
+
+        let mut restored = Event::new(task, &actor, EventKind::TaskUnarchived);
+        restored.lamport = 3;
+        snapshot.apply(&restored);
+        assert!(!snapshot.archived);
+
+        let replayed = TaskSnapshot::replay(&[restored, unarchived, archived]);
+        assert!(!replayed.archived);
+    }
+
+    #[test]
+    fn comment_reactions_are_tracked_per_actor() {
+        let task = TaskId::new();
+        let alice = Actor {
+            name: "alice".into(),
+            email: "alice@example.invalid".into(),
+        };
+        let bob = Actor {
+            name: "bob".into(),
+            email: "bob@example.invalid".into(),
+        };
+        let comment_id = fixed_event_id(7);
+        let react = |actor: &Actor, reaction: &str| {
+            Event::new(
+                task,
+                actor,
+                EventKind::CommentReactionAdded {
+                    comment_id,
+                    reaction: reaction.into(),
+                },
+            )
+        };
+
+        let mut snapshot = TaskSnapshot::default();
+        snapshot.apply(&react(&alice, "+1"));
+        snapshot.apply(&react(&bob, "+1"));
+        snapshot.apply(&react(&bob, "eyes"));
+        snapshot.apply(&Event::new(
+            task,
+            &alice,
+            EventKind::CommentReactionRemoved {
+                comment_id,
+                reaction: "+1".into(),
+            },
+        ));
+
+        let reactions = snapshot
+            .comment_reactions
+            .get(&comment_id)
+            .unwrap_or_else(|| panic!("reactions for comment"));
+        assert_eq!(
+            reactions
+                .get("+1")
+                .map(|actors| actors.iter().cloned().collect::<Vec<_>>()),
+            Some(vec!["bob@example.invalid".to_string()])
+        );
+        assert!(reactions.contains_key("eyes"));
+    }
+
+    #[test]
+    fn comment_deletion_hides_reactions() {
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        let comment_id = fixed_event_id(8);
+
+        let mut snapshot = TaskSnapshot::default();
+        snapshot.apply(&Event::new(
+            task,
+            &actor,
+            EventKind::CommentReactionAdded {
+                comment_id,
+                reaction: "+1".into(),
+            },
+        ));
+        assert!(snapshot.comment_reactions.contains_key(&comment_id));
+
+        snapshot.apply(&Event::new(
+            task,
+            &actor,
+            EventKind::CommentDeleted { comment_id },
+        ));
+        assert!(snapshot.deleted_comments.contains(&comment_id));
+        assert!(snapshot.comment_reactions.is_empty());
+    }
+
     #[test]
     fn task_description_event_handles_none() {
         let task = TaskId::new();
@@ -1411,6 +1751,106 @@ mod tests {
         assert!(snapshot.assignees.contains("bob"));
     }
 
+    #[test]
+    fn field_events_resolve_last_writer_wins() {
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        let mut high = Event::new(
+            task,
+            &actor,
+            EventKind::FieldSet {
+                name: "priority".into(),
+                value: "high".into(),
+            },
+        );
+        high.lamport = 2;
+        let mut low = Event::new(
+            task,
+            &actor,
+            EventKind::FieldSet {
+                name: "priority".into(),
+                value: "low".into(),
+            },
+        );
+        low.lamport = 1;
+        let mut cleared = Event::new(
+            task,
+            &actor,
+            EventKind::FieldCleared {
+                name: "priority".into(),
+            },
+        );
+        cleared.lamport = 3;
+
+        let mut snapshot = TaskSnapshot::default();
+        snapshot.apply(&high);
+        snapshot.apply(&low);
+        assert_eq!(snapshot.fields.get("priority").map(String::as_str), Some("high"));
+
+        snapshot.apply(&cleared);
+        assert!(snapshot.fields.is_empty());
+    }
+
+    #[test]
+    fn milestone_events_resolve_last_writer_wins() {
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        let first = MilestoneId::new();
+        let second = MilestoneId::new();
+        let mut later = Event::new(task, &actor, EventKind::MilestoneSet { milestone: second });
+        later.lamport = 2;
+        let mut earlier = Event::new(task, &actor, EventKind::MilestoneSet { milestone: first });
+        earlier.lamport = 1;
+        let mut cleared = Event::new(task, &actor, EventKind::MilestoneCleared);
+        cleared.lamport = 3;
+
+        let mut snapshot = TaskSnapshot::default();
+        snapshot.apply(&later);
+        snapshot.apply(&earlier);
+        assert_eq!(snapshot.milestone, Some(second));
+
+        snapshot.apply(&cleared);
+        assert_eq!(snapshot.milestone, None);
+    }
+
+    #[test]
+    fn concurrent_rank_events_converge_regardless_of_arrival_order() {
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        let ts = OffsetDateTime::now_utc();
+        let reorder = |seed: u128, rank: &str| {
+            let mut event = Event::new(
+                task,
+                &actor,
+                EventKind::TaskRankSet {
+                    rank: Some(rank.into()),
+                },
+            );
+            event.id = fixed_event_id(seed);
+            event.lamport = 4;
+            event.ts = ts;
+            event
+        };
+        let ours = reorder(1, "F");
+        let theirs = reorder(2, "k");
+
+        let forward = TaskSnapshot::replay(&[ours.clone(), theirs.clone()]);
+        let mut backward = TaskSnapshot::default();
+        backward.apply(&theirs);
+        backward.apply(&ours);
+        assert_eq!(forward.rank.as_deref(), Some("k"));
+        assert_eq!(backward.rank, forward.rank);
+    }
+
     #[test]
     fn child_link_events_update_relationships() {
         let parent = TaskId::new();
@@ -1581,6 +2021,67 @@ mod tests {
         assert!(!filter.matches(&snapshot));
     }
 
+    #[test]
+    fn task_filter_matches_custom_fields() {
+        let mut snapshot = blank_snapshot();
+        snapshot.fields.insert("priority".into(), "high".into());
+
+        let filter = TaskFilterBuilder::new().field("priority", "HIGH").build();
+        assert!(!filter.is_empty());
+        assert!(filter.matches(&snapshot));
+
+        let filter = TaskFilterBuilder::new().field("due", "2025-01-31").build();
+        assert!(!filter.matches(&snapshot));
+    }
+
+    #[test]
+    fn task_filter_matches_any_listed_milestone() {
+        let milestone = MilestoneId::new();
+        let mut snapshot = blank_snapshot();
+
+        let filter = TaskFilterBuilder::new()
+            .milestones([milestone, MilestoneId::new()])
+            .build();
+        assert!(!filter.is_empty());
+        assert!(!filter.matches(&snapshot));
+
+        snapshot.milestone = Some(milestone);
+        assert!(filter.matches(&snapshot));
+    }
+
+    #[test]
+    fn task_filter_matches_open_checklist_items_from_description() {
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        let describe = |lamport: u64, description: &str| {
+            let mut event = Event::new(
+                task,
+                &actor,
+                EventKind::TaskDescriptionSet {
+                    description: Some(description.into()),
+                },
+            );
+            event.lamport = lamport;
+            event
+        };
+        let filter = TaskFilterBuilder::new().open_checklist(true).build();
+        assert!(!filter.is_empty());
+
+        let mut snapshot = blank_snapshot();
+        assert!(!filter.matches(&snapshot));
+
+        snapshot.apply(&describe(1, "- [x] design\n- [ ] build"));
+        assert_eq!((snapshot.checklist.done, snapshot.checklist.total), (1, 2));
+        assert!(filter.matches(&snapshot));
+
+        snapshot.apply(&describe(2, "- [x] design\n- [x] build"));
+        assert_eq!(snapshot.checklist.open(), 0);
+        assert!(!filter.matches(&snapshot));
+    }
+
     #[test]
     fn task_filter_matches_state_kind_constraints() {
         let mut snapshot = blank_snapshot();
@@ -1692,6 +2193,45 @@ mod tests {
         assert!(!filter.matches(&snapshot));
     }
 
+    #[test]
+    fn task_filter_hides_archived_tasks_unless_requested() {
+        let mut snapshot = blank_snapshot();
+        snapshot.archived = true;
+
+        let filter = TaskFilter::default();
+        assert!(!filter.matches(&snapshot));
+
+        let filter = TaskFilterBuilder::new().include_archived(true).build();
+        assert!(filter.matches(&snapshot));
+        assert!(!filter.is_empty());
+
+        snapshot.archived = false;
+        assert!(TaskFilter::default().matches(&snapshot));
+    }
+
+    #[test]
+    fn task_filter_combines_query_with_other_criteria() {
+        let mut snapshot = blank_snapshot();
+        snapshot.labels.insert("type/bug".into());
+        snapshot.state = Some("state/todo".into());
+
+        let parse =
+            |input: &str| TaskQuery::parse(input).unwrap_or_else(|err| panic!("query should parse: {err}"));
+
+        let mut filter = TaskFilterBuilder::new()
+            .query(parse("label:type/bug -state:state/done"))
+            .build();
+        assert!(!filter.is_empty());
+        assert!(filter.matches(&snapshot));
+
+        filter.labels.insert("type/doc".into());
+        assert!(!filter.matches(&snapshot));
+
+        snapshot.archived = true;
+        let filter = TaskFilterBuilder::new().query(parse("archived:true")).build();
+        assert!(filter.matches(&snapshot));
+    }
+
     #[test]
     fn task_filter_is_empty_tracks_criteria() {
         let filter = TaskFilter::default();
@@ -1711,5 +2251,14 @@ mod tests {
         filter.text = None;
         filter.state_kinds.exclude.insert(StateKind::Done);
         assert!(!filter.is_empty());
+        assert!(filter.has_criteria());
+
+        // Including archived tasks widens the selection, so it is not a criterion.
+        let filter = TaskFilter {
+            include_archived: true,
+            ..TaskFilter::default()
+        };
+        assert!(!filter.is_empty());
+        assert!(!filter.has_criteria());
     }
 }
@@ -0,0 +1,269 @@
+use crate::event::Actor;
+use crate::id::{EventId, MilestoneId};
+use crate::state::StateKindCounts;
+use crate::{EventStamp, TaskSnapshot};
+use crdts::lwwreg::LWWReg;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use time::OffsetDateTime;
+
+/// Milestone event envelope stored as JSON in the commit message body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneEvent {
+    /// Schema identifier for forward compatibility.
+    pub schema: String,
+    /// Unique event identifier.
+    pub id: EventId,
+    /// Lamport clock for deterministic ordering.
+    #[serde(default)]
+    pub lamport: u64,
+    #[serde(with = "time::serde::rfc3339")]
+    /// Event timestamp in UTC.
+    pub ts: OffsetDateTime,
+    /// Actor who authored the event.
+    pub actor: Actor,
+    /// Target milestone identifier.
+    pub milestone: MilestoneId,
+    /// Domain-specific payload.
+    pub kind: MilestoneEventKind,
+}
+
+/// Milestone event kinds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MilestoneEventKind {
+    /// A new milestone is created.
+    MilestoneCreated {
+        /// Human-readable title.
+        title: String,
+        /// Optional target date (`YYYY-MM-DD`).
+        #[serde(default)]
+        due: Option<String>,
+        /// Optional description in Markdown.
+        #[serde(default)]
+        description: Option<String>,
+    },
+    /// The milestone title is overwritten.
+    MilestoneTitleSet {
+        /// New title.
+        title: String,
+    },
+    /// The target date is overwritten.
+    MilestoneDueSet {
+        /// New target date (`YYYY-MM-DD`), or `None` to clear it.
+        #[serde(default)]
+        due: Option<String>,
+    },
+    /// The milestone description is overwritten.
+    MilestoneDescriptionSet {
+        /// New description in Markdown (or `None` to clear).
+        #[serde(default)]
+        description: Option<String>,
+    },
+    /// The milestone is closed.
+    MilestoneClosed,
+    /// A closed milestone is reopened.
+    MilestoneReopened,
+}
+
+impl MilestoneEvent {
+    /// Create a new milestone event with the current timestamp.
+    #[must_use]
+    pub fn new(milestone: MilestoneId, actor: &Actor, kind: MilestoneEventKind) -> Self {
+        Self {
+            schema: "git-mile-milestone-event@1".to_owned(),
+            id: EventId::new(),
+            lamport: 0,
+            ts: OffsetDateTime::now_utc(),
+            actor: actor.clone(),
+            milestone,
+            kind,
+        }
+    }
+
+    const fn stamp(&self) -> EventStamp {
+        EventStamp {
+            lamport: self.lamport,
+            ts: self.ts,
+            id: self.id,
+        }
+    }
+}
+
+/// Materialized view of a milestone by replaying its events.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MilestoneSnapshot {
+    /// Identifier of the milestone.
+    pub id: MilestoneId,
+    /// Human-readable title.
+    pub title: String,
+    /// Target date (`YYYY-MM-DD`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// Latest description in Markdown.
+    #[serde(default)]
+    pub description: String,
+    /// Whether the milestone has been closed.
+    #[serde(default)]
+    pub closed: bool,
+    /// RFC 3339 timestamp of the most recent event.
+    pub updated_rfc3339: Option<String>,
+}
+
+impl MilestoneSnapshot {
+    /// Replay milestone events; the result does not depend on their order.
+    #[must_use]
+    pub fn replay(events: &[MilestoneEvent]) -> Self {
+        let mut title: LWWReg<String, EventStamp> = LWWReg::default();
+        let mut due: LWWReg<Option<String>, EventStamp> = LWWReg::default();
+        let mut description: LWWReg<String, EventStamp> = LWWReg::default();
+        let mut closed: LWWReg<bool, EventStamp> = LWWReg::default();
+        let mut updated: Option<EventStamp> = None;
+        let mut id = MilestoneId::default();
+
+        for event in events {
+            let stamp = event.stamp();
+            id = event.milestone;
+            updated = Some(updated.map_or(stamp, |existing| existing.max(stamp)));
+            match &event.kind {
+                MilestoneEventKind::MilestoneCreated {
+                    title: initial_title,
+                    due: initial_due,
+                    description: initial_description,
+                } => {
+                    title.update(initial_title.clone(), stamp);
+                    due.update(initial_due.clone(), stamp);
+                    description.update(initial_description.clone().unwrap_or_default(), stamp);
+                }
+                MilestoneEventKind::MilestoneTitleSet { title: value } => title.update(value.clone(), stamp),
+                MilestoneEventKind::MilestoneDueSet { due: value } => due.update(value.clone(), stamp),
+                MilestoneEventKind::MilestoneDescriptionSet { description: value } => {
+                    description.update(value.clone().unwrap_or_default(), stamp);
+                }
+                MilestoneEventKind::MilestoneClosed => closed.update(true, stamp),
+                MilestoneEventKind::MilestoneReopened => closed.update(false, stamp),
+            }
+        }
+
+        Self {
+            id,
+            title: title.val,
+            due: due.val,
+            description: description.val,
+            closed: closed.val,
+            updated_rfc3339: updated.and_then(EventStamp::into_rfc3339),
+        }
+    }
+
+    /// Order milestones by target date (undated last), then title.
+    #[must_use]
+    pub fn schedule_order(&self, other: &Self) -> Ordering {
+        match (&self.due, &other.due) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| self.title.cmp(&other.title))
+        .then_with(|| self.id.cmp(&other.id))
+    }
+}
+
+/// Count the non-archived tasks of `tasks` that belong to `milestone`, by state kind.
+pub fn progress<'a>(
+    milestone: MilestoneId,
+    tasks: impl IntoIterator<Item = &'a TaskSnapshot>,
+) -> StateKindCounts {
+    let mut counts = StateKindCounts::default();
+    for task in tasks {
+        if !task.archived && task.milestone == Some(milestone) {
+            counts.record(task.state_kind);
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::TaskId;
+    use crate::state::StateKind;
+    use time::Duration;
+
+    fn actor() -> Actor {
+        Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        }
+    }
+
+    #[test]
+    fn replay_merges_concurrent_updates_by_stamp() {
+        let id = MilestoneId::new();
+        let created = MilestoneEvent::new(
+            id,
+            &actor(),
+            MilestoneEventKind::MilestoneCreated {
+                title: "v1.0".into(),
+                due: Some("2025-06-30".into()),
+                description: None,
+            },
+        );
+        let mut retitled = MilestoneEvent::new(
+            id,
+            &actor(),
+            MilestoneEventKind::MilestoneTitleSet {
+                title: "v1.0 GA".into(),
+            },
+        );
+        retitled.lamport = 1;
+        let mut stale = MilestoneEvent::new(
+            id,
+            &actor(),
+            MilestoneEventKind::MilestoneTitleSet {
+                title: "v1.0 beta".into(),
+            },
+        );
+        stale.lamport = 1;
+        stale.ts = retitled.ts - Duration::seconds(5);
+        let mut closed = MilestoneEvent::new(id, &actor(), MilestoneEventKind::MilestoneClosed);
+        closed.lamport = 2;
+
+        let forward =
+            MilestoneSnapshot::replay(&[created.clone(), stale.clone(), retitled.clone(), closed.clone()]);
+        let backward = MilestoneSnapshot::replay(&[closed, retitled, stale, created]);
+        for snapshot in [&forward, &backward] {
+            assert_eq!(snapshot.id, id);
+            assert_eq!(snapshot.title, "v1.0 GA");
+            assert_eq!(snapshot.due.as_deref(), Some("2025-06-30"));
+            assert!(snapshot.closed);
+        }
+    }
+
+    #[test]
+    fn progress_counts_members_by_state_kind() {
+        let milestone = MilestoneId::new();
+        let task = |kind: Option<StateKind>, member: bool, archived: bool| TaskSnapshot {
+            id: TaskId::new(),
+            state_kind: kind,
+            milestone: member.then_some(milestone),
+            archived,
+            ..TaskSnapshot::default()
+        };
+        let tasks = [
+            task(Some(StateKind::Done), true, false),
+            task(Some(StateKind::InProgress), true, false),
+            task(None, true, false),
+            task(Some(StateKind::Done), true, true),
+            task(Some(StateKind::Done), false, false),
+        ];
+
+        let progress = progress(milestone, &tasks);
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.done, 1);
+        assert_eq!(progress.in_progress, 1);
+        assert_eq!(progress.unclassified, 1);
+        assert_eq!(progress.percent_done(), 33);
+        assert_eq!(StateKindCounts::default().percent_done(), 0);
+    }
+}
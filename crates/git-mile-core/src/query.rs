@@ -0,0 +1,818 @@
+//! Query language compiled into a predicate over [`TaskSnapshot`].
+//!
+//! ```text
+//! query   := or
+//! or      := and ("OR" and)*
+//! and     := unary (["AND"] unary)*
+//! unary   := ("NOT" | "-") unary | "(" or ")" | term
+//! term    := [field ":"] value
+//! value   := word | "quoted phrase"
+//! ```
+//!
+//! Bare terms match the same fields as the plain text filter. Keywords are case-insensitive and
+//! adjacent terms are joined with `AND`. Custom fields are addressed as `field.<name>:`.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, Duration, OffsetDateTime, UtcOffset};
+
+use crate::id::{TaskId, TaskIdPrefix, TaskIdResolveError};
+use crate::text_matcher::TextMatcher;
+use crate::{StateKind, TaskSnapshot};
+
+/// Prefix that turns the rest of a field name into a custom field lookup, e.g. `field.due:`.
+const CUSTOM_FIELD_PREFIX: &str = "field.";
+
+/// Field names accepted before `:` in a query term, besides `field.<name>`.
+pub const QUERY_FIELDS: &[&str] = &[
+    "text", "title", "label", "assignee", "state", "kind", "parent", "child", "id", "archived", "updated",
+];
+
+/// Parsed task query.
+///
+/// Serializes as its source text so filters holding a query stay readable in JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TaskQuery {
+    source: String,
+    expr: Expr,
+}
+
+/// Errors produced while parsing a [`TaskQuery`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum QueryParseError {
+    /// The query contained no terms.
+    #[error("query is empty")]
+    Empty,
+    /// A quoted phrase was not closed.
+    #[error("unterminated quote in query")]
+    UnterminatedQuote,
+    /// Parentheses do not balance.
+    #[error("unbalanced parenthesis in query")]
+    UnbalancedParenthesis,
+    /// An operator was not followed by a term.
+    #[error("`{operator}` is missing an operand")]
+    MissingOperand {
+        /// Operator keyword as written.
+        operator: String,
+    },
+    /// The field prefix is not recognised.
+    #[error("unknown query field `{field}` (expected one of: {}, field.<name>)", QUERY_FIELDS.join(", "))]
+    UnknownField {
+        /// Field name as written.
+        field: String,
+    },
+    /// The value does not fit the field.
+    #[error("invalid value `{value}` for `{field}`: {reason}")]
+    InvalidValue {
+        /// Field name.
+        field: &'static str,
+        /// Value as written.
+        value: String,
+        /// Why it was rejected.
+        reason: &'static str,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    And(Vec<Self>),
+    Or(Vec<Self>),
+    Not(Box<Self>),
+    Term(Term),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Text(TextMatcher),
+    Title(String),
+    Label(String),
+    Assignee(String),
+    State(String),
+    Kind(StateKind),
+    Parent(TaskIdPrefix),
+    Child(TaskIdPrefix),
+    IdPrefix(String),
+    Archived(bool),
+    Updated(Comparison, Bound),
+    Field {
+        name: String,
+        comparison: Comparison,
+        value: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    /// Age relative to the evaluation time.
+    Age(Duration),
+    /// Calendar day in UTC.
+    Day(Date),
+    /// Exact instant.
+    At(OffsetDateTime),
+}
+
+impl TaskQuery {
+    /// Parse a query string.
+    ///
+    /// # Errors
+    /// Returns [`QueryParseError`] when the input is empty or malformed.
+    pub fn parse(input: &str) -> Result<Self, QueryParseError> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err(QueryParseError::Empty);
+        }
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(QueryParseError::UnbalancedParenthesis);
+        }
+        Ok(Self {
+            source: input.trim().to_string(),
+            expr,
+        })
+    }
+
+    /// Query text as entered by the user.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluate the query against a snapshot, resolving relative dates against the current time.
+    #[must_use]
+    pub fn matches(&self, task: &TaskSnapshot) -> bool {
+        self.matches_at(task, OffsetDateTime::now_utc())
+    }
+
+    /// Evaluate the query with an explicit reference time for relative dates.
+    #[must_use]
+    pub fn matches_at(&self, task: &TaskSnapshot, now: OffsetDateTime) -> bool {
+        self.expr.matches(task, now)
+    }
+
+    /// Pin abbreviated `parent:` and `child:` ids to the single task in `known` they name.
+    ///
+    /// An unresolved prefix matches every related task whose id starts with it; resolving
+    /// reports prefixes that name no task or several, like `--task` does.
+    ///
+    /// # Errors
+    /// Returns [`TaskIdResolveError`] when a prefix matches no known task or more than one.
+    pub fn resolve_task_ids(&self, known: &[TaskId]) -> Result<Self, TaskIdResolveError> {
+        let mut resolved = self.clone();
+        resolved.expr.resolve_task_ids(known)?;
+        Ok(resolved)
+    }
+
+    /// Whether the query has an `archived:` term, in which case archived tasks should be considered.
+    #[must_use]
+    pub fn mentions_archived(&self) -> bool {
+        self.expr.mentions_archived()
+    }
+}
+
+impl fmt::Display for TaskQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for TaskQuery {
+    type Err = QueryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for TaskQuery {
+    type Error = QueryParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<TaskQuery> for String {
+    fn from(query: TaskQuery) -> Self {
+        query.source
+    }
+}
+
+impl Expr {
+    fn matches(&self, task: &TaskSnapshot, now: OffsetDateTime) -> bool {
+        match self {
+            Self::And(items) => items.iter().all(|item| item.matches(task, now)),
+            Self::Or(items) => items.iter().any(|item| item.matches(task, now)),
+            Self::Not(inner) => !inner.matches(task, now),
+            Self::Term(term) => term.matches(task, now),
+        }
+    }
+
+    fn resolve_task_ids(&mut self, known: &[TaskId]) -> Result<(), TaskIdResolveError> {
+        match self {
+            Self::And(items) | Self::Or(items) => {
+                items.iter_mut().try_for_each(|item| item.resolve_task_ids(known))
+            }
+            Self::Not(inner) => inner.resolve_task_ids(known),
+            Self::Term(Term::Parent(prefix) | Term::Child(prefix)) => {
+                *prefix = TaskIdPrefix::from(prefix.resolve(known.iter().copied())?);
+                Ok(())
+            }
+            Self::Term(_) => Ok(()),
+        }
+    }
+
+    fn mentions_archived(&self) -> bool {
+        match self {
+            Self::And(items) | Self::Or(items) => items.iter().any(Self::mentions_archived),
+            Self::Not(inner) => inner.mentions_archived(),
+            Self::Term(term) => matches!(term, Term::Archived(_)),
+        }
+    }
+}
+
+impl Term {
+    fn matches(&self, task: &TaskSnapshot, now: OffsetDateTime) -> bool {
+        match self {
+            Self::Text(matcher) => matcher.matches(task),
+            Self::Title(needle) => task.title.to_ascii_lowercase().contains(needle),
+            Self::Label(label) => task.labels.iter().any(|value| value.eq_ignore_ascii_case(label)),
+            Self::Assignee(assignee) => task
+                .assignees
+                .iter()
+                .any(|value| value.eq_ignore_ascii_case(assignee)),
+            Self::State(state) => task
+                .state
+                .as_deref()
+                .is_some_and(|value| value.eq_ignore_ascii_case(state)),
+            Self::Kind(kind) => task.state_kind == Some(*kind),
+            Self::Parent(parent) => task.parents.iter().any(|id| parent.matches(*id)),
+            Self::Child(child) => task.children.iter().any(|id| child.matches(*id)),
+            Self::IdPrefix(prefix) => task.id.to_string().starts_with(prefix.as_str()),
+            Self::Archived(archived) => task.archived == *archived,
+            Self::Updated(comparison, bound) => task
+                .updated_at()
+                .is_some_and(|updated| bound.compare(*comparison, updated, now)),
+            Self::Field {
+                name,
+                comparison,
+                value,
+            } => task
+                .fields
+                .get(name)
+                .is_some_and(|actual| comparison.holds(compare_field_values(actual, value))),
+        }
+    }
+}
+
+impl Bound {
+    fn compare(self, comparison: Comparison, updated: OffsetDateTime, now: OffsetDateTime) -> bool {
+        match self {
+            // `updated:<7d` reads as "less than seven days old", so ages compare directly and a bare
+            // duration means "within".
+            Self::Age(age) => {
+                let actual = now - updated;
+                match comparison {
+                    Comparison::Lt => actual < age,
+                    Comparison::Le | Comparison::Eq => actual <= age,
+                    Comparison::Gt => actual > age,
+                    Comparison::Ge => actual >= age,
+                }
+            }
+            Self::Day(day) => comparison.apply(&updated.to_offset(UtcOffset::UTC).date(), &day),
+            Self::At(at) => comparison.apply(&updated, &at),
+        }
+    }
+}
+
+impl Comparison {
+    fn apply<T: Ord>(self, actual: &T, expected: &T) -> bool {
+        self.holds(actual.cmp(expected))
+    }
+
+    const fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+            Self::Eq => ordering.is_eq(),
+        }
+    }
+
+    /// Split a leading `<`, `<=`, `>`, `>=` or `=` off `value`; no operator means equality.
+    fn split(value: &str) -> (Self, &str) {
+        [
+            ("<=", Self::Le),
+            (">=", Self::Ge),
+            ("<", Self::Lt),
+            (">", Self::Gt),
+            ("=", Self::Eq),
+        ]
+        .into_iter()
+        .find_map(|(prefix, comparison)| value.strip_prefix(prefix).map(|rest| (comparison, rest.trim())))
+        .unwrap_or((Self::Eq, value))
+    }
+}
+
+/// Order two custom field values: numerically when both are numbers, otherwise as text
+/// ignoring ASCII case. ISO dates therefore compare chronologically.
+#[must_use]
+pub fn compare_field_values(a: &str, b: &str) -> Ordering {
+    if let (Ok(x), Ok(y)) = (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        return x.total_cmp(&y);
+    }
+    a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    Not,
+    Word {
+        field: Option<String>,
+        value: String,
+        quoted: bool,
+    },
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, QueryParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        match ch {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '-' => {
+                chars.next();
+                if chars.peek().is_some_and(|next| !next.is_whitespace()) {
+                    tokens.push(Token::Not);
+                } else {
+                    tokens.push(Token::Word {
+                        field: None,
+                        value: "-".into(),
+                        quoted: false,
+                    });
+                }
+            }
+            '"' => {
+                chars.next();
+                tokens.push(Token::Word {
+                    field: None,
+                    value: read_quoted(&mut chars)?,
+                    quoted: true,
+                });
+            }
+            _ => {
+                let mut word = String::new();
+                let mut field = None;
+                let mut quoted = false;
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    chars.next();
+                    if c == ':' && field.is_none() {
+                        field = Some(std::mem::take(&mut word));
+                        if chars.peek() == Some(&'"') {
+                            chars.next();
+                            word = read_quoted(&mut chars)?;
+                            quoted = true;
+                            break;
+                        }
+                    } else {
+                        word.push(c);
+                    }
+                }
+                tokens.push(Token::Word {
+                    field,
+                    value: word,
+                    quoted,
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn read_quoted(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Result<String, QueryParseError> {
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(value),
+            Some('\\') => match chars.next() {
+                Some(escaped) => value.push(escaped),
+                None => return Err(QueryParseError::UnterminatedQuote),
+            },
+            Some(c) => value.push(c),
+            None => return Err(QueryParseError::UnterminatedQuote),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(
+            self.peek(),
+            Some(Token::Word { field: None, value, quoted: false }) if value.eq_ignore_ascii_case(keyword)
+        )
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, QueryParseError> {
+        let mut items = vec![self.parse_and()?];
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            if self.at_operand_end() {
+                return Err(QueryParseError::MissingOperand {
+                    operator: "OR".into(),
+                });
+            }
+            items.push(self.parse_and()?);
+        }
+        Ok(collapse(items, Expr::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, QueryParseError> {
+        let mut items = vec![self.parse_unary()?];
+        loop {
+            if self.peek_keyword("and") {
+                self.pos += 1;
+                if self.at_operand_end() {
+                    return Err(QueryParseError::MissingOperand {
+                        operator: "AND".into(),
+                    });
+                }
+            } else if self.at_operand_end() || self.peek_keyword("or") {
+                break;
+            }
+            items.push(self.parse_unary()?);
+        }
+        Ok(collapse(items, Expr::And))
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, QueryParseError> {
+        if matches!(self.peek(), Some(Token::Not)) || self.peek_keyword("not") {
+            self.pos += 1;
+            if self.at_operand_end() {
+                return Err(QueryParseError::MissingOperand {
+                    operator: "NOT".into(),
+                });
+            }
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+
+        match self.tokens.get(self.pos).cloned() {
+            Some(Token::Open) => {
+                self.pos += 1;
+                let inner = self.parse_or()?;
+                if !matches!(self.peek(), Some(Token::Close)) {
+                    return Err(QueryParseError::UnbalancedParenthesis);
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(Token::Word { field, value, .. }) => {
+                self.pos += 1;
+                parse_term(field.as_deref(), &value).map(Expr::Term)
+            }
+            Some(Token::Close) => Err(QueryParseError::UnbalancedParenthesis),
+            Some(Token::Not) | None => Err(QueryParseError::Empty),
+        }
+    }
+
+    fn at_operand_end(&self) -> bool {
+        matches!(self.peek(), None | Some(Token::Close))
+    }
+}
+
+fn collapse(mut items: Vec<Expr>, wrap: fn(Vec<Expr>) -> Expr) -> Expr {
+    if items.len() == 1 {
+        items.remove(0)
+    } else {
+        wrap(items)
+    }
+}
+
+fn parse_term(field: Option<&str>, value: &str) -> Result<Term, QueryParseError> {
+    let Some(field) = field else {
+        return text_term(value);
+    };
+    if let Some((prefix, name)) = field.trim().split_at_checked(CUSTOM_FIELD_PREFIX.len())
+        && prefix.eq_ignore_ascii_case(CUSTOM_FIELD_PREFIX)
+    {
+        return parse_custom_field(name, value);
+    }
+
+    let normalized = field.trim().to_ascii_lowercase().replace('_', "-");
+    let canonical = match normalized.as_str() {
+        "label" | "labels" => "label",
+        "assignee" | "assignees" => "assignee",
+        "kind" | "state-kind" => "kind",
+        "parent" | "parents" => "parent",
+        "child" | "children" => "child",
+        other => QUERY_FIELDS
+            .iter()
+            .copied()
+            .find(|known| *known == other)
+            .ok_or_else(|| QueryParseError::UnknownField {
+                field: field.to_string(),
+            })?,
+    };
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(invalid(canonical, value, "value is empty"));
+    }
+
+    match canonical {
+        "text" => text_term(value),
+        "title" => Ok(Term::Title(value.to_ascii_lowercase())),
+        "label" => Ok(Term::Label(value.to_string())),
+        "assignee" => Ok(Term::Assignee(value.to_string())),
+        "state" => Ok(Term::State(value.to_string())),
+        "kind" => parse_state_kind(value).map(Term::Kind).ok_or_else(|| {
+            invalid(
+                "kind",
+                value,
+                "expected todo, in_progress, blocked, done or backlog",
+            )
+        }),
+        "parent" => parse_task_id("parent", value).map(Term::Parent),
+        "child" => parse_task_id("child", value).map(Term::Child),
+        "id" => Ok(Term::IdPrefix(value.to_ascii_lowercase())),
+        "archived" => match value.to_ascii_lowercase().as_str() {
+            "true" | "yes" => Ok(Term::Archived(true)),
+            "false" | "no" => Ok(Term::Archived(false)),
+            _ => Err(invalid("archived", value, "expected true or false")),
+        },
+        _ => parse_updated(value),
+    }
+}
+
+fn text_term(value: &str) -> Result<Term, QueryParseError> {
+    TextMatcher::new(value)
+        .map(Term::Text)
+        .ok_or_else(|| invalid("text", value, "value is empty"))
+}
+
+fn parse_state_kind(value: &str) -> Option<StateKind> {
+    match value.to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+        "todo" => Some(StateKind::Todo),
+        "in_progress" | "inprogress" => Some(StateKind::InProgress),
+        "blocked" => Some(StateKind::Blocked),
+        "done" => Some(StateKind::Done),
+        "backlog" => Some(StateKind::Backlog),
+        _ => None,
+    }
+}
+
+fn parse_task_id(field: &'static str, value: &str) -> Result<TaskIdPrefix, QueryParseError> {
+    TaskIdPrefix::parse(value).map_err(|_| invalid(field, value, "expected a task id or id prefix"))
+}
+
+fn parse_custom_field(name: &str, value: &str) -> Result<Term, QueryParseError> {
+    if name.is_empty() {
+        return Err(invalid("field", value, "expected field.<name>"));
+    }
+    let (comparison, expected) = Comparison::split(value.trim());
+    if expected.is_empty() {
+        return Err(invalid("field", value, "value is empty"));
+    }
+    Ok(Term::Field {
+        name: name.to_string(),
+        comparison,
+        value: expected.to_string(),
+    })
+}
+
+fn parse_updated(value: &str) -> Result<Term, QueryParseError> {
+    let (comparison, rest) = Comparison::split(value);
+
+    if let Some(age) = parse_age(rest) {
+        return Ok(Term::Updated(comparison, Bound::Age(age)));
+    }
+    if let Ok(day) = Date::parse(rest, format_description!("[year]-[month]-[day]")) {
+        return Ok(Term::Updated(comparison, Bound::Day(day)));
+    }
+    if let Ok(at) = OffsetDateTime::parse(rest, &Rfc3339) {
+        if comparison == Comparison::Eq {
+            return Err(invalid("updated", value, "timestamps need <, <=, > or >="));
+        }
+        return Ok(Term::Updated(comparison, Bound::At(at)));
+    }
+    Err(invalid(
+        "updated",
+        value,
+        "expected a duration like 7d, a date like 2024-01-31 or an RFC3339 timestamp",
+    ))
+}
+
+/// Parse `<n><unit>` where unit is one of `m`, `h`, `d` or `w`, e.g. `7d`.
+#[must_use]
+pub fn parse_age(value: &str) -> Option<Duration> {
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (digits, unit) = value.split_at(split);
+    let amount: i64 = digits.parse().ok()?;
+    let unit_seconds = match unit {
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+    amount.checked_mul(unit_seconds).map(Duration::seconds)
+}
+
+fn invalid(field: &'static str, value: &str, reason: &'static str) -> QueryParseError {
+    QueryParseError::InvalidValue {
+        field,
+        value: value.to_string(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    const NOW: OffsetDateTime = datetime!(2025-03-10 12:00 UTC);
+
+    fn task(title: &str, labels: &[&str], state: &str, updated: OffsetDateTime) -> TaskSnapshot {
+        TaskSnapshot {
+            title: title.into(),
+            labels: labels.iter().map(|label| (*label).to_string()).collect(),
+            state: Some(state.into()),
+            updated_rfc3339: updated.format(&Rfc3339).ok(),
+            ..TaskSnapshot::default()
+        }
+    }
+
+    fn query(input: &str) -> TaskQuery {
+        TaskQuery::parse(input).unwrap_or_else(|err| panic!("query should parse: {err}"))
+    }
+
+    #[test]
+    fn boolean_operators_and_negation() {
+        let bug = task("Crash on start", &["type/bug"], "state/todo", NOW);
+        let doc = task("Write guide", &["type/doc"], "state/done", NOW);
+
+        let q = query("label:type/bug OR label:type/doc");
+        assert!(q.matches_at(&bug, NOW) && q.matches_at(&doc, NOW));
+
+        let q = query("(label:type/bug OR label:type/doc) -state:state/done");
+        assert!(q.matches_at(&bug, NOW));
+        assert!(!q.matches_at(&doc, NOW));
+
+        let q = query("NOT crash AND guide");
+        assert!(!q.matches_at(&bug, NOW));
+        assert!(q.matches_at(&doc, NOW));
+    }
+
+    #[test]
+    fn quoted_phrases_match_as_one_term() {
+        let snapshot = task("Crash on start", &[], "state/todo", NOW);
+        assert!(query("\"on start\"").matches_at(&snapshot, NOW));
+        assert!(query("title:\"crash on\"").matches_at(&snapshot, NOW));
+        assert!(!query("\"start crash\"").matches_at(&snapshot, NOW));
+    }
+
+    #[test]
+    fn relative_and_absolute_dates() {
+        let recent = task("recent", &[], "state/todo", NOW - Duration::days(2));
+        let stale = task("stale", &[], "state/todo", NOW - Duration::days(30));
+
+        let q = query("updated:<7d");
+        assert!(q.matches_at(&recent, NOW));
+        assert!(!q.matches_at(&stale, NOW));
+
+        let q = query("updated:>2w");
+        assert!(!q.matches_at(&recent, NOW));
+        assert!(q.matches_at(&stale, NOW));
+
+        assert!(query("updated:2025-03-08").matches_at(&recent, NOW));
+        assert!(query("updated:<2025-03-01").matches_at(&stale, NOW));
+        assert!(query("updated:>=2025-03-01T00:00:00Z").matches_at(&recent, NOW));
+    }
+
+    #[test]
+    fn custom_fields_compare_numbers_and_dates() {
+        let mut snapshot = task("Ship", &[], "state/todo", NOW);
+        snapshot.fields.insert("estimate".into(), "8".into());
+        snapshot.fields.insert("due".into(), "2025-03-14".into());
+        snapshot.fields.insert("priority".into(), "high".into());
+
+        assert!(query("field.priority:HIGH").matches_at(&snapshot, NOW));
+        assert!(query("field.estimate:>=8 field.estimate:<10").matches_at(&snapshot, NOW));
+        assert!(!query("field.estimate:>10").matches_at(&snapshot, NOW));
+        assert!(query("field.due:<2025-04-01").matches_at(&snapshot, NOW));
+        assert!(!query("field.owner:alice").matches_at(&snapshot, NOW));
+        assert!(query("-field.owner:alice").matches_at(&snapshot, NOW));
+        assert!(matches!(
+            TaskQuery::parse("field.:x"),
+            Err(QueryParseError::InvalidValue { field: "field", .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        assert_eq!(TaskQuery::parse("  "), Err(QueryParseError::Empty));
+        assert_eq!(
+            TaskQuery::parse("(label:a"),
+            Err(QueryParseError::UnbalancedParenthesis)
+        );
+        assert_eq!(
+            TaskQuery::parse("\"open"),
+            Err(QueryParseError::UnterminatedQuote)
+        );
+        assert!(matches!(
+            TaskQuery::parse("a OR"),
+            Err(QueryParseError::MissingOperand { .. })
+        ));
+        assert!(matches!(
+            TaskQuery::parse("colour:red"),
+            Err(QueryParseError::UnknownField { .. })
+        ));
+        assert!(matches!(
+            TaskQuery::parse("updated:<soon"),
+            Err(QueryParseError::InvalidValue { field: "updated", .. })
+        ));
+    }
+
+    #[test]
+    fn parent_and_child_terms_accept_id_prefixes() {
+        let parent = TaskId(uuid::Uuid::from_u128(0x0192_f3a1_7b00_7000_8000_0000_0000_0001));
+        let sibling = TaskId(uuid::Uuid::from_u128(0x0192_f3a1_7c00_7000_8000_0000_0000_0002));
+        let mut child = task("Child", &[], "state/todo", NOW);
+        child.parents.insert(parent);
+
+        assert!(query(&format!("parent:{parent}")).matches_at(&child, NOW));
+        assert!(query("parent:0192f3a1-7b").matches_at(&child, NOW));
+        assert!(!query("parent:0192f3a17c").matches_at(&child, NOW));
+        assert!(matches!(
+            TaskQuery::parse("child:019"),
+            Err(QueryParseError::InvalidValue { field: "child", .. })
+        ));
+
+        let known = [parent, sibling];
+        let resolved = query("parent:0192f3a17b")
+            .resolve_task_ids(&known)
+            .unwrap_or_else(|err| panic!("prefix should resolve: {err}"));
+        assert!(resolved.matches_at(&child, NOW));
+        assert!(matches!(
+            query("NOT parent:0192f3a1").resolve_task_ids(&known),
+            Err(TaskIdResolveError::Ambiguous { .. })
+        ));
+        assert!(matches!(
+            query("child:0192f3a17d").resolve_task_ids(&known),
+            Err(TaskIdResolveError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn serializes_as_source_text() {
+        let q = query("label:a -archived:true");
+        assert!(q.mentions_archived());
+        let json = serde_json::to_string(&q).unwrap_or_else(|err| panic!("serialize: {err}"));
+        assert_eq!(json, "\"label:a -archived:true\"");
+        let back: TaskQuery = serde_json::from_str(&json).unwrap_or_else(|err| panic!("deserialize: {err}"));
+        assert_eq!(back, q);
+    }
+}
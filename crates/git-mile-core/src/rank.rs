@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+
+use crate::TaskSnapshot;
+use crate::id::TaskId;
+
+/// Digits of a rank, in ascending byte order so ranks compare as plain strings.
+const DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Whether `rank` is a fraction this module can place other ranks around.
+///
+/// Valid ranks are non-empty, use only ASCII letters and digits and never end in `0`,
+/// so there is always room for another rank on either side.
+#[must_use]
+pub fn is_valid(rank: &str) -> bool {
+    !rank.is_empty() && !rank.ends_with('0') && rank.bytes().all(|byte| DIGITS.contains(&byte))
+}
+
+/// A rank that sorts strictly after `before` and strictly before `after`.
+///
+/// `None` stands for the start or end of the list. Bounds that are not [valid](is_valid)
+/// are ignored, as is an `after` that does not sort after `before`, so a corrupted or
+/// concurrently written neighbour never prevents a move.
+#[must_use]
+pub fn between(before: Option<&str>, after: Option<&str>) -> String {
+    let before = before
+        .filter(|rank| is_valid(rank))
+        .map(digits)
+        .unwrap_or_default();
+    let after = after
+        .filter(|rank| is_valid(rank))
+        .map(digits)
+        .filter(|after| *after > before);
+    midpoint(&before, after.as_deref())
+        .into_iter()
+        .map(|digit| char::from(DIGITS[usize::from(digit)]))
+        .collect()
+}
+
+/// Queue order: ranked tasks first by rank, rank ties by [`TaskId`].
+///
+/// Two unranked tasks compare equal so callers can order them by something else.
+#[must_use]
+pub fn compare(a: &TaskSnapshot, b: &TaskSnapshot) -> Ordering {
+    match (a.rank.as_deref(), b.rank.as_deref()) {
+        (Some(x), Some(y)) => x.cmp(y).then_with(|| a.id.cmp(&b.id)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Ranks to write so that `order[from]` ends up at position `to` of `order`.
+///
+/// `order` is expected in [`compare`] order. Unranked tasks that would end up in front
+/// of the moved task are ranked as well, since ranked tasks always sort first; ranks
+/// that already fit are kept, so a move usually writes a single rank.
+#[must_use]
+pub fn plan_move(order: &[&TaskSnapshot], from: usize, to: usize) -> Vec<(TaskId, String)> {
+    if from >= order.len() {
+        return Vec::new();
+    }
+    let mut moved = order.to_vec();
+    let task = moved.remove(from);
+    let to = to.min(moved.len());
+    moved.insert(to, task);
+
+    let mut planned = Vec::new();
+    let mut previous: Option<String> = None;
+    for (index, snapshot) in moved.iter().enumerate().take(to + 1) {
+        let fits =
+            |rank: &&str| is_valid(rank) && previous.as_deref().is_none_or(|previous| *rank > previous);
+        let next_index = if index + 1 == to { to + 1 } else { index + 1 };
+        let next = moved
+            .get(next_index)
+            .and_then(|next| next.rank.as_deref())
+            .filter(fits);
+        let kept = snapshot
+            .rank
+            .as_deref()
+            .filter(fits)
+            .filter(|rank| next.is_none_or(|next| *rank < next));
+        let rank = kept.map_or_else(
+            || {
+                let rank = between(previous.as_deref(), next);
+                planned.push((snapshot.id, rank.clone()));
+                rank
+            },
+            str::to_owned,
+        );
+        previous = Some(rank);
+    }
+    planned
+}
+
+fn digits(rank: &str) -> Vec<u8> {
+    rank.bytes()
+        .filter_map(|byte| DIGITS.iter().position(|digit| *digit == byte))
+        .filter_map(|digit| u8::try_from(digit).ok())
+        .collect()
+}
+
+/// Digits of a fraction strictly between `before` and `after` (the end of the list when `None`).
+///
+/// Both bounds must be valid and `before < after`; the result never ends in `0`.
+fn midpoint(mut before: &[u8], mut after: Option<&[u8]>) -> Vec<u8> {
+    const BASE: u8 = 62;
+    let mut out = Vec::new();
+    loop {
+        if let Some(upper) = after {
+            // Copy the prefix shared with `before`, reading missing digits of `before` as zeros.
+            let shared = upper
+                .iter()
+                .enumerate()
+                .take_while(|(index, digit)| before.get(*index).copied().unwrap_or(0) == **digit)
+                .count();
+            out.extend_from_slice(&upper[..shared]);
+            before = before.get(shared..).unwrap_or_default();
+            after = Some(&upper[shared..]);
+        }
+        let low = before.first().copied().unwrap_or(0);
+        let high = after.and_then(|upper| upper.first().copied()).unwrap_or(BASE);
+        if high - low > 1 {
+            out.push(low + (high - low) / 2);
+            return out;
+        }
+        if let Some(upper) = after.filter(|upper| upper.len() > 1) {
+            out.push(upper[0]);
+            return out;
+        }
+        out.push(low);
+        before = before.get(1..).unwrap_or_default();
+        after = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranked(rank: Option<&str>) -> TaskSnapshot {
+        TaskSnapshot {
+            id: TaskId::new(),
+            rank: rank.map(str::to_owned),
+            ..TaskSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn between_stays_strictly_inside_its_bounds() {
+        assert_eq!(between(None, None), "V");
+        let mut last = between(None, None);
+        let mut first = last.clone();
+        for _ in 0..200 {
+            let next = between(Some(&last), None);
+            assert!(next > last && is_valid(&next), "{next} after {last}");
+            last = next;
+            let previous = between(None, Some(&first));
+            assert!(
+                previous < first && is_valid(&previous),
+                "{previous} before {first}"
+            );
+            first = previous;
+        }
+        for (low, high) in [("V", "W"), ("1", "11"), ("az", "b"), ("0V", "1"), ("zz", "zzV")] {
+            let mid = between(Some(low), Some(high));
+            assert!(
+                low < mid.as_str() && mid.as_str() < high,
+                "{low} < {mid} < {high}"
+            );
+            assert!(is_valid(&mid));
+        }
+        assert_eq!(between(Some("not a rank!"), Some("b")), between(None, Some("b")));
+        assert_eq!(between(Some("c"), Some("b")), between(Some("c"), None));
+    }
+
+    #[test]
+    fn compare_puts_ranked_tasks_first_and_breaks_ties_by_id() {
+        let mut tied = [ranked(Some("V")), ranked(Some("V"))];
+        tied.sort_by_key(|task| task.id);
+        let unranked = ranked(None);
+        assert_eq!(compare(&tied[0], &tied[1]), Ordering::Less);
+        assert_eq!(compare(&tied[1], &unranked), Ordering::Less);
+        assert_eq!(compare(&unranked, &ranked(None)), Ordering::Equal);
+    }
+
+    #[test]
+    fn plan_move_ranks_only_what_the_new_position_needs() {
+        let a = ranked(Some("F"));
+        let b = ranked(Some("V"));
+        let c = ranked(None);
+        let d = ranked(None);
+        let order = [&a, &b, &c, &d];
+
+        let up = plan_move(&order, 1, 0);
+        assert_eq!(up.len(), 1);
+        assert_eq!(up[0].0, b.id);
+        assert!(up[0].1.as_str() < "F");
+
+        let down = plan_move(&order, 3, 2);
+        let ids: Vec<TaskId> = down.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![d.id]);
+        assert!(down[0].1.as_str() > "V");
+
+        let past_unranked = plan_move(&order, 0, 3);
+        let ids: Vec<TaskId> = past_unranked.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![c.id, d.id, a.id]);
+        let ranks: Vec<&str> = past_unranked.iter().map(|(_, rank)| rank.as_str()).collect();
+        assert!(ranks[0] > "V" && ranks[0] < ranks[1] && ranks[1] < ranks[2]);
+
+        assert!(plan_move(&order, 1, 1).is_empty());
+    }
+}
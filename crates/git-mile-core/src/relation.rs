@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// First-class relation kinds stored in [`crate::TaskSnapshot::relates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RelationKind {
+    /// This task blocks the target.
+    Blocks,
+    /// This task is blocked by the target.
+    BlockedBy,
+    /// This task duplicates the target.
+    Duplicates,
+    /// This task is loosely related to the target.
+    RelatesTo,
+}
+
+impl RelationKind {
+    /// Every supported relation kind, in display order.
+    pub const ALL: [Self; 4] = [Self::Blocks, Self::BlockedBy, Self::Duplicates, Self::RelatesTo];
+
+    /// Key stored in relation events.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::BlockedBy => "blocked-by",
+            Self::Duplicates => "duplicates",
+            Self::RelatesTo => "relates-to",
+        }
+    }
+
+    /// Relation recorded on the target task, if the kind is symmetric or has a counterpart.
+    #[must_use]
+    pub const fn inverse(self) -> Option<Self> {
+        match self {
+            Self::Blocks => Some(Self::BlockedBy),
+            Self::BlockedBy => Some(Self::Blocks),
+            Self::Duplicates => None,
+            Self::RelatesTo => Some(Self::RelatesTo),
+        }
+    }
+}
+
+impl fmt::Display for RelationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an unknown relation kind.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown relation kind '{0}' (expected blocks, blocked-by, duplicates or relates-to)")]
+pub struct ParseRelationKindError(pub String);
+
+impl FromStr for RelationKind {
+    type Err = ParseRelationKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_ascii_lowercase().replace('_', "-");
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == normalized)
+            .ok_or_else(|| ParseRelationKindError(s.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_canonical_and_snake_case_names() {
+        for kind in RelationKind::ALL {
+            assert_eq!(kind.as_str().parse::<RelationKind>(), Ok(kind));
+        }
+        assert_eq!("Blocked_By".parse::<RelationKind>(), Ok(RelationKind::BlockedBy));
+        assert!("parent".parse::<RelationKind>().is_err());
+    }
+
+    #[test]
+    fn inverse_pairs_blocking_relations() {
+        assert_eq!(RelationKind::Blocks.inverse(), Some(RelationKind::BlockedBy));
+        assert_eq!(RelationKind::BlockedBy.inverse(), Some(RelationKind::Blocks));
+        assert_eq!(RelationKind::RelatesTo.inverse(), Some(RelationKind::RelatesTo));
+        assert_eq!(RelationKind::Duplicates.inverse(), None);
+    }
+}
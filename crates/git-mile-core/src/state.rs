@@ -30,6 +30,49 @@ impl StateKind {
     }
 }
 
+/// Task counts grouped by [`StateKind`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateKindCounts {
+    /// Tasks counted.
+    pub total: usize,
+    /// Tasks in a `done` state.
+    pub done: usize,
+    /// Tasks in an `in_progress` state.
+    pub in_progress: usize,
+    /// Tasks in a `blocked` state.
+    pub blocked: usize,
+    /// Tasks in a `todo` state.
+    pub todo: usize,
+    /// Tasks in a `backlog` state.
+    pub backlog: usize,
+    /// Tasks whose state has no kind.
+    pub unclassified: usize,
+}
+
+impl StateKindCounts {
+    /// Count one more task in the bucket for `kind`.
+    pub const fn record(&mut self, kind: Option<StateKind>) {
+        self.total += 1;
+        match kind {
+            Some(StateKind::Done) => self.done += 1,
+            Some(StateKind::InProgress) => self.in_progress += 1,
+            Some(StateKind::Blocked) => self.blocked += 1,
+            Some(StateKind::Todo) => self.todo += 1,
+            Some(StateKind::Backlog) => self.backlog += 1,
+            None => self.unclassified += 1,
+        }
+    }
+
+    /// Share of counted tasks that are done, rounded down to a whole percent.
+    #[must_use]
+    pub const fn percent_done(&self) -> usize {
+        match (self.done * 100).checked_div(self.total) {
+            Some(percent) => percent,
+            None => 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,7 @@
 use crate::TaskSnapshot;
 
 /// Case-insensitive substring matcher for task fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TextMatcher {
     needle: String,
 }
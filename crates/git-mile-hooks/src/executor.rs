@@ -51,7 +51,6 @@ impl HookExecutor {
                 exit_code: 0,
                 stdout: String::new(),
                 stderr: String::new(),
-                modified_event: None,
             });
         }
 
@@ -89,9 +88,13 @@ impl HookExecutor {
             .stderr(Stdio::piped())
             .spawn()?;
 
-        // Write input to stdin
+        // Write input to stdin. Hooks that never read it may exit first, so a
+        // broken pipe is not an error.
         if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(input_json.as_bytes())?;
+            match stdin.write_all(input_json.as_bytes()) {
+                Err(err) if err.kind() == std::io::ErrorKind::BrokenPipe => {}
+                result => result?,
+            }
         }
 
         // Wait for the process with timeout
@@ -102,18 +105,10 @@ impl HookExecutor {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         let exit_code = output.status.code().unwrap_or(-1);
 
-        // Try to parse modified event from stdout
-        let modified_event = if stdout.is_empty() {
-            None
-        } else {
-            serde_json::from_str(&stdout).ok()
-        };
-
         Ok(HookResult {
             exit_code,
             stdout,
             stderr,
-            modified_event,
         })
     }
 }
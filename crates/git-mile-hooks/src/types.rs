@@ -163,8 +163,11 @@ impl HookKind {
 /// Context passed to hook scripts
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HookContext {
-    /// The event being processed
+    /// The event being processed; the first one when the hook runs for a batch
     pub event: Event,
+    /// Every event of the batch, in commit order, when one commit carries several events
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<Event>,
     /// Additional hook-specific data
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<serde_json::Value>,
@@ -176,15 +179,35 @@ impl HookContext {
     pub fn new(event: &Event) -> Self {
         Self {
             event: event.clone(),
+            events: Vec::new(),
             data: None,
         }
     }
 
+    /// Create a context for the events of one commit.
+    ///
+    /// A single event produces the same context as [`new`](Self::new); returns `None`
+    /// when `events` is empty.
+    #[must_use]
+    pub fn batch(events: &[Event]) -> Option<Self> {
+        let (first, rest) = events.split_first()?;
+        Some(Self {
+            event: first.clone(),
+            events: if rest.is_empty() {
+                Vec::new()
+            } else {
+                events.to_vec()
+            },
+            data: None,
+        })
+    }
+
     /// Create a new hook context with additional data
     #[must_use]
     pub fn with_data(event: &Event, data: serde_json::Value) -> Self {
         Self {
             event: event.clone(),
+            events: Vec::new(),
             data: Some(data),
         }
     }
@@ -199,8 +222,6 @@ pub struct HookResult {
     pub stdout: String,
     /// Standard error
     pub stderr: String,
-    /// Modified event (if returned by hook)
-    pub modified_event: Option<Event>,
 }
 
 impl HookResult {
@@ -209,4 +230,36 @@ impl HookResult {
     pub const fn is_success(&self) -> bool {
         self.exit_code == 0
     }
+
+    /// Replacement event printed by the hook on stdout.
+    ///
+    /// Returns `Ok(None)` when the hook printed nothing (or only whitespace), meaning
+    /// the event goes through unmodified.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error when stdout holds anything other than a serialized [`Event`].
+    pub fn modified_event(&self) -> serde_json::Result<Option<Event>> {
+        if self.stdout.trim().is_empty() {
+            Ok(None)
+        } else {
+            serde_json::from_str(&self.stdout).map(Some)
+        }
+    }
+
+    /// Replacement events printed by a hook that ran for a batch.
+    ///
+    /// The hook prints a JSON array holding one event per batch event, in the
+    /// same order. Returns `Ok(None)` when the hook printed nothing (or only whitespace).
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error when stdout holds anything other than an array of events.
+    pub fn modified_events(&self) -> serde_json::Result<Option<Vec<Event>>> {
+        if self.stdout.trim().is_empty() {
+            Ok(None)
+        } else {
+            serde_json::from_str(&self.stdout).map(Some)
+        }
+    }
 }
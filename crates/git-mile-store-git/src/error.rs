@@ -25,6 +25,10 @@ pub enum GitStoreError {
     #[error("Failed to serialize event: {0}")]
     EventSerializeError(String),
 
+    /// Another writer moved the task ref after its tip was read.
+    #[error("Task ref {0} was updated concurrently")]
+    RefConflict(String),
+
     /// Failed to acquire repository lock.
     #[error("Repository lock error")]
     LockError,
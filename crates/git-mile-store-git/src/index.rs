@@ -0,0 +1,153 @@
+//! Persistent on-disk caches keyed by task ref tips.
+//!
+//! Walking every commit under `refs/git-mile/tasks/*` dominates start-up time
+//! once a repository holds thousands of tasks. A [`TipIndex`] stores one value
+//! per task together with the ref tip it was derived from, so a later process
+//! only has to redo the work for refs whose tip moved since the last load.
+//!
+//! The store keeps the decoded events of each task in an [`EventIndex`];
+//! callers that materialize views can keep them in their own index file keyed
+//! by the same tips.
+//!
+//! An index file is purely a cache: a missing, unreadable, or outdated index is
+//! discarded and rebuilt from the refs.
+
+use anyhow::{Context, Result};
+use git_mile_core::event::Event;
+use git_mile_core::id::TaskId;
+use git2::Oid;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Directory under the Git directory holding git-mile local state.
+const INDEX_DIR: &str = "git-mile";
+/// File name of the event index inside [`INDEX_DIR`].
+pub const EVENT_INDEX_FILE: &str = "events.json";
+/// Bump whenever the on-disk layout or the meaning of cached events changes.
+pub const EVENT_INDEX_VERSION: u32 = 2;
+
+/// Decoded task histories, each tagged with the ref tip they were read at.
+pub type EventIndex = TipIndex<Vec<Event>>;
+
+/// Location of the index file `file` for a repository whose Git directory is `git_dir`.
+#[must_use]
+pub fn index_path(git_dir: &Path, file: &str) -> PathBuf {
+    git_dir.join(INDEX_DIR).join(file)
+}
+
+/// Values keyed by task id, each tagged with the ref tip it reflects.
+///
+/// `version` identifies the layout of `T`; an index written with another
+/// version is discarded on load.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TipIndex<T> {
+    version: u32,
+    tasks: BTreeMap<TaskId, IndexEntry<T>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexEntry<T> {
+    tip: String,
+    value: T,
+}
+
+impl<T> TipIndex<T> {
+    /// Create an empty index for schema `version`.
+    #[must_use]
+    pub const fn new(version: u32) -> Self {
+        Self {
+            version,
+            tasks: BTreeMap::new(),
+        }
+    }
+
+    /// Read the index at `path`, falling back to an empty index when it is
+    /// missing, corrupt, or written by another schema `version`.
+    #[must_use]
+    pub fn load(path: &Path, version: u32) -> Self
+    where
+        T: DeserializeOwned,
+    {
+        let raw = match fs::read(path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Self::new(version),
+            Err(err) => {
+                warn!(path = %path.display(), "Failed to read index, rebuilding: {err}");
+                return Self::new(version);
+            }
+        };
+        match serde_json::from_slice::<Self>(&raw) {
+            Ok(index) if index.version == version => index,
+            Ok(index) => {
+                debug!(
+                    path = %path.display(),
+                    found = index.version,
+                    expected = version,
+                    "Index schema changed, rebuilding"
+                );
+                Self::new(version)
+            }
+            Err(err) => {
+                warn!(path = %path.display(), "Index is corrupt, rebuilding: {err}");
+                Self::new(version)
+            }
+        }
+    }
+
+    /// Remove and return the cached value for `task` if it was derived at `tip`.
+    pub fn take(&mut self, task: TaskId, tip: Oid) -> Option<T> {
+        let entry = self.tasks.remove(&task)?;
+        (entry.tip == tip.to_string()).then_some(entry.value)
+    }
+
+    /// Record the value derived for `task` at `tip`.
+    pub fn insert(&mut self, task: TaskId, tip: Oid, value: T) {
+        self.tasks.insert(
+            task,
+            IndexEntry {
+                tip: tip.to_string(),
+                value,
+            },
+        );
+    }
+
+    /// Number of tasks in the index.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Whether the index holds no tasks.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Consume the index, yielding every cached value with its task id.
+    pub fn into_values(self) -> impl Iterator<Item = (TaskId, T)> {
+        self.tasks.into_iter().map(|(task, entry)| (task, entry.value))
+    }
+
+    /// Write the index to `path` atomically via a sibling temporary file.
+    ///
+    /// # Errors
+    /// Returns an error if the directory cannot be created or the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()>
+    where
+        T: Serialize,
+    {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let tmp = path.with_extension(format!("json.tmp-{}", std::process::id()));
+        let body = serde_json::to_vec(self)?;
+        fs::write(&tmp, body).with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, path).with_context(|| format!("Failed to replace {}", path.display()))?;
+        Ok(())
+    }
+}
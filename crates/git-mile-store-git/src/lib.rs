@@ -3,14 +3,21 @@
 //! Git-backed storage implementation for git-mile.
 
 mod error;
+mod index;
 
 pub use error::GitStoreError;
+pub use index::{TipIndex, index_path};
+
+use crate::index::{EVENT_INDEX_FILE, EVENT_INDEX_VERSION, EventIndex};
 
 use anyhow::{Context, Result, anyhow};
-use git_mile_core::event::Event;
-use git_mile_core::id::TaskId;
+use git_mile_core::event::{Actor, Event};
+use git_mile_core::id::{EventId, MilestoneId, TaskId};
+use git_mile_core::milestone::MilestoneEvent;
 use git2::{Commit, Cred, CredentialType, Oid, RemoteCallbacks, Repository, Signature, Sort};
 use lru::LruCache;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::{env, num::NonZeroUsize};
@@ -20,12 +27,53 @@ use tracing::{debug, info, warn};
 const DEFAULT_EVENT_CACHE_CAPACITY: usize = 256;
 /// Environment variable controlling the `GitStore` event cache capacity.
 const EVENT_CACHE_CAPACITY_ENV_VAR: &str = "GIT_MILE_CACHE_CAPACITY";
-/// Prefix placed ahead of every git-mile event commit message.
+/// Prefix placed ahead of every single-event commit message.
 const EVENT_COMMIT_PREFIX: &str = "git-mile-event: ";
+/// Prefix placed ahead of commit messages carrying a JSON array of events.
+const EVENT_BATCH_COMMIT_PREFIX: &str = "git-mile-events: ";
+/// Ref namespaces under `refs/git-mile/` exchanged by push and pull.
+const SYNCED_NAMESPACES: [&str; 2] = ["tasks", "milestones"];
 /// Canonical OID of Git's empty tree object.
 const EMPTY_TREE_OID_HEX: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
 
-/// Storage based on git refs under `refs/git-mile/tasks/*`.
+/// Outcome of pulling task and milestone refs from a single remote.
+///
+/// Ahead/behind counts are measured in event commits per ref before
+/// merging and summed across every ref tracked for the remote.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullSummary {
+    /// Name of the remote that was pulled.
+    pub remote: String,
+    /// Refs that only existed on the remote and were created locally.
+    pub created: usize,
+    /// Refs fast-forwarded to the remote tip.
+    pub fast_forwarded: usize,
+    /// Refs that diverged and received a merge commit.
+    pub merged: usize,
+    /// Refs already identical to the remote tip.
+    pub up_to_date: usize,
+    /// Event commits present locally but missing on the remote.
+    pub ahead: usize,
+    /// Event commits present on the remote but missing locally.
+    pub behind: usize,
+}
+
+impl PullSummary {
+    fn new(remote: &str) -> Self {
+        Self {
+            remote: remote.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// Number of local refs that changed as a result of the pull.
+    #[must_use]
+    pub const fn updated(&self) -> usize {
+        self.created + self.fast_forwarded + self.merged
+    }
+}
+
+/// Storage based on git refs under `refs/git-mile/tasks/*` and `refs/git-mile/milestones/*`.
 pub struct GitStore {
     repo: Repository,
     repo_path: PathBuf,
@@ -138,6 +186,12 @@ impl GitStore {
         })
     }
 
+    /// Git directory (`.git`) of the opened repository.
+    #[must_use]
+    pub fn git_dir(&self) -> &Path {
+        &self.repo_path
+    }
+
     /// Name of the ref for a task.
     fn refname(task: &TaskId) -> String {
         format!("refs/git-mile/tasks/{task}")
@@ -147,6 +201,11 @@ impl GitStore {
         name.strip_prefix("refs/git-mile/tasks/")?.parse().ok()
     }
 
+    /// Name of the ref for a milestone.
+    fn milestone_refname(milestone: &MilestoneId) -> String {
+        format!("refs/git-mile/milestones/{milestone}")
+    }
+
     fn event_cache_capacity() -> NonZeroUsize {
         let env_value = env::var(EVENT_CACHE_CAPACITY_ENV_VAR).ok();
         Self::cache_capacity_from_override(env_value).unwrap_or_else(Self::default_cache_capacity)
@@ -197,28 +256,39 @@ impl GitStore {
         Ok(idx.write_tree()?)
     }
 
-    fn decode_event_from_commit(&self, oid: Oid) -> Result<Option<Event>> {
+    fn decode_events_from_commit<E: DeserializeOwned>(&self, oid: Oid) -> Result<Vec<E>> {
         let commit = self
             .repo
             .find_commit(oid)
             .with_context(|| format!("Object is not a commit: {oid}"))?;
-        Self::event_from_commit(&commit, oid)
+        Self::events_from_commit(&commit, oid)
     }
 
-    fn event_from_commit(commit: &Commit<'_>, oid: Oid) -> Result<Option<Event>> {
+    /// Decode the events recorded by a commit.
+    ///
+    /// Single-event commits carry one JSON object; batch commits written by
+    /// [`append_events`](Self::append_events) carry a JSON array. Merge commits
+    /// and other foreign commits yield no events.
+    fn events_from_commit<E: DeserializeOwned>(commit: &Commit<'_>, oid: Oid) -> Result<Vec<E>> {
         let Some(message) = commit.message() else {
-            return Ok(None);
+            return Ok(Vec::new());
         };
         let Some((head, body)) = message.split_once("\n\n") else {
-            return Ok(None);
+            return Ok(Vec::new());
         };
+
+        if head.starts_with(EVENT_BATCH_COMMIT_PREFIX) {
+            let events: Vec<E> = serde_json::from_str(body)
+                .with_context(|| format!("Failed to parse event batch JSON in commit {oid}"))?;
+            return Ok(events);
+        }
         if !head.starts_with(EVENT_COMMIT_PREFIX) {
-            return Ok(None);
+            return Ok(Vec::new());
         }
 
-        let ev: Event = serde_json::from_str(body)
+        let ev: E = serde_json::from_str(body)
             .with_context(|| format!("Failed to parse event JSON in commit {oid}"))?;
-        Ok(Some(ev))
+        Ok(vec![ev])
     }
 
     /// Append an event as a single commit with empty tree.
@@ -226,11 +296,47 @@ impl GitStore {
     /// # Errors
     /// Returns an error if any Git object manipulation fails.
     pub fn append_event(&self, ev: &Event) -> Result<Oid> {
+        self.append_events(std::slice::from_ref(ev))
+    }
+
+    /// Append several events for one task as a single commit with empty tree.
+    ///
+    /// Either every event becomes visible or none does. A lone event is written
+    /// in the single-event format so older readers keep understanding it.
+    ///
+    /// The ref only moves if it still points at the tip the new commit was built
+    /// on. When another writer got there first, nothing is published and a
+    /// [`GitStoreError::RefConflict`] is returned so the caller can restamp the
+    /// events and try again.
+    ///
+    /// # Errors
+    /// Returns an error if the batch is empty, spans several tasks, the ref was
+    /// updated concurrently, or any Git object manipulation fails.
+    pub fn append_events(&self, events: &[Event]) -> Result<Oid> {
+        let [ev, rest @ ..] = events else {
+            return Err(anyhow!("Cannot append an empty event batch"));
+        };
+        if let Some(other) = rest.iter().find(|other| other.task != ev.task) {
+            return Err(anyhow!("Event batch mixes tasks {} and {}", ev.task, other.task));
+        }
         let refname = Self::refname(&ev.task);
+        let appended = self.commit_events(&refname, &ev.actor, ev.id, events);
+        self.invalidate_cached_events(ev.task);
+        appended
+    }
 
+    /// Commit serialized `events` on top of `refname`, moving the ref only if it
+    /// still points at the tip the commit was built on.
+    fn commit_events<T: Serialize>(
+        &self,
+        refname: &str,
+        actor: &Actor,
+        first_id: EventId,
+        events: &[T],
+    ) -> Result<Oid> {
         // Author/committer signature from event actor.
-        let sig = Signature::now(&ev.actor.name, &ev.actor.email)
-            .with_context(|| format!("Invalid signature: {} <{}>", ev.actor.name, ev.actor.email))?;
+        let sig = Signature::now(&actor.name, &actor.email)
+            .with_context(|| format!("Invalid signature: {} <{}>", actor.name, actor.email))?;
 
         debug_assert_eq!(
             self.empty_tree_oid.to_string(),
@@ -239,27 +345,46 @@ impl GitStore {
         );
         let tree = self.repo.find_tree(self.empty_tree_oid)?;
 
-        // Parent (if ref exists)
-        let parents: Vec<Commit<'_>> = match self.repo.find_reference(&refname) {
-            Ok(r) => {
-                let target = r.target().ok_or_else(|| anyhow!("Ref {refname} has no target"))?;
-                let parent = self.repo.find_commit(target)?;
-                vec![parent]
-            }
-            Err(_) => Vec::new(),
+        // Parent (if ref exists); the ref update below only succeeds if it still points here.
+        let expected_tip = match self.repo.find_reference(refname) {
+            Ok(r) => Some(r.target().ok_or_else(|| anyhow!("Ref {refname} has no target"))?),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        let parents: Vec<Commit<'_>> = match expected_tip {
+            Some(tip) => vec![self.repo.find_commit(tip)?],
+            None => Vec::new(),
         };
 
         // Commit message: first line + blank + pretty JSON
-        let body = serde_json::to_string_pretty(ev)?;
-        let msg = format!("{EVENT_COMMIT_PREFIX}{}\n\n{}", ev.id, body);
+        let msg = if let [single] = events {
+            let body = serde_json::to_string_pretty(single)?;
+            format!("{EVENT_COMMIT_PREFIX}{first_id}\n\n{body}")
+        } else {
+            let body = serde_json::to_string_pretty(events)?;
+            format!("{EVENT_BATCH_COMMIT_PREFIX}{first_id}\n\n{body}")
+        };
 
         let parent_refs: Vec<&Commit<'_>> = parents.iter().collect();
-        let oid = self
-            .repo
-            .commit(Some(&refname), &sig, &sig, &msg, &tree, &parent_refs)?;
+        let oid = self.repo.commit(None, &sig, &sig, &msg, &tree, &parent_refs)?;
 
-        info!(%oid, %refname, "Appended event");
-        self.invalidate_cached_events(ev.task);
+        let reflog = format!("git-mile: append {first_id}");
+        let updated = expected_tip.map_or_else(
+            || self.repo.reference(refname, oid, false, &reflog),
+            |tip| self.repo.reference_matching(refname, oid, true, tip, &reflog),
+        );
+        if let Err(e) = updated {
+            if matches!(
+                e.code(),
+                git2::ErrorCode::Modified | git2::ErrorCode::Exists | git2::ErrorCode::Locked
+            ) {
+                debug!(%refname, "Ref moved while appending: {e}");
+                return Err(GitStoreError::RefConflict(refname.to_owned()).into());
+            }
+            return Err(e.into());
+        }
+
+        info!(%oid, %refname, count = events.len(), "Appended events");
         Ok(oid)
     }
 
@@ -283,10 +408,19 @@ impl GitStore {
 
     /// Load events for every known task reference.
     ///
+    /// Histories are served from the on-disk event index under
+    /// `.git/git-mile/` when the ref tip is unchanged; only refs that moved
+    /// since the index was written are walked. The refreshed index is saved
+    /// back, and failures to persist it are logged rather than returned.
+    ///
     /// # Errors
     /// Returns an error if reference enumeration fails.
     pub fn load_all_task_events(&self) -> Result<Vec<(TaskId, Vec<Event>)>> {
-        let mut results = Vec::new();
+        let index_path = index_path(&self.repo_path, EVENT_INDEX_FILE);
+        let mut previous = EventIndex::load(&index_path, EVENT_INDEX_VERSION);
+        let mut index = EventIndex::new(EVENT_INDEX_VERSION);
+        let mut walked = 0_usize;
+
         let references = self.repo.references_glob("refs/git-mile/tasks/*")?;
         for reference in references {
             let reference = reference?;
@@ -296,11 +430,24 @@ impl GitStore {
             let Some(task_id) = Self::task_id_from_refname(name) else {
                 continue;
             };
-            let events = self.load_events_from_reference(task_id, &reference)?;
+            let tip = reference.target().ok_or_else(|| anyhow!("Ref has no target"))?;
+            let events = if let Some(events) = previous.take(task_id, tip) {
+                events
+            } else {
+                walked += 1;
+                self.load_events_from_tip(task_id, tip)?
+            };
             self.cache_events(task_id, &events);
-            results.push((task_id, events));
+            index.insert(task_id, tip, events);
+        }
+
+        if walked > 0 || !previous.is_empty() {
+            debug!(walked, path = %index_path.display(), "Refreshing event index");
+            if let Err(err) = index.save(&index_path) {
+                warn!("Failed to persist event index: {err:#}");
+            }
         }
-        Ok(results)
+        Ok(index.into_values().collect())
     }
 
     fn load_events_from_reference(
@@ -320,7 +467,7 @@ impl GitStore {
         let mut out = Vec::new();
         for oid in rev {
             let oid = oid?;
-            if let Some(ev) = self.decode_event_from_commit(oid)? {
+            for ev in self.decode_events_from_commit::<Event>(oid)? {
                 if ev.task == task {
                     out.push(ev);
                 } else {
@@ -348,6 +495,27 @@ impl GitStore {
         Ok(ids)
     }
 
+    /// List every task ref together with the commit it currently points at.
+    ///
+    /// Comparing tips between calls detects created, fast-forwarded, merged,
+    /// and deleted refs regardless of the timestamps carried by their events.
+    ///
+    /// # Errors
+    /// Returns an error if reference enumeration fails.
+    pub fn list_task_tips(&self) -> Result<Vec<(TaskId, Oid)>> {
+        let mut tips = Vec::new();
+        for reference in self.repo.references_glob("refs/git-mile/tasks/*")? {
+            let reference = reference?;
+            let Some(task_id) = reference.name().and_then(Self::task_id_from_refname) else {
+                continue;
+            };
+            if let Some(tip) = reference.target() {
+                tips.push((task_id, tip));
+            }
+        }
+        Ok(tips)
+    }
+
     /// Check if a task exists without loading its events.
     ///
     /// # Errors
@@ -390,7 +558,72 @@ impl GitStore {
         Ok(modified_tasks)
     }
 
-    /// Push task refs to a remote repository.
+    /// Append several events for one milestone as a single commit with empty tree.
+    ///
+    /// Like [`append_events`](Self::append_events), the ref only moves if no other
+    /// writer updated it in the meantime.
+    ///
+    /// # Errors
+    /// Returns an error if the batch is empty, spans several milestones, the ref was
+    /// updated concurrently, or any Git object manipulation fails.
+    pub fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<Oid> {
+        let [ev, rest @ ..] = events else {
+            return Err(anyhow!("Cannot append an empty event batch"));
+        };
+        if let Some(other) = rest.iter().find(|other| other.milestone != ev.milestone) {
+            return Err(anyhow!(
+                "Event batch mixes milestones {} and {}",
+                ev.milestone,
+                other.milestone
+            ));
+        }
+        self.commit_events(&Self::milestone_refname(&ev.milestone), &ev.actor, ev.id, events)
+    }
+
+    /// Load events by walking commits reachable from `refs/git-mile/milestones/<id>`.
+    ///
+    /// # Errors
+    /// Returns an error if the milestone ref is missing or commit history cannot be traversed.
+    pub fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>> {
+        let refname = Self::milestone_refname(&milestone);
+        let reference = self
+            .repo
+            .find_reference(&refname)
+            .with_context(|| format!("Milestone not found: {refname}"))?;
+        let tip = reference.target().ok_or_else(|| anyhow!("Ref has no target"))?;
+
+        let mut rev = self.repo.revwalk()?;
+        rev.set_sorting(Sort::TIME | Sort::REVERSE)?;
+        rev.push(tip)?;
+        let mut out = Vec::new();
+        for oid in rev {
+            let oid = oid?;
+            let events: Vec<MilestoneEvent> = self.decode_events_from_commit(oid)?;
+            out.extend(events.into_iter().filter(|ev| ev.milestone == milestone));
+        }
+        Ok(out)
+    }
+
+    /// List milestone ids by scanning `refs/git-mile/milestones/*`.
+    ///
+    /// # Errors
+    /// Returns an error if reference enumeration fails.
+    pub fn list_milestones(&self) -> Result<Vec<MilestoneId>> {
+        let mut ids = Vec::new();
+        for reference in self.repo.references_glob("refs/git-mile/milestones/*")? {
+            let reference = reference?;
+            let name = reference.name().ok_or_else(|| anyhow!("Invalid ref name"))?;
+            if let Some(id) = name
+                .strip_prefix("refs/git-mile/milestones/")
+                .and_then(|raw| raw.parse().ok())
+            {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Push task and milestone refs to a remote repository.
     ///
     /// # Errors
     /// Returns an error if the remote doesn't exist, push fails, or network issues occur.
@@ -400,30 +633,33 @@ impl GitStore {
             .find_remote(remote_name)
             .with_context(|| format!("Remote '{remote_name}' not found"))?;
 
-        // Collect all task refs
+        // Collect all task and milestone refs
         let mut refspecs = Vec::new();
-        let references = self.repo.references_glob("refs/git-mile/tasks/*")?;
-
-        for reference in references {
-            let reference = reference?;
-            let Some(name) = reference.name() else {
-                continue;
-            };
+        for namespace in SYNCED_NAMESPACES {
+            for reference in self
+                .repo
+                .references_glob(&format!("refs/git-mile/{namespace}/*"))?
+            {
+                let reference = reference?;
+                let Some(name) = reference.name() else {
+                    continue;
+                };
 
-            let refspec = if force {
-                format!("+{name}:{name}")
-            } else {
-                format!("{name}:{name}")
-            };
-            refspecs.push(refspec);
+                let refspec = if force {
+                    format!("+{name}:{name}")
+                } else {
+                    format!("{name}:{name}")
+                };
+                refspecs.push(refspec);
+            }
         }
 
         if refspecs.is_empty() {
-            info!(%remote_name, "No task refs to push");
+            info!(%remote_name, "No git-mile refs to push");
             return Ok(());
         }
 
-        info!(%remote_name, count = refspecs.len(), "Pushing task refs");
+        info!(%remote_name, count = refspecs.len(), "Pushing git-mile refs");
 
         // Set up authentication callbacks
         let callbacks = Self::create_auth_callbacks();
@@ -435,23 +671,30 @@ impl GitStore {
             .push(&refspec_strs, Some(&mut push_options))
             .with_context(|| format!("Failed to push to remote '{remote_name}'"))?;
 
-        info!(%remote_name, "Successfully pushed task refs");
+        info!(%remote_name, "Successfully pushed git-mile refs");
         Ok(())
     }
 
-    /// Pull (fetch and merge) task refs from a remote repository.
+    /// Pull (fetch and merge) task and milestone refs from a remote repository.
+    ///
+    /// Remote refs are tracked under `refs/remotes/<remote>/git-mile/tasks/*` and
+    /// `refs/remotes/<remote>/git-mile/milestones/*` so that several remotes
+    /// (e.g. a fork and an upstream) can be synced independently.
     ///
     /// # Errors
     /// Returns an error if the remote doesn't exist, fetch fails, or merge conflicts occur.
-    pub fn pull_refs(&self, remote_name: &str) -> Result<()> {
+    pub fn pull_refs(&self, remote_name: &str) -> Result<PullSummary> {
         let mut remote = self
             .repo
             .find_remote(remote_name)
             .with_context(|| format!("Remote '{remote_name}' not found"))?;
 
-        let refspec = "refs/git-mile/tasks/*:refs/remotes/origin/git-mile/tasks/*";
+        let refspecs: Vec<String> = SYNCED_NAMESPACES
+            .iter()
+            .map(|namespace| Self::remote_tracking_refspec(remote_name, namespace))
+            .collect();
 
-        info!(%remote_name, %refspec, "Fetching task refs");
+        info!(%remote_name, ?refspecs, "Fetching git-mile refs");
 
         // Set up authentication callbacks
         let callbacks = Self::create_auth_callbacks();
@@ -459,19 +702,58 @@ impl GitStore {
         fetch_options.remote_callbacks(callbacks);
 
         remote
-            .fetch(&[refspec], Some(&mut fetch_options), None)
+            .fetch(&refspecs, Some(&mut fetch_options), None)
             .with_context(|| format!("Failed to fetch from remote '{remote_name}'"))?;
 
-        info!(%remote_name, "Successfully fetched task refs");
+        info!(%remote_name, "Successfully fetched git-mile refs");
 
         // Merge fetched refs into local refs
-        self.merge_remote_refs(remote_name)?;
+        self.merge_remote_refs(remote_name)
+    }
 
-        Ok(())
+    fn remote_tracking_prefix(remote_name: &str, namespace: &str) -> String {
+        format!("refs/remotes/{remote_name}/git-mile/{namespace}/")
     }
 
-    fn merge_remote_refs(&self, remote_name: &str) -> Result<()> {
-        let remote_ref_prefix = format!("refs/remotes/{remote_name}/git-mile/tasks/");
+    fn remote_tracking_refspec(remote_name: &str, namespace: &str) -> String {
+        format!(
+            "+refs/git-mile/{namespace}/*:{}*",
+            Self::remote_tracking_prefix(remote_name, namespace)
+        )
+    }
+
+    fn count_commits(&self, tip: Oid) -> Result<usize> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push(tip)?;
+        Ok(revwalk.count())
+    }
+
+    fn merge_remote_refs(&self, remote_name: &str) -> Result<PullSummary> {
+        let mut summary = PullSummary::new(remote_name);
+        let mut touched = Vec::new();
+        for namespace in SYNCED_NAMESPACES {
+            self.merge_remote_namespace(remote_name, namespace, &mut summary, &mut touched)?;
+        }
+
+        self.invalidate_tasks_cache(&touched);
+        info!(
+            %remote_name,
+            ahead = summary.ahead,
+            behind = summary.behind,
+            updated = summary.updated(),
+            "Merged remote git-mile refs"
+        );
+        Ok(summary)
+    }
+
+    fn merge_remote_namespace(
+        &self,
+        remote_name: &str,
+        namespace: &str,
+        summary: &mut PullSummary,
+        touched: &mut Vec<TaskId>,
+    ) -> Result<()> {
+        let remote_ref_prefix = Self::remote_tracking_prefix(remote_name, namespace);
         let references = self.repo.references_glob(&format!("{remote_ref_prefix}*"))?;
 
         for reference in references {
@@ -480,12 +762,12 @@ impl GitStore {
                 continue;
             };
 
-            // Extract task ID from remote ref
-            let Some(task_id_str) = remote_ref_name.strip_prefix(&remote_ref_prefix) else {
+            // Extract the task or milestone ID from remote ref
+            let Some(id_str) = remote_ref_name.strip_prefix(&remote_ref_prefix) else {
                 continue;
             };
 
-            let local_ref_name = format!("refs/git-mile/tasks/{task_id_str}");
+            let local_ref_name = format!("refs/git-mile/{namespace}/{id_str}");
             let remote_target = reference
                 .target()
                 .ok_or_else(|| anyhow!("Remote ref {remote_ref_name} has no target"))?;
@@ -500,9 +782,14 @@ impl GitStore {
                     if local_target == remote_target {
                         // Already up to date
                         debug!(%local_ref_name, "Already up to date");
+                        summary.up_to_date += 1;
                         continue;
                     }
 
+                    let (ahead, behind) = self.repo.graph_ahead_behind(local_target, remote_target)?;
+                    summary.ahead += ahead;
+                    summary.behind += behind;
+
                     // Check if remote_target is reachable from local_target
                     if self.repo.graph_descendant_of(remote_target, local_target)? {
                         // Remote is ahead, fast-forward
@@ -513,13 +800,16 @@ impl GitStore {
                             true,
                             "git-mile pull: fast-forward",
                         )?;
+                        summary.fast_forwarded += 1;
                     } else if self.repo.graph_descendant_of(local_target, remote_target)? {
                         // Local is ahead, no action needed
                         debug!(%local_ref_name, "Local is ahead of remote");
+                        continue;
                     } else {
                         // Diverged, create merge commit
                         debug!(%local_ref_name, "Creating merge commit");
                         self.create_merge_commit(&local_ref_name, local_target, remote_target)?;
+                        summary.merged += 1;
                     }
                 }
                 Err(e) if e.code() == git2::ErrorCode::NotFound => {
@@ -531,11 +821,16 @@ impl GitStore {
                         false,
                         "git-mile pull: create ref",
                     )?;
+                    summary.behind += self.count_commits(remote_target)?;
+                    summary.created += 1;
                 }
                 Err(e) => return Err(e.into()),
             }
-        }
 
+            if let Some(task) = Self::task_id_from_refname(&local_ref_name) {
+                touched.push(task);
+            }
+        }
         Ok(())
     }
 
@@ -757,6 +1052,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn append_events_writes_batch_as_single_commit() -> Result<()> {
+        let base = temp_repo_path()?;
+        Repository::init(&base)?;
+
+        let store = GitStore::open(&base)?;
+        let task = TaskId::new();
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        store.append_event(&Event::new(
+            task,
+            &actor,
+            EventKind::TaskCreated {
+                title: "Batch".into(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        ))?;
+
+        let batch = [
+            Event::new(
+                task,
+                &actor,
+                EventKind::TaskTitleSet {
+                    title: "Renamed".into(),
+                },
+            ),
+            Event::new(
+                task,
+                &actor,
+                EventKind::LabelsAdded {
+                    labels: vec!["type/bug".into()],
+                },
+            ),
+        ];
+        let oid = store.append_events(&batch)?;
+        let commit = store.repo.find_commit(oid)?;
+        assert_eq!(commit.parent_count(), 1);
+        assert!(
+            commit
+                .message()
+                .is_some_and(|message| message.starts_with(EVENT_BATCH_COMMIT_PREFIX))
+        );
+
+        let events = store.load_events(task)?;
+        let ids: Vec<_> = events.iter().map(|ev| ev.id).collect();
+        assert_eq!(ids[1..], [batch[0].id, batch[1].id]);
+
+        let other = Event::new(TaskId::new(), &actor, EventKind::TaskArchived);
+        assert!(store.append_events(&[batch[0].clone(), other]).is_err());
+        assert!(store.append_events(&[]).is_err());
+
+        fs::remove_dir_all(&base)?;
+        Ok(())
+    }
+
     #[test]
     fn load_events_cache_is_invalidated_after_append() -> Result<()> {
         let base = temp_repo_path()?;
@@ -839,6 +1195,105 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn load_all_events_reuses_index_until_ref_tip_moves() -> Result<()> {
+        let base = temp_repo_path()?;
+        Repository::init(&base)?;
+
+        let store = GitStore::open(&base)?;
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        let task = TaskId::new();
+        store.append_event(&Event::new(
+            task,
+            &actor,
+            EventKind::TaskCreated {
+                title: "From git".into(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        ))?;
+        store.load_all_task_events()?;
+
+        let index_path = index_path(&store.repo_path, EVENT_INDEX_FILE);
+        let raw = fs::read_to_string(&index_path)?;
+        fs::write(&index_path, raw.replace("From git", "From index"))?;
+
+        let title_of = |all: &[(TaskId, Vec<Event>)]| match &all[0].1[0].kind {
+            EventKind::TaskCreated { title, .. } => title.clone(),
+            other => panic!("unexpected event kind: {other:?}"),
+        };
+        let all = store.load_all_task_events()?;
+        assert_eq!(
+            title_of(&all),
+            "From index",
+            "unchanged tip must be served from the index"
+        );
+
+        store.append_event(&Event::new(
+            task,
+            &actor,
+            EventKind::TaskTitleSet {
+                title: "Renamed".into(),
+            },
+        ))?;
+        let all = store.load_all_task_events()?;
+        assert_eq!(all[0].1.len(), 2);
+        assert_eq!(
+            title_of(&all),
+            "From git",
+            "moved tip must be re-read from the ref"
+        );
+
+        fs::remove_dir_all(&base)?;
+        Ok(())
+    }
+
+    #[test]
+    fn load_all_events_rebuilds_corrupt_or_outdated_index() -> Result<()> {
+        let base = temp_repo_path()?;
+        Repository::init(&base)?;
+
+        let store = GitStore::open(&base)?;
+        let actor = Actor {
+            name: "tester".into(),
+            email: "tester@example.invalid".into(),
+        };
+        store.append_event(&Event::new(
+            TaskId::new(),
+            &actor,
+            EventKind::TaskCreated {
+                title: "Indexed".into(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        ))?;
+
+        let index_path = index_path(&store.repo_path, EVENT_INDEX_FILE);
+        fs::create_dir_all(index_path.parent().context("index must live in a directory")?)?;
+        for stale in ["not json", r#"{"version":0,"tasks":{}}"#] {
+            fs::write(&index_path, stale)?;
+            let all = store.load_all_task_events()?;
+            assert_eq!(all.len(), 1);
+            assert_eq!(all[0].1.len(), 1);
+
+            let rebuilt: Value = serde_json::from_str(&fs::read_to_string(&index_path)?)?;
+            assert_eq!(rebuilt["version"], EVENT_INDEX_VERSION);
+            assert_eq!(rebuilt["tasks"].as_object().map(serde_json::Map::len), Some(1));
+        }
+
+        fs::remove_dir_all(&base)?;
+        Ok(())
+    }
+
     #[test]
     fn task_exists_returns_true_for_existing_task() -> Result<()> {
         let base = temp_repo_path()?;
@@ -2,7 +2,8 @@
 
 use anyhow::Result;
 use git_mile_core::event::{Actor, Event, EventKind};
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{MilestoneId, TaskId};
+use git_mile_core::milestone::{MilestoneEvent, MilestoneEventKind, MilestoneSnapshot};
 use git_mile_store_git::GitStore;
 use git2::Repository;
 use std::fs;
@@ -295,3 +296,200 @@ fn test_push_with_force_flag() -> Result<()> {
     fs::remove_dir_all(&remote_path)?;
     Ok(())
 }
+
+fn created_event(task: TaskId, actor: &Actor, title: &str) -> Event {
+    Event::new(
+        task,
+        actor,
+        EventKind::TaskCreated {
+            title: title.into(),
+            labels: vec![],
+            assignees: vec![],
+            description: None,
+            state: None,
+            state_kind: None,
+        },
+    )
+}
+
+#[test]
+fn test_pull_tracks_refs_per_remote() -> Result<()> {
+    let (origin_path, _origin_repo) = setup_remote_repo()?;
+    let (upstream_path, _upstream_repo) = setup_remote_repo()?;
+    let (fork_path, fork_store) = setup_local_repo_with_remote(&origin_path)?;
+    let (upstream_local_path, upstream_store) = setup_local_repo_with_remote(&upstream_path)?;
+
+    let actor = Actor {
+        name: "tester".into(),
+        email: "tester@example.invalid".into(),
+    };
+
+    let fork_task = TaskId::new();
+    fork_store.append_event(&created_event(fork_task, &actor, "Fork task"))?;
+    fork_store.push_refs("origin", false)?;
+
+    let upstream_task = TaskId::new();
+    upstream_store.append_event(&created_event(upstream_task, &actor, "Upstream task"))?;
+    upstream_store.push_refs("origin", false)?;
+
+    let (local_path, local_store) = setup_local_repo_with_remote(&origin_path)?;
+    let local_repo = Repository::open(&local_path)?;
+    local_repo.remote("upstream", &format!("file://{}", upstream_path.display()))?;
+
+    let origin_summary = local_store.pull_refs("origin")?;
+    assert_eq!(origin_summary.remote, "origin");
+    assert_eq!(origin_summary.created, 1);
+    assert_eq!(origin_summary.behind, 1);
+
+    let upstream_summary = local_store.pull_refs("upstream")?;
+    assert_eq!(upstream_summary.remote, "upstream");
+    assert_eq!(upstream_summary.created, 1);
+    assert_eq!(upstream_summary.behind, 1);
+
+    // Each remote is fetched into its own namespace.
+    assert!(
+        local_repo
+            .find_reference(&format!("refs/remotes/origin/git-mile/tasks/{fork_task}"))
+            .is_ok()
+    );
+    assert!(
+        local_repo
+            .find_reference(&format!("refs/remotes/upstream/git-mile/tasks/{upstream_task}"))
+            .is_ok()
+    );
+    assert!(
+        local_repo
+            .find_reference(&format!("refs/remotes/origin/git-mile/tasks/{upstream_task}"))
+            .is_err()
+    );
+
+    let mut tasks = local_store.list_tasks()?;
+    tasks.sort();
+    let mut expected = vec![fork_task, upstream_task];
+    expected.sort();
+    assert_eq!(tasks, expected);
+
+    // Cleanup
+    fs::remove_dir_all(&local_path)?;
+    fs::remove_dir_all(&fork_path)?;
+    fs::remove_dir_all(&upstream_local_path)?;
+    fs::remove_dir_all(&origin_path)?;
+    fs::remove_dir_all(&upstream_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_pull_reports_ahead_behind_per_remote() -> Result<()> {
+    let (origin_path, _origin_repo) = setup_remote_repo()?;
+    let (upstream_path, _upstream_repo) = setup_remote_repo()?;
+    let (local_path, local_store) = setup_local_repo_with_remote(&origin_path)?;
+    let local_repo = Repository::open(&local_path)?;
+    local_repo.remote("upstream", &format!("file://{}", upstream_path.display()))?;
+
+    let actor = Actor {
+        name: "tester".into(),
+        email: "tester@example.invalid".into(),
+    };
+    let task = TaskId::new();
+    local_store.append_event(&created_event(task, &actor, "Shared task"))?;
+    local_store.push_refs("origin", false)?;
+    local_store.push_refs("upstream", false)?;
+
+    // Upstream gains two events from another clone.
+    let (other_path, other_store) = setup_local_repo_with_remote(&upstream_path)?;
+    other_store.pull_refs("origin")?;
+    for label in ["a", "b"] {
+        other_store.append_event(&Event::new(
+            task,
+            &actor,
+            EventKind::LabelsAdded {
+                labels: vec![label.into()],
+            },
+        ))?;
+    }
+    other_store.push_refs("origin", false)?;
+
+    // Local gains one event that origin has not seen yet.
+    local_store.append_event(&Event::new(
+        task,
+        &actor,
+        EventKind::LabelsAdded {
+            labels: vec!["local".into()],
+        },
+    ))?;
+
+    let origin_summary = local_store.pull_refs("origin")?;
+    assert_eq!(origin_summary.ahead, 1);
+    assert_eq!(origin_summary.behind, 0);
+    assert_eq!(origin_summary.updated(), 0);
+
+    let upstream_summary = local_store.pull_refs("upstream")?;
+    assert_eq!(upstream_summary.ahead, 1);
+    assert_eq!(upstream_summary.behind, 2);
+    assert_eq!(upstream_summary.merged, 1);
+
+    let snapshot = git_mile_core::TaskSnapshot::replay(&local_store.load_events(task)?);
+    assert!(snapshot.labels.contains("a"));
+    assert!(snapshot.labels.contains("b"));
+    assert!(snapshot.labels.contains("local"));
+
+    // Cleanup
+    fs::remove_dir_all(&local_path)?;
+    fs::remove_dir_all(&other_path)?;
+    fs::remove_dir_all(&origin_path)?;
+    fs::remove_dir_all(&upstream_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_push_pull_merges_milestone_refs() -> Result<()> {
+    let (remote_path, _remote_repo) = setup_remote_repo()?;
+    let (local1_path, local1_store) = setup_local_repo_with_remote(&remote_path)?;
+    let (local2_path, local2_store) = setup_local_repo_with_remote(&remote_path)?;
+    let actor = Actor {
+        name: "tester".into(),
+        email: "tester@example.invalid".into(),
+    };
+
+    let milestone = MilestoneId::new();
+    local1_store.append_milestone_events(&[MilestoneEvent::new(
+        milestone,
+        &actor,
+        MilestoneEventKind::MilestoneCreated {
+            title: "v1.0".into(),
+            due: Some("2025-06-30".into()),
+            description: None,
+        },
+    )])?;
+    local1_store.push_refs("origin", false)?;
+
+    let summary = local2_store.pull_refs("origin")?;
+    assert_eq!(summary.created, 1);
+    assert_eq!(local2_store.list_milestones()?, vec![milestone]);
+
+    // Diverge: one side closes the milestone while the other renames it.
+    let mut closed = MilestoneEvent::new(milestone, &actor, MilestoneEventKind::MilestoneClosed);
+    closed.lamport = 1;
+    local1_store.append_milestone_events(&[closed])?;
+    local1_store.push_refs("origin", false)?;
+    let mut renamed = MilestoneEvent::new(
+        milestone,
+        &actor,
+        MilestoneEventKind::MilestoneTitleSet {
+            title: "v1.0 GA".into(),
+        },
+    );
+    renamed.lamport = 1;
+    local2_store.append_milestone_events(&[renamed])?;
+
+    let summary = local2_store.pull_refs("origin")?;
+    assert_eq!(summary.merged, 1);
+    let snapshot = MilestoneSnapshot::replay(&local2_store.load_milestone_events(milestone)?);
+    assert_eq!(snapshot.title, "v1.0 GA");
+    assert!(snapshot.closed);
+
+    fs::remove_dir_all(&local1_path)?;
+    fs::remove_dir_all(&local2_path)?;
+    fs::remove_dir_all(&remote_path)?;
+    Ok(())
+}
@@ -1,22 +1,34 @@
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::Path;
 use std::str::FromStr;
 
-use anyhow::{Context, Result, anyhow};
-use git_mile_core::TaskFilter;
-use git_mile_core::event::Event;
-use git_mile_core::id::TaskId;
+use anyhow::{Context, Result, anyhow, bail};
+use git_mile_core::event::{Actor, Event};
+use git_mile_core::id::{EventId, MilestoneId, TaskId, TaskIdAbbreviations};
+use git_mile_core::{RelationKind, TaskFilter};
 
+use super::milestone;
+use super::show::{self, ShowContext};
 use crate::event_log::{
     entries_from_events, format_actor, format_timestamp, single_line_detail, truncate_detail,
 };
-use crate::{Command, LogFormat, LsFormat};
+use crate::tui::editor::{edit_task_editor_template, parse_edit_task_editor_output};
+use crate::tui::terminal::launch_editor;
+use crate::{Command, FilterArgs, LogFormat, LsFormat, LsSort, ShowFormat, WriteFormat};
 use git_mile_app::actor_from_params_or_default;
 use git_mile_app::{
-    CommentInput, CreateTaskInput, TaskFilterBuilder, TaskRepository, TaskService, TaskStore, WorkflowConfig,
+    ActivityFeed, ActivityFilter, BulkUpdateOutcome, CommentInput, CreateTaskInput, DescriptionPatch,
+    HistoryPoint, MilestonePatch, MilestoneStore, ProjectConfig, SetDiff, StatePatch, TaskCache,
+    TaskEditData, TaskFilterBuilder, TaskRepository, TaskService, TaskStore, TaskUpdate, TaskView,
+    TaskWriteResult, ViewColumn, ViewSort, WorkflowConfig, blame, events_until, normalize_field_filters,
+    parse_time_bound,
 };
+use serde::Serialize;
+use time::OffsetDateTime;
 
-pub fn run<S: TaskStore, R: TaskStore>(
+#[allow(clippy::too_many_lines)]
+pub fn run<S: TaskStore + MilestoneStore, R: TaskStore>(
     command: Command,
     service: &TaskService<S>,
     repository: &TaskRepository<R>,
@@ -30,6 +42,7 @@ pub fn run<S: TaskStore, R: TaskStore>(
             assignees,
             description,
             parents,
+            fields,
             actor_name,
             actor_email,
         } => handle_new(
@@ -40,6 +53,7 @@ pub fn run<S: TaskStore, R: TaskStore>(
             assignees,
             description,
             parents,
+            fields.into_iter().collect(),
             actor_name.as_deref(),
             actor_email.as_deref(),
             repo_root,
@@ -57,35 +71,305 @@ pub fn run<S: TaskStore, R: TaskStore>(
             actor_email.as_deref(),
             repo_root,
         ),
+        Command::DeleteComment {
+            task,
+            comment,
+            actor_name,
+            actor_email,
+        } => handle_delete_comment(
+            service,
+            &task,
+            &comment,
+            actor_name.as_deref(),
+            actor_email.as_deref(),
+            repo_root,
+        ),
+        Command::React {
+            task,
+            comment,
+            reaction,
+            remove,
+            actor_name,
+            actor_email,
+        } => handle_react(
+            service,
+            &task,
+            &comment,
+            &reaction,
+            remove,
+            actor_name.as_deref(),
+            actor_email.as_deref(),
+            repo_root,
+        ),
+        Command::Archive {
+            task,
+            undo,
+            actor_name,
+            actor_email,
+        } => handle_archive(
+            service,
+            &task,
+            !undo,
+            actor_name.as_deref(),
+            actor_email.as_deref(),
+            repo_root,
+        ),
+        Command::Revert {
+            event,
+            task,
+            actor_name,
+            actor_email,
+            format,
+        } => {
+            let event = EventId::from_str(&event).with_context(|| format!("Invalid event id: {event}"))?;
+            let task = task.map(|task| resolve_task_id(service, &task)).transpose()?;
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let result = service.revert(event, task, &actor)?;
+            write_update_result(&result, format, &mut std::io::stdout())
+        }
+        Command::Edit {
+            task,
+            title,
+            description,
+            clear_description,
+            set_fields,
+            clear_fields,
+            milestone,
+            clear_milestone,
+            editor,
+            actor_name,
+            actor_email,
+            format,
+        } => {
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let result = if editor {
+                let task = resolve_task_id(service, &task)?;
+                let Some(data) = edit_in_editor(service, task, launch_editor)? else {
+                    println!("edit cancelled: {task}");
+                    return Ok(());
+                };
+                service.edit(task, data, &actor)?
+            } else {
+                let description = if clear_description {
+                    Some(DescriptionPatch::Clear)
+                } else {
+                    description.map(|description| DescriptionPatch::Set { description })
+                };
+                let fields = set_fields
+                    .into_iter()
+                    .map(|(name, value)| (name, Some(value)))
+                    .chain(clear_fields.into_iter().map(|name| (name, None)))
+                    .collect();
+                let milestone = if clear_milestone {
+                    Some(MilestonePatch::Clear)
+                } else {
+                    milestone
+                        .map(|raw| service.milestones().resolve(&raw))
+                        .transpose()?
+                        .map(|milestone| MilestonePatch::Set { milestone })
+                };
+                let update = TaskUpdate {
+                    title,
+                    description,
+                    fields,
+                    milestone,
+                    ..TaskUpdate::default()
+                };
+                service.update(resolve_task_id(service, &task)?, update, &actor)?
+            };
+            write_update_result(&result, format, &mut std::io::stdout())
+        }
+        Command::SetState {
+            task,
+            state,
+            clear: _,
+            actor_name,
+            actor_email,
+            format,
+        } => {
+            let task = resolve_task_id(service, &task)?;
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let result = service.set_state(task, state, &actor)?;
+            write_update_result(&result, format, &mut std::io::stdout())
+        }
+        Command::Label {
+            task,
+            add,
+            remove,
+            actor_name,
+            actor_email,
+            format,
+        } => {
+            let task = resolve_task_id(service, &task)?;
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let update = TaskUpdate {
+                labels: SetDiff {
+                    added: add,
+                    removed: remove,
+                },
+                ..TaskUpdate::default()
+            };
+            let result = service.update(task, update, &actor)?;
+            write_update_result(&result, format, &mut std::io::stdout())
+        }
+        Command::Assign {
+            task,
+            add,
+            remove,
+            actor_name,
+            actor_email,
+            format,
+        } => {
+            let task = resolve_task_id(service, &task)?;
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let update = TaskUpdate {
+                assignees: SetDiff {
+                    added: add,
+                    removed: remove,
+                },
+                ..TaskUpdate::default()
+            };
+            let result = service.update(task, update, &actor)?;
+            write_update_result(&result, format, &mut std::io::stdout())
+        }
+        Command::Link {
+            task,
+            kind,
+            targets,
+            parents,
+            actor_name,
+            actor_email,
+            format,
+        } => handle_link(
+            service,
+            &task,
+            kind,
+            targets,
+            parents,
+            true,
+            actor_name.as_deref(),
+            actor_email.as_deref(),
+            repo_root,
+            format,
+            &mut std::io::stdout(),
+        ),
+        Command::Unlink {
+            task,
+            kind,
+            targets,
+            parents,
+            actor_name,
+            actor_email,
+            format,
+        } => handle_link(
+            service,
+            &task,
+            kind,
+            targets,
+            parents,
+            false,
+            actor_name.as_deref(),
+            actor_email.as_deref(),
+            repo_root,
+            format,
+            &mut std::io::stdout(),
+        ),
+        Command::Bulk {
+            filter,
+            view,
+            set_state,
+            clear_state,
+            add_labels,
+            remove_labels,
+            add_assignees,
+            remove_assignees,
+            yes,
+            actor_name,
+            actor_email,
+            format,
+        } => {
+            let filter_args = CliFilterArgs::from_flags(service, filter)?;
+            let state = if clear_state {
+                Some(StatePatch::Clear)
+            } else {
+                set_state.map(|state| StatePatch::Set { state })
+            };
+            let update = TaskUpdate {
+                state,
+                labels: SetDiff {
+                    added: add_labels,
+                    removed: remove_labels,
+                },
+                assignees: SetDiff {
+                    added: add_assignees,
+                    removed: remove_assignees,
+                },
+                ..TaskUpdate::default()
+            };
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let confirm = |count| if yes { Ok(true) } else { confirm_on_stdin(count) };
+            handle_bulk(
+                service,
+                repository,
+                repo_root,
+                view,
+                filter_args,
+                &update,
+                &actor,
+                format,
+                &mut std::io::stdout(),
+                confirm,
+            )
+        }
         Command::Log { task, format } => handle_log(service, &task, format, &mut std::io::stdout()),
-        Command::Show { task } => handle_show(service, &task),
+        Command::Activity {
+            since,
+            until,
+            actors,
+            kinds,
+            format,
+        } => handle_activity(
+            service,
+            since.as_deref(),
+            until.as_deref(),
+            actors,
+            kinds,
+            format,
+            &mut std::io::stdout(),
+        ),
+        Command::Show { task, at, format } => handle_show(
+            service,
+            repository,
+            &task,
+            at.as_deref(),
+            format,
+            &mut std::io::stdout(),
+        ),
+        Command::Blame { task, format } => handle_blame(service, &task, format, &mut std::io::stdout()),
+        Command::Ready { format } => handle_ready(service.workflow(), repository, format),
         Command::Ls {
-            states,
-            labels,
-            assignees,
-            state_kinds,
-            exclude_state_kinds,
-            parents,
-            children,
-            updated_since,
-            updated_until,
-            text,
+            filter,
+            view,
+            sort,
             format,
         } => handle_ls(
             service,
             repository,
-            states,
-            labels,
-            assignees,
-            state_kinds,
-            exclude_state_kinds,
-            parents,
-            children,
-            updated_since,
-            updated_until,
-            text,
+            repo_root,
+            view,
+            sort,
+            CliFilterArgs::from_flags(service, filter)?,
             format,
         ),
+        Command::Milestone { subcommand } => {
+            milestone::run(service, repository, subcommand, repo_root, &mut std::io::stdout())
+        }
         _ => unreachable!("Unhandled command routed to TaskService"),
     }
 }
@@ -99,11 +383,12 @@ fn handle_new<S: TaskStore>(
     assignees: Vec<String>,
     description: Option<String>,
     parents: Vec<String>,
+    fields: BTreeMap<String, String>,
     actor_name: Option<&str>,
     actor_email: Option<&str>,
     repo_root: &Path,
 ) -> Result<()> {
-    let parent_ids = parse_task_ids(parents)?;
+    let parent_ids = resolve_task_ids(service, parents)?;
     let actor = actor_from_params_or_default(actor_name, actor_email, repo_root);
     let output = service.create_with_parents(CreateTaskInput {
         title,
@@ -112,6 +397,7 @@ fn handle_new<S: TaskStore>(
         assignees,
         description,
         parents: parent_ids,
+        fields,
         actor,
     })?;
 
@@ -130,23 +416,159 @@ fn handle_comment<S: TaskStore>(
     actor_email: Option<&str>,
     repo_root: &Path,
 ) -> Result<()> {
-    let task = parse_task_id(task)?;
+    let task = resolve_task_id(service, task)?;
     let actor = actor_from_params_or_default(actor_name, actor_email, repo_root);
     let output = service.add_comment(CommentInput { task, message, actor })?;
     println!("commented: {} ({})", output.task, output.oid);
     Ok(())
 }
 
+fn handle_delete_comment<S: TaskStore>(
+    service: &TaskService<S>,
+    task: &str,
+    comment: &str,
+    actor_name: Option<&str>,
+    actor_email: Option<&str>,
+    repo_root: &Path,
+) -> Result<()> {
+    let task = resolve_task_id(service, task)?;
+    let comment = parse_comment_id(comment)?;
+    let actor = actor_from_params_or_default(actor_name, actor_email, repo_root);
+    let oid = service.delete_comment(task, comment, &actor)?;
+    println!("deleted comment: {comment} ({oid})");
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_react<S: TaskStore>(
+    service: &TaskService<S>,
+    task: &str,
+    comment: &str,
+    reaction: &str,
+    remove: bool,
+    actor_name: Option<&str>,
+    actor_email: Option<&str>,
+    repo_root: &Path,
+) -> Result<()> {
+    let task = resolve_task_id(service, task)?;
+    let comment = parse_comment_id(comment)?;
+    let actor = actor_from_params_or_default(actor_name, actor_email, repo_root);
+    let oid = service.react_to_comment(task, comment, reaction, remove, &actor)?;
+    if remove {
+        println!("removed reaction {}: {comment} ({oid})", reaction.trim());
+    } else {
+        println!("reacted {}: {comment} ({oid})", reaction.trim());
+    }
+    Ok(())
+}
+
+fn handle_archive<S: TaskStore>(
+    service: &TaskService<S>,
+    task: &str,
+    archived: bool,
+    actor_name: Option<&str>,
+    actor_email: Option<&str>,
+    repo_root: &Path,
+) -> Result<()> {
+    let task = resolve_task_id(service, task)?;
+    let actor = actor_from_params_or_default(actor_name, actor_email, repo_root);
+    let oid = service.set_archived(task, archived, &actor)?;
+    if archived {
+        println!("archived: {task} ({oid})");
+    } else {
+        println!("unarchived: {task} ({oid})");
+    }
+    Ok(())
+}
+
+/// Open the edit template for `task` in an editor, returning `None` when the user cancels.
+fn edit_in_editor<S: TaskStore>(
+    service: &TaskService<S>,
+    task: TaskId,
+    edit: impl FnOnce(&str) -> Result<String>,
+) -> Result<Option<TaskEditData>> {
+    let events = service.event_log(task)?;
+    if events.is_empty() {
+        return Err(anyhow!("task {task} not found"));
+    }
+    let view = TaskView::from_events(&events);
+    let workflow = service.workflow();
+    let template = edit_task_editor_template(&view, workflow.state_hint().as_deref(), workflow.fields());
+    let raw = edit(&template)?;
+    parse_edit_task_editor_output(&raw).map_err(|msg| anyhow!(msg))
+}
+
+fn write_update_result(result: &TaskWriteResult, format: WriteFormat, writer: &mut dyn Write) -> Result<()> {
+    match format {
+        WriteFormat::Json => writeln!(writer, "{}", serde_json::to_string_pretty(result)?)?,
+        WriteFormat::Text if result.events.is_empty() => writeln!(writer, "no changes: {}", result.task)?,
+        WriteFormat::Text => {
+            for oid in &result.events {
+                writeln!(writer, "updated: {} ({oid})", result.task)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_link<S: TaskStore>(
+    service: &TaskService<S>,
+    task: &str,
+    kind: Option<RelationKind>,
+    targets: Vec<String>,
+    parents: Vec<String>,
+    link: bool,
+    actor_name: Option<&str>,
+    actor_email: Option<&str>,
+    repo_root: &Path,
+    format: WriteFormat,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let task = resolve_task_id(service, task)?;
+    let targets = resolve_task_ids(service, targets)?;
+    let parents = resolve_task_ids(service, parents)?;
+    let actor = actor_from_params_or_default(actor_name, actor_email, repo_root);
+
+    let mut events = Vec::new();
+    if let Some(kind) = kind {
+        events.extend(service.set_relation(task, kind, &targets, link, &actor)?);
+    }
+    if !parents.is_empty() {
+        events.extend(service.set_parents(task, &parents, link, &actor)?.events);
+    }
+
+    if format == WriteFormat::Json {
+        let result = TaskWriteResult {
+            task,
+            events,
+            comment_id: None,
+        };
+        writeln!(writer, "{}", serde_json::to_string_pretty(&result)?)?;
+        return Ok(());
+    }
+    let verb = if link { "linked" } else { "unlinked" };
+    if let Some(kind) = kind {
+        for target in targets {
+            writeln!(writer, "{verb}: {task} {kind} {target}")?;
+        }
+    }
+    for parent in parents {
+        writeln!(writer, "{verb}: {task} child-of {parent}")?;
+    }
+    Ok(())
+}
+
 fn handle_log<S: TaskStore>(
     service: &TaskService<S>,
     task: &str,
     format: LogFormat,
     writer: &mut dyn Write,
 ) -> Result<()> {
-    let task = parse_task_id(task)?;
+    let task = resolve_task_id(service, task)?;
     let events = service.event_log(task)?;
     match format {
-        LogFormat::Table => render_log_table(&events, writer),
+        LogFormat::Table => render_log_table(&events, &service.id_abbreviations()?, writer),
         LogFormat::Json => {
             let json = serde_json::to_string_pretty(&events)?;
             writeln!(writer, "{json}")?;
@@ -155,48 +577,148 @@ fn handle_log<S: TaskStore>(
     }
 }
 
-fn handle_show<S: TaskStore>(service: &TaskService<S>, task: &str) -> Result<()> {
-    let task = parse_task_id(task)?;
-    let snapshot = service.materialize(task)?;
-    println!("{}", serde_json::to_string_pretty(&snapshot)?);
+fn handle_activity<S: TaskStore>(
+    service: &TaskService<S>,
+    since: Option<&str>,
+    until: Option<&str>,
+    actors: Vec<String>,
+    kinds: Vec<String>,
+    format: LogFormat,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let now = OffsetDateTime::now_utc();
+    let bound = |flag: &str, raw: Option<&str>| {
+        raw.map(|raw| {
+            parse_time_bound(raw, now).ok_or_else(|| {
+                anyhow!("Invalid --{flag} value '{raw}': expected RFC3339, YYYY-MM-DD or an age such as 1d")
+            })
+        })
+        .transpose()
+    };
+    let filter = ActivityFilter {
+        since: bound("since", since)?,
+        until: bound("until", until)?,
+        actors: actors.into_iter().collect(),
+        kinds: kinds.into_iter().collect(),
+    };
+    let feed = service.activity(&filter)?;
+    match format {
+        LogFormat::Table => render_activity_table(&feed, &service.id_abbreviations()?, writer),
+        LogFormat::Json => {
+            let rows: Vec<ActivityJson<'_>> = feed
+                .events
+                .iter()
+                .map(|event| ActivityJson {
+                    event,
+                    task_title: feed.title(event.task),
+                })
+                .collect();
+            writeln!(writer, "{}", serde_json::to_string_pretty(&rows)?)?;
+            Ok(())
+        }
+    }
+}
+
+/// JSON row of `activity`: the raw event plus the title of its task.
+#[derive(Serialize)]
+struct ActivityJson<'a> {
+    #[serde(flatten)]
+    event: &'a Event,
+    task_title: &'a str,
+}
+
+fn handle_blame<S: TaskStore>(
+    service: &TaskService<S>,
+    task: &str,
+    format: LogFormat,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let task = resolve_task_id(service, task)?;
+    let events = service.event_log(task)?;
+    if events.is_empty() {
+        bail!("Task not found: {task}");
+    }
+    let entries = blame(&events);
+    match format {
+        LogFormat::Table => {
+            writeln!(writer, "Field | Timestamp | Actor | EventId")?;
+            writeln!(writer, "----- | --------- | ----- | -------")?;
+            for entry in entries {
+                writeln!(
+                    writer,
+                    "{} | {} | {} | {}",
+                    entry.field,
+                    format_timestamp(entry.ts),
+                    format_actor(&entry.actor),
+                    entry.event_id
+                )?;
+            }
+        }
+        LogFormat::Json => writeln!(writer, "{}", serde_json::to_string_pretty(&entries)?)?,
+    }
     Ok(())
 }
 
+fn handle_show<S: TaskStore + MilestoneStore, R: TaskStore>(
+    service: &TaskService<S>,
+    repository: &TaskRepository<R>,
+    task: &str,
+    at: Option<&str>,
+    format: ShowFormat,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let task = resolve_task_id(service, task)?;
+    let mut events = service.event_log(task)?;
+    if events.is_empty() {
+        bail!("Task not found: {task}");
+    }
+    if let Some(at) = at {
+        events = events_until(&events, at.parse::<HistoryPoint>()?)?;
+        if events.is_empty() {
+            bail!("Task {task} did not exist yet at {at}");
+        }
+    }
+    let view = TaskView::from_events(&events);
+    let snapshot = &view.snapshot;
+    let has_related = !snapshot.parents.is_empty()
+        || !snapshot.children.is_empty()
+        || snapshot.relates.values().any(|targets| !targets.is_empty());
+    // Only tasks with neighbours need the full cache for titles, states and the subtask rollup.
+    let related = if has_related {
+        repository.get_cache()?
+    } else {
+        TaskCache::default()
+    };
+    let milestone = match snapshot.milestone {
+        Some(id) if format != ShowFormat::Json => service.milestones().get(id).ok(),
+        _ => None,
+    };
+    let ctx = ShowContext {
+        related: &related,
+        workflow: service.workflow(),
+        ids: &service.id_abbreviations()?,
+        milestone: milestone.as_ref(),
+    };
+    show::render(&view, &ctx, format, writer)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn handle_ls<S: TaskStore, R: TaskStore>(
     service: &TaskService<S>,
     repository: &TaskRepository<R>,
-    states: Vec<String>,
-    labels: Vec<String>,
-    assignees: Vec<String>,
-    state_kinds: Vec<String>,
-    exclude_state_kinds: Vec<String>,
-    parents: Vec<String>,
-    children: Vec<String>,
-    updated_since: Option<String>,
-    updated_until: Option<String>,
-    text: Option<String>,
+    repo_root: &Path,
+    view: Option<String>,
+    sort: Option<LsSort>,
+    filter_args: CliFilterArgs,
     format: LsFormat,
 ) -> Result<()> {
     let workflow = service.workflow();
-    for state in &states {
-        workflow.validate_state(Some(state))?;
-    }
-
-    let filter = build_filter(CliFilterArgs {
-        states,
-        labels,
-        assignees,
-        include_state_kinds: state_kinds,
-        exclude_state_kinds,
-        parents,
-        children,
-        updated_since,
-        updated_until,
-        text,
-    })?;
+    let (filter, view_sort, columns) = select_filter(service, repo_root, view, filter_args)?;
     let filter_empty = filter.is_empty();
-    let tasks = repository.list_snapshots(Some(&filter))?;
+    let mut tasks = repository.list_snapshots(Some(&filter))?;
+    if let Some(sort) = sort.map(ViewSort::from).or(view_sort) {
+        sort.sort(workflow, &mut tasks);
+    }
 
     if tasks.is_empty() {
         if filter_empty {
@@ -208,26 +730,179 @@ fn handle_ls<S: TaskStore, R: TaskStore>(
     }
 
     match format {
-        LsFormat::Table => render_task_table(&tasks, workflow),
+        LsFormat::Table => render_task_table(
+            &tasks,
+            workflow,
+            &columns,
+            &repository.id_abbreviations()?,
+            &mut std::io::stdout(),
+        )?,
         LsFormat::Json => println!("{}", serde_json::to_string_pretty(&tasks)?),
     }
     Ok(())
 }
 
-struct CliFilterArgs {
-    states: Vec<String>,
-    labels: Vec<String>,
-    assignees: Vec<String>,
-    include_state_kinds: Vec<String>,
-    exclude_state_kinds: Vec<String>,
+/// Build the filter, sort order and columns from a saved view or the filter flags.
+fn select_filter<S: TaskStore>(
+    service: &TaskService<S>,
+    repo_root: &Path,
+    view: Option<String>,
+    mut args: CliFilterArgs,
+) -> Result<(TaskFilter, Option<ViewSort>, Vec<ViewColumn>)> {
+    if let Some(name) = view {
+        let config = ProjectConfig::load(repo_root)?;
+        let view = config.view(&name)?.clone();
+        return Ok((view.filter, Some(view.sort), view.columns));
+    }
+    for state in &args.states {
+        service.workflow().validate_state(Some(state))?;
+    }
+    args.fields = normalize_field_filters(service.workflow(), args.fields)?;
+    let filter = build_filter(args, |raw| resolve_task_id(service, raw))?;
+    Ok((filter, None, ViewColumn::all()))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_bulk<S: TaskStore, R: TaskStore>(
+    service: &TaskService<S>,
+    repository: &TaskRepository<R>,
+    repo_root: &Path,
+    view: Option<String>,
+    filter_args: CliFilterArgs,
+    update: &TaskUpdate,
+    actor: &Actor,
+    format: WriteFormat,
+    writer: &mut dyn Write,
+    confirm: impl FnOnce(usize) -> Result<bool>,
+) -> Result<()> {
+    if let Some(StatePatch::Set { state }) = &update.state {
+        service.workflow().validate_state(Some(state))?;
+    }
+    let (filter, sort, columns) = select_filter(service, repo_root, view, filter_args)?;
+    if !filter.has_criteria() {
+        bail!("bulk needs a filter or --view; refusing to update every task");
+    }
+    let mut tasks = repository.list_snapshots(Some(&filter))?;
+    if let Some(sort) = sort {
+        sort.sort(service.workflow(), &mut tasks);
+    }
+    if tasks.is_empty() {
+        writeln!(writer, "No tasks matched the provided filters")?;
+        return Ok(());
+    }
+
+    let ids = repository.id_abbreviations()?;
+    if format == WriteFormat::Text {
+        render_task_table(&tasks, service.workflow(), &columns, &ids, writer)?;
+    }
+    if !confirm(tasks.len())? {
+        writeln!(writer, "aborted: no tasks changed")?;
+        return Ok(());
+    }
+
+    let outcomes = service.bulk_update(&tasks, update, actor);
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, BulkUpdateOutcome::Failed { .. }))
+        .count();
+    match format {
+        WriteFormat::Json => writeln!(writer, "{}", serde_json::to_string_pretty(&outcomes)?)?,
+        WriteFormat::Text => {
+            let mut updated = 0;
+            for outcome in &outcomes {
+                let task = ids.abbreviate(outcome.task());
+                match outcome {
+                    BulkUpdateOutcome::Updated { events, .. } => {
+                        updated += 1;
+                        for oid in events {
+                            writeln!(writer, "updated: {task} ({oid})")?;
+                        }
+                    }
+                    BulkUpdateOutcome::Unchanged { .. } => writeln!(writer, "unchanged: {task}")?,
+                    BulkUpdateOutcome::Failed { error, .. } => writeln!(writer, "failed: {task}: {error}")?,
+                }
+            }
+            writeln!(
+                writer,
+                "{updated} updated, {} unchanged, {failed} failed",
+                outcomes.len() - updated - failed
+            )?;
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} of {} tasks were not updated", outcomes.len());
+    }
+    Ok(())
+}
+
+/// Ask on the terminal whether to apply a bulk change to `count` tasks.
+fn confirm_on_stdin(count: usize) -> Result<bool> {
+    eprint!("Apply to {count} task(s)? [y/N] ");
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
+struct CliFilterArgs {
+    states: Vec<String>,
+    labels: Vec<String>,
+    assignees: Vec<String>,
+    include_state_kinds: Vec<String>,
+    exclude_state_kinds: Vec<String>,
     parents: Vec<String>,
     children: Vec<String>,
     updated_since: Option<String>,
     updated_until: Option<String>,
     text: Option<String>,
+    query: Option<String>,
+    fields: Vec<(String, String)>,
+    milestones: Vec<MilestoneId>,
+    open_checklist: bool,
+    include_archived: bool,
+}
+
+impl CliFilterArgs {
+    /// Take the `ls` / `bulk` filter flags, resolving milestone ids against the store.
+    fn from_flags<S: MilestoneStore>(service: &TaskService<S>, flags: FilterArgs) -> Result<Self> {
+        let FilterArgs {
+            states,
+            labels,
+            assignees,
+            state_kinds,
+            exclude_state_kinds,
+            parents,
+            children,
+            updated_since,
+            updated_until,
+            text,
+            query,
+            fields,
+            milestones,
+            open_checklist,
+            include_archived,
+        } = flags;
+        Ok(Self {
+            states,
+            labels,
+            assignees,
+            include_state_kinds: state_kinds,
+            exclude_state_kinds,
+            parents,
+            children,
+            updated_since,
+            updated_until,
+            text,
+            query,
+            fields,
+            milestones: resolve_milestone_ids(service, &milestones)?,
+            open_checklist,
+            include_archived,
+        })
+    }
 }
 
-fn build_filter(args: CliFilterArgs) -> Result<TaskFilter> {
+fn build_filter(args: CliFilterArgs, resolve_id: impl Fn(&str) -> Result<TaskId>) -> Result<TaskFilter> {
     let CliFilterArgs {
         states,
         labels,
@@ -239,10 +914,21 @@ fn build_filter(args: CliFilterArgs) -> Result<TaskFilter> {
         updated_since,
         updated_until,
         text,
+        query,
+        fields,
+        milestones,
+        open_checklist,
+        include_archived,
     } = args;
 
-    let parent_ids = parse_task_ids(parents)?;
-    let child_ids = parse_task_ids(children)?;
+    let parent_ids = parents
+        .iter()
+        .map(|raw| resolve_id(raw))
+        .collect::<Result<Vec<_>>>()?;
+    let child_ids = children
+        .iter()
+        .map(|raw| resolve_id(raw))
+        .collect::<Result<Vec<_>>>()?;
 
     let mut builder = TaskFilterBuilder::new()
         .with_states(&states)
@@ -253,14 +939,60 @@ fn build_filter(args: CliFilterArgs) -> Result<TaskFilter> {
 
     builder = builder.with_state_kinds(&include_state_kinds, &exclude_state_kinds)?;
     builder = builder.with_text(text);
+    builder = builder.with_query(query.as_deref())?;
     builder = builder.with_time_range(updated_since, updated_until)?;
+    builder = builder.with_fields(fields);
+    builder = builder.with_milestones(&milestones);
+    builder = builder.with_open_checklist(open_checklist);
+    builder = builder.with_archived(include_archived);
 
     builder.build().map_err(|err| anyhow!(err))
 }
 
-fn render_task_table(tasks: &[git_mile_core::TaskSnapshot], workflow: &WorkflowConfig) {
-    println!("ID | State | Title | Labels | Assignees | Updated");
-    println!("-- | ----- | ----- | ------ | --------- | -------");
+fn handle_ready<R: TaskStore>(
+    workflow: &WorkflowConfig,
+    repository: &TaskRepository<R>,
+    format: LsFormat,
+) -> Result<()> {
+    let work = repository.ready_work()?;
+    let ids = repository.id_abbreviations()?;
+    for cycle in &work.cycles {
+        let chain = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|id| ids.abbreviate(*id))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        eprintln!("warning: dependency cycle: {chain}");
+    }
+
+    match format {
+        LsFormat::Table if work.ready.is_empty() => println!("No ready tasks"),
+        LsFormat::Table => {
+            render_task_table(
+                &work.ready,
+                workflow,
+                &ViewColumn::all(),
+                &ids,
+                &mut std::io::stdout(),
+            )?;
+        }
+        LsFormat::Json => println!("{}", serde_json::to_string_pretty(&work)?),
+    }
+    Ok(())
+}
+
+fn render_task_table(
+    tasks: &[git_mile_core::TaskSnapshot],
+    workflow: &WorkflowConfig,
+    columns: &[ViewColumn],
+    ids: &TaskIdAbbreviations,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let headers: Vec<_> = columns.iter().map(|column| column.header()).collect();
+    let rules: Vec<_> = headers.iter().map(|header| "-".repeat(header.len())).collect();
+    writeln!(writer, "{}", headers.join(" | "))?;
+    writeln!(writer, "{}", rules.join(" | "))?;
 
     for snapshot in tasks {
         let state_display = snapshot.state.as_deref().map_or_else(
@@ -286,18 +1018,27 @@ fn render_task_table(tasks: &[git_mile_core::TaskSnapshot], workflow: &WorkflowC
         };
         let updated = snapshot.updated_rfc3339.as_deref().unwrap_or("-").to_string();
 
-        println!(
-            "{} | {} | {} | {} | {} | {}",
-            snapshot.id, state_display, snapshot.title, labels, assignees, updated
-        );
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| match column {
+                ViewColumn::Id => ids.abbreviate(snapshot.id),
+                ViewColumn::State => state_display.clone(),
+                ViewColumn::Title => snapshot.title.clone(),
+                ViewColumn::Labels => labels.clone(),
+                ViewColumn::Assignees => assignees.clone(),
+                ViewColumn::Updated => updated.clone(),
+            })
+            .collect();
+        writeln!(writer, "{}", cells.join(" | "))?;
     }
+    Ok(())
 }
 
-fn render_log_table(events: &[Event], writer: &mut dyn Write) -> Result<()> {
+fn render_log_table(events: &[Event], ids: &TaskIdAbbreviations, writer: &mut dyn Write) -> Result<()> {
     writeln!(writer, "Timestamp | Actor | Event | Detail | EventId")?;
     writeln!(writer, "--------- | ----- | ----- | ------ | -------")?;
 
-    for entry in entries_from_events(events) {
+    for entry in entries_from_events(events, ids) {
         let ts = format_timestamp(entry.ts);
         let actor = format_actor(&entry.actor);
         let detail = entry.detail.as_deref().map_or_else(
@@ -313,21 +1054,65 @@ fn render_log_table(events: &[Event], writer: &mut dyn Write) -> Result<()> {
     Ok(())
 }
 
-fn parse_task_ids(inputs: Vec<String>) -> Result<Vec<TaskId>> {
-    inputs.into_iter().map(|raw| parse_task_id(&raw)).collect()
+fn render_activity_table(
+    feed: &ActivityFeed,
+    ids: &TaskIdAbbreviations,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    writeln!(writer, "Timestamp | Actor | Task | Event | Detail")?;
+    writeln!(writer, "--------- | ----- | ---- | ----- | ------")?;
+
+    for (event, entry) in feed.events.iter().zip(entries_from_events(&feed.events, ids)) {
+        let ts = format_timestamp(entry.ts);
+        let actor = format_actor(&entry.actor);
+        let task = format!(
+            "{} {}",
+            ids.abbreviate(event.task),
+            truncate_detail(feed.title(event.task), 40)
+        );
+        let detail = entry.detail.as_deref().map_or_else(
+            || "-".to_owned(),
+            |text| truncate_detail(&single_line_detail(text), 80),
+        );
+        writeln!(writer, "{ts} | {actor} | {task} | {} | {detail}", entry.action)?;
+    }
+    Ok(())
+}
+
+fn resolve_task_ids<S: TaskStore>(service: &TaskService<S>, inputs: Vec<String>) -> Result<Vec<TaskId>> {
+    inputs
+        .into_iter()
+        .map(|raw| resolve_task_id(service, &raw))
+        .collect()
+}
+
+fn resolve_milestone_ids<S: MilestoneStore>(
+    service: &TaskService<S>,
+    inputs: &[String],
+) -> Result<Vec<MilestoneId>> {
+    let milestones = service.milestones();
+    inputs.iter().map(|raw| milestones.resolve(raw)).collect()
+}
+
+/// Resolve a full or abbreviated task id typed on the command line.
+fn resolve_task_id<S: TaskStore>(service: &TaskService<S>, raw: &str) -> Result<TaskId> {
+    service
+        .resolve_task_id(raw)
+        .with_context(|| format!("Invalid task id: {raw}"))
 }
 
-fn parse_task_id(raw: &str) -> Result<TaskId> {
-    TaskId::from_str(raw).with_context(|| format!("Invalid task id: {raw}"))
+fn parse_comment_id(raw: &str) -> Result<EventId> {
+    EventId::from_str(raw).with_context(|| format!("Invalid comment id: {raw}"))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Command, LogFormat, LsFormat};
+    use crate::{Command, LogFormat, LsFormat, ShowFormat, WriteFormat};
     use anyhow::{Context, Result, anyhow};
     use git_mile_core::StateKind;
     use git_mile_core::event::{Actor, Event, EventKind};
+    use git_mile_core::milestone::MilestoneEvent;
     use std::collections::{HashMap, HashSet};
     use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
@@ -346,6 +1131,7 @@ mod tests {
         list: Mutex<Vec<TaskId>>,
         list_calls: Mutex<u32>,
         events: Mutex<HashMap<TaskId, Vec<Event>>>,
+        milestones: Mutex<HashMap<MilestoneId, Vec<MilestoneEvent>>>,
         next_oid: Mutex<u8>,
     }
 
@@ -396,6 +1182,30 @@ mod tests {
         }
     }
 
+    impl MilestoneStore for MockStore {
+        type Error = anyhow::Error;
+
+        fn append_milestone_events(&self, events: &[MilestoneEvent]) -> Result<git2::Oid, Self::Error> {
+            let first = events.first().ok_or_else(|| anyhow!("no milestone events"))?;
+            guard(&self.inner.milestones)
+                .entry(first.milestone)
+                .or_default()
+                .extend_from_slice(events);
+            Ok(fake_oid(0))
+        }
+
+        fn load_milestone_events(&self, milestone: MilestoneId) -> Result<Vec<MilestoneEvent>, Self::Error> {
+            guard(&self.inner.milestones)
+                .get(&milestone)
+                .cloned()
+                .ok_or_else(|| anyhow!("missing milestone {milestone}"))
+        }
+
+        fn list_milestones(&self) -> Result<Vec<MilestoneId>, Self::Error> {
+            Ok(guard(&self.inner.milestones).keys().copied().collect())
+        }
+    }
+
     impl MockStore {
         fn appended(&self) -> Vec<Event> {
             guard(&self.inner.appended).clone()
@@ -454,54 +1264,97 @@ mod tests {
     }
 
     #[test]
-    fn parse_task_ids_roundtrip() -> Result<()> {
+    fn resolve_task_ids_accepts_full_and_abbreviated_ids() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
         let ids = vec![TaskId::new(), TaskId::new()];
+        store.set_list(ids.clone());
         let raw: Vec<_> = ids.iter().map(ToString::to_string).collect();
-        let parsed = parse_task_ids(raw)?;
-        assert_eq!(parsed, ids);
+        assert_eq!(resolve_task_ids(&service, raw)?, ids);
+
+        let short = TaskIdAbbreviations::new(ids.iter().copied()).abbreviate(ids[0]);
+        assert_eq!(resolve_task_id(&service, &short)?, ids[0]);
         Ok(())
     }
 
     #[test]
-    fn parse_task_ids_rejects_invalid_value() {
-        let Err(err) = parse_task_ids(vec!["not-a-task-id".into()]) else {
+    fn resolve_task_ids_rejects_invalid_value() {
+        let (service, _repository, _store) = service_with_store();
+        let Err(err) = resolve_task_ids(&service, vec!["not-a-task-id".into()]) else {
             panic!("expected invalid id error");
         };
         assert!(err.to_string().contains("Invalid task id"));
     }
 
+    #[test]
+    fn resolve_task_id_reports_ambiguous_prefixes() {
+        let (service, _repository, store) = service_with_store();
+        let first = TaskId::from_str("0192f3a1-7b00-7000-8000-000000000001")
+            .unwrap_or_else(|e| panic!("valid id: {e}"));
+        let second = TaskId::from_str("0192f3a1-7c00-7000-8000-000000000002")
+            .unwrap_or_else(|e| panic!("valid id: {e}"));
+        store.set_list(vec![first, second]);
+
+        let Err(err) = resolve_task_id(&service, "0192f3a1") else {
+            panic!("shared prefix must be ambiguous");
+        };
+        let message = format!("{err:#}");
+        assert!(message.contains("ambiguous"), "{message}");
+        assert!(message.contains(&first.to_string()), "{message}");
+        assert!(message.contains(&second.to_string()), "{message}");
+    }
+
+    fn full_id(raw: &str) -> Result<TaskId> {
+        TaskId::from_str(raw).map_err(Into::into)
+    }
+
     #[test]
     fn build_filter_trims_text_input() -> Result<()> {
-        let filter = build_filter(CliFilterArgs {
-            states: Vec::new(),
-            labels: Vec::new(),
-            assignees: Vec::new(),
-            include_state_kinds: Vec::new(),
-            exclude_state_kinds: Vec::new(),
-            parents: Vec::new(),
-            children: Vec::new(),
-            updated_since: None,
-            updated_until: None,
-            text: Some("  panic at the disco  ".into()),
-        })?;
+        let filter = build_filter(
+            CliFilterArgs {
+                states: Vec::new(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                include_state_kinds: Vec::new(),
+                exclude_state_kinds: Vec::new(),
+                parents: Vec::new(),
+                children: Vec::new(),
+                updated_since: None,
+                updated_until: None,
+                text: Some("  panic at the disco  ".into()),
+                query: None,
+                fields: vec![],
+                milestones: vec![],
+                open_checklist: false,
+                include_archived: false,
+            },
+            full_id,
+        )?;
         assert_eq!(filter.text.as_deref(), Some("panic at the disco"));
         Ok(())
     }
 
     #[test]
     fn build_filter_discards_blank_text() -> Result<()> {
-        let filter = build_filter(CliFilterArgs {
-            states: Vec::new(),
-            labels: Vec::new(),
-            assignees: Vec::new(),
-            include_state_kinds: Vec::new(),
-            exclude_state_kinds: Vec::new(),
-            parents: Vec::new(),
-            children: Vec::new(),
-            updated_since: None,
-            updated_until: None,
-            text: Some("   ".into()),
-        })?;
+        let filter = build_filter(
+            CliFilterArgs {
+                states: Vec::new(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                include_state_kinds: Vec::new(),
+                exclude_state_kinds: Vec::new(),
+                parents: Vec::new(),
+                children: Vec::new(),
+                updated_since: None,
+                updated_until: None,
+                text: Some("   ".into()),
+                query: None,
+                fields: vec![],
+                milestones: vec![],
+                open_checklist: false,
+                include_archived: false,
+            },
+            full_id,
+        )?;
         assert!(filter.text.is_none());
         Ok(())
     }
@@ -510,18 +1363,26 @@ mod tests {
     fn build_filter_applies_state_kinds_and_parents() -> Result<()> {
         let parent = TaskId::new();
         let child = TaskId::new();
-        let filter = build_filter(CliFilterArgs {
-            states: Vec::new(),
-            labels: Vec::new(),
-            assignees: Vec::new(),
-            include_state_kinds: vec!["todo".into()],
-            exclude_state_kinds: vec!["done".into()],
-            parents: vec![parent.to_string()],
-            children: vec![child.to_string()],
-            updated_since: Some("2024-01-01T00:00:00Z".into()),
-            updated_until: None,
-            text: None,
-        })?;
+        let filter = build_filter(
+            CliFilterArgs {
+                states: Vec::new(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                include_state_kinds: vec!["todo".into()],
+                exclude_state_kinds: vec!["done".into()],
+                parents: vec![parent.to_string()],
+                children: vec![child.to_string()],
+                updated_since: Some("2024-01-01T00:00:00Z".into()),
+                updated_until: None,
+                text: None,
+                query: None,
+                fields: vec![],
+                milestones: vec![],
+                open_checklist: false,
+                include_archived: false,
+            },
+            full_id,
+        )?;
 
         assert!(filter.parents.contains(&parent));
         assert!(filter.children.contains(&child));
@@ -533,18 +1394,26 @@ mod tests {
 
     #[test]
     fn build_filter_rejects_invalid_state_kind() {
-        let Err(err) = build_filter(CliFilterArgs {
-            states: Vec::new(),
-            labels: Vec::new(),
-            assignees: Vec::new(),
-            include_state_kinds: vec!["unknown".into()],
-            exclude_state_kinds: Vec::new(),
-            parents: Vec::new(),
-            children: Vec::new(),
-            updated_since: None,
-            updated_until: None,
-            text: None,
-        }) else {
+        let Err(err) = build_filter(
+            CliFilterArgs {
+                states: Vec::new(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                include_state_kinds: vec!["unknown".into()],
+                exclude_state_kinds: Vec::new(),
+                parents: Vec::new(),
+                children: Vec::new(),
+                updated_since: None,
+                updated_until: None,
+                text: None,
+                query: None,
+                fields: vec![],
+                milestones: vec![],
+                open_checklist: false,
+                include_archived: false,
+            },
+            full_id,
+        ) else {
             panic!("filter should reject invalid state kind");
         };
         assert!(err.to_string().contains("invalid state kind"));
@@ -552,7 +1421,34 @@ mod tests {
 
     #[test]
     fn build_filter_rejects_invalid_timestamp() {
-        let Err(err) = build_filter(CliFilterArgs {
+        let Err(err) = build_filter(
+            CliFilterArgs {
+                states: Vec::new(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                include_state_kinds: Vec::new(),
+                exclude_state_kinds: Vec::new(),
+                parents: Vec::new(),
+                children: Vec::new(),
+                updated_since: Some("not-a-timestamp".into()),
+                updated_until: None,
+                text: None,
+                query: None,
+                fields: vec![],
+                milestones: vec![],
+                open_checklist: false,
+                include_archived: false,
+            },
+            full_id,
+        ) else {
+            panic!("filter should reject timestamp");
+        };
+        assert!(err.to_string().contains("invalid updated_since timestamp"));
+    }
+
+    #[test]
+    fn build_filter_parses_query_and_reports_errors() -> Result<()> {
+        let args = |query: &str| CliFilterArgs {
             states: Vec::new(),
             labels: Vec::new(),
             assignees: Vec::new(),
@@ -560,13 +1456,27 @@ mod tests {
             exclude_state_kinds: Vec::new(),
             parents: Vec::new(),
             children: Vec::new(),
-            updated_since: Some("not-a-timestamp".into()),
+            updated_since: None,
             updated_until: None,
             text: None,
-        }) else {
-            panic!("filter should reject timestamp");
+            query: Some(query.into()),
+            fields: vec![],
+            milestones: vec![],
+            open_checklist: false,
+            include_archived: false,
         };
-        assert!(err.to_string().contains("invalid updated_since timestamp"));
+
+        let filter = build_filter(args("label:type/bug OR -state:state/done"), full_id)?;
+        assert_eq!(
+            filter.query.as_ref().map(ToString::to_string).as_deref(),
+            Some("label:type/bug OR -state:state/done")
+        );
+
+        let Err(err) = build_filter(args("(label:type/bug"), full_id) else {
+            panic!("filter should reject unbalanced query");
+        };
+        assert!(err.to_string().contains("invalid query"));
+        Ok(())
     }
 
     #[test]
@@ -601,6 +1511,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn activity_merges_tasks_and_filters_by_kind() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let first = created_task(&store, "First task");
+        let second = created_task(&store, "Second task");
+        let mut state_set = Event::new(
+            second,
+            &sample_actor(),
+            EventKind::TaskStateSet {
+                state: "state/done".into(),
+                state_kind: None,
+            },
+        );
+        state_set.lamport = 5;
+        let mut events = store.load_events(second)?;
+        events.push(state_set);
+        store.set_events(second, events);
+        store.set_list(vec![first, second]);
+
+        let mut output = Vec::new();
+        super::handle_activity(
+            &service,
+            Some("1d"),
+            None,
+            vec![],
+            vec![],
+            LogFormat::Table,
+            &mut output,
+        )?;
+        let text = String::from_utf8(output).context("activity output must be utf8")?;
+        assert!(text.starts_with("Timestamp | Actor | Task | Event | Detail"));
+        assert_eq!(text.matches("Task created").count(), 2);
+        let created_idx = text
+            .find("Second task | Task created")
+            .context("second task row")?;
+        let state_idx = text.find("Second task | State set").context("state row")?;
+        assert!(created_idx < state_idx, "events must follow lamport order");
+
+        let mut output = Vec::new();
+        super::handle_activity(
+            &service,
+            None,
+            None,
+            vec![],
+            vec!["taskStateSet".into()],
+            LogFormat::Json,
+            &mut output,
+        )?;
+        let rows: serde_json::Value = serde_json::from_slice(&output)?;
+        assert_eq!(rows.as_array().map(Vec::len), Some(1));
+        assert_eq!(rows[0]["task_title"], "Second task");
+        assert_eq!(rows[0]["kind"]["type"], "taskStateSet");
+
+        let err = super::handle_activity(
+            &service,
+            Some("soon"),
+            None,
+            vec![],
+            vec![],
+            LogFormat::Table,
+            &mut Vec::new(),
+        )
+        .err()
+        .context("invalid --since must fail")?;
+        assert!(err.to_string().contains("--since"));
+        Ok(())
+    }
+
     #[test]
     fn run_new_dispatches_to_service() -> Result<()> {
         let (service, repository, store) = service_with_store();
@@ -612,6 +1590,7 @@ mod tests {
                 assignees: vec![],
                 description: None,
                 parents: vec![],
+                fields: vec![],
                 actor_name: Some("run".into()),
                 actor_email: Some("run@example.invalid".into()),
             },
@@ -639,6 +1618,7 @@ mod tests {
                 assignees: vec![],
                 description: None,
                 parents: vec![],
+                fields: vec![],
                 actor_name: Some("alice".into()),
                 actor_email: Some("alice@example.invalid".into()),
             },
@@ -671,6 +1651,597 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn run_delete_comment_and_react_append_events() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        run(
+            Command::New {
+                title: "task for reactions".into(),
+                state: None,
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                parents: vec![],
+                fields: vec![],
+                actor_name: Some("alice".into()),
+                actor_email: Some("alice@example.invalid".into()),
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+        let task = store.appended()[0].task;
+        run(
+            Command::Comment {
+                task: task.to_string(),
+                message: "pasted a secret".into(),
+                actor_name: Some("alice".into()),
+                actor_email: Some("alice@example.invalid".into()),
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+        let comment = match &store.appended()[1].kind {
+            EventKind::CommentAdded { comment_id, .. } => comment_id.to_string(),
+            other => panic!("unexpected event kind: {other:?}"),
+        };
+
+        run(
+            Command::React {
+                task: task.to_string(),
+                comment: comment.clone(),
+                reaction: "eyes".into(),
+                remove: false,
+                actor_name: Some("bob".into()),
+                actor_email: Some("bob@example.invalid".into()),
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+        run(
+            Command::DeleteComment {
+                task: task.to_string(),
+                comment,
+                actor_name: Some("alice".into()),
+                actor_email: Some("alice@example.invalid".into()),
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+
+        let events = store.appended();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[2].kind, EventKind::CommentReactionAdded { .. }));
+        assert!(matches!(events[3].kind, EventKind::CommentDeleted { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn run_archive_toggles_archived_flag() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let task = TaskId::new();
+        store.set_events(
+            task,
+            vec![Event::new(
+                task,
+                &Actor {
+                    name: "alice".into(),
+                    email: "alice@example.invalid".into(),
+                },
+                EventKind::TaskCreated {
+                    title: "junk".into(),
+                    labels: vec![],
+                    assignees: vec![],
+                    description: None,
+                    state: None,
+                    state_kind: None,
+                },
+            )],
+        );
+
+        for undo in [false, true] {
+            run(
+                Command::Archive {
+                    task: task.to_string(),
+                    undo,
+                    actor_name: Some("alice".into()),
+                    actor_email: Some("alice@example.invalid".into()),
+                },
+                &service,
+                &repository,
+                Path::new("."),
+            )?;
+        }
+
+        let events = store.appended();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0].kind, EventKind::TaskArchived));
+        assert!(matches!(events[1].kind, EventKind::TaskUnarchived));
+        Ok(())
+    }
+
+    #[test]
+    fn run_link_writes_relation_and_inverse() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let actor = Actor {
+            name: "alice".into(),
+            email: "alice@example.invalid".into(),
+        };
+        let task = TaskId::new();
+        let target = TaskId::new();
+        for id in [task, target] {
+            store.set_events(
+                id,
+                vec![Event::new(
+                    id,
+                    &actor,
+                    EventKind::TaskCreated {
+                        title: "related".into(),
+                        labels: vec![],
+                        assignees: vec![],
+                        description: None,
+                        state: None,
+                        state_kind: None,
+                    },
+                )],
+            );
+        }
+
+        run(
+            Command::Link {
+                task: task.to_string(),
+                kind: Some(RelationKind::Blocks),
+                targets: vec![target.to_string()],
+                parents: vec![],
+                actor_name: Some("alice".into()),
+                actor_email: Some("alice@example.invalid".into()),
+                format: WriteFormat::Text,
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+
+        let events = store.appended();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].task, task);
+        assert!(matches!(
+            &events[0].kind,
+            EventKind::RelationAdded { kind, target: t } if kind == "blocks" && *t == target
+        ));
+        assert_eq!(events[1].task, target);
+        assert!(matches!(
+            &events[1].kind,
+            EventKind::RelationAdded { kind, target: t } if kind == "blocked-by" && *t == task
+        ));
+        Ok(())
+    }
+
+    fn created_task(store: &MockStore, title: &str) -> TaskId {
+        let task = TaskId::new();
+        store.set_events(
+            task,
+            vec![Event::new(
+                task,
+                &sample_actor(),
+                EventKind::TaskCreated {
+                    title: title.into(),
+                    labels: vec!["keep".into()],
+                    assignees: vec![],
+                    description: Some("old".into()),
+                    state: None,
+                    state_kind: None,
+                },
+            )],
+        );
+        task
+    }
+
+    fn label_filter(label: &str) -> CliFilterArgs {
+        CliFilterArgs {
+            states: Vec::new(),
+            labels: vec![label.into()],
+            assignees: Vec::new(),
+            include_state_kinds: Vec::new(),
+            exclude_state_kinds: Vec::new(),
+            parents: Vec::new(),
+            children: Vec::new(),
+            updated_since: None,
+            updated_until: None,
+            text: None,
+            query: None,
+            fields: vec![],
+            milestones: vec![],
+            open_checklist: false,
+            include_archived: false,
+        }
+    }
+
+    #[test]
+    fn bulk_previews_then_applies_update_to_matching_tasks() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let first = created_task(&store, "first");
+        let second = created_task(&store, "second");
+        store.set_list(vec![first, second]);
+        service.update(
+            second,
+            TaskUpdate {
+                labels: SetDiff {
+                    added: vec!["done-already".into()],
+                    removed: vec![],
+                },
+                ..TaskUpdate::default()
+            },
+            &sample_actor(),
+        )?;
+        let update = TaskUpdate {
+            labels: SetDiff {
+                added: vec!["done-already".into()],
+                removed: vec![],
+            },
+            ..TaskUpdate::default()
+        };
+
+        let mut output = Vec::new();
+        handle_bulk(
+            &service,
+            &repository,
+            Path::new("."),
+            None,
+            label_filter("keep"),
+            &update,
+            &sample_actor(),
+            WriteFormat::Text,
+            &mut output,
+            |count| {
+                assert_eq!(count, 2);
+                Ok(false)
+            },
+        )?;
+        let text = String::from_utf8(output)?;
+        assert!(text.contains("first") && text.contains("second"), "{text}");
+        assert!(text.contains("aborted"), "{text}");
+        assert!(!service.materialize(first)?.labels.contains("done-already"));
+
+        let mut output = Vec::new();
+        handle_bulk(
+            &service,
+            &repository,
+            Path::new("."),
+            None,
+            label_filter("keep"),
+            &update,
+            &sample_actor(),
+            WriteFormat::Text,
+            &mut output,
+            |_| Ok(true),
+        )?;
+        let text = String::from_utf8(output)?;
+        assert!(text.contains("1 updated, 1 unchanged, 0 failed"), "{text}");
+        assert!(service.materialize(first)?.labels.contains("done-already"));
+        Ok(())
+    }
+
+    #[test]
+    fn bulk_refuses_an_empty_filter() {
+        let (service, repository, _store) = service_with_store();
+        let mut filter = label_filter("unused");
+        filter.labels.clear();
+        let Err(err) = handle_bulk(
+            &service,
+            &repository,
+            Path::new("."),
+            None,
+            filter,
+            &TaskUpdate::default(),
+            &sample_actor(),
+            WriteFormat::Json,
+            &mut Vec::new(),
+            |_| Ok(true),
+        ) else {
+            panic!("an empty filter must be rejected");
+        };
+        assert!(err.to_string().contains("needs a filter"));
+    }
+
+    #[test]
+    fn bulk_refuses_include_archived_without_other_filters() {
+        let (service, repository, store) = service_with_store();
+        store.set_list(vec![created_task(&store, "untouched")]);
+        let mut filter = label_filter("unused");
+        filter.labels.clear();
+        filter.include_archived = true;
+        let Err(err) = handle_bulk(
+            &service,
+            &repository,
+            Path::new("."),
+            None,
+            filter,
+            &TaskUpdate {
+                title: Some("overwritten".into()),
+                ..TaskUpdate::default()
+            },
+            &sample_actor(),
+            WriteFormat::Json,
+            &mut Vec::new(),
+            |_| Ok(true),
+        ) else {
+            panic!("--include-archived alone must be rejected");
+        };
+        assert!(err.to_string().contains("needs a filter"));
+    }
+
+    #[test]
+    fn run_label_assign_and_set_state_write_updates() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let task = created_task(&store, "mutable");
+        let actor = || (Some("alice".to_owned()), Some("alice@example.invalid".to_owned()));
+
+        let (actor_name, actor_email) = actor();
+        run(
+            Command::Label {
+                task: task.to_string(),
+                add: vec!["type/bug".into()],
+                remove: vec!["keep".into()],
+                actor_name,
+                actor_email,
+                format: WriteFormat::Text,
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+        let (actor_name, actor_email) = actor();
+        run(
+            Command::Assign {
+                task: task.to_string(),
+                add: vec!["bob".into()],
+                remove: vec![],
+                actor_name,
+                actor_email,
+                format: WriteFormat::Json,
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+        let (actor_name, actor_email) = actor();
+        run(
+            Command::SetState {
+                task: task.to_string(),
+                state: Some("state/done".into()),
+                clear: false,
+                actor_name,
+                actor_email,
+                format: WriteFormat::Text,
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+
+        let snapshot = service.materialize(task)?;
+        assert_eq!(snapshot.labels.iter().collect::<Vec<_>>(), vec!["type/bug"]);
+        assert!(snapshot.assignees.contains("bob"));
+        assert_eq!(snapshot.state.as_deref(), Some("state/done"));
+        Ok(())
+    }
+
+    #[test]
+    fn run_edit_with_flags_sets_title_and_clears_description() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let task = created_task(&store, "before");
+        run(
+            Command::Edit {
+                task: task.to_string(),
+                title: Some("after".into()),
+                description: None,
+                clear_description: true,
+                set_fields: vec![],
+                clear_fields: vec![],
+                milestone: None,
+                clear_milestone: false,
+                editor: false,
+                actor_name: Some("alice".into()),
+                actor_email: Some("alice@example.invalid".into()),
+                format: WriteFormat::Text,
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+
+        let snapshot = service.materialize(task)?;
+        assert_eq!(snapshot.title, "after");
+        assert!(snapshot.description.is_empty());
+        assert!(matches!(
+            store.appended().as_slice(),
+            [
+                Event {
+                    kind: EventKind::TaskTitleSet { .. },
+                    ..
+                },
+                Event {
+                    kind: EventKind::TaskDescriptionSet { description: None },
+                    ..
+                }
+            ]
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn milestone_membership_drives_show_progress_and_ls_filter() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let milestone = service
+            .milestones()
+            .create(git_mile_app::CreateMilestoneInput {
+                title: "v1.0".into(),
+                due: Some("2025-06-30".into()),
+                description: None,
+                actor: sample_actor(),
+            })?
+            .id;
+        let shipped = created_task(&store, "shipped");
+        let pending = created_task(&store, "pending");
+        let outside = created_task(&store, "outside");
+        store.set_list(vec![shipped, pending, outside]);
+        let prefix: String = milestone.0.simple().to_string().chars().take(24).collect();
+        for task in [shipped, pending] {
+            run(
+                Command::Edit {
+                    task: task.to_string(),
+                    title: None,
+                    description: None,
+                    clear_description: false,
+                    set_fields: vec![],
+                    clear_fields: vec![],
+                    milestone: Some(prefix.clone()),
+                    clear_milestone: false,
+                    editor: false,
+                    actor_name: None,
+                    actor_email: None,
+                    format: WriteFormat::Text,
+                },
+                &service,
+                &repository,
+                Path::new("."),
+            )?;
+        }
+        store.append_event(&Event::new(
+            shipped,
+            &sample_actor(),
+            EventKind::TaskStateSet {
+                state: "state/done".into(),
+                state_kind: Some(StateKind::Done),
+            },
+        ))?;
+
+        let mut output = Vec::new();
+        milestone::run(
+            &service,
+            &repository,
+            crate::MilestoneSubcommand::Show {
+                milestone: prefix,
+                format: LsFormat::Table,
+            },
+            Path::new("."),
+            &mut output,
+        )?;
+        let text = String::from_utf8(output)?;
+        assert!(text.contains("Progress:  1/2 done (50%)"), "{text}");
+        assert!(text.contains("pending"), "{text}");
+        assert!(!text.contains("outside"), "{text}");
+
+        let filter = build_filter(
+            CliFilterArgs {
+                milestones: resolve_milestone_ids(&service, &[milestone.to_string()])?,
+                ..label_filter("keep")
+            },
+            full_id,
+        )?;
+        let mut members: Vec<_> = repository
+            .list_snapshots(Some(&filter))?
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+        members.sort();
+        let mut expected = vec![shipped, pending];
+        expected.sort();
+        assert_eq!(members, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn edit_in_editor_round_trips_the_tui_template() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let task = created_task(&store, "before");
+
+        let data = edit_in_editor(&service, task, |template| {
+            assert!(template.contains("title: before"));
+            assert!(template.contains("labels: keep"));
+            Ok(template
+                .replace("title: before", "title: after")
+                .replace("labels: keep", "labels: keep, new"))
+        })?
+        .context("edited template must not cancel")?;
+        assert_eq!(data.title, "after");
+        assert_eq!(data.labels, vec!["keep", "new"]);
+        assert_eq!(data.description.as_deref(), Some("old"));
+
+        let cancelled = edit_in_editor(&service, task, |_| Ok(String::new()))?;
+        assert!(cancelled.is_none());
+        assert!(edit_in_editor(&service, TaskId::new(), |_| Ok(String::new())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn link_parents_and_json_output() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let task = created_task(&store, "child");
+        let parent = created_task(&store, "parent");
+
+        let mut output = Vec::new();
+        handle_link(
+            &service,
+            &task.to_string(),
+            None,
+            vec![],
+            vec![parent.to_string()],
+            true,
+            Some("alice"),
+            Some("alice@example.invalid"),
+            Path::new("."),
+            WriteFormat::Json,
+            &mut output,
+        )?;
+        let json: serde_json::Value = serde_json::from_slice(&output)?;
+        assert_eq!(json["task"], task.to_string());
+        assert_eq!(json["events"].as_array().map(Vec::len), Some(2));
+        assert!(json["events"][0].is_string());
+        assert!(service.materialize(task)?.parents.contains(&parent));
+
+        let mut output = Vec::new();
+        handle_link(
+            &service,
+            &task.to_string(),
+            None,
+            vec![],
+            vec![parent.to_string()],
+            false,
+            Some("alice"),
+            Some("alice@example.invalid"),
+            Path::new("."),
+            WriteFormat::Text,
+            &mut output,
+        )?;
+        let text = String::from_utf8(output).context("link output must be utf8")?;
+        assert_eq!(text, format!("unlinked: {task} child-of {parent}\n"));
+        assert!(service.materialize(task)?.parents.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn run_ready_loads_all_tasks() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let task = TaskId::new();
+        store.set_list(vec![task]);
+        run(
+            Command::Ready {
+                format: LsFormat::Json,
+            },
+            &service,
+            &repository,
+            Path::new("."),
+        )?;
+        assert_eq!(store.list_calls(), 1);
+        assert_eq!(store.load_calls(), vec![task]);
+        Ok(())
+    }
+
     #[test]
     fn run_ls_lists_all_tasks() -> Result<()> {
         let (service, repository, store) = service_with_store();
@@ -678,16 +2249,9 @@ mod tests {
         store.set_list(vec![task]);
         run(
             Command::Ls {
-                states: vec![],
-                labels: vec![],
-                assignees: vec![],
-                state_kinds: vec![],
-                exclude_state_kinds: vec![],
-                parents: vec![],
-                children: vec![],
-                updated_since: None,
-                updated_until: None,
-                text: None,
+                filter: FilterArgs::default(),
+                view: None,
+                sort: None,
                 format: LsFormat::Table,
             },
             &service,
@@ -699,13 +2263,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn run_ls_with_unknown_view_reports_available_views() -> Result<()> {
+        let (service, repository, _store) = service_with_store();
+        let dir = tempfile::tempdir()?;
+        git2::Repository::init(dir.path())?;
+        std::fs::create_dir_all(dir.path().join(".git-mile"))?;
+        std::fs::write(
+            dir.path().join(".git-mile/config.toml"),
+            "[views.my-open.filter]\nassignees = [\"alice\"]\n",
+        )?;
+
+        let Err(err) = run(
+            Command::Ls {
+                filter: FilterArgs::default(),
+                view: Some("missing".into()),
+                sort: None,
+                format: LsFormat::Table,
+            },
+            &service,
+            &repository,
+            dir.path(),
+        ) else {
+            panic!("unknown view should error");
+        };
+        assert!(err.to_string().contains("Available views: my-open"));
+        Ok(())
+    }
+
     #[test]
     fn run_show_materializes_snapshot() -> Result<()> {
         let (service, repository, store) = service_with_store();
-        let task = TaskId::new();
+        let task = created_task(&store, "shown");
         run(
             Command::Show {
                 task: task.to_string(),
+                at: None,
+                format: ShowFormat::Json,
             },
             &service,
             &repository,
@@ -716,4 +2310,214 @@ mod tests {
         assert_eq!(calls, vec![task]);
         Ok(())
     }
+
+    #[test]
+    fn show_renders_children_relations_and_comments() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let parent = created_task(&store, "Parent epic");
+        let child = created_task(&store, "Child step");
+        let blocker = created_task(&store, "Blocker");
+        let actor = sample_actor();
+        let comment = EventId::new();
+        let mut parent_events = store.load_events(parent)?;
+        parent_events.extend([
+            Event::new(parent, &actor, EventKind::ChildLinked { parent, child }),
+            Event::new(
+                parent,
+                &actor,
+                EventKind::TaskDescriptionSet {
+                    description: Some("- [x] scope\n- [ ] rollout".into()),
+                },
+            ),
+            Event::new(
+                parent,
+                &actor,
+                EventKind::RelationAdded {
+                    kind: "blocked-by".into(),
+                    target: blocker,
+                },
+            ),
+            Event::new(
+                parent,
+                &actor,
+                EventKind::CommentAdded {
+                    comment_id: comment,
+                    body_md: "looks good".into(),
+                },
+            ),
+        ]);
+        store.set_events(parent, parent_events);
+        let mut child_events = store.load_events(child)?;
+        child_events.extend([
+            Event::new(child, &actor, EventKind::ChildLinked { parent, child }),
+            Event::new(
+                child,
+                &actor,
+                EventKind::TaskStateSet {
+                    state: "state/done".into(),
+                    state_kind: Some(StateKind::Done),
+                },
+            ),
+        ]);
+        store.set_events(child, child_events);
+        store.set_list(vec![parent, child, blocker]);
+        let ids = service.id_abbreviations()?;
+
+        let mut output = Vec::new();
+        super::handle_show(
+            &service,
+            &repository,
+            &parent.to_string(),
+            None,
+            ShowFormat::Table,
+            &mut output,
+        )?;
+        let text = String::from_utf8(output).context("show output must be utf8")?;
+        assert!(text.starts_with("Parent epic\n"));
+        assert!(text.contains("Labels:    keep"));
+        assert!(text.contains(&format!("{}  Child step [state/done] ✓", ids.abbreviate(child))));
+        assert!(text.contains("Relation: blocked-by"));
+        assert!(text.contains("Blocker [-]"));
+        assert!(text.contains("Progress:  1/1 subtasks done (100%)"), "{text}");
+        assert!(text.contains("Checklist: 1/2 items done"), "{text}");
+        assert!(text.contains(&comment.to_string()));
+        assert!(text.contains("  looks good"));
+
+        let mut output = Vec::new();
+        super::handle_show(
+            &service,
+            &repository,
+            &child.to_string(),
+            None,
+            ShowFormat::Markdown,
+            &mut output,
+        )?;
+        let text = String::from_utf8(output).context("show output must be utf8")?;
+        assert!(text.contains("- **State:** state/done (done)"));
+        assert!(text.contains("- **Path:** Parent epic"));
+
+        let mut output = Vec::new();
+        super::handle_show(
+            &service,
+            &repository,
+            &parent.to_string(),
+            None,
+            ShowFormat::Json,
+            &mut output,
+        )?;
+        let json: serde_json::Value = serde_json::from_slice(&output)?;
+        assert_eq!(json["title"], "Parent epic");
+        assert_eq!(json["rollup"]["children"]["done"], 1);
+        assert_eq!(json["rollup"]["percent_done"], 100);
+        assert_eq!(json["checklist"]["total"], 2);
+        assert_eq!(json["comments"][0]["body_md"], "looks good");
+        Ok(())
+    }
+
+    #[test]
+    fn show_at_replays_history_up_to_the_given_event() -> Result<()> {
+        let (service, repository, store) = service_with_store();
+        let task = created_task(&store, "Before");
+        let mut events = store.load_events(task)?;
+        let created_id = events[0].id;
+        let mut retitled = Event::new(
+            task,
+            &sample_actor(),
+            EventKind::TaskTitleSet {
+                title: "After".into(),
+            },
+        );
+        retitled.lamport = 1;
+        events.push(retitled);
+        store.set_events(task, events);
+
+        let mut output = Vec::new();
+        let at = created_id.to_string();
+        super::handle_show(
+            &service,
+            &repository,
+            &task.to_string(),
+            Some(&at),
+            ShowFormat::Json,
+            &mut output,
+        )?;
+        let json: serde_json::Value = serde_json::from_slice(&output)?;
+        assert_eq!(json["title"], "Before");
+
+        let err = super::handle_show(
+            &service,
+            &repository,
+            &task.to_string(),
+            Some("1999-01-01T00:00:00Z"),
+            ShowFormat::Table,
+            &mut Vec::new(),
+        )
+        .err()
+        .context("show before creation must fail")?;
+        assert!(err.to_string().contains("did not exist yet"));
+        Ok(())
+    }
+
+    #[test]
+    fn blame_names_the_event_behind_each_field() -> Result<()> {
+        let (service, _repository, store) = service_with_store();
+        let task = created_task(&store, "Blamed");
+        let mut events = store.load_events(task)?;
+        let reviewer = Actor {
+            name: "closer".into(),
+            email: "closer@example.invalid".into(),
+        };
+        let mut closed = Event::new(
+            task,
+            &reviewer,
+            EventKind::TaskStateSet {
+                state: "state/done".into(),
+                state_kind: None,
+            },
+        );
+        closed.lamport = 1;
+        let closed_id = closed.id;
+        events.push(closed);
+        store.set_events(task, events);
+
+        let mut output = Vec::new();
+        super::handle_blame(&service, &task.to_string(), LogFormat::Table, &mut output)?;
+        let text = String::from_utf8(output).context("blame output must be utf8")?;
+        let state_line = text
+            .lines()
+            .find(|line| line.starts_with("state |"))
+            .context("state row")?;
+        assert!(state_line.contains("closer <closer@example.invalid>"));
+        assert!(state_line.ends_with(&closed_id.to_string()));
+        assert!(text.contains("label keep | "));
+
+        let mut output = Vec::new();
+        super::handle_blame(&service, &task.to_string(), LogFormat::Json, &mut output)?;
+        let rows: serde_json::Value = serde_json::from_slice(&output)?;
+        assert_eq!(rows[0]["field"], "title");
+        assert!(
+            rows.as_array()
+                .into_iter()
+                .flatten()
+                .any(|row| row["field"] == "label" && row["value"] == "keep")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn show_rejects_unknown_task() {
+        let (service, repository, _store) = service_with_store();
+        let task = TaskId::new();
+        let err = super::handle_show(
+            &service,
+            &repository,
+            &task.to_string(),
+            None,
+            ShowFormat::Table,
+            &mut Vec::new(),
+        )
+        .err()
+        .unwrap_or_else(|| panic!("unknown task should error"));
+        assert!(err.to_string().contains("Task not found"));
+    }
 }
@@ -0,0 +1,225 @@
+//! `git-mile milestone` subcommands.
+
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use git_mile_app::{
+    CreateMilestoneInput, MilestoneStore, MilestoneUpdate, MilestoneView, TaskRepository, TaskService,
+    TaskStore, WorkflowConfig, actor_from_params_or_default,
+};
+use git_mile_core::id::TaskIdAbbreviations;
+use git_mile_core::milestone::MilestoneSnapshot;
+use git_mile_core::{StateKindCounts, TaskSnapshot};
+use serde::Serialize;
+
+use crate::event_log::state_kind_marker;
+use crate::{LsFormat, MilestoneSubcommand};
+
+/// Run one `milestone` subcommand, writing its output to `writer`.
+///
+/// # Errors
+/// Returns an error when a milestone cannot be resolved, a value is invalid, or storage fails.
+pub fn run<S: TaskStore + MilestoneStore, R: TaskStore>(
+    service: &TaskService<S>,
+    repository: &TaskRepository<R>,
+    subcommand: MilestoneSubcommand,
+    repo_root: &Path,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let milestones = service.milestones();
+    match subcommand {
+        MilestoneSubcommand::New {
+            title,
+            due,
+            description,
+            actor_name,
+            actor_email,
+        } => {
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let milestone = milestones.create(CreateMilestoneInput {
+                title,
+                due,
+                description,
+                actor,
+            })?;
+            writeln!(writer, "created milestone: {}", milestone.id)?;
+        }
+        MilestoneSubcommand::Ls { all, format } => {
+            let tasks = repository.list_snapshots(None)?;
+            let views: Vec<MilestoneView> = milestones
+                .list()?
+                .into_iter()
+                .filter(|milestone| all || !milestone.closed)
+                .map(|milestone| MilestoneView::new(milestone, &tasks))
+                .collect();
+            match format {
+                LsFormat::Json => writeln!(writer, "{}", serde_json::to_string_pretty(&views)?)?,
+                LsFormat::Table if views.is_empty() => writeln!(writer, "No milestones found")?,
+                LsFormat::Table => render_milestone_table(&views, writer)?,
+            }
+        }
+        MilestoneSubcommand::Show { milestone, format } => {
+            let milestone = milestones.get(milestones.resolve(&milestone)?)?;
+            let mut members: Vec<TaskSnapshot> = repository
+                .list_snapshots(None)?
+                .into_iter()
+                .filter(|task| task.milestone == Some(milestone.id) && !task.archived)
+                .collect();
+            members.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.id.cmp(&b.id)));
+            let view = MilestoneView::new(milestone, &members);
+            match format {
+                LsFormat::Json => {
+                    let json = MilestoneJson {
+                        view: &view,
+                        tasks: &members,
+                    };
+                    writeln!(writer, "{}", serde_json::to_string_pretty(&json)?)?;
+                }
+                LsFormat::Table => render_milestone(
+                    &view,
+                    &members,
+                    service.workflow(),
+                    &repository.id_abbreviations()?,
+                    writer,
+                )?,
+            }
+        }
+        MilestoneSubcommand::Edit {
+            milestone,
+            title,
+            due,
+            clear_due,
+            description,
+            clear_description,
+            close,
+            reopen,
+            actor_name,
+            actor_email,
+        } => {
+            let id = milestones.resolve(&milestone)?;
+            let actor =
+                actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), repo_root);
+            let update = MilestoneUpdate {
+                title,
+                due: if clear_due { Some(None) } else { due.map(Some) },
+                description: if clear_description {
+                    Some(None)
+                } else {
+                    description.map(Some)
+                },
+                closed: (close || reopen).then_some(close),
+            };
+            let milestone = milestones.update(id, update, &actor)?;
+            writeln!(writer, "updated milestone: {}", milestone.id)?;
+        }
+    }
+    Ok(())
+}
+
+fn render_milestone_table(views: &[MilestoneView], writer: &mut dyn Write) -> Result<()> {
+    writeln!(writer, "ID | Due | Status | Progress | Title")?;
+    writeln!(writer, "-- | --- | ------ | -------- | -----")?;
+    for view in views {
+        let milestone = &view.milestone;
+        writeln!(
+            writer,
+            "{} | {} | {} | {} | {}",
+            milestone.id,
+            milestone.due.as_deref().unwrap_or("-"),
+            status(milestone),
+            progress_text(&view.progress, view.percent_done),
+            milestone.title
+        )?;
+    }
+    Ok(())
+}
+
+fn render_milestone(
+    view: &MilestoneView,
+    members: &[TaskSnapshot],
+    workflow: &WorkflowConfig,
+    ids: &TaskIdAbbreviations,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let milestone = &view.milestone;
+    writeln!(writer, "{}", milestone.title)?;
+    writeln!(writer, "{}", "=".repeat(milestone.title.chars().count().max(1)))?;
+    writeln!(writer, "ID:        {}", milestone.id)?;
+    writeln!(writer, "Status:    {}", status(milestone))?;
+    if let Some(due) = &milestone.due {
+        writeln!(writer, "Due:       {due}")?;
+    }
+    writeln!(
+        writer,
+        "Progress:  {}",
+        progress_text(&view.progress, view.percent_done)
+    )?;
+    let breakdown = breakdown(&view.progress);
+    if !breakdown.is_empty() {
+        writeln!(writer, "           {breakdown}")?;
+    }
+
+    if !milestone.description.trim().is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "Description")?;
+        writeln!(writer, "-----------")?;
+        for line in milestone.description.trim_end().lines() {
+            writeln!(writer, "  {line}")?;
+        }
+    }
+
+    if !members.is_empty() {
+        let heading = format!("Tasks ({})", members.len());
+        writeln!(writer)?;
+        writeln!(writer, "{heading}")?;
+        writeln!(writer, "{}", "-".repeat(heading.chars().count()))?;
+        for task in members {
+            let state = task
+                .state
+                .as_deref()
+                .map_or("-", |state| workflow.display_label(Some(state)));
+            writeln!(
+                writer,
+                "  {}  {} [{state}]{}",
+                ids.abbreviate(task.id),
+                task.title,
+                state_kind_marker(task.state_kind)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+const fn status(milestone: &MilestoneSnapshot) -> &'static str {
+    if milestone.closed { "closed" } else { "open" }
+}
+
+fn progress_text(progress: &StateKindCounts, percent_done: usize) -> String {
+    format!("{}/{} done ({percent_done}%)", progress.done, progress.total)
+}
+
+/// Non-zero state kind counts, e.g. `in_progress 2, todo 1`.
+fn breakdown(progress: &StateKindCounts) -> String {
+    [
+        ("in_progress", progress.in_progress),
+        ("blocked", progress.blocked),
+        ("todo", progress.todo),
+        ("backlog", progress.backlog),
+        ("unclassified", progress.unclassified),
+    ]
+    .into_iter()
+    .filter(|(_, count)| *count > 0)
+    .map(|(kind, count)| format!("{kind} {count}"))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// JSON shape of `milestone show`: the milestone, its progress and its member tasks.
+#[derive(Serialize)]
+struct MilestoneJson<'a> {
+    #[serde(flatten)]
+    view: &'a MilestoneView,
+    tasks: &'a [TaskSnapshot],
+}
@@ -1,4 +1,6 @@
 mod handlers;
+mod milestone;
+mod show;
 mod sync;
 
 pub use handlers::run;
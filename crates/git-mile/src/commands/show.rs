@@ -0,0 +1,441 @@
+//! Rendering of a single task for `git-mile show`.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::Write;
+
+use anyhow::Result;
+use git_mile_app::{BlockedDescendant, TaskCache, TaskComment, TaskRollup, TaskView, WorkflowConfig};
+use git_mile_core::event::Actor;
+use git_mile_core::id::{EventId, TaskId, TaskIdAbbreviations};
+use git_mile_core::milestone::MilestoneSnapshot;
+use git_mile_core::{RelationKind, StateKind, TaskSnapshot};
+use serde::Serialize;
+
+use crate::ShowFormat;
+use crate::event_log::{format_actor, format_timestamp, state_kind_marker};
+
+/// Everything needed to render a task besides the task itself.
+pub struct ShowContext<'a> {
+    /// Tasks referenced as parents, children or relation targets.
+    pub related: &'a TaskCache,
+    /// Workflow used to label states.
+    pub workflow: &'a WorkflowConfig,
+    /// Shortest unique prefixes for referenced task ids.
+    pub ids: &'a TaskIdAbbreviations,
+    /// Milestone the task belongs to, when it could be loaded.
+    pub milestone: Option<&'a MilestoneSnapshot>,
+}
+
+/// Write `view` to `writer` in the requested format.
+///
+/// # Errors
+/// Returns an error if serialization or writing fails.
+pub fn render(
+    view: &TaskView,
+    ctx: &ShowContext<'_>,
+    format: ShowFormat,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    match format {
+        ShowFormat::Table => render_table(view, ctx, writer),
+        ShowFormat::Markdown => render_markdown(view, ctx, writer),
+        ShowFormat::Json => {
+            let json = serde_json::to_string_pretty(&ShowJson::new(view, ctx))?;
+            writeln!(writer, "{json}")?;
+            Ok(())
+        }
+    }
+}
+
+fn render_table(view: &TaskView, ctx: &ShowContext<'_>, writer: &mut dyn Write) -> Result<()> {
+    let task = &view.snapshot;
+    writeln!(writer, "{}", task.title)?;
+    writeln!(writer, "{}", "=".repeat(task.title.chars().count().max(1)))?;
+    writeln!(writer, "ID:        {}", task.id)?;
+    writeln!(writer, "State:     {}", state_text(task, ctx.workflow))?;
+    if !task.labels.is_empty() {
+        writeln!(writer, "Labels:    {}", join(&task.labels))?;
+    }
+    if !task.assignees.is_empty() {
+        writeln!(writer, "Assignees: {}", join(&task.assignees))?;
+    }
+    if let Some(milestone) = milestone_text(task, ctx) {
+        writeln!(writer, "Milestone: {milestone}")?;
+    }
+    if let Some(updated) = view.last_updated {
+        writeln!(writer, "Updated:   {}", format_timestamp(updated))?;
+    }
+    if task.archived {
+        writeln!(writer, "Archived:  yes")?;
+    }
+    let path = ancestor_titles(task, ctx.related);
+    if !path.is_empty() {
+        writeln!(writer, "Path:      {} > (this task)", path.join(" > "))?;
+    }
+    if !task.checklist.is_empty() {
+        writeln!(writer, "Checklist: {}", checklist_text(task))?;
+    }
+    if let Some(rollup) = ctx.related.rollup(task.id) {
+        writeln!(writer, "Progress:  {}", rollup_progress(rollup))?;
+        if let Some(estimate) = rollup.estimate {
+            writeln!(writer, "Estimate:  {estimate} (sum of subtasks)")?;
+        }
+        if let Some(blocked) = &rollup.deepest_blocked {
+            writeln!(writer, "Blocked:   {}", blocked_text(blocked, ctx))?;
+        }
+    }
+
+    if !task.fields.is_empty() {
+        write_heading(writer, "Fields")?;
+        let width = task
+            .fields
+            .keys()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or_default();
+        for (name, value) in &task.fields {
+            writeln!(writer, "  {name:<width$}  {value}")?;
+        }
+    }
+
+    if !task.description.trim().is_empty() {
+        write_heading(writer, "Description")?;
+        for line in task.description.trim_end().lines() {
+            writeln!(writer, "  {line}")?;
+        }
+    }
+
+    write_task_section(writer, "Parents", &task.parents, ctx)?;
+    write_task_section(writer, "Children", &task.children, ctx)?;
+    for (kind, targets) in relation_buckets(task) {
+        write_task_section(writer, &format!("Relation: {kind}"), targets, ctx)?;
+    }
+
+    if !view.comments.is_empty() {
+        write_heading(writer, &format!("Comments ({})", view.comments.len()))?;
+        for comment in &view.comments {
+            writeln!(writer, "{}", comment_header(comment))?;
+            for line in comment_body(comment).lines() {
+                writeln!(writer, "  {line}")?;
+            }
+            if let Some(reactions) = reaction_summary(comment) {
+                writeln!(writer, "  {reactions}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_task_section(
+    writer: &mut dyn Write,
+    heading: &str,
+    tasks: &BTreeSet<TaskId>,
+    ctx: &ShowContext<'_>,
+) -> Result<()> {
+    if tasks.is_empty() {
+        return Ok(());
+    }
+    write_heading(writer, &format!("{heading} ({})", tasks.len()))?;
+    for id in tasks {
+        let abbr = ctx.ids.abbreviate(*id);
+        match related_snapshot(ctx.related, *id) {
+            Some(other) => writeln!(
+                writer,
+                "  {abbr}  {} [{}]{}",
+                other.title,
+                state_label(other, ctx.workflow),
+                state_kind_marker(other.state_kind)
+            )?,
+            None => writeln!(writer, "  {abbr}  (unknown task)")?,
+        }
+    }
+    Ok(())
+}
+
+fn write_heading(writer: &mut dyn Write, heading: &str) -> Result<()> {
+    writeln!(writer)?;
+    writeln!(writer, "{heading}")?;
+    writeln!(writer, "{}", "-".repeat(heading.chars().count()))?;
+    Ok(())
+}
+
+fn render_markdown(view: &TaskView, ctx: &ShowContext<'_>, writer: &mut dyn Write) -> Result<()> {
+    let task = &view.snapshot;
+    writeln!(writer, "# {}", task.title)?;
+    writeln!(writer)?;
+    writeln!(writer, "- **ID:** `{}`", task.id)?;
+    writeln!(writer, "- **State:** {}", state_text(task, ctx.workflow))?;
+    if !task.labels.is_empty() {
+        writeln!(writer, "- **Labels:** {}", join(&task.labels))?;
+    }
+    if !task.assignees.is_empty() {
+        writeln!(writer, "- **Assignees:** {}", join(&task.assignees))?;
+    }
+    if let Some(milestone) = milestone_text(task, ctx) {
+        writeln!(writer, "- **Milestone:** {milestone}")?;
+    }
+    if let Some(updated) = view.last_updated {
+        writeln!(writer, "- **Updated:** {}", format_timestamp(updated))?;
+    }
+    if task.archived {
+        writeln!(writer, "- **Archived:** yes")?;
+    }
+    let path = ancestor_titles(task, ctx.related);
+    if !path.is_empty() {
+        writeln!(writer, "- **Path:** {}", path.join(" > "))?;
+    }
+    if !task.checklist.is_empty() {
+        writeln!(writer, "- **Checklist:** {}", checklist_text(task))?;
+    }
+    if let Some(rollup) = ctx.related.rollup(task.id) {
+        writeln!(writer, "- **Progress:** {}", rollup_progress(rollup))?;
+        if let Some(estimate) = rollup.estimate {
+            writeln!(writer, "- **Estimate:** {estimate} (sum of subtasks)")?;
+        }
+        if let Some(blocked) = &rollup.deepest_blocked {
+            writeln!(writer, "- **Blocked:** {}", blocked_text(blocked, ctx))?;
+        }
+    }
+    for (name, value) in &task.fields {
+        writeln!(writer, "- **{name}:** {value}")?;
+    }
+
+    if !task.description.trim().is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "{}", task.description.trim_end())?;
+    }
+
+    write_markdown_tasks(writer, "Parents", &task.parents, ctx)?;
+    write_markdown_tasks(writer, "Children", &task.children, ctx)?;
+    let buckets = relation_buckets(task);
+    if !buckets.is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "## Relations")?;
+        for (kind, targets) in buckets {
+            writeln!(writer)?;
+            writeln!(writer, "### {kind}")?;
+            writeln!(writer)?;
+            for id in targets {
+                writeln!(writer, "- {}", markdown_task_ref(*id, ctx))?;
+            }
+        }
+    }
+
+    if !view.comments.is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "## Comments")?;
+        for comment in &view.comments {
+            writeln!(writer)?;
+            writeln!(writer, "### {}", comment_header(comment))?;
+            writeln!(writer)?;
+            writeln!(writer, "{}", comment_body(comment).trim_end())?;
+            if let Some(reactions) = reaction_summary(comment) {
+                writeln!(writer)?;
+                writeln!(writer, "{reactions}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_markdown_tasks(
+    writer: &mut dyn Write,
+    heading: &str,
+    tasks: &BTreeSet<TaskId>,
+    ctx: &ShowContext<'_>,
+) -> Result<()> {
+    if tasks.is_empty() {
+        return Ok(());
+    }
+    writeln!(writer)?;
+    writeln!(writer, "## {heading}")?;
+    writeln!(writer)?;
+    for id in tasks {
+        let done = related_snapshot(ctx.related, *id).is_some_and(|t| t.state_kind == Some(StateKind::Done));
+        let check = if done { "x" } else { " " };
+        writeln!(writer, "- [{check}] {}", markdown_task_ref(*id, ctx))?;
+    }
+    Ok(())
+}
+
+fn markdown_task_ref(id: TaskId, ctx: &ShowContext<'_>) -> String {
+    let abbr = ctx.ids.abbreviate(id);
+    related_snapshot(ctx.related, id).map_or_else(
+        || format!("`{abbr}` (unknown task)"),
+        |task| format!("`{abbr}` {} ({})", task.title, state_label(task, ctx.workflow)),
+    )
+}
+
+/// State label followed by its kind, e.g. `In progress (in_progress)`.
+fn state_text(task: &TaskSnapshot, workflow: &WorkflowConfig) -> String {
+    let label = state_label(task, workflow);
+    task.state_kind
+        .map_or_else(|| label.to_owned(), |kind| format!("{label} ({})", kind.as_str()))
+}
+
+/// Configured label of the task's state, or `-` when it has none.
+fn state_label<'a>(task: &'a TaskSnapshot, workflow: &'a WorkflowConfig) -> &'a str {
+    task.state
+        .as_deref()
+        .map_or("-", |state| workflow.display_label(Some(state)))
+}
+
+/// Done share of the direct subtasks, e.g. `2/5 subtasks done (40%)`.
+fn checklist_text(task: &TaskSnapshot) -> String {
+    format!("{}/{} items done", task.checklist.done, task.checklist.total)
+}
+
+fn rollup_progress(rollup: &TaskRollup) -> String {
+    format!(
+        "{}/{} subtasks done ({}%)",
+        rollup.children.done, rollup.children.total, rollup.percent_done
+    )
+}
+
+fn blocked_text(blocked: &BlockedDescendant, ctx: &ShowContext<'_>) -> String {
+    let levels = if blocked.depth == 1 { "level" } else { "levels" };
+    format!(
+        "{}  {} ({} {levels} down)",
+        ctx.ids.abbreviate(blocked.task_id),
+        blocked.title,
+        blocked.depth
+    )
+}
+
+/// Milestone title with its target date, or the bare id when it could not be loaded.
+fn milestone_text(task: &TaskSnapshot, ctx: &ShowContext<'_>) -> Option<String> {
+    let id = task.milestone?;
+    Some(ctx.milestone.filter(|milestone| milestone.id == id).map_or_else(
+        || id.to_string(),
+        |milestone| {
+            milestone.due.as_ref().map_or_else(
+                || milestone.title.clone(),
+                |due| format!("{} (due {due})", milestone.title),
+            )
+        },
+    ))
+}
+
+/// Titles from the root ancestor down to the direct parent, following first parents.
+fn ancestor_titles<'a>(task: &TaskSnapshot, related: &'a TaskCache) -> Vec<&'a str> {
+    let mut chain = Vec::new();
+    let mut visited = HashSet::from([task.id]);
+    let mut next = task.parents.first().copied();
+    while let Some(id) = next
+        && visited.insert(id)
+        && let Some(parent) = related_snapshot(related, id)
+    {
+        chain.push(parent.title.as_str());
+        next = parent.parents.first().copied();
+    }
+    chain.reverse();
+    chain
+}
+
+/// Non-empty relation buckets, built-in kinds first and custom kinds after.
+fn relation_buckets(task: &TaskSnapshot) -> Vec<(&str, &BTreeSet<TaskId>)> {
+    let known = RelationKind::ALL.iter().map(|kind| kind.as_str());
+    let custom = task
+        .relates
+        .keys()
+        .map(String::as_str)
+        .filter(|key| key.parse::<RelationKind>().is_err());
+    known
+        .chain(custom)
+        .filter_map(|key| {
+            let targets = task.relates.get(key).filter(|targets| !targets.is_empty())?;
+            Some((key, targets))
+        })
+        .collect()
+}
+
+fn related_snapshot(related: &TaskCache, id: TaskId) -> Option<&TaskSnapshot> {
+    related
+        .task_index
+        .get(&id)
+        .and_then(|&idx| related.tasks.get(idx))
+        .map(|view| &view.snapshot)
+}
+
+fn comment_header(comment: &TaskComment) -> String {
+    let edited = comment
+        .updated_at
+        .map(|ts| format!(" (edited {})", format_timestamp(ts)))
+        .unwrap_or_default();
+    format!(
+        "{} · {} · {}{edited}",
+        format_actor(&comment.actor),
+        format_timestamp(comment.created_at),
+        comment.id
+    )
+}
+
+fn comment_body(comment: &TaskComment) -> &str {
+    if comment.deleted {
+        "(deleted)"
+    } else {
+        &comment.body
+    }
+}
+
+fn reaction_summary(comment: &TaskComment) -> Option<String> {
+    (!comment.reactions.is_empty()).then(|| {
+        comment
+            .reactions
+            .iter()
+            .map(|(reaction, actors)| format!("{reaction} {}", actors.len()))
+            .collect::<Vec<_>>()
+            .join("  ")
+    })
+}
+
+fn join(values: &BTreeSet<String>) -> String {
+    values.iter().map(String::as_str).collect::<Vec<_>>().join(", ")
+}
+
+/// JSON shape of `show`: the snapshot fields, the subtask rollup and the comment thread.
+#[derive(Serialize)]
+struct ShowJson<'a> {
+    #[serde(flatten)]
+    snapshot: &'a TaskSnapshot,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rollup: Option<&'a TaskRollup>,
+    comments: Vec<CommentJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct CommentJson<'a> {
+    comment_id: EventId,
+    actor: &'a Actor,
+    body_md: &'a str,
+    created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deleted: bool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    reactions: &'a BTreeMap<String, BTreeSet<String>>,
+}
+
+impl<'a> ShowJson<'a> {
+    fn new(view: &'a TaskView, ctx: &ShowContext<'a>) -> Self {
+        let comments = view
+            .comments
+            .iter()
+            .map(|comment| CommentJson {
+                comment_id: comment.id,
+                actor: &comment.actor,
+                body_md: &comment.body,
+                created_at: format_timestamp(comment.created_at),
+                updated_at: comment.updated_at.map(format_timestamp),
+                deleted: comment.deleted,
+                reactions: &comment.reactions,
+            })
+            .collect();
+        Self {
+            snapshot: &view.snapshot,
+            rollup: ctx.related.rollup(view.snapshot.id),
+            comments,
+        }
+    }
+}
@@ -3,12 +3,21 @@ use git_mile_store_git::GitStore;
 
 pub fn run_push(store: &GitStore, remote: &str, force: bool) -> Result<()> {
     store.push_refs(remote, force)?;
-    println!("Successfully pushed task refs to remote '{remote}'");
+    println!("Successfully pushed task and milestone refs to remote '{remote}'");
     Ok(())
 }
 
 pub fn run_pull(store: &GitStore, remote: &str) -> Result<()> {
-    store.pull_refs(remote)?;
-    println!("Successfully pulled task refs from remote '{remote}'");
+    let summary = store.pull_refs(remote)?;
+    println!("Successfully pulled task and milestone refs from remote '{remote}'");
+    println!(
+        "  {} ahead, {} behind ({} created, {} fast-forwarded, {} merged, {} up to date)",
+        summary.ahead,
+        summary.behind,
+        summary.created,
+        summary.fast_forwarded,
+        summary.merged,
+        summary.up_to_date
+    );
     Ok(())
 }
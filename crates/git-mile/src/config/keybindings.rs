@@ -44,13 +44,16 @@ pub struct KeyBindingsConfig {
     pub tree_view: TreeViewKeyBindings,
     /// Keybindings for the state picker.
     pub state_picker: StatePickerKeyBindings,
+    /// Keybindings for the relation picker.
+    #[serde(default)]
+    pub relation_picker: RelationPickerKeyBindings,
     /// Keybindings for the comment viewer.
-    pub comment_viewer: ViewerKeyBindings,
+    pub comment_viewer: CommentViewerKeyBindings,
     /// Keybindings for the description viewer.
-    pub description_viewer: ViewerKeyBindings,
+    pub description_viewer: DescriptionViewerKeyBindings,
     /// Keybindings for the log viewer.
     #[serde(default)]
-    pub log_viewer: ViewerKeyBindings,
+    pub log_viewer: LogViewerKeyBindings,
 }
 
 /// Keybindings for the task list view.
@@ -87,6 +90,27 @@ pub struct TaskListKeyBindings {
     /// Open log viewer.
     #[serde(default = "default_open_log_viewer")]
     pub open_log_viewer: Vec<String>,
+    /// Archive or restore the selected task.
+    #[serde(default = "default_toggle_archive")]
+    pub toggle_archive: Vec<String>,
+    /// Open relation picker.
+    #[serde(default = "default_open_relation_picker")]
+    pub open_relation_picker: Vec<String>,
+    /// Switch to the next saved view.
+    #[serde(default = "default_cycle_view")]
+    pub cycle_view: Vec<String>,
+    /// Mark or unmark the selected task for bulk changes.
+    #[serde(default = "default_toggle_mark")]
+    pub toggle_mark: Vec<String>,
+    /// Revert the last change made by the current actor.
+    #[serde(default = "default_undo")]
+    pub undo: Vec<String>,
+    /// Move the selected task one place up in the manual queue.
+    #[serde(default = "default_move_task_up")]
+    pub move_task_up: Vec<String>,
+    /// Move the selected task one place down in the manual queue.
+    #[serde(default = "default_move_task_down")]
+    pub move_task_down: Vec<String>,
     /// Edit filter.
     pub edit_filter: Vec<String>,
 }
@@ -121,9 +145,48 @@ pub struct StatePickerKeyBindings {
     pub select: Vec<String>,
 }
 
-/// Keybindings for viewers (comments and description).
+/// Keybindings for the relation picker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationPickerKeyBindings {
+    /// Close relation picker.
+    pub close: Vec<String>,
+    /// Move down in the picker.
+    pub down: Vec<String>,
+    /// Move up in the picker.
+    pub up: Vec<String>,
+    /// Toggle the relation to the highlighted task.
+    pub select: Vec<String>,
+    /// Cycle through relation kinds.
+    pub next_kind: Vec<String>,
+}
+
+/// Keybindings for the comment viewer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentViewerKeyBindings {
+    /// Close viewer.
+    pub close: Vec<String>,
+    /// Scroll down.
+    pub scroll_down: Vec<String>,
+    /// Scroll up.
+    pub scroll_up: Vec<String>,
+    /// Scroll down fast (half page).
+    pub scroll_down_fast: Vec<String>,
+    /// Scroll up fast (half page).
+    pub scroll_up_fast: Vec<String>,
+    /// Select the next comment.
+    #[serde(default = "default_next_comment")]
+    pub next_comment: Vec<String>,
+    /// Delete the selected comment (press twice to confirm).
+    #[serde(default = "default_delete_comment")]
+    pub delete_comment: Vec<String>,
+    /// Add or withdraw a +1 reaction on the selected comment.
+    #[serde(default = "default_react_to_comment")]
+    pub react: Vec<String>,
+}
+
+/// Keybindings for the description viewer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ViewerKeyBindings {
+pub struct DescriptionViewerKeyBindings {
     /// Close viewer.
     pub close: Vec<String>,
     /// Scroll down.
@@ -134,12 +197,88 @@ pub struct ViewerKeyBindings {
     pub scroll_down_fast: Vec<String>,
     /// Scroll up fast (half page).
     pub scroll_up_fast: Vec<String>,
+    /// Select the next checklist item.
+    #[serde(default = "default_next_checklist_item")]
+    pub next_item: Vec<String>,
+    /// Tick or untick the selected checklist item.
+    #[serde(default = "default_toggle_checklist_item")]
+    pub toggle_item: Vec<String>,
+}
+
+/// Keybindings for the log viewer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogViewerKeyBindings {
+    /// Close viewer.
+    pub close: Vec<String>,
+    /// Scroll down.
+    pub scroll_down: Vec<String>,
+    /// Scroll up.
+    pub scroll_up: Vec<String>,
+    /// Scroll down fast (half page).
+    pub scroll_down_fast: Vec<String>,
+    /// Scroll up fast (half page).
+    pub scroll_up_fast: Vec<String>,
+    /// Highlight the event that last set the next field.
+    #[serde(default = "default_next_field")]
+    pub next_field: Vec<String>,
 }
 
 fn default_open_log_viewer() -> Vec<String> {
     vec_of_strings!["l", "L"]
 }
 
+fn default_toggle_archive() -> Vec<String> {
+    vec_of_strings!["a", "A"]
+}
+
+fn default_open_relation_picker() -> Vec<String> {
+    vec_of_strings!["b", "B"]
+}
+
+fn default_cycle_view() -> Vec<String> {
+    vec_of_strings!["w", "W"]
+}
+
+fn default_toggle_mark() -> Vec<String> {
+    vec_of_strings!["Space"]
+}
+
+fn default_undo() -> Vec<String> {
+    vec_of_strings!["u", "U"]
+}
+
+fn default_move_task_up() -> Vec<String> {
+    vec_of_strings!["Alt+k", "Shift+Up"]
+}
+
+fn default_move_task_down() -> Vec<String> {
+    vec_of_strings!["Alt+j", "Shift+Down"]
+}
+
+fn default_next_field() -> Vec<String> {
+    vec_of_strings!["Tab"]
+}
+
+fn default_next_comment() -> Vec<String> {
+    vec_of_strings!["Tab"]
+}
+
+fn default_delete_comment() -> Vec<String> {
+    vec_of_strings!["d", "Delete"]
+}
+
+fn default_react_to_comment() -> Vec<String> {
+    vec_of_strings!["r"]
+}
+
+fn default_next_checklist_item() -> Vec<String> {
+    vec_of_strings!["Tab"]
+}
+
+fn default_toggle_checklist_item() -> Vec<String> {
+    vec_of_strings!["Space", "x"]
+}
+
 impl Default for TaskListKeyBindings {
     fn default() -> Self {
         Self {
@@ -158,6 +297,13 @@ impl Default for TaskListKeyBindings {
             open_comment_viewer: vec_of_strings!["v", "V"],
             open_description_viewer: vec_of_strings!["d", "D"],
             open_log_viewer: default_open_log_viewer(),
+            toggle_archive: default_toggle_archive(),
+            open_relation_picker: default_open_relation_picker(),
+            cycle_view: default_cycle_view(),
+            toggle_mark: default_toggle_mark(),
+            undo: default_undo(),
+            move_task_up: default_move_task_up(),
+            move_task_down: default_move_task_down(),
             edit_filter: vec_of_strings!["f", "F"],
         }
     }
@@ -187,7 +333,34 @@ impl Default for StatePickerKeyBindings {
     }
 }
 
-impl Default for ViewerKeyBindings {
+impl Default for RelationPickerKeyBindings {
+    fn default() -> Self {
+        Self {
+            close: vec_of_strings!["q", "Q", "Esc"],
+            down: vec_of_strings!["j", "J", "Down"],
+            up: vec_of_strings!["k", "K", "Up"],
+            select: vec_of_strings!["Enter"],
+            next_kind: vec_of_strings!["Tab"],
+        }
+    }
+}
+
+impl Default for CommentViewerKeyBindings {
+    fn default() -> Self {
+        Self {
+            close: vec_of_strings!["q", "Q", "Esc"],
+            scroll_down: vec_of_strings!["j", "J"],
+            scroll_up: vec_of_strings!["k", "K"],
+            scroll_down_fast: vec_of_strings!["Ctrl+d"],
+            scroll_up_fast: vec_of_strings!["Ctrl+u"],
+            next_comment: default_next_comment(),
+            delete_comment: default_delete_comment(),
+            react: default_react_to_comment(),
+        }
+    }
+}
+
+impl Default for DescriptionViewerKeyBindings {
     fn default() -> Self {
         Self {
             close: vec_of_strings!["q", "Q", "Esc"],
@@ -195,6 +368,21 @@ impl Default for ViewerKeyBindings {
             scroll_up: vec_of_strings!["k", "K"],
             scroll_down_fast: vec_of_strings!["Ctrl+d"],
             scroll_up_fast: vec_of_strings!["Ctrl+u"],
+            next_item: default_next_checklist_item(),
+            toggle_item: default_toggle_checklist_item(),
+        }
+    }
+}
+
+impl Default for LogViewerKeyBindings {
+    fn default() -> Self {
+        Self {
+            close: vec_of_strings!["q", "Q", "Esc"],
+            scroll_down: vec_of_strings!["j", "J"],
+            scroll_up: vec_of_strings!["k", "K"],
+            scroll_down_fast: vec_of_strings!["Ctrl+d"],
+            scroll_up_fast: vec_of_strings!["Ctrl+u"],
+            next_field: default_next_field(),
         }
     }
 }
@@ -237,7 +425,7 @@ pub fn generate_default_config_toml() -> Result<String> {
 #
 # Supported key formats:
 # - Single characters: "j", "k", "a", "1"
-# - Special keys: "Enter", "Esc", "Tab", "Backspace", "Delete"
+# - Special keys: "Enter", "Esc", "Tab", "Space", "Backspace", "Delete"
 # - Arrow keys: "Up", "Down", "Left", "Right"
 # - Navigation keys: "Home", "End", "PageUp", "PageDown"
 # - Modified keys: "Ctrl+d", "Alt+k", "Shift+Up"
@@ -332,6 +520,7 @@ fn parse_key_code(s: &str) -> Result<KeyCode> {
         "PageUp" => Ok(KeyCode::PageUp),
         "PageDown" => Ok(KeyCode::PageDown),
         "Tab" => Ok(KeyCode::Tab),
+        "Space" => Ok(KeyCode::Char(' ')),
         "Delete" => Ok(KeyCode::Delete),
         "Insert" => Ok(KeyCode::Insert),
         s if s.len() == 1 => {
@@ -375,16 +564,26 @@ pub fn validate_keybindings_config(config: &KeyBindingsConfig) -> Result<()> {
     Ok(())
 }
 
+/// Fail when the binding list `$field` is empty.
+macro_rules! check_non_empty {
+    ($field:expr, $name:expr) => {
+        if $field.is_empty() {
+            bail!("{} must have at least one key binding", $name);
+        }
+    };
+}
+
+/// Fail on the first key in `$field` that [`parse_key`] rejects.
+macro_rules! validate_keys {
+    ($field:expr, $name:expr) => {
+        for key in $field {
+            parse_key(key).with_context(|| format!("Invalid key '{}' in {}", key, $name))?;
+        }
+    };
+}
+
 /// Validate that all keybinding fields have at least one key.
 fn validate_non_empty_bindings(config: &KeyBindingsConfig) -> Result<()> {
-    macro_rules! check_non_empty {
-        ($field:expr, $name:expr) => {
-            if $field.is_empty() {
-                bail!("{} must have at least one key binding", $name);
-            }
-        };
-    }
-
     // TaskList
     check_non_empty!(config.task_list.quit, "task_list.quit");
     check_non_empty!(config.task_list.down, "task_list.down");
@@ -407,6 +606,16 @@ fn validate_non_empty_bindings(config: &KeyBindingsConfig) -> Result<()> {
         "task_list.open_description_viewer"
     );
     check_non_empty!(config.task_list.open_log_viewer, "task_list.open_log_viewer");
+    check_non_empty!(config.task_list.toggle_archive, "task_list.toggle_archive");
+    check_non_empty!(
+        config.task_list.open_relation_picker,
+        "task_list.open_relation_picker"
+    );
+    check_non_empty!(config.task_list.cycle_view, "task_list.cycle_view");
+    check_non_empty!(config.task_list.toggle_mark, "task_list.toggle_mark");
+    check_non_empty!(config.task_list.undo, "task_list.undo");
+    check_non_empty!(config.task_list.move_task_up, "task_list.move_task_up");
+    check_non_empty!(config.task_list.move_task_down, "task_list.move_task_down");
     check_non_empty!(config.task_list.edit_filter, "task_list.edit_filter");
 
     // TreeView
@@ -423,7 +632,18 @@ fn validate_non_empty_bindings(config: &KeyBindingsConfig) -> Result<()> {
     check_non_empty!(config.state_picker.up, "state_picker.up");
     check_non_empty!(config.state_picker.select, "state_picker.select");
 
-    // Viewers
+    // RelationPicker
+    check_non_empty!(config.relation_picker.close, "relation_picker.close");
+    check_non_empty!(config.relation_picker.down, "relation_picker.down");
+    check_non_empty!(config.relation_picker.up, "relation_picker.up");
+    check_non_empty!(config.relation_picker.select, "relation_picker.select");
+    check_non_empty!(config.relation_picker.next_kind, "relation_picker.next_kind");
+
+    validate_non_empty_viewer_bindings(config)
+}
+
+/// Validate that every viewer keybinding field has at least one key.
+fn validate_non_empty_viewer_bindings(config: &KeyBindingsConfig) -> Result<()> {
     check_non_empty!(config.comment_viewer.close, "comment_viewer.close");
     check_non_empty!(config.comment_viewer.scroll_down, "comment_viewer.scroll_down");
     check_non_empty!(config.comment_viewer.scroll_up, "comment_viewer.scroll_up");
@@ -435,6 +655,12 @@ fn validate_non_empty_bindings(config: &KeyBindingsConfig) -> Result<()> {
         config.comment_viewer.scroll_up_fast,
         "comment_viewer.scroll_up_fast"
     );
+    check_non_empty!(config.comment_viewer.next_comment, "comment_viewer.next_comment");
+    check_non_empty!(
+        config.comment_viewer.delete_comment,
+        "comment_viewer.delete_comment"
+    );
+    check_non_empty!(config.comment_viewer.react, "comment_viewer.react");
 
     check_non_empty!(config.description_viewer.close, "description_viewer.close");
     check_non_empty!(
@@ -453,26 +679,27 @@ fn validate_non_empty_bindings(config: &KeyBindingsConfig) -> Result<()> {
         config.description_viewer.scroll_up_fast,
         "description_viewer.scroll_up_fast"
     );
+    check_non_empty!(
+        config.description_viewer.next_item,
+        "description_viewer.next_item"
+    );
+    check_non_empty!(
+        config.description_viewer.toggle_item,
+        "description_viewer.toggle_item"
+    );
 
     check_non_empty!(config.log_viewer.close, "log_viewer.close");
     check_non_empty!(config.log_viewer.scroll_down, "log_viewer.scroll_down");
     check_non_empty!(config.log_viewer.scroll_up, "log_viewer.scroll_up");
     check_non_empty!(config.log_viewer.scroll_down_fast, "log_viewer.scroll_down_fast");
     check_non_empty!(config.log_viewer.scroll_up_fast, "log_viewer.scroll_up_fast");
+    check_non_empty!(config.log_viewer.next_field, "log_viewer.next_field");
 
     Ok(())
 }
 
 /// Validate that all key expressions can be parsed.
 fn validate_key_expressions(config: &KeyBindingsConfig) -> Result<()> {
-    macro_rules! validate_keys {
-        ($field:expr, $name:expr) => {
-            for key in $field {
-                parse_key(key).with_context(|| format!("Invalid key '{}' in {}", key, $name))?;
-            }
-        };
-    }
-
     // TaskList
     validate_keys!(&config.task_list.quit, "task_list.quit");
     validate_keys!(&config.task_list.down, "task_list.down");
@@ -495,6 +722,16 @@ fn validate_key_expressions(config: &KeyBindingsConfig) -> Result<()> {
         "task_list.open_description_viewer"
     );
     validate_keys!(&config.task_list.open_log_viewer, "task_list.open_log_viewer");
+    validate_keys!(&config.task_list.toggle_archive, "task_list.toggle_archive");
+    validate_keys!(
+        &config.task_list.open_relation_picker,
+        "task_list.open_relation_picker"
+    );
+    validate_keys!(&config.task_list.cycle_view, "task_list.cycle_view");
+    validate_keys!(&config.task_list.toggle_mark, "task_list.toggle_mark");
+    validate_keys!(&config.task_list.undo, "task_list.undo");
+    validate_keys!(&config.task_list.move_task_up, "task_list.move_task_up");
+    validate_keys!(&config.task_list.move_task_down, "task_list.move_task_down");
     validate_keys!(&config.task_list.edit_filter, "task_list.edit_filter");
 
     // TreeView
@@ -511,7 +748,18 @@ fn validate_key_expressions(config: &KeyBindingsConfig) -> Result<()> {
     validate_keys!(&config.state_picker.up, "state_picker.up");
     validate_keys!(&config.state_picker.select, "state_picker.select");
 
-    // Viewers
+    // RelationPicker
+    validate_keys!(&config.relation_picker.close, "relation_picker.close");
+    validate_keys!(&config.relation_picker.down, "relation_picker.down");
+    validate_keys!(&config.relation_picker.up, "relation_picker.up");
+    validate_keys!(&config.relation_picker.select, "relation_picker.select");
+    validate_keys!(&config.relation_picker.next_kind, "relation_picker.next_kind");
+
+    validate_viewer_key_expressions(config)
+}
+
+/// Validate that all viewer key expressions can be parsed.
+fn validate_viewer_key_expressions(config: &KeyBindingsConfig) -> Result<()> {
     validate_keys!(&config.comment_viewer.close, "comment_viewer.close");
     validate_keys!(&config.comment_viewer.scroll_down, "comment_viewer.scroll_down");
     validate_keys!(&config.comment_viewer.scroll_up, "comment_viewer.scroll_up");
@@ -523,6 +771,12 @@ fn validate_key_expressions(config: &KeyBindingsConfig) -> Result<()> {
         &config.comment_viewer.scroll_up_fast,
         "comment_viewer.scroll_up_fast"
     );
+    validate_keys!(&config.comment_viewer.next_comment, "comment_viewer.next_comment");
+    validate_keys!(
+        &config.comment_viewer.delete_comment,
+        "comment_viewer.delete_comment"
+    );
+    validate_keys!(&config.comment_viewer.react, "comment_viewer.react");
 
     validate_keys!(&config.description_viewer.close, "description_viewer.close");
     validate_keys!(
@@ -541,12 +795,21 @@ fn validate_key_expressions(config: &KeyBindingsConfig) -> Result<()> {
         &config.description_viewer.scroll_up_fast,
         "description_viewer.scroll_up_fast"
     );
+    validate_keys!(
+        &config.description_viewer.next_item,
+        "description_viewer.next_item"
+    );
+    validate_keys!(
+        &config.description_viewer.toggle_item,
+        "description_viewer.toggle_item"
+    );
 
     validate_keys!(&config.log_viewer.close, "log_viewer.close");
     validate_keys!(&config.log_viewer.scroll_down, "log_viewer.scroll_down");
     validate_keys!(&config.log_viewer.scroll_up, "log_viewer.scroll_up");
     validate_keys!(&config.log_viewer.scroll_down_fast, "log_viewer.scroll_down_fast");
     validate_keys!(&config.log_viewer.scroll_up_fast, "log_viewer.scroll_up_fast");
+    validate_keys!(&config.log_viewer.next_field, "log_viewer.next_field");
 
     Ok(())
 }
@@ -556,6 +819,7 @@ fn validate_keybindings(config: &KeyBindingsConfig) -> Result<()> {
     validate_view_keybindings("task_list", collect_task_list_bindings(config))?;
     validate_view_keybindings("tree_view", collect_tree_view_bindings(config))?;
     validate_view_keybindings("state_picker", collect_state_picker_bindings(config))?;
+    validate_view_keybindings("relation_picker", collect_relation_picker_bindings(config))?;
     validate_view_keybindings("comment_viewer", collect_comment_viewer_bindings(config))?;
     validate_view_keybindings("description_viewer", collect_description_viewer_bindings(config))?;
     validate_view_keybindings("log_viewer", collect_log_viewer_bindings(config))?;
@@ -624,6 +888,22 @@ fn collect_task_list_bindings(config: &KeyBindingsConfig) -> HashMap<String, Vec
         "open_log_viewer".to_string(),
         config.task_list.open_log_viewer.clone(),
     );
+    bindings.insert(
+        "toggle_archive".to_string(),
+        config.task_list.toggle_archive.clone(),
+    );
+    bindings.insert(
+        "open_relation_picker".to_string(),
+        config.task_list.open_relation_picker.clone(),
+    );
+    bindings.insert("cycle_view".to_string(), config.task_list.cycle_view.clone());
+    bindings.insert("toggle_mark".to_string(), config.task_list.toggle_mark.clone());
+    bindings.insert("undo".to_string(), config.task_list.undo.clone());
+    bindings.insert("move_task_up".to_string(), config.task_list.move_task_up.clone());
+    bindings.insert(
+        "move_task_down".to_string(),
+        config.task_list.move_task_down.clone(),
+    );
     bindings.insert("edit_filter".to_string(), config.task_list.edit_filter.clone());
     bindings
 }
@@ -648,6 +928,16 @@ fn collect_state_picker_bindings(config: &KeyBindingsConfig) -> HashMap<String,
     bindings
 }
 
+fn collect_relation_picker_bindings(config: &KeyBindingsConfig) -> HashMap<String, Vec<String>> {
+    let mut bindings = HashMap::new();
+    bindings.insert("close".to_string(), config.relation_picker.close.clone());
+    bindings.insert("down".to_string(), config.relation_picker.down.clone());
+    bindings.insert("up".to_string(), config.relation_picker.up.clone());
+    bindings.insert("select".to_string(), config.relation_picker.select.clone());
+    bindings.insert("next_kind".to_string(), config.relation_picker.next_kind.clone());
+    bindings
+}
+
 fn collect_comment_viewer_bindings(config: &KeyBindingsConfig) -> HashMap<String, Vec<String>> {
     let mut bindings = HashMap::new();
     bindings.insert("close".to_string(), config.comment_viewer.close.clone());
@@ -664,6 +954,15 @@ fn collect_comment_viewer_bindings(config: &KeyBindingsConfig) -> HashMap<String
         "scroll_up_fast".to_string(),
         config.comment_viewer.scroll_up_fast.clone(),
     );
+    bindings.insert(
+        "next_comment".to_string(),
+        config.comment_viewer.next_comment.clone(),
+    );
+    bindings.insert(
+        "delete_comment".to_string(),
+        config.comment_viewer.delete_comment.clone(),
+    );
+    bindings.insert("react".to_string(), config.comment_viewer.react.clone());
     bindings
 }
 
@@ -686,6 +985,14 @@ fn collect_description_viewer_bindings(config: &KeyBindingsConfig) -> HashMap<St
         "scroll_up_fast".to_string(),
         config.description_viewer.scroll_up_fast.clone(),
     );
+    bindings.insert(
+        "next_item".to_string(),
+        config.description_viewer.next_item.clone(),
+    );
+    bindings.insert(
+        "toggle_item".to_string(),
+        config.description_viewer.toggle_item.clone(),
+    );
     bindings
 }
 
@@ -702,6 +1009,7 @@ fn collect_log_viewer_bindings(config: &KeyBindingsConfig) -> HashMap<String, Ve
         "scroll_up_fast".to_string(),
         config.log_viewer.scroll_up_fast.clone(),
     );
+    bindings.insert("next_field".to_string(), config.log_viewer.next_field.clone());
     bindings
 }
 
@@ -714,6 +1022,8 @@ pub enum ViewType {
     TreeView,
     /// State picker.
     StatePicker,
+    /// Relation picker.
+    RelationPicker,
     /// Comment viewer.
     CommentViewer,
     /// Description viewer.
@@ -760,6 +1070,20 @@ pub enum Action {
     OpenDescriptionViewer,
     /// Open log viewer.
     OpenLogViewer,
+    /// Archive or restore task.
+    ToggleArchive,
+    /// Open relation picker.
+    OpenRelationPicker,
+    /// Switch saved view.
+    CycleView,
+    /// Mark task for bulk changes.
+    ToggleMark,
+    /// Revert own last change.
+    Undo,
+    /// Move task up in the queue.
+    MoveTaskUp,
+    /// Move task down in the queue.
+    MoveTaskDown,
     /// Edit filter.
     EditFilter,
 
@@ -775,6 +1099,10 @@ pub enum Action {
     /// Select state.
     Select,
 
+    // RelationPicker specific
+    /// Cycle relation kind.
+    NextRelationKind,
+
     // Viewer specific
     /// Scroll down.
     ScrollDown,
@@ -784,6 +1112,24 @@ pub enum Action {
     ScrollDownFast,
     /// Scroll up fast.
     ScrollUpFast,
+
+    // CommentViewer specific
+    /// Select the next comment.
+    NextComment,
+    /// Delete the selected comment.
+    DeleteComment,
+    /// Toggle a reaction on the selected comment.
+    ReactToComment,
+
+    // DescriptionViewer specific
+    /// Select the next checklist item.
+    NextChecklistItem,
+    /// Tick or untick the selected checklist item.
+    ToggleChecklistItem,
+
+    // LogViewer specific
+    /// Highlight the event behind the next field.
+    NextField,
 }
 
 impl KeyBindingsConfig {
@@ -799,15 +1145,16 @@ impl KeyBindingsConfig {
             ViewType::TaskList => self.generate_task_list_help(),
             ViewType::TreeView => self.generate_tree_view_help(),
             ViewType::StatePicker => self.generate_state_picker_help(),
-            ViewType::CommentViewer | ViewType::DescriptionViewer | ViewType::LogViewer => {
-                self.generate_viewer_help(view)
-            }
+            ViewType::RelationPicker => self.generate_relation_picker_help(),
+            ViewType::CommentViewer => self.generate_comment_viewer_help(),
+            ViewType::DescriptionViewer => self.generate_description_viewer_help(),
+            ViewType::LogViewer => self.generate_log_viewer_help(),
         }
     }
 
     fn generate_task_list_help(&self) -> String {
         format!(
-            "{}:移動 {}:ツリー {}:新規 {}:子タスク {}:編集 {}:コメント {}:コメント表示 {}:説明表示 {}:ログ {}:再読込 {}:親へ {}:IDコピー {}:状態 {}:フィルタ {}:終了",
+            "{}:移動 {}:ツリー {}:新規 {}:子タスク {}:編集 {}:コメント {}:コメント表示 {}:説明表示 {}:ログ {}:アーカイブ {}:関連 {}:再読込 {}:親へ {}:IDコピー {}:状態 {}:複数選択 {}:取り消し {}:並べ替え {}:フィルタ {}:ビュー {}:終了",
             self.format_key_pair(&self.task_list.down, &self.task_list.up),
             self.format_first_key(&self.task_list.open_tree),
             self.format_first_key(&self.task_list.create_task),
@@ -817,11 +1164,17 @@ impl KeyBindingsConfig {
             self.format_first_key(&self.task_list.open_comment_viewer),
             self.format_first_key(&self.task_list.open_description_viewer),
             self.format_first_key(&self.task_list.open_log_viewer),
+            self.format_first_key(&self.task_list.toggle_archive),
+            self.format_first_key(&self.task_list.open_relation_picker),
             self.format_first_key(&self.task_list.refresh),
             self.format_first_key(&self.task_list.jump_to_parent),
             self.format_first_key(&self.task_list.copy_task_id),
             self.format_first_key(&self.task_list.open_state_picker),
+            self.format_first_key(&self.task_list.toggle_mark),
+            self.format_first_key(&self.task_list.undo),
+            self.format_key_pair(&self.task_list.move_task_down, &self.task_list.move_task_up),
             self.format_first_key(&self.task_list.edit_filter),
+            self.format_first_key(&self.task_list.cycle_view),
             self.format_first_key(&self.task_list.quit),
         )
     }
@@ -846,18 +1199,51 @@ impl KeyBindingsConfig {
         )
     }
 
-    fn generate_viewer_help(&self, view: ViewType) -> String {
-        let bindings = match view {
-            ViewType::CommentViewer => &self.comment_viewer,
-            ViewType::DescriptionViewer => &self.description_viewer,
-            ViewType::LogViewer => &self.log_viewer,
-            _ => unreachable!("viewer help called for non-viewer"),
-        };
+    fn generate_relation_picker_help(&self) -> String {
+        format!(
+            "{}:移動 {}:種類切替 {}:関連付け/解除 {}:閉じる",
+            self.format_key_pair(&self.relation_picker.down, &self.relation_picker.up),
+            self.format_first_key(&self.relation_picker.next_kind),
+            self.format_first_key(&self.relation_picker.select),
+            self.format_first_key(&self.relation_picker.close),
+        )
+    }
+
+    fn generate_comment_viewer_help(&self) -> String {
+        let bindings = &self.comment_viewer;
+        format!(
+            "{}:スクロール {}/{}:半画面スクロール {}:次のコメント {}:削除 {}:+1 {}:閉じる",
+            self.format_key_pair(&bindings.scroll_down, &bindings.scroll_up),
+            self.format_first_key(&bindings.scroll_down_fast),
+            self.format_first_key(&bindings.scroll_up_fast),
+            self.format_first_key(&bindings.next_comment),
+            self.format_first_key(&bindings.delete_comment),
+            self.format_first_key(&bindings.react),
+            self.format_first_key(&bindings.close),
+        )
+    }
+
+    fn generate_description_viewer_help(&self) -> String {
+        let bindings = &self.description_viewer;
+        format!(
+            "{}:スクロール {}/{}:半画面スクロール {}:次の項目 {}:チェック切替 {}:閉じる",
+            self.format_key_pair(&bindings.scroll_down, &bindings.scroll_up),
+            self.format_first_key(&bindings.scroll_down_fast),
+            self.format_first_key(&bindings.scroll_up_fast),
+            self.format_first_key(&bindings.next_item),
+            self.format_first_key(&bindings.toggle_item),
+            self.format_first_key(&bindings.close),
+        )
+    }
+
+    fn generate_log_viewer_help(&self) -> String {
+        let bindings = &self.log_viewer;
         format!(
-            "{}:スクロール {}/{}:半画面スクロール {}:閉じる",
+            "{}:スクロール {}/{}:半画面スクロール {}:項目の変更元 {}:閉じる",
             self.format_key_pair(&bindings.scroll_down, &bindings.scroll_up),
             self.format_first_key(&bindings.scroll_down_fast),
             self.format_first_key(&bindings.scroll_up_fast),
+            self.format_first_key(&bindings.next_field),
             self.format_first_key(&bindings.close),
         )
     }
@@ -946,6 +1332,13 @@ impl KeyBindingsConfig {
             (TaskList, OpenCommentViewer) => &self.task_list.open_comment_viewer,
             (TaskList, OpenDescriptionViewer) => &self.task_list.open_description_viewer,
             (TaskList, OpenLogViewer) => &self.task_list.open_log_viewer,
+            (TaskList, ToggleArchive) => &self.task_list.toggle_archive,
+            (TaskList, OpenRelationPicker) => &self.task_list.open_relation_picker,
+            (TaskList, CycleView) => &self.task_list.cycle_view,
+            (TaskList, ToggleMark) => &self.task_list.toggle_mark,
+            (TaskList, Undo) => &self.task_list.undo,
+            (TaskList, MoveTaskUp) => &self.task_list.move_task_up,
+            (TaskList, MoveTaskDown) => &self.task_list.move_task_down,
             (TaskList, EditFilter) => &self.task_list.edit_filter,
 
             // TreeView
@@ -962,12 +1355,22 @@ impl KeyBindingsConfig {
             (StatePicker, Up) => &self.state_picker.up,
             (StatePicker, Select) => &self.state_picker.select,
 
+            // RelationPicker
+            (RelationPicker, Close) => &self.relation_picker.close,
+            (RelationPicker, Down) => &self.relation_picker.down,
+            (RelationPicker, Up) => &self.relation_picker.up,
+            (RelationPicker, Select) => &self.relation_picker.select,
+            (RelationPicker, NextRelationKind) => &self.relation_picker.next_kind,
+
             // CommentViewer
             (CommentViewer, Close) => &self.comment_viewer.close,
             (CommentViewer, ScrollDown) => &self.comment_viewer.scroll_down,
             (CommentViewer, ScrollUp) => &self.comment_viewer.scroll_up,
             (CommentViewer, ScrollDownFast) => &self.comment_viewer.scroll_down_fast,
             (CommentViewer, ScrollUpFast) => &self.comment_viewer.scroll_up_fast,
+            (CommentViewer, NextComment) => &self.comment_viewer.next_comment,
+            (CommentViewer, DeleteComment) => &self.comment_viewer.delete_comment,
+            (CommentViewer, ReactToComment) => &self.comment_viewer.react,
 
             // DescriptionViewer
             (DescriptionViewer, Close) => &self.description_viewer.close,
@@ -975,6 +1378,8 @@ impl KeyBindingsConfig {
             (DescriptionViewer, ScrollUp) => &self.description_viewer.scroll_up,
             (DescriptionViewer, ScrollDownFast) => &self.description_viewer.scroll_down_fast,
             (DescriptionViewer, ScrollUpFast) => &self.description_viewer.scroll_up_fast,
+            (DescriptionViewer, NextChecklistItem) => &self.description_viewer.next_item,
+            (DescriptionViewer, ToggleChecklistItem) => &self.description_viewer.toggle_item,
 
             // LogViewer
             (LogViewer, Close) => &self.log_viewer.close,
@@ -982,6 +1387,7 @@ impl KeyBindingsConfig {
             (LogViewer, ScrollUp) => &self.log_viewer.scroll_up,
             (LogViewer, ScrollDownFast) => &self.log_viewer.scroll_down_fast,
             (LogViewer, ScrollUpFast) => &self.log_viewer.scroll_up_fast,
+            (LogViewer, NextField) => &self.log_viewer.next_field,
 
             // Invalid combinations
             _ => &[],
@@ -1021,6 +1427,13 @@ mod tests {
         assert_eq!(config.task_list.open_comment_viewer, vec!["v", "V"]);
         assert_eq!(config.task_list.open_description_viewer, vec!["d", "D"]);
         assert_eq!(config.task_list.open_log_viewer, vec!["l", "L"]);
+        assert_eq!(config.task_list.toggle_archive, vec!["a", "A"]);
+        assert_eq!(config.task_list.open_relation_picker, vec!["b", "B"]);
+        assert_eq!(config.task_list.cycle_view, vec!["w", "W"]);
+        assert_eq!(config.task_list.toggle_mark, vec!["Space"]);
+        assert_eq!(config.task_list.undo, vec!["u", "U"]);
+        assert_eq!(config.task_list.move_task_up, vec!["Alt+k", "Shift+Up"]);
+        assert_eq!(config.task_list.move_task_down, vec!["Alt+j", "Shift+Down"]);
         assert_eq!(config.task_list.edit_filter, vec!["f", "F"]);
 
         // TreeView のデフォルト値を確認
@@ -1037,18 +1450,30 @@ mod tests {
         assert_eq!(config.state_picker.up, vec!["k", "K", "Up"]);
         assert_eq!(config.state_picker.select, vec!["Enter"]);
 
+        // RelationPicker のデフォルト値を確認
+        assert_eq!(config.relation_picker.close, vec!["q", "Q", "Esc"]);
+        assert_eq!(config.relation_picker.select, vec!["Enter"]);
+        assert_eq!(config.relation_picker.next_kind, vec!["Tab"]);
+
         // Viewer のデフォルト値を確認
         assert_eq!(config.comment_viewer.close, vec!["q", "Q", "Esc"]);
         assert_eq!(config.comment_viewer.scroll_down, vec!["j", "J"]);
         assert_eq!(config.comment_viewer.scroll_up, vec!["k", "K"]);
         assert_eq!(config.comment_viewer.scroll_down_fast, vec!["Ctrl+d"]);
         assert_eq!(config.comment_viewer.scroll_up_fast, vec!["Ctrl+u"]);
+        assert_eq!(config.comment_viewer.next_comment, vec!["Tab"]);
+        assert_eq!(config.comment_viewer.delete_comment, vec!["d", "Delete"]);
+        assert_eq!(config.comment_viewer.react, vec!["r"]);
 
         assert_eq!(config.log_viewer.close, vec!["q", "Q", "Esc"]);
         assert_eq!(config.log_viewer.scroll_down, vec!["j", "J"]);
         assert_eq!(config.log_viewer.scroll_up, vec!["k", "K"]);
         assert_eq!(config.log_viewer.scroll_down_fast, vec!["Ctrl+d"]);
         assert_eq!(config.log_viewer.scroll_up_fast, vec!["Ctrl+u"]);
+        assert_eq!(config.log_viewer.next_field, vec!["Tab"]);
+
+        assert_eq!(config.description_viewer.next_item, vec!["Tab"]);
+        assert_eq!(config.description_viewer.toggle_item, vec!["Space", "x"]);
     }
 
     #[test]
@@ -1111,6 +1536,8 @@ mod tests {
         let config: KeyBindingsConfig = toml::from_str(toml_str).unwrap();
         assert_eq!(config.task_list.quit, vec!["q", "Q"]);
         assert_eq!(config.task_list.down, vec!["j"]);
+        assert_eq!(config.log_viewer.next_field, vec!["Tab"]);
+        assert_eq!(config.description_viewer.toggle_item, vec!["Space", "x"]);
     }
 
     #[test]
@@ -1209,6 +1636,15 @@ mod tests {
         assert!(help.contains("キャンセル"));
     }
 
+    #[test]
+    fn test_default_help_text_relation_picker() {
+        let config = KeyBindingsConfig::default();
+        let help = config.generate_help_text(ViewType::RelationPicker);
+
+        assert!(help.contains("Tab:種類切替"));
+        assert!(help.contains("関連付け/解除"));
+    }
+
     #[test]
     fn test_default_help_text_viewer() {
         let config = KeyBindingsConfig::default();
@@ -1217,6 +1653,8 @@ mod tests {
         assert!(help.contains("スクロール"));
         assert!(help.contains("半画面スクロール"));
         assert!(help.contains("閉じる"));
+        assert!(help.contains("Tab:次のコメント"));
+        assert!(help.contains("d:削除"));
     }
 
     #[test]
@@ -1334,6 +1772,7 @@ mod tests {
             "Enter",
             "Esc",
             "Tab",
+            "Space",
             "Backspace",
             "Delete",
             "Up",
@@ -1,9 +1,11 @@
 //! Shared helpers for rendering task event logs.
 
+use std::collections::HashSet;
 use std::fmt::Write;
 
+use git_mile_core::StateKind;
 use git_mile_core::event::{Actor, Event, EventKind};
-use git_mile_core::id::EventId;
+use git_mile_core::id::{EventId, TaskIdAbbreviations};
 use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 
 /// Renderable representation of a task event.
@@ -24,15 +26,38 @@ pub struct LogEntry {
 }
 
 /// Convert raw events to display-friendly entries.
+///
+/// Bodies of comments that were later deleted, or whose body was already
+/// cleared upstream, are redacted, and task ids in details are shortened with `ids`.
 #[must_use]
-pub fn entries_from_events(events: &[Event]) -> Vec<LogEntry> {
-    events.iter().map(entry_from_event).collect()
+pub fn entries_from_events(events: &[Event], ids: &TaskIdAbbreviations) -> Vec<LogEntry> {
+    let deleted: HashSet<EventId> = events
+        .iter()
+        .filter_map(|event| match &event.kind {
+            EventKind::CommentDeleted { comment_id } => Some(*comment_id),
+            _ => None,
+        })
+        .collect();
+
+    events
+        .iter()
+        .map(|event| {
+            let mut entry = entry_from_event(event, ids);
+            if let EventKind::CommentAdded { comment_id, body_md }
+            | EventKind::CommentUpdated { comment_id, body_md } = &event.kind
+                && (deleted.contains(comment_id) || body_md.is_empty())
+            {
+                entry.detail = Some("(deleted)".to_owned());
+            }
+            entry
+        })
+        .collect()
 }
 
 /// Convert a single event to a display-friendly entry.
 #[must_use]
-pub fn entry_from_event(event: &Event) -> LogEntry {
-    let (detail, description_body) = detail_for_kind(&event.kind);
+pub fn entry_from_event(event: &Event, ids: &TaskIdAbbreviations) -> LogEntry {
+    let (detail, description_body) = detail_for_kind(&event.kind, ids);
 
     LogEntry {
         id: event.id,
@@ -77,11 +102,26 @@ pub fn truncate_detail(detail: &str, max_chars: usize) -> String {
     truncated
 }
 
+/// Suffix marking a task's state kind in task listings, empty when unclassified.
+#[must_use]
+pub const fn state_kind_marker(kind: Option<StateKind>) -> &'static str {
+    match kind {
+        Some(StateKind::Done) => " ✓",
+        Some(StateKind::InProgress) => " →",
+        Some(StateKind::Blocked) => " ⊗",
+        Some(StateKind::Todo) => " □",
+        Some(StateKind::Backlog) => " ◇",
+        None => "",
+    }
+}
+
 fn action_for_kind(kind: &EventKind) -> String {
     match kind {
         EventKind::TaskCreated { .. } => "Task created",
         EventKind::TaskStateSet { .. } => "State set",
         EventKind::TaskStateCleared => "State cleared",
+        EventKind::TaskArchived => "Task archived",
+        EventKind::TaskUnarchived => "Task unarchived",
         EventKind::TaskTitleSet { .. } => "Title set",
         EventKind::TaskDescriptionSet { .. } => "Description set",
         EventKind::LabelsAdded { .. } => "Labels added",
@@ -90,15 +130,23 @@ fn action_for_kind(kind: &EventKind) -> String {
         EventKind::AssigneesRemoved { .. } => "Assignees removed",
         EventKind::CommentAdded { .. } => "Comment added",
         EventKind::CommentUpdated { .. } => "Comment updated",
+        EventKind::CommentDeleted { .. } => "Comment deleted",
+        EventKind::CommentReactionAdded { .. } => "Reaction added",
+        EventKind::CommentReactionRemoved { .. } => "Reaction removed",
         EventKind::ChildLinked { .. } => "Child linked",
         EventKind::ChildUnlinked { .. } => "Child unlinked",
         EventKind::RelationAdded { .. } => "Relation added",
         EventKind::RelationRemoved { .. } => "Relation removed",
+        EventKind::FieldSet { .. } => "Field set",
+        EventKind::FieldCleared { .. } => "Field cleared",
+        EventKind::MilestoneSet { .. } => "Milestone set",
+        EventKind::MilestoneCleared => "Milestone cleared",
+        EventKind::TaskRankSet { .. } => "Rank set",
     }
     .to_owned()
 }
 
-fn detail_for_kind(kind: &EventKind) -> (Option<String>, Option<String>) {
+fn detail_for_kind(kind: &EventKind, ids: &TaskIdAbbreviations) -> (Option<String>, Option<String>) {
     match kind {
         EventKind::TaskCreated {
             title,
@@ -132,7 +180,10 @@ fn detail_for_kind(kind: &EventKind) -> (Option<String>, Option<String>) {
             }
             (Some(line), None)
         }
-        EventKind::TaskStateCleared => (None, None),
+        EventKind::TaskStateCleared
+        | EventKind::TaskArchived
+        | EventKind::TaskUnarchived
+        | EventKind::MilestoneCleared => (None, None),
         EventKind::TaskTitleSet { title } => (Some(format!("title: {title}")), None),
         EventKind::TaskDescriptionSet { description } => (
             description
@@ -150,12 +201,29 @@ fn detail_for_kind(kind: &EventKind) -> (Option<String>, Option<String>) {
         EventKind::CommentAdded { body_md, .. } | EventKind::CommentUpdated { body_md, .. } => {
             (Some(body_md.clone()), None)
         }
+        EventKind::CommentDeleted { comment_id } => (Some(format!("comment: {comment_id}")), None),
+        EventKind::CommentReactionAdded { comment_id, reaction }
+        | EventKind::CommentReactionRemoved { comment_id, reaction } => {
+            (Some(format!("reaction: {reaction}, comment: {comment_id}")), None)
+        }
         EventKind::ChildLinked { parent, child } | EventKind::ChildUnlinked { parent, child } => {
+            let (parent, child) = (ids.abbreviate(*parent), ids.abbreviate(*child));
             (Some(format!("parent: {parent}, child: {child}")), None)
         }
-        EventKind::RelationAdded { kind, target } | EventKind::RelationRemoved { kind, target } => {
-            (Some(format!("kind: {kind}, target: {target}")), None)
-        }
+        EventKind::RelationAdded { kind, target } | EventKind::RelationRemoved { kind, target } => (
+            Some(format!("kind: {kind}, target: {}", ids.abbreviate(*target))),
+            None,
+        ),
+        EventKind::FieldSet { name, value } => (Some(format!("{name}: {value}")), None),
+        EventKind::FieldCleared { name } => (Some(format!("field: {name}")), None),
+        EventKind::MilestoneSet { milestone } => (Some(format!("milestone: {milestone}")), None),
+        EventKind::TaskRankSet { rank } => (
+            Some(
+                rank.as_deref()
+                    .map_or_else(|| "rank: cleared".to_owned(), |rank| format!("rank: {rank}")),
+            ),
+            None,
+        ),
     }
 }
 
@@ -171,7 +239,6 @@ fn join_parts(parts: Vec<String>) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use git_mile_core::StateKind;
     use git_mile_core::event::EventKind;
     use git_mile_core::id::TaskId;
 
@@ -195,7 +262,7 @@ mod tests {
             },
         );
 
-        let entry = entry_from_event(&event);
+        let entry = entry_from_event(&event, &TaskIdAbbreviations::default());
 
         assert_eq!(entry.id, event.id);
         assert_eq!(entry.action, "State set");
@@ -203,6 +270,28 @@ mod tests {
         assert_eq!(entry.actor.email, actor.email);
     }
 
+    #[test]
+    fn entries_redact_deleted_comment_bodies() {
+        let task = TaskId::new();
+        let actor = sample_actor();
+        let comment_id = EventId::new();
+        let events = vec![
+            Event::new(
+                task,
+                &actor,
+                EventKind::CommentAdded {
+                    comment_id,
+                    body_md: "api key".into(),
+                },
+            ),
+            Event::new(task, &actor, EventKind::CommentDeleted { comment_id }),
+        ];
+
+        let entries = entries_from_events(&events, &TaskIdAbbreviations::default());
+        assert_eq!(entries[0].detail.as_deref(), Some("(deleted)"));
+        assert_eq!(entries[1].action, "Comment deleted");
+    }
+
     #[test]
     fn single_line_detail_collapses_whitespace() {
         let raw = "line one\nline   two\tthree";
@@ -228,7 +317,7 @@ mod tests {
             },
         );
 
-        let entry = entry_from_event(&event);
+        let entry = entry_from_event(&event, &TaskIdAbbreviations::default());
         assert_eq!(entry.description_body.as_deref(), Some("line1\nline2"));
         assert_eq!(entry.detail.as_deref(), Some("line1\nline2"));
     }
@@ -250,8 +339,28 @@ mod tests {
             },
         );
 
-        let entry = entry_from_event(&event);
+        let entry = entry_from_event(&event, &TaskIdAbbreviations::default());
         assert_eq!(entry.description_body.as_deref(), Some("first\nsecond"));
         assert_eq!(entry.detail.as_deref(), Some("title: t"));
     }
+
+    #[test]
+    fn relation_detail_uses_abbreviated_target() {
+        let task = TaskId::new();
+        let target = TaskId::new();
+        let event = Event::new(
+            task,
+            &sample_actor(),
+            EventKind::RelationAdded {
+                kind: "blocked_by".into(),
+                target,
+            },
+        );
+
+        let ids = TaskIdAbbreviations::new([task, target]);
+        let entry = entry_from_event(&event, &ids);
+        let detail = entry.detail.unwrap_or_default();
+        assert!(detail.ends_with(&ids.abbreviate(target)), "{detail}");
+        assert!(!detail.contains(&target.to_string()), "{detail}");
+    }
 }
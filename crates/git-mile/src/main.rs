@@ -3,12 +3,13 @@
 //! CLI entry point for git-mile.
 
 use anyhow::Result;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 use std::path::Path;
 use std::sync::Arc;
 use tracing_subscriber::{EnvFilter, fmt::format::FmtSpan};
 
-use git_mile_app::{ProjectConfig, TaskService};
+use git_mile_app::{ProjectConfig, TaskService, ViewSort};
+use git_mile_core::RelationKind;
 use git_mile_store_git::GitStore;
 use rmcp::ServiceExt;
 
@@ -50,6 +51,9 @@ enum Command {
         description: Option<String>,
         #[arg(short = 'p', long = "parent")]
         parents: Vec<String>,
+        /// Custom field value as `NAME=VALUE`; repeat for several.
+        #[arg(long = "field", value_parser = parse_field_assignment)]
+        fields: Vec<(String, String)>,
         #[arg(long)]
         actor_name: Option<String>,
         #[arg(long)]
@@ -68,6 +72,218 @@ enum Command {
         actor_email: Option<String>,
     },
 
+    /// Delete a comment, hiding its body from task views.
+    DeleteComment {
+        #[arg(long)]
+        task: String,
+        #[arg(long)]
+        comment: String,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+    },
+
+    /// React to a comment (or withdraw a reaction with --remove).
+    React {
+        #[arg(long)]
+        task: String,
+        #[arg(long)]
+        comment: String,
+        /// Reaction token, e.g. an emoji or a short word.
+        #[arg(long)]
+        reaction: String,
+        #[arg(long)]
+        remove: bool,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+    },
+
+    /// Archive a task so it is hidden from default listings.
+    Archive {
+        #[arg(long)]
+        task: String,
+        /// Restore a previously archived task instead.
+        #[arg(long)]
+        undo: bool,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+    },
+
+    /// Undo one event by appending its compensating change.
+    Revert {
+        /// Event to undo, as shown by `log`.
+        event: String,
+        /// Task the event belongs to; searched across all tasks when omitted.
+        #[arg(long)]
+        task: Option<String>,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// Edit the title and description of a task, or every field at once with --editor.
+    #[command(group(
+        ArgGroup::new("fields")
+            .required(true)
+            .multiple(true)
+            .args([
+                "title", "description", "clear_description", "set_fields", "clear_fields", "milestone",
+                "clear_milestone", "editor",
+            ])
+    ))]
+    Edit {
+        #[arg(long)]
+        task: String,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long, conflicts_with = "clear_description")]
+        description: Option<String>,
+        /// Remove the description.
+        #[arg(long)]
+        clear_description: bool,
+        /// Set a custom field as `NAME=VALUE`; repeat for several.
+        #[arg(long = "field", value_parser = parse_field_assignment)]
+        set_fields: Vec<(String, String)>,
+        /// Clear a custom field; repeat for several.
+        #[arg(long = "clear-field")]
+        clear_fields: Vec<String>,
+        /// Move the task into a milestone (full id or unique prefix).
+        #[arg(long, conflicts_with = "clear_milestone")]
+        milestone: Option<String>,
+        /// Take the task out of its milestone.
+        #[arg(long)]
+        clear_milestone: bool,
+        /// Edit every field, custom fields included, in `$GIT_MILE_EDITOR` / `$VISUAL` / `$EDITOR`.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "title", "description", "clear_description", "set_fields", "clear_fields", "milestone",
+                "clear_milestone",
+            ]
+        )]
+        editor: bool,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// Set (or clear with --clear) the workflow state of a task.
+    SetState {
+        #[arg(long)]
+        task: String,
+        #[arg(long, required_unless_present = "clear", conflicts_with = "clear")]
+        state: Option<String>,
+        /// Clear the state instead of setting one.
+        #[arg(long)]
+        clear: bool,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// Add or remove labels on a task.
+    #[command(group(ArgGroup::new("changes").required(true).multiple(true).args(["add", "remove"])))]
+    Label {
+        #[arg(long)]
+        task: String,
+        /// Label to add; repeat for several.
+        #[arg(long)]
+        add: Vec<String>,
+        /// Label to remove; repeat for several.
+        #[arg(long)]
+        remove: Vec<String>,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// Add or remove assignees on a task.
+    #[command(group(ArgGroup::new("changes").required(true).multiple(true).args(["add", "remove"])))]
+    Assign {
+        #[arg(long)]
+        task: String,
+        /// Assignee to add; repeat for several.
+        #[arg(long)]
+        add: Vec<String>,
+        /// Assignee to remove; repeat for several.
+        #[arg(long)]
+        remove: Vec<String>,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// Relate a task to other tasks or parents.
+    #[command(group(ArgGroup::new("links").required(true).multiple(true).args(["targets", "parents"])))]
+    Link {
+        #[arg(long)]
+        task: String,
+        /// Relation kind: blocks, blocked-by, duplicates or relates-to.
+        #[arg(long, requires = "targets")]
+        kind: Option<RelationKind>,
+        /// Related task id; repeat to relate several tasks at once.
+        #[arg(long = "target", requires = "kind")]
+        targets: Vec<String>,
+        /// Parent task id; repeat to link several parents at once.
+        #[arg(short = 'p', long = "parent")]
+        parents: Vec<String>,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// Remove relations or parent links from a task.
+    #[command(group(ArgGroup::new("links").required(true).multiple(true).args(["targets", "parents"])))]
+    Unlink {
+        #[arg(long)]
+        task: String,
+        /// Relation kind to remove.
+        #[arg(long, requires = "targets")]
+        kind: Option<RelationKind>,
+        /// Related task id; repeat to remove several relations at once.
+        #[arg(long = "target", requires = "kind")]
+        targets: Vec<String>,
+        /// Parent task id; repeat to detach from several parents at once.
+        #[arg(short = 'p', long = "parent")]
+        parents: Vec<String>,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
     /// Show event log for a task.
     Log {
         #[arg(long)]
@@ -77,49 +293,117 @@ enum Command {
         format: LogFormat,
     },
 
-    /// Show a materialized snapshot of a task.
+    /// Show events from every task, newest last.
+    Activity {
+        /// Only events at or after this point (RFC3339, YYYY-MM-DD, or an age such as 1d).
+        #[arg(long)]
+        since: Option<String>,
+        /// Only events at or before this point (same formats as --since).
+        #[arg(long)]
+        until: Option<String>,
+        /// Only events by this actor name or email (repeatable).
+        #[arg(long = "actor")]
+        actors: Vec<String>,
+        /// Only events of this type, e.g. taskCreated or commentAdded (repeatable).
+        #[arg(long = "kind")]
+        kinds: Vec<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = LogFormat::Table)]
+        format: LogFormat,
+    },
+
+    /// Show which event last set each field of a task.
+    Blame {
+        #[arg(long)]
+        task: String,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = LogFormat::Table)]
+        format: LogFormat,
+    },
+
+    /// Show a task with its description, related tasks and comments.
     Show {
         #[arg(long)]
         task: String,
+        /// Show the task as it was at an RFC3339 timestamp or right after an event id.
+        #[arg(long)]
+        at: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = ShowFormat::Table)]
+        format: ShowFormat,
     },
 
     /// List tasks with optional filters.
     Ls {
-        /// Match specific workflow states.
-        #[arg(long = "state", short = 's')]
-        states: Vec<String>,
-        /// Require tasks to include these labels (logical AND).
-        #[arg(long = "label", short = 'l')]
-        labels: Vec<String>,
-        /// Match tasks assigned to any of these actors.
-        #[arg(long = "assignee", short = 'a')]
-        assignees: Vec<String>,
-        /// Include only these workflow state kinds.
-        #[arg(long = "state-kind")]
-        state_kinds: Vec<String>,
-        /// Exclude these workflow state kinds.
-        #[arg(long = "exclude-state-kind")]
-        exclude_state_kinds: Vec<String>,
-        /// Require tasks to include any of these parents.
-        #[arg(long = "parent")]
-        parents: Vec<String>,
-        /// Require tasks to include any of these children.
-        #[arg(long = "child")]
-        children: Vec<String>,
-        /// Match tasks updated at or after this timestamp (RFC3339).
-        #[arg(long = "updated-since")]
-        updated_since: Option<String>,
-        /// Match tasks updated at or before this timestamp (RFC3339).
-        #[arg(long = "updated-until")]
-        updated_until: Option<String>,
-        /// Case-insensitive substring matched against title/description/state/labels/assignees.
-        #[arg(long = "text")]
-        text: Option<String>,
+        #[command(flatten)]
+        filter: FilterArgs,
+        /// Use a saved view from `.git-mile/config.toml` instead of filter flags.
+        #[arg(long = "view", conflicts_with = "FilterArgs")]
+        view: Option<String>,
+        /// Order of the listed tasks; overrides the sort of `--view`.
+        #[arg(long = "sort", value_enum)]
+        sort: Option<LsSort>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = LsFormat::Table)]
+        format: LsFormat,
+    },
+
+    /// Apply one change to every task matching a filter.
+    #[command(group(
+        ArgGroup::new("changes")
+            .required(true)
+            .multiple(true)
+            .args(["set_state", "clear_state", "add_labels", "remove_labels", "add_assignees", "remove_assignees"])
+    ))]
+    Bulk {
+        #[command(flatten)]
+        filter: FilterArgs,
+        /// Select tasks with a saved view from `.git-mile/config.toml` instead of filter flags.
+        #[arg(long = "view", conflicts_with = "FilterArgs")]
+        view: Option<String>,
+        /// Workflow state to move every selected task to.
+        #[arg(long = "set-state", conflicts_with = "clear_state")]
+        set_state: Option<String>,
+        /// Clear the workflow state of every selected task.
+        #[arg(long = "clear-state")]
+        clear_state: bool,
+        /// Label to add; repeat for several.
+        #[arg(long = "add-label")]
+        add_labels: Vec<String>,
+        /// Label to remove; repeat for several.
+        #[arg(long = "remove-label")]
+        remove_labels: Vec<String>,
+        /// Assignee to add; repeat for several.
+        #[arg(long = "add-assignee")]
+        add_assignees: Vec<String>,
+        /// Assignee to remove; repeat for several.
+        #[arg(long = "remove-assignee")]
+        remove_assignees: Vec<String>,
+        /// Apply without asking for confirmation after the preview.
+        #[arg(long, short = 'y')]
+        yes: bool,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = WriteFormat::Text)]
+        format: WriteFormat,
+    },
+
+    /// List open tasks whose blockers and subtasks are all done.
+    Ready {
         /// Output format.
         #[arg(long = "format", value_enum, default_value_t = LsFormat::Table)]
         format: LsFormat,
     },
 
+    /// Manage milestones and see how far along their tasks are.
+    Milestone {
+        #[command(subcommand)]
+        subcommand: MilestoneSubcommand,
+    },
+
     /// Launch interactive terminal UI.
     Tui,
 
@@ -132,7 +416,7 @@ enum Command {
         subcommand: ConfigSubcommand,
     },
 
-    /// Push task refs to remote repository.
+    /// Push task and milestone refs to remote repository.
     Push {
         /// Remote name (defaults to "origin").
         #[arg(long, short = 'r', default_value = "origin")]
@@ -143,7 +427,7 @@ enum Command {
         force: bool,
     },
 
-    /// Pull task refs from remote repository.
+    /// Pull task and milestone refs from remote repository.
     Pull {
         /// Remote name (defaults to "origin").
         #[arg(long, short = 'r', default_value = "origin")]
@@ -151,6 +435,130 @@ enum Command {
     },
 }
 
+/// Task selection flags shared by `ls` and `bulk`.
+#[derive(Args, Debug, Default)]
+struct FilterArgs {
+    /// Match specific workflow states.
+    #[arg(long = "state", short = 's')]
+    states: Vec<String>,
+    /// Require tasks to include these labels (logical AND).
+    #[arg(long = "label", short = 'l')]
+    labels: Vec<String>,
+    /// Match tasks assigned to any of these actors.
+    #[arg(long = "assignee", short = 'a')]
+    assignees: Vec<String>,
+    /// Include only these workflow state kinds.
+    #[arg(long = "state-kind")]
+    state_kinds: Vec<String>,
+    /// Exclude these workflow state kinds.
+    #[arg(long = "exclude-state-kind")]
+    exclude_state_kinds: Vec<String>,
+    /// Require tasks to include any of these parents.
+    #[arg(long = "parent")]
+    parents: Vec<String>,
+    /// Require tasks to include any of these children.
+    #[arg(long = "child")]
+    children: Vec<String>,
+    /// Match tasks updated at or after this timestamp (RFC3339).
+    #[arg(long = "updated-since")]
+    updated_since: Option<String>,
+    /// Match tasks updated at or before this timestamp (RFC3339).
+    #[arg(long = "updated-until")]
+    updated_until: Option<String>,
+    /// Case-insensitive substring matched against title/description/state/labels/assignees.
+    #[arg(long = "text")]
+    text: Option<String>,
+    /// Query expression, e.g. `label:type/bug -state:state/done updated:<7d`.
+    #[arg(long = "query", short = 'q')]
+    query: Option<String>,
+    /// Require a declared custom field to have this value, as `NAME=VALUE` (logical AND).
+    #[arg(long = "field", value_parser = parse_field_assignment)]
+    fields: Vec<(String, String)>,
+    /// Match tasks in any of these milestones (full id or unique prefix).
+    #[arg(long = "milestone")]
+    milestones: Vec<String>,
+    /// Only match tasks whose description checklist has unticked items.
+    #[arg(long = "open-checklist")]
+    open_checklist: bool,
+    /// Include archived tasks.
+    #[arg(long = "include-archived")]
+    include_archived: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum MilestoneSubcommand {
+    /// Create a milestone.
+    New {
+        #[arg(long)]
+        title: String,
+        /// Target date (`YYYY-MM-DD`).
+        #[arg(long)]
+        due: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+    },
+
+    /// List milestones with the progress of their tasks, soonest target date first.
+    Ls {
+        /// Include closed milestones.
+        #[arg(long)]
+        all: bool,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = LsFormat::Table)]
+        format: LsFormat,
+    },
+
+    /// Show a milestone, its progress and its tasks.
+    Show {
+        /// Milestone id (full or unique prefix).
+        #[arg(long)]
+        milestone: String,
+        /// Output format.
+        #[arg(long = "format", value_enum, default_value_t = LsFormat::Table)]
+        format: LsFormat,
+    },
+
+    /// Rename, reschedule, describe, close or reopen a milestone.
+    #[command(group(
+        ArgGroup::new("changes")
+            .required(true)
+            .multiple(true)
+            .args(["title", "due", "clear_due", "description", "clear_description", "close", "reopen"])
+    ))]
+    Edit {
+        /// Milestone id (full or unique prefix).
+        #[arg(long)]
+        milestone: String,
+        #[arg(long)]
+        title: Option<String>,
+        /// Target date (`YYYY-MM-DD`).
+        #[arg(long, conflicts_with = "clear_due")]
+        due: Option<String>,
+        /// Remove the target date.
+        #[arg(long)]
+        clear_due: bool,
+        #[arg(long, conflicts_with = "clear_description")]
+        description: Option<String>,
+        /// Remove the description.
+        #[arg(long)]
+        clear_description: bool,
+        /// Close the milestone.
+        #[arg(long, conflicts_with = "reopen")]
+        close: bool,
+        /// Reopen a closed milestone.
+        #[arg(long)]
+        reopen: bool,
+        #[arg(long)]
+        actor_name: Option<String>,
+        #[arg(long)]
+        actor_email: Option<String>,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum ConfigSubcommand {
     /// Initialize keybindings configuration file with defaults.
@@ -174,6 +582,43 @@ pub(crate) enum LsFormat {
     Json,
 }
 
+/// Sort order accepted by `ls --sort`, named like the `sort` of a saved view.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub(crate) enum LsSort {
+    /// Most recently updated first.
+    Updated,
+    /// Least recently updated first.
+    UpdatedAsc,
+    /// Title, ignoring case.
+    Title,
+    /// Workflow state value.
+    State,
+    /// Manual queue order, then the remaining tasks by most recent update.
+    Rank,
+}
+
+impl From<LsSort> for ViewSort {
+    fn from(sort: LsSort) -> Self {
+        match sort {
+            LsSort::Updated => Self::Updated,
+            LsSort::UpdatedAsc => Self::UpdatedAsc,
+            LsSort::Title => Self::Title,
+            LsSort::State => Self::State,
+            LsSort::Rank => Self::Rank,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub(crate) enum WriteFormat {
+    /// Print one line per written change.
+    Text,
+    /// Emit the resulting `TaskWriteResult` as JSON.
+    Json,
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
 #[value(rename_all = "snake_case")]
 pub(crate) enum LogFormat {
@@ -183,6 +628,17 @@ pub(crate) enum LogFormat {
     Json,
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub(crate) enum ShowFormat {
+    /// Render a human-readable terminal view.
+    Table,
+    /// Emit the task snapshot and its comments as JSON.
+    Json,
+    /// Render the task as a Markdown document.
+    Markdown,
+}
+
 fn main() -> Result<()> {
     let Cli { repo, cmd } = Cli::parse();
 
@@ -198,12 +654,13 @@ fn execute_command(repo_path: &str, command: Command) -> Result<()> {
     let config = ProjectConfig::load(repo_path)?;
     let workflow = config.workflow;
     let hooks = config.hooks;
+    let views = config.views;
     let base_dir = std::path::PathBuf::from(repo_path).join(".git-mile");
 
     match (command, workflow, hooks, base_dir) {
         (Command::Tui, workflow, hooks, base_dir) => {
             let store = GitStore::open(repo_path)?;
-            tui::run(store, workflow, hooks, base_dir)
+            tui::run(store, workflow, hooks, views, base_dir)
         }
 
         (Command::Config { subcommand }, _, _, _) => match subcommand {
@@ -214,7 +671,7 @@ fn execute_command(repo_path: &str, command: Command) -> Result<()> {
 
         (Command::Mcp, workflow, hooks, base_dir) => {
             let store = GitStore::open(repo_path)?;
-            let server = mcp::GitMileServer::new(store, workflow, hooks, base_dir);
+            let server = mcp::GitMileServer::new(store, workflow, hooks, views, base_dir);
             tokio::runtime::Runtime::new()?
                 .block_on(async move {
                     let transport = (tokio::io::stdin(), tokio::io::stdout());
@@ -249,6 +706,18 @@ fn execute_command(repo_path: &str, command: Command) -> Result<()> {
     }
 }
 
+/// Parse a `NAME=VALUE` custom field assignment.
+fn parse_field_assignment(raw: &str) -> Result<(String, String), String> {
+    let (name, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{raw}'"))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing field name in '{raw}'"));
+    }
+    Ok((name.to_owned(), value.trim().to_owned()))
+}
+
 const fn should_install_tracing(cmd: &Command) -> bool {
     !matches!(cmd, Command::Mcp)
 }
@@ -339,16 +808,26 @@ mod tests {
         let cli = Cli::parse_from(["git-mile", "ls"]);
         match cli.cmd {
             Command::Ls {
-                states,
-                labels,
-                assignees,
-                state_kinds,
-                exclude_state_kinds,
-                parents,
-                children,
-                updated_since,
-                updated_until,
-                text,
+                filter:
+                    FilterArgs {
+                        states,
+                        labels,
+                        assignees,
+                        state_kinds,
+                        exclude_state_kinds,
+                        parents,
+                        children,
+                        updated_since,
+                        updated_until,
+                        text,
+                        query,
+                        fields,
+                        milestones,
+                        open_checklist,
+                        include_archived,
+                    },
+                view,
+                sort,
                 format,
             } => {
                 assert!(states.is_empty());
@@ -361,6 +840,13 @@ mod tests {
                 assert!(updated_since.is_none());
                 assert!(updated_until.is_none());
                 assert!(text.is_none());
+                assert!(query.is_none());
+                assert!(fields.is_empty());
+                assert!(milestones.is_empty());
+                assert!(!open_checklist);
+                assert!(!include_archived);
+                assert!(view.is_none());
+                assert!(sort.is_none());
                 assert_eq!(format, LsFormat::Table);
             }
             _ => panic!("expected ls command"),
@@ -382,21 +868,41 @@ mod tests {
             "alice",
             "--text",
             "fix bug",
+            "--query",
+            "label:type/bug OR updated:<7d",
+            "--field",
+            "priority=high",
+            "--milestone",
+            "0192f3a1",
+            "--open-checklist",
+            "--include-archived",
+            "--sort",
+            "rank",
             "--format",
             "json",
         ]);
         match cli.cmd {
             Command::Ls {
-                states,
-                labels,
-                assignees,
-                state_kinds,
-                exclude_state_kinds,
-                parents,
-                children,
-                updated_since,
-                updated_until,
-                text,
+                filter:
+                    FilterArgs {
+                        states,
+                        labels,
+                        assignees,
+                        state_kinds,
+                        exclude_state_kinds,
+                        parents,
+                        children,
+                        updated_since,
+                        updated_until,
+                        text,
+                        query,
+                        fields,
+                        milestones,
+                        open_checklist,
+                        include_archived,
+                    },
+                view,
+                sort,
                 format,
             } => {
                 assert_eq!(states, vec!["state/todo"]);
@@ -409,10 +915,31 @@ mod tests {
                 assert!(updated_since.is_none());
                 assert!(updated_until.is_none());
                 assert_eq!(text.as_deref(), Some("fix bug"));
+                assert_eq!(query.as_deref(), Some("label:type/bug OR updated:<7d"));
+                assert_eq!(fields, vec![("priority".to_owned(), "high".to_owned())]);
+                assert_eq!(milestones, vec!["0192f3a1"]);
+                assert!(open_checklist);
+                assert!(include_archived);
+                assert!(view.is_none());
+                assert_eq!(sort, Some(LsSort::Rank));
+                assert_eq!(format, LsFormat::Json);
+            }
+            _ => panic!("expected ls command"),
+        }
+    }
+
+    #[test]
+    fn parse_ls_command_with_view() {
+        let cli = Cli::parse_from(["git-mile", "ls", "--view", "my-open", "--format", "json"]);
+        match cli.cmd {
+            Command::Ls { view, format, .. } => {
+                assert_eq!(view.as_deref(), Some("my-open"));
                 assert_eq!(format, LsFormat::Json);
             }
             _ => panic!("expected ls command"),
         }
+
+        assert!(Cli::try_parse_from(["git-mile", "ls", "--view", "my-open", "--label", "type/bug"]).is_err());
     }
 
     #[test]
@@ -435,12 +962,16 @@ mod tests {
         ]);
         match cli.cmd {
             Command::Ls {
-                state_kinds,
-                exclude_state_kinds,
-                parents,
-                children,
-                updated_since,
-                updated_until,
+                filter:
+                    FilterArgs {
+                        state_kinds,
+                        exclude_state_kinds,
+                        parents,
+                        children,
+                        updated_since,
+                        updated_until,
+                        ..
+                    },
                 ..
             } => {
                 assert_eq!(state_kinds, vec!["todo"]);
@@ -454,6 +985,348 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_react_command() {
+        let cli = Cli::parse_from([
+            "git-mile",
+            "react",
+            "--task",
+            "00000000-0000-0000-0000-000000000001",
+            "--comment",
+            "00000000-0000-0000-0000-000000000002",
+            "--reaction",
+            "+1",
+            "--remove",
+        ]);
+        match cli.cmd {
+            Command::React {
+                task,
+                comment,
+                reaction,
+                remove,
+                ..
+            } => {
+                assert_eq!(task, "00000000-0000-0000-0000-000000000001");
+                assert_eq!(comment, "00000000-0000-0000-0000-000000000002");
+                assert_eq!(reaction, "+1");
+                assert!(remove);
+            }
+            _ => panic!("expected react command"),
+        }
+    }
+
+    #[test]
+    fn parse_archive_command() {
+        let cli = Cli::parse_from([
+            "git-mile",
+            "archive",
+            "--task",
+            "00000000-0000-0000-0000-000000000001",
+            "--undo",
+        ]);
+        match cli.cmd {
+            Command::Archive {
+                task,
+                undo,
+                actor_name,
+                actor_email,
+            } => {
+                assert_eq!(task, "00000000-0000-0000-0000-000000000001");
+                assert!(undo);
+                assert!(actor_name.is_none());
+                assert!(actor_email.is_none());
+            }
+            _ => panic!("expected archive command"),
+        }
+    }
+
+    #[test]
+    fn parse_link_command() {
+        let cli = Cli::parse_from([
+            "git-mile",
+            "link",
+            "--task",
+            "00000000-0000-0000-0000-000000000001",
+            "--kind",
+            "blocked_by",
+            "--target",
+            "00000000-0000-0000-0000-000000000002",
+            "--target",
+            "00000000-0000-0000-0000-000000000003",
+        ]);
+        match cli.cmd {
+            Command::Link {
+                task, kind, targets, ..
+            } => {
+                assert_eq!(task, "00000000-0000-0000-0000-000000000001");
+                assert_eq!(kind, Some(RelationKind::BlockedBy));
+                assert_eq!(targets.len(), 2);
+            }
+            _ => panic!("expected link command"),
+        }
+        assert!(
+            Cli::try_parse_from([
+                "git-mile",
+                "link",
+                "--task",
+                "00000000-0000-0000-0000-000000000001",
+                "--kind",
+                "parent",
+                "--target",
+                "00000000-0000-0000-0000-000000000002",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_link_and_unlink_parents() {
+        let cli = Cli::parse_from([
+            "git-mile",
+            "unlink",
+            "--task",
+            "00000000-0000-0000-0000-000000000001",
+            "--parent",
+            "00000000-0000-0000-0000-000000000002",
+            "--format",
+            "json",
+        ]);
+        match cli.cmd {
+            Command::Unlink {
+                kind,
+                targets,
+                parents,
+                format,
+                ..
+            } => {
+                assert!(kind.is_none());
+                assert!(targets.is_empty());
+                assert_eq!(parents, vec!["00000000-0000-0000-0000-000000000002".to_string()]);
+                assert_eq!(format, WriteFormat::Json);
+            }
+            _ => panic!("expected unlink command"),
+        }
+
+        let task = "00000000-0000-0000-0000-000000000001";
+        assert!(Cli::try_parse_from(["git-mile", "link", "--task", task]).is_err());
+        assert!(Cli::try_parse_from(["git-mile", "link", "--task", task, "--kind", "blocks"]).is_err());
+        assert!(Cli::try_parse_from(["git-mile", "link", "--task", task, "--target", task]).is_err());
+        assert!(
+            Cli::try_parse_from(["git-mile", "link", "--task", task, "--parent", task, "--remove"]).is_err()
+        );
+    }
+
+    #[test]
+    fn parse_edit_command_requires_a_field() {
+        let task = "00000000-0000-0000-0000-000000000001";
+        let cli = Cli::parse_from([
+            "git-mile",
+            "edit",
+            "--task",
+            task,
+            "--title",
+            "Renamed",
+            "--clear-description",
+        ]);
+        match cli.cmd {
+            Command::Edit {
+                title,
+                description,
+                clear_description,
+                editor,
+                format,
+                ..
+            } => {
+                assert_eq!(title.as_deref(), Some("Renamed"));
+                assert!(description.is_none());
+                assert!(clear_description);
+                assert!(!editor);
+                assert_eq!(format, WriteFormat::Text);
+            }
+            _ => panic!("expected edit command"),
+        }
+
+        assert!(Cli::try_parse_from(["git-mile", "edit", "--task", task]).is_err());
+        assert!(
+            Cli::try_parse_from(["git-mile", "edit", "--task", task, "--editor", "--title", "x"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from([
+                "git-mile",
+                "edit",
+                "--task",
+                task,
+                "--description",
+                "x",
+                "--clear-description"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_custom_field_assignments() {
+        let task = "00000000-0000-0000-0000-000000000001";
+        let cli = Cli::parse_from([
+            "git-mile",
+            "edit",
+            "--task",
+            task,
+            "--field",
+            "due = 2025-03-01",
+            "--clear-field",
+            "estimate",
+        ]);
+        match cli.cmd {
+            Command::Edit {
+                set_fields,
+                clear_fields,
+                ..
+            } => {
+                assert_eq!(set_fields, vec![("due".to_owned(), "2025-03-01".to_owned())]);
+                assert_eq!(clear_fields, vec!["estimate"]);
+            }
+            _ => panic!("expected edit command"),
+        }
+
+        let cli = Cli::parse_from(["git-mile", "new", "--title", "t", "--field", "priority=high"]);
+        assert!(
+            matches!(cli.cmd, Command::New { fields, .. } if fields == [("priority".to_owned(), "high".to_owned())])
+        );
+
+        assert!(Cli::try_parse_from(["git-mile", "new", "--title", "t", "--field", "priority"]).is_err());
+        assert!(Cli::try_parse_from(["git-mile", "new", "--title", "t", "--field", "=high"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "git-mile",
+                "edit",
+                "--task",
+                task,
+                "--editor",
+                "--clear-field",
+                "due"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_set_state_label_and_assign_commands() {
+        let task = "00000000-0000-0000-0000-000000000001";
+        match Cli::parse_from(["git-mile", "set-state", "--task", task, "--clear"]).cmd {
+            Command::SetState { state, clear, .. } => {
+                assert!(state.is_none());
+                assert!(clear);
+            }
+            _ => panic!("expected set-state command"),
+        }
+        assert!(Cli::try_parse_from(["git-mile", "set-state", "--task", task]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "git-mile",
+                "set-state",
+                "--task",
+                task,
+                "--state",
+                "state/done",
+                "--clear"
+            ])
+            .is_err()
+        );
+
+        match Cli::parse_from([
+            "git-mile", "label", "--task", task, "--add", "a", "--add", "b", "--remove", "c",
+        ])
+        .cmd
+        {
+            Command::Label { add, remove, .. } => {
+                assert_eq!(add, vec!["a", "b"]);
+                assert_eq!(remove, vec!["c"]);
+            }
+            _ => panic!("expected label command"),
+        }
+        assert!(Cli::try_parse_from(["git-mile", "assign", "--task", task]).is_err());
+    }
+
+    #[test]
+    fn parse_revert_takes_event_and_optional_task() {
+        let event = "00000000-0000-0000-0000-000000000002";
+        match Cli::parse_from(["git-mile", "revert", event]).cmd {
+            Command::Revert {
+                event: parsed, task, ..
+            } => {
+                assert_eq!(parsed, event);
+                assert!(task.is_none());
+            }
+            _ => panic!("expected revert command"),
+        }
+        assert!(Cli::try_parse_from(["git-mile", "revert"]).is_err());
+    }
+
+    #[test]
+    fn parse_bulk_command_requires_a_change() {
+        match Cli::parse_from([
+            "git-mile",
+            "bulk",
+            "--label",
+            "milestone/v1",
+            "--set-state",
+            "state/done",
+            "--add-label",
+            "released",
+            "--yes",
+        ])
+        .cmd
+        {
+            Command::Bulk {
+                filter: FilterArgs { labels, .. },
+                set_state,
+                add_labels,
+                yes,
+                ..
+            } => {
+                assert_eq!(labels, vec!["milestone/v1"]);
+                assert_eq!(set_state.as_deref(), Some("state/done"));
+                assert_eq!(add_labels, vec!["released"]);
+                assert!(yes);
+            }
+            _ => panic!("expected bulk command"),
+        }
+        assert!(Cli::try_parse_from(["git-mile", "bulk", "--label", "milestone/v1"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "git-mile",
+                "bulk",
+                "--view",
+                "mine",
+                "--label",
+                "x",
+                "--clear-state"
+            ])
+            .is_err()
+        );
+        assert!(
+            Cli::try_parse_from([
+                "git-mile",
+                "bulk",
+                "--view",
+                "mine",
+                "--include-archived",
+                "--clear-state"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_ready_command() {
+        let cli = Cli::parse_from(["git-mile", "ready", "--format", "json"]);
+        match cli.cmd {
+            Command::Ready { format } => assert_eq!(format, LsFormat::Json),
+            _ => panic!("expected ready command"),
+        }
+    }
+
     #[test]
     fn parse_tui_command() {
         let cli = Cli::parse_from(["git-mile", "tui"]);
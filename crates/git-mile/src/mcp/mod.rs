@@ -5,7 +5,7 @@ mod tools;
 
 pub use params::*;
 
-use git_mile_app::{AsyncTaskRepository, HooksConfig, WorkflowConfig};
+use git_mile_app::{AsyncTaskRepository, HooksConfig, SavedView, WorkflowConfig};
 use git_mile_store_git::GitStore;
 use rmcp::handler::server::ServerHandler;
 use rmcp::handler::server::tool::{ToolCallContext, ToolRouter};
@@ -16,6 +16,7 @@ use rmcp::model::{
 };
 use rmcp::service::{RequestContext, RoleServer};
 use rmcp::{ErrorData as McpError, tool, tool_router};
+use std::collections::BTreeMap;
 use std::future::Future;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -33,6 +34,7 @@ pub struct GitMileServer {
     repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
     workflow: WorkflowConfig,
     hooks_config: HooksConfig,
+    views: Arc<BTreeMap<String, SavedView>>,
     base_dir: PathBuf,
 }
 
@@ -43,6 +45,7 @@ impl GitMileServer {
         store: GitStore,
         workflow: WorkflowConfig,
         hooks_config: HooksConfig,
+        views: BTreeMap<String, SavedView>,
         base_dir: PathBuf,
     ) -> Self {
         let store_arc = Arc::new(Mutex::new(store));
@@ -54,14 +57,17 @@ impl GitMileServer {
             repository,
             workflow,
             hooks_config,
+            views: Arc::new(views),
             base_dir,
         }
     }
 
     /// List tasks with optional filters.
-    #[tool(description = "List tasks in the repository, optionally filtered by state/label/assignee/text")]
+    #[tool(
+        description = "List tasks in the repository, optionally filtered by state/label/assignee/text or a query expression"
+    )]
     async fn list_tasks(&self, params: Parameters<ListTasksParams>) -> Result<CallToolResult, McpError> {
-        tools::list_tasks::handle_list_tasks(self.repository.clone(), params).await
+        tools::list_tasks::handle_list_tasks(self.repository.clone(), &self.workflow, params).await
     }
 
     /// List workflow states configured for this repository.
@@ -71,7 +77,9 @@ impl GitMileServer {
     }
 
     /// Fetch a single task snapshot by ID.
-    #[tool(description = "Fetch a single task snapshot by ID")]
+    #[tool(
+        description = "Fetch a single task snapshot by ID. Tasks with subtasks include a `rollup`: child counts per state kind, percent done, summed `estimate` field and the deepest blocked descendant"
+    )]
     async fn get_task(&self, params: Parameters<GetTaskParams>) -> Result<CallToolResult, McpError> {
         tools::get_task::handle_get_task(self.repository.clone(), params).await
     }
@@ -94,8 +102,44 @@ impl GitMileServer {
         tools::list_task_events::handle_list_task_events(self.repository.clone(), params).await
     }
 
+    /// List events across every task.
+    #[tool(
+        description = "List events from every task in chronological order, optionally limited by time range (since/until as RFC3339, YYYY-MM-DD or an age like 1d), actor and event type"
+    )]
+    async fn list_activity(
+        &self,
+        params: Parameters<ListActivityParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::list_activity::handle_list_activity(self.repository.clone(), params).await
+    }
+
+    /// List tasks that can be started now.
+    #[tool(
+        description = "List open tasks that are ready to start: every blocker (blocked-by/blocks relation) and every subtask is in a done state. Also reports dependency cycles as chains of task IDs"
+    )]
+    async fn list_ready_tasks(&self) -> Result<CallToolResult, McpError> {
+        tools::list_ready_tasks::handle_list_ready_tasks(self.repository.clone()).await
+    }
+
+    /// List saved views configured for this repository.
+    #[tool(
+        description = "List saved views (named filters with sort order and columns) configured under [views] in .git-mile/config.toml"
+    )]
+    async fn list_views(&self) -> Result<CallToolResult, McpError> {
+        tools::list_views::handle_list_views(&self.views).await
+    }
+
+    /// List tasks matched by a saved view.
+    #[tool(description = "List the tasks matched by a saved view, sorted as the view specifies")]
+    async fn apply_view(&self, params: Parameters<ApplyViewParams>) -> Result<CallToolResult, McpError> {
+        tools::apply_view::handle_apply_view(self.repository.clone(), &self.views, &self.workflow, params)
+            .await
+    }
+
     /// List all subtasks of a parent task.
-    #[tool(description = "List all subtasks (children) of a given parent task")]
+    #[tool(
+        description = "List all subtasks (children) of a given parent task; subtasks that have children of their own include a `rollup`"
+    )]
     async fn list_subtasks(
         &self,
         params: Parameters<ListSubtasksParams>,
@@ -121,7 +165,7 @@ impl GitMileServer {
 
     /// Update an existing task.
     #[tool(
-        description = "Update an existing task's title, description, state, labels, assignees, or parent tasks"
+        description = "Update an existing task's title, description, state, labels, assignees, custom fields, milestone, parent tasks, or relations (blocks, blocked-by, duplicates, relates-to)"
     )]
     async fn update_task(&self, params: Parameters<UpdateTaskParams>) -> Result<CallToolResult, McpError> {
         tools::update_task::handle_update_task(
@@ -135,6 +179,25 @@ impl GitMileServer {
         .await
     }
 
+    /// Apply one update to every task matching a filter.
+    #[tool(
+        description = "Apply one change (state, labels, assignees) to every task matching a list_tasks-style filter. Each task is written separately and reports its own outcome, including hook rejections. Use dry_run to preview the matched tasks first"
+    )]
+    async fn bulk_update_tasks(
+        &self,
+        params: Parameters<BulkUpdateTasksParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::bulk_update_tasks::handle_bulk_update_tasks(
+            self.store.clone(),
+            self.repository.clone(),
+            self.workflow.clone(),
+            self.hooks_config.clone(),
+            self.base_dir.clone(),
+            params,
+        )
+        .await
+    }
+
     /// Update a comment.
     #[tool(description = "Update an existing comment's body")]
     async fn update_comment(
@@ -150,6 +213,87 @@ impl GitMileServer {
         .await
     }
 
+    /// Archive or restore a task.
+    #[tool(description = "Archive a task to hide it from default listings, or restore it with unarchive")]
+    async fn archive_task(&self, params: Parameters<ArchiveTaskParams>) -> Result<CallToolResult, McpError> {
+        tools::archive_task::handle_archive_task(
+            self.store.clone(),
+            self.repository.clone(),
+            self.workflow.clone(),
+            self.hooks_config.clone(),
+            self.base_dir.clone(),
+            params,
+        )
+        .await
+    }
+
+    /// Delete a comment.
+    #[tool(description = "Delete a comment so its body is hidden from task views")]
+    async fn delete_comment(
+        &self,
+        params: Parameters<DeleteCommentParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::delete_comment::handle_delete_comment(
+            self.store.clone(),
+            self.workflow.clone(),
+            self.hooks_config.clone(),
+            self.base_dir.clone(),
+            params,
+        )
+        .await
+    }
+
+    /// Tick or untick a checklist item in a task description.
+    #[tool(
+        description = "Tick or untick one `- [ ]` checklist item in a task description by its zero-based index; returns the updated task"
+    )]
+    async fn toggle_checklist_item(
+        &self,
+        params: Parameters<ToggleChecklistItemParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::toggle_checklist_item::handle_toggle_checklist_item(
+            self.store.clone(),
+            self.repository.clone(),
+            self.workflow.clone(),
+            self.hooks_config.clone(),
+            self.base_dir.clone(),
+            params,
+        )
+        .await
+    }
+
+    /// Move a task within the manual queue.
+    #[tool(
+        description = "Move a task in the manual queue (the `rank` sort): directly before before_task_id, directly after after_task_id, or to the front when neither is given; returns the moved task"
+    )]
+    async fn reorder_task(&self, params: Parameters<ReorderTaskParams>) -> Result<CallToolResult, McpError> {
+        tools::reorder_task::handle_reorder_task(
+            self.store.clone(),
+            self.repository.clone(),
+            self.workflow.clone(),
+            self.hooks_config.clone(),
+            self.base_dir.clone(),
+            params,
+        )
+        .await
+    }
+
+    /// Add or withdraw a reaction on a comment.
+    #[tool(description = "Add a reaction to a comment, or withdraw it with remove")]
+    async fn react_to_comment(
+        &self,
+        params: Parameters<ReactToCommentParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::react_to_comment::handle_react_to_comment(
+            self.store.clone(),
+            self.workflow.clone(),
+            self.hooks_config.clone(),
+            self.base_dir.clone(),
+            params,
+        )
+        .await
+    }
+
     /// Add a comment to a task.
     #[tool(description = "Add a comment to a task")]
     async fn add_comment(&self, params: Parameters<AddCommentParams>) -> Result<CallToolResult, McpError> {
@@ -162,6 +306,51 @@ impl GitMileServer {
         )
         .await
     }
+
+    /// List milestones with progress.
+    #[tool(
+        description = "List open milestones (or all with include_closed) with progress counts of their member tasks by state kind, soonest target date first"
+    )]
+    async fn list_milestones(
+        &self,
+        params: Parameters<ListMilestonesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::list_milestones::handle_list_milestones(self.store.clone(), self.repository.clone(), params)
+            .await
+    }
+
+    /// Fetch a milestone with its member tasks.
+    #[tool(description = "Fetch a milestone by ID with its progress and member tasks")]
+    async fn get_milestone(
+        &self,
+        params: Parameters<GetMilestoneParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::get_milestone::handle_get_milestone(self.store.clone(), self.repository.clone(), params).await
+    }
+
+    /// Create a milestone.
+    #[tool(
+        description = "Create a milestone with a title, optional target date (YYYY-MM-DD) and description"
+    )]
+    async fn create_milestone(
+        &self,
+        params: Parameters<CreateMilestoneParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::create_milestone::handle_create_milestone(self.store.clone(), self.base_dir.clone(), params)
+            .await
+    }
+
+    /// Update a milestone.
+    #[tool(
+        description = "Update a milestone's title, target date or description, or close/reopen it. Assign tasks to a milestone with update_task"
+    )]
+    async fn update_milestone(
+        &self,
+        params: Parameters<UpdateMilestoneParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::update_milestone::handle_update_milestone(self.store.clone(), self.base_dir.clone(), params)
+            .await
+    }
 }
 
 impl ServerHandler for GitMileServer {
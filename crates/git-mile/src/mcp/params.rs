@@ -1,9 +1,11 @@
 //! Parameter definitions for MCP tools.
 
-use git_mile_core::StateKind;
-use git_mile_core::event::Actor;
+use git_mile_app::{BulkUpdateOutcome, FieldDefinition, MilestoneView, SavedView, TaskRollup};
+use git_mile_core::event::{Actor, Event};
+use git_mile_core::{StateKind, TaskSnapshot};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Parameters for creating a new task.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -25,6 +27,9 @@ pub struct CreateTaskParams {
     /// Parent task IDs to link this task to.
     #[serde(default)]
     pub parents: Vec<String>,
+    /// Custom field values keyed by a field name declared under `[workflow.fields]`.
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
     /// Optional actor display name provided via MCP params.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actor_name: Option<String>,
@@ -68,6 +73,24 @@ pub struct UpdateTaskParams {
     /// Parent task IDs to unlink.
     #[serde(default)]
     pub unlink_parents: Vec<String>,
+    /// Relations to add (the inverse is recorded on the target task).
+    #[serde(default)]
+    pub link_relations: Vec<RelationParam>,
+    /// Relations to remove.
+    #[serde(default)]
+    pub unlink_relations: Vec<RelationParam>,
+    /// Custom field values to set, keyed by a field name declared under `[workflow.fields]`.
+    #[serde(default)]
+    pub set_fields: BTreeMap<String, String>,
+    /// Custom fields to clear.
+    #[serde(default)]
+    pub clear_fields: Vec<String>,
+    /// Milestone ID to plan the task for.
+    #[serde(default)]
+    pub milestone_id: Option<String>,
+    /// If true, removes the task from its milestone.
+    #[serde(default)]
+    pub clear_milestone: bool,
     /// Optional actor display name provided via MCP params.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actor_name: Option<String>,
@@ -76,6 +99,15 @@ pub struct UpdateTaskParams {
     pub actor_email: Option<String>,
 }
 
+/// A typed relation to another task.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RelationParam {
+    /// Relation kind: `blocks`, `blocked-by`, `duplicates`, or `relates-to`.
+    pub kind: String,
+    /// Related task ID.
+    pub task_id: String,
+}
+
 /// Parameters for updating a comment.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateCommentParams {
@@ -93,6 +125,41 @@ pub struct UpdateCommentParams {
     pub actor_email: Option<String>,
 }
 
+/// Parameters for deleting a comment.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DeleteCommentParams {
+    /// Task ID containing the comment.
+    pub task_id: String,
+    /// Comment ID to delete.
+    pub comment_id: String,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
+/// Parameters for reacting to a comment.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReactToCommentParams {
+    /// Task ID containing the comment.
+    pub task_id: String,
+    /// Comment ID to react to.
+    pub comment_id: String,
+    /// Reaction token such as an emoji or a short word (no whitespace).
+    pub reaction: String,
+    /// Withdraw the actor's reaction instead of adding it.
+    #[serde(default)]
+    pub remove: bool,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
 /// Parameters for adding a comment.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AddCommentParams {
@@ -108,6 +175,56 @@ pub struct AddCommentParams {
     pub actor_email: Option<String>,
 }
 
+/// Parameters for archiving or restoring a task.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ArchiveTaskParams {
+    /// Task ID to archive.
+    pub task_id: String,
+    /// Restore the task instead of archiving it.
+    #[serde(default)]
+    pub unarchive: bool,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
+/// Parameters for ticking or unticking a checklist item in a task description.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ToggleChecklistItemParams {
+    /// Task ID whose description holds the checklist.
+    pub task_id: String,
+    /// Zero-based position of the `- [ ]` item among the description's checklist items.
+    pub index: usize,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
+/// Parameters for moving a task within the manual queue.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReorderTaskParams {
+    /// Task ID to move.
+    pub task_id: String,
+    /// Place the task directly before this task. Mutually exclusive with `after_task_id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_task_id: Option<String>,
+    /// Place the task directly after this task. Mutually exclusive with `before_task_id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_task_id: Option<String>,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
 /// Parameters for retrieving a single task snapshot.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetTaskParams {
@@ -115,6 +232,21 @@ pub struct GetTaskParams {
     pub task_id: String,
 }
 
+/// Parameters for applying a saved view.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ApplyViewParams {
+    /// Name of the view as declared under `[views.<name>]` in `.git-mile/config.toml`.
+    pub name: String,
+}
+
+/// Saved view entry returned by the MCP tool.
+#[derive(Debug, Serialize)]
+pub struct SavedViewEntry {
+    pub name: String,
+    #[serde(flatten)]
+    pub view: SavedView,
+}
+
 /// Parameters for listing comments on a task.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ListCommentsParams {
@@ -129,6 +261,31 @@ pub struct ListTaskEventsParams {
     pub task_id: String,
 }
 
+/// Parameters for the repository-wide activity feed.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ListActivityParams {
+    /// Only events at or after this point: RFC3339, `YYYY-MM-DD`, or an age such as `1d`.
+    #[serde(default)]
+    pub since: Option<String>,
+    /// Only events at or before this point, in the same formats as `since`.
+    #[serde(default)]
+    pub until: Option<String>,
+    /// Only events whose actor name or email matches one of these.
+    #[serde(default)]
+    pub actors: Vec<String>,
+    /// Only events of these types, e.g. `taskCreated`, `taskStateSet` or `commentAdded`.
+    #[serde(default)]
+    pub kinds: Vec<String>,
+}
+
+/// Activity feed row: the raw event plus the title of its task.
+#[derive(Debug, Serialize)]
+pub struct ActivityEntry {
+    #[serde(flatten)]
+    pub event: Event,
+    pub task_title: String,
+}
+
 /// Parameters for listing subtasks of a parent task.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ListSubtasksParams {
@@ -170,6 +327,76 @@ pub struct ListTasksParams {
     /// Case-insensitive substring search across title/description/state/labels/assignees.
     #[serde(default)]
     pub text: Option<String>,
+    /// Query expression such as `label:type/bug -state:state/done updated:<7d`.
+    /// Supports AND/OR/NOT, `-` negation, parentheses, quoted phrases and field prefixes.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Require every listed custom field to have exactly this value (case-insensitive).
+    /// Fields must be declared under `[workflow.fields]`; values are normalized like on write.
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    /// Match tasks planned for any of these milestone IDs.
+    #[serde(default)]
+    pub milestones: Vec<String>,
+    /// Only match tasks whose description checklist has unticked `- [ ]` items.
+    #[serde(default)]
+    pub open_checklist: bool,
+    /// Include archived tasks (hidden by default).
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+/// Parameters for applying one update to every task matching a filter.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BulkUpdateTasksParams {
+    /// Selects the tasks to update, with the same fields and defaults as `list_tasks`.
+    /// At least one criterion is required.
+    pub filter: ListTasksParams,
+    /// Workflow state to move every matched task to.
+    #[serde(default)]
+    pub state: Option<String>,
+    /// If true, clears the workflow state of every matched task.
+    #[serde(default)]
+    pub clear_state: bool,
+    /// Labels to add.
+    #[serde(default)]
+    pub add_labels: Vec<String>,
+    /// Labels to remove.
+    #[serde(default)]
+    pub remove_labels: Vec<String>,
+    /// Assignees to add.
+    #[serde(default)]
+    pub add_assignees: Vec<String>,
+    /// Assignees to remove.
+    #[serde(default)]
+    pub remove_assignees: Vec<String>,
+    /// Only report the matched tasks without writing anything.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
+/// Task selected by a bulk update.
+#[derive(Debug, Serialize)]
+pub struct BulkMatchedTask {
+    pub task_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// Response body for bulk updates.
+#[derive(Debug, Serialize)]
+pub struct BulkUpdateResponse {
+    pub dry_run: bool,
+    pub matched: Vec<BulkMatchedTask>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub outcomes: Vec<BulkUpdateOutcome>,
 }
 
 /// Workflow state entry returned by the MCP tool.
@@ -189,6 +416,98 @@ pub struct WorkflowStatesResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_state: Option<String>,
     pub states: Vec<WorkflowStateEntry>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, FieldDefinition>,
+}
+
+/// Parameters for listing milestones.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ListMilestonesParams {
+    /// Include closed milestones (hidden by default).
+    #[serde(default)]
+    pub include_closed: bool,
+}
+
+/// Parameters for fetching a milestone.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetMilestoneParams {
+    /// Milestone ID (full or abbreviated).
+    pub milestone_id: String,
+}
+
+/// Parameters for creating a milestone.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreateMilestoneParams {
+    /// Human-readable title.
+    pub title: String,
+    /// Optional target date (`YYYY-MM-DD`).
+    #[serde(default)]
+    pub due: Option<String>,
+    /// Optional description in Markdown.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
+/// Parameters for updating a milestone.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateMilestoneParams {
+    /// Milestone ID to update (full or abbreviated).
+    pub milestone_id: String,
+    /// New title.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// New target date (`YYYY-MM-DD`).
+    #[serde(default)]
+    pub due: Option<String>,
+    /// If true, clears the target date.
+    #[serde(default)]
+    pub clear_due: bool,
+    /// New description (an empty string clears it).
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Close (`true`) or reopen (`false`) the milestone.
+    #[serde(default)]
+    pub closed: Option<bool>,
+    /// Optional actor display name provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    /// Optional actor email provided via MCP params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_email: Option<String>,
+}
+
+/// Task snapshot with the rollup of its subtasks, if it has any.
+#[derive(Debug, Serialize)]
+pub struct TaskWithRollup {
+    #[serde(flatten)]
+    pub snapshot: TaskSnapshot,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<TaskRollup>,
+}
+
+/// Member task listed with a milestone.
+#[derive(Debug, Serialize)]
+pub struct MilestoneTaskEntry {
+    pub task_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_kind: Option<StateKind>,
+}
+
+/// Response body for a single milestone.
+#[derive(Debug, Serialize)]
+pub struct MilestoneDetail {
+    #[serde(flatten)]
+    pub view: MilestoneView,
+    pub tasks: Vec<MilestoneTaskEntry>,
 }
 
 /// Comment entry returned by the MCP tool.
@@ -200,4 +519,8 @@ pub struct TaskCommentEntry {
     pub created_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deleted: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub reactions: BTreeMap<String, BTreeSet<String>>,
 }
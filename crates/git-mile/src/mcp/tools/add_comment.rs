@@ -1,11 +1,10 @@
 //! Add comment tool implementation.
 
 use crate::mcp::params::AddCommentParams;
-use crate::mcp::tools::common::with_store;
+use crate::mcp::tools::common::{map_task_write_error, resolve_task_id, with_store};
 use git_mile_app::WorkflowConfig;
 use git_mile_app::actor_from_params_or_default;
-use git_mile_app::{CommentRequest, TaskWriteError, TaskWriter};
-use git_mile_core::id::TaskId;
+use git_mile_app::{CommentRequest, TaskWriter};
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -14,35 +13,6 @@ use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-fn map_task_write_error(err: TaskWriteError) -> McpError {
-    match err {
-        TaskWriteError::InvalidState(state) => {
-            McpError::invalid_params(format!("Invalid workflow state: {state}"), None)
-        }
-        TaskWriteError::MissingParent(parent) => {
-            McpError::invalid_params(format!("Parent task not found: {parent}"), None)
-        }
-        TaskWriteError::MissingTask(task) => {
-            McpError::invalid_params(format!("Task not found: {task}"), None)
-        }
-        TaskWriteError::Store(error) => McpError::internal_error(error.to_string(), None),
-        TaskWriteError::NotImplemented(name) => {
-            McpError::internal_error(format!("{name} not implemented"), None)
-        }
-        TaskWriteError::HookRejected {
-            hook,
-            exit_code,
-            stderr,
-        } => McpError::invalid_params(
-            format!("Hook '{hook}' rejected operation (exit code {exit_code}): {stderr}"),
-            None,
-        ),
-        TaskWriteError::HookFailed { hook, error } => {
-            McpError::internal_error(format!("Hook '{hook}' failed: {error}"), None)
-        }
-    }
-}
-
 /// Add a comment to a task.
 pub async fn handle_add_comment(
     store: Arc<Mutex<GitStore>>,
@@ -58,9 +28,7 @@ pub async fn handle_add_comment(
         actor_email,
     } = params;
 
-    let task: TaskId = task_id
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid task ID: {e}"), None))?;
+    let task = resolve_task_id(&store, &task_id, "task ID").await?;
 
     let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
     let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
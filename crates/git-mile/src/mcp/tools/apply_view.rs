@@ -0,0 +1,56 @@
+//! Apply saved view tool implementation.
+
+use std::collections::BTreeMap;
+
+use crate::mcp::params::ApplyViewParams;
+use git_mile_app::{AsyncTaskRepository, SavedView, WorkflowConfig};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+fn find_view<'a>(views: &'a BTreeMap<String, SavedView>, name: &str) -> Result<&'a SavedView, McpError> {
+    views.get(name).ok_or_else(|| {
+        let names = views.keys().map(String::as_str).collect::<Vec<_>>().join(", ");
+        McpError::invalid_params(format!("Unknown view '{name}'. Available views: [{names}]"), None)
+    })
+}
+
+/// List the tasks matched by a saved view, in the view's sort order.
+pub async fn handle_apply_view(
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    views: &BTreeMap<String, SavedView>,
+    workflow: &WorkflowConfig,
+    Parameters(params): Parameters<ApplyViewParams>,
+) -> Result<CallToolResult, McpError> {
+    let view = find_view(views, params.name.trim())?;
+
+    let mut tasks = repository
+        .list_snapshots(Some(&view.filter))
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    view.sort.sort(workflow, &mut tasks);
+
+    let json_str =
+        serde_json::to_string_pretty(&tasks).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_view_lists_available_names() {
+        let views = BTreeMap::from([("my-open".to_owned(), SavedView::default())]);
+        assert!(find_view(&views, "my-open").is_ok());
+
+        let Err(err) = find_view(&views, "missing") else {
+            panic!("unknown view should error");
+        };
+        assert!(err.message.contains("Available views: [my-open]"));
+    }
+}
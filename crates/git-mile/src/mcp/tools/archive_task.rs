@@ -0,0 +1,53 @@
+//! Archive task tool implementation.
+
+use crate::mcp::params::ArchiveTaskParams;
+use crate::mcp::tools::common::{map_task_write_error, resolve_task_id, with_store};
+use git_mile_app::TaskWriter;
+use git_mile_app::actor_from_params_or_default;
+use git_mile_app::{AsyncTaskRepository, WorkflowConfig};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Archive or restore a task.
+pub async fn handle_archive_task(
+    store: Arc<Mutex<GitStore>>,
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    workflow: WorkflowConfig,
+    hooks_config: git_mile_app::HooksConfig,
+    base_dir: std::path::PathBuf,
+    Parameters(params): Parameters<ArchiveTaskParams>,
+) -> Result<CallToolResult, McpError> {
+    let ArchiveTaskParams {
+        task_id,
+        unarchive,
+        actor_name,
+        actor_email,
+    } = params;
+
+    let task = resolve_task_id(&store, &task_id, "task ID").await?;
+
+    let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+    let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
+
+    with_store(store, move |cloned_store| {
+        TaskWriter::new(cloned_store, workflow, hooks_config, base_dir)
+            .set_archived(task, !unarchive, &actor)
+            .map_err(map_task_write_error)?;
+        Ok(())
+    })
+    .await?;
+
+    let snapshot = repository
+        .get_snapshot(task)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let json_str =
+        serde_json::to_string_pretty(&snapshot).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
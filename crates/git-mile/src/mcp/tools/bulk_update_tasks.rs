@@ -0,0 +1,105 @@
+//! Bulk update tool implementation.
+
+use crate::mcp::params::{BulkMatchedTask, BulkUpdateResponse, BulkUpdateTasksParams};
+use crate::mcp::tools::common::with_store;
+use crate::mcp::tools::list_tasks::filter_from_params;
+use git_mile_app::actor_from_params_or_default;
+use git_mile_app::{
+    AsyncTaskRepository, HooksConfig, SetDiff, StatePatch, TaskUpdate, TaskWriter, WorkflowConfig,
+};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Apply one update to every task matching a filter, reporting each task's outcome.
+pub async fn handle_bulk_update_tasks(
+    store: Arc<Mutex<GitStore>>,
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    workflow: WorkflowConfig,
+    hooks_config: HooksConfig,
+    base_dir: PathBuf,
+    Parameters(params): Parameters<BulkUpdateTasksParams>,
+) -> Result<CallToolResult, McpError> {
+    let BulkUpdateTasksParams {
+        filter,
+        state,
+        clear_state,
+        add_labels,
+        remove_labels,
+        add_assignees,
+        remove_assignees,
+        dry_run,
+        actor_name,
+        actor_email,
+    } = params;
+
+    if !filter.has_criteria() {
+        return Err(McpError::invalid_params(
+            "filter must set at least one criterion; refusing to update every task",
+            None,
+        ));
+    }
+    if let Some(state) = &state {
+        workflow
+            .validate_state(Some(state))
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
+    }
+    let update = TaskUpdate {
+        state: state.map_or_else(
+            || clear_state.then_some(StatePatch::Clear),
+            |value| Some(StatePatch::Set { state: value }),
+        ),
+        labels: SetDiff {
+            added: add_labels,
+            removed: remove_labels,
+        },
+        assignees: SetDiff {
+            added: add_assignees,
+            removed: remove_assignees,
+        },
+        ..TaskUpdate::default()
+    };
+    if update.is_empty() {
+        return Err(McpError::invalid_params("no changes requested", None));
+    }
+
+    let filter = filter_from_params(&repository, &workflow, filter).await?;
+    let tasks = repository
+        .list_snapshots(Some(&filter))
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let matched = tasks
+        .iter()
+        .map(|snapshot| BulkMatchedTask {
+            task_id: snapshot.id.to_string(),
+            title: snapshot.title.clone(),
+            state: snapshot.state.clone(),
+        })
+        .collect();
+
+    let outcomes = if dry_run || tasks.is_empty() {
+        Vec::new()
+    } else {
+        let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+        let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
+        with_store(store, move |cloned_store| {
+            let writer = TaskWriter::new(cloned_store, workflow, hooks_config, base_dir);
+            Ok(writer.bulk_update(&tasks, &update, &actor))
+        })
+        .await?
+    };
+
+    let response = BulkUpdateResponse {
+        dry_run,
+        matched,
+        outcomes,
+    };
+    let json_str =
+        serde_json::to_string_pretty(&response).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
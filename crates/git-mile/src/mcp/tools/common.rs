@@ -1,5 +1,7 @@
 //! Shared helpers for MCP tool implementations.
 
+use git_mile_app::{AsyncTaskStore, MilestoneService, TaskWriteError};
+use git_mile_core::id::{MilestoneId, TaskId, TaskIdResolveError};
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use std::sync::Arc;
@@ -20,6 +22,108 @@ where
         .map_err(|e| McpError::internal_error(format!("Task join error: {e}"), None))?
 }
 
+/// Map a [`TaskWriteError`] to the MCP error reported to the client.
+pub fn map_task_write_error(err: TaskWriteError) -> McpError {
+    match err {
+        TaskWriteError::InvalidState(state) => {
+            McpError::invalid_params(format!("Invalid workflow state: {state}"), None)
+        }
+        TaskWriteError::MissingParent(parent) => {
+            McpError::invalid_params(format!("Parent task not found: {parent}"), None)
+        }
+        TaskWriteError::MissingTask(task) => {
+            McpError::invalid_params(format!("Task not found: {task}"), None)
+        }
+        TaskWriteError::Store(error) => McpError::internal_error(error.to_string(), None),
+        TaskWriteError::NotImplemented(name) => {
+            McpError::internal_error(format!("{name} not implemented"), None)
+        }
+        TaskWriteError::HookRejected {
+            hook,
+            exit_code,
+            stderr,
+        } => McpError::invalid_params(
+            format!("Hook '{hook}' rejected operation (exit code {exit_code}): {stderr}"),
+            None,
+        ),
+        TaskWriteError::HookFailed { hook, error } => {
+            McpError::internal_error(format!("Hook '{hook}' failed: {error}"), None)
+        }
+        TaskWriteError::InvalidHookEvent { hook, reason } => {
+            McpError::internal_error(format!("Hook '{hook}' returned an invalid event: {reason}"), None)
+        }
+        TaskWriteError::MissingComment { task, comment } => {
+            McpError::invalid_params(format!("Comment {comment} not found in task {task}"), None)
+        }
+        TaskWriteError::InvalidReaction(reaction) => {
+            McpError::invalid_params(format!("Invalid reaction: '{reaction}'"), None)
+        }
+        TaskWriteError::SelfRelation(task) => {
+            McpError::invalid_params(format!("Task {task} cannot be related to itself"), None)
+        }
+        TaskWriteError::MissingEvent { task, event } => {
+            McpError::invalid_params(format!("Event {event} not found in task {task}"), None)
+        }
+        TaskWriteError::NotRevertible { event, kind } => {
+            McpError::invalid_params(format!("Event {event} ({kind}) cannot be reverted"), None)
+        }
+        TaskWriteError::RevertConflict { event, later } => McpError::invalid_params(
+            format!("Event {event} cannot be reverted: event {later} changed the same field afterwards"),
+            None,
+        ),
+        TaskWriteError::MissingChecklistItem { task, index } => {
+            McpError::invalid_params(format!("Task {task} has no checklist item {index}"), None)
+        }
+        TaskWriteError::InvalidField(violation) => McpError::invalid_params(violation.to_string(), None),
+        TaskWriteError::Transition { task, violation } => {
+            McpError::invalid_params(format!("Task {task}: {violation}"), None)
+        }
+    }
+}
+
+/// Map a task id resolution failure; malformed, unknown and ambiguous ids are the caller's fault.
+pub fn map_resolve_error(err: &anyhow::Error, context: &str) -> McpError {
+    if err.downcast_ref::<TaskIdResolveError>().is_some() {
+        McpError::invalid_params(format!("Invalid {context}: {err}"), None)
+    } else {
+        McpError::internal_error(err.to_string(), None)
+    }
+}
+
+/// Resolve a full or abbreviated task id against the task refs in the store.
+pub async fn resolve_task_id(
+    store: &Arc<Mutex<GitStore>>,
+    raw: &str,
+    context: &str,
+) -> Result<TaskId, McpError> {
+    AsyncTaskStore::resolve_task_id(store, raw)
+        .await
+        .map_err(|err| map_resolve_error(&err, context))
+}
+
+/// Resolve every id in `ids` with [`resolve_task_id`].
+pub async fn resolve_task_ids(
+    store: &Arc<Mutex<GitStore>>,
+    ids: Vec<String>,
+    context: &str,
+) -> Result<Vec<TaskId>, McpError> {
+    let mut resolved = Vec::with_capacity(ids.len());
+    for raw in ids {
+        resolved.push(resolve_task_id(store, &raw, context).await?);
+    }
+    Ok(resolved)
+}
+
+/// Resolve a full or abbreviated milestone id against the milestone refs in the store.
+pub async fn resolve_milestone_id(store: Arc<Mutex<GitStore>>, raw: String) -> Result<MilestoneId, McpError> {
+    with_store(store, move |store| {
+        MilestoneService::new(store)
+            .resolve(&raw)
+            .map_err(|err| McpError::invalid_params(format!("Invalid milestone ID: {err}"), None))
+    })
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
@@ -0,0 +1,45 @@
+//! Create milestone tool implementation.
+
+use crate::mcp::params::CreateMilestoneParams;
+use crate::mcp::tools::common::with_store;
+use git_mile_app::{CreateMilestoneInput, MilestoneService, actor_from_params_or_default};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Create a milestone.
+pub async fn handle_create_milestone(
+    store: Arc<Mutex<GitStore>>,
+    base_dir: PathBuf,
+    Parameters(params): Parameters<CreateMilestoneParams>,
+) -> Result<CallToolResult, McpError> {
+    let CreateMilestoneParams {
+        title,
+        due,
+        description,
+        actor_name,
+        actor_email,
+    } = params;
+    let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+    let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
+
+    let milestone = with_store(store, move |store| {
+        MilestoneService::new(store)
+            .create(CreateMilestoneInput {
+                title,
+                due,
+                description,
+                actor,
+            })
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))
+    })
+    .await?;
+    let json_str = serde_json::to_string_pretty(&milestone)
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
@@ -1,11 +1,10 @@
 //! Create task tool implementation.
 
 use crate::mcp::params::CreateTaskParams;
-use crate::mcp::tools::common::with_store;
+use crate::mcp::tools::common::{map_task_write_error, resolve_task_ids, with_store};
 use git_mile_app::actor_from_params_or_default;
 use git_mile_app::{AsyncTaskRepository, WorkflowConfig};
-use git_mile_app::{CreateTaskRequest, TaskWriteError, TaskWriter};
-use git_mile_core::id::TaskId;
+use git_mile_app::{CreateTaskRequest, TaskWriter};
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -14,45 +13,6 @@ use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-fn parse_task_ids(ids: Vec<String>, context: &str) -> Result<Vec<TaskId>, McpError> {
-    ids.into_iter()
-        .map(|value| {
-            value
-                .parse()
-                .map_err(|e| McpError::invalid_params(format!("Invalid {context}: {e}"), None))
-        })
-        .collect()
-}
-
-fn map_task_write_error(err: TaskWriteError) -> McpError {
-    match err {
-        TaskWriteError::InvalidState(state) => {
-            McpError::invalid_params(format!("Invalid workflow state: {state}"), None)
-        }
-        TaskWriteError::MissingParent(parent) => {
-            McpError::invalid_params(format!("Parent task not found: {parent}"), None)
-        }
-        TaskWriteError::MissingTask(task) => {
-            McpError::invalid_params(format!("Task not found: {task}"), None)
-        }
-        TaskWriteError::Store(error) => McpError::internal_error(error.to_string(), None),
-        TaskWriteError::NotImplemented(name) => {
-            McpError::internal_error(format!("{name} not implemented"), None)
-        }
-        TaskWriteError::HookRejected {
-            hook,
-            exit_code,
-            stderr,
-        } => McpError::invalid_params(
-            format!("Hook '{hook}' rejected operation (exit code {exit_code}): {stderr}"),
-            None,
-        ),
-        TaskWriteError::HookFailed { hook, error } => {
-            McpError::internal_error(format!("Hook '{hook}' failed: {error}"), None)
-        }
-    }
-}
-
 /// Create a new task with title, labels, assignees, description, state, and parent tasks.
 pub async fn handle_create_task(
     store: Arc<Mutex<GitStore>>,
@@ -69,11 +29,12 @@ pub async fn handle_create_task(
         assignees,
         description,
         parents,
+        fields,
         actor_name,
         actor_email,
     } = params;
 
-    let parents = parse_task_ids(parents, "parent task ID")?;
+    let parents = resolve_task_ids(&store, parents, "parent task ID").await?;
     let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
     let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
     let request = CreateTaskRequest {
@@ -83,6 +44,7 @@ pub async fn handle_create_task(
         assignees,
         description,
         parents,
+        fields,
         actor,
     };
 
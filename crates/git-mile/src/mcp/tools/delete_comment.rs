@@ -0,0 +1,58 @@
+//! Delete comment tool implementation.
+
+use crate::mcp::params::DeleteCommentParams;
+use crate::mcp::tools::common::{map_task_write_error, resolve_task_id, with_store};
+use git_mile_app::TaskWriter;
+use git_mile_app::WorkflowConfig;
+use git_mile_app::actor_from_params_or_default;
+use git_mile_core::id::EventId;
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Delete a comment so its body is hidden from task views.
+pub async fn handle_delete_comment(
+    store: Arc<Mutex<GitStore>>,
+    workflow: WorkflowConfig,
+    hooks_config: git_mile_app::HooksConfig,
+    base_dir: std::path::PathBuf,
+    Parameters(params): Parameters<DeleteCommentParams>,
+) -> Result<CallToolResult, McpError> {
+    let DeleteCommentParams {
+        task_id,
+        comment_id,
+        actor_name,
+        actor_email,
+    } = params;
+
+    let task = resolve_task_id(&store, &task_id, "task ID").await?;
+    let comment: EventId = comment_id
+        .parse()
+        .map_err(|e| McpError::invalid_params(format!("Invalid comment ID: {e}"), None))?;
+
+    let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+    let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
+
+    with_store(store, move |cloned_store| {
+        TaskWriter::new(cloned_store, workflow, hooks_config, base_dir)
+            .delete_comment(task, comment, &actor)
+            .map_err(map_task_write_error)?;
+        Ok(())
+    })
+    .await?;
+
+    let response = serde_json::json!({
+        "task_id": task.to_string(),
+        "comment_id": comment.to_string(),
+        "status": "deleted"
+    });
+
+    let json_str =
+        serde_json::to_string_pretty(&response).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
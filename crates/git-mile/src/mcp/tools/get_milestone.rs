@@ -0,0 +1,49 @@
+//! Get milestone tool implementation.
+
+use crate::mcp::params::{GetMilestoneParams, MilestoneDetail, MilestoneTaskEntry};
+use crate::mcp::tools::common::{resolve_milestone_id, with_store};
+use git_mile_app::{AsyncTaskRepository, MilestoneService, MilestoneView};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Fetch a milestone with its progress and member tasks.
+pub async fn handle_get_milestone(
+    store: Arc<Mutex<GitStore>>,
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    Parameters(params): Parameters<GetMilestoneParams>,
+) -> Result<CallToolResult, McpError> {
+    let id = resolve_milestone_id(store.clone(), params.milestone_id).await?;
+    let milestone = with_store(store, move |store| {
+        MilestoneService::new(store)
+            .get(id)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))
+    })
+    .await?;
+    let tasks = repository
+        .list_snapshots(None)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let members = tasks
+        .iter()
+        .filter(|task| task.milestone == Some(id) && !task.archived)
+        .map(|task| MilestoneTaskEntry {
+            task_id: task.id.to_string(),
+            title: task.title.clone(),
+            state: task.state.clone(),
+            state_kind: task.state_kind,
+        })
+        .collect();
+    let detail = MilestoneDetail {
+        view: MilestoneView::new(milestone, &tasks),
+        tasks: members,
+    };
+    let json_str =
+        serde_json::to_string_pretty(&detail).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
@@ -1,8 +1,8 @@
 //! Get task tool implementation.
 
-use crate::mcp::params::GetTaskParams;
+use crate::mcp::params::{GetTaskParams, TaskWithRollup};
+use crate::mcp::tools::common::map_resolve_error;
 use git_mile_app::AsyncTaskRepository;
-use git_mile_core::id::TaskId;
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -19,22 +19,27 @@ fn map_task_error(err: &anyhow::Error, raw_id: &str) -> McpError {
     }
 }
 
-/// Fetch a single task snapshot by ID.
+/// Fetch a single task snapshot by ID, with its subtask rollup when it has children.
 pub async fn handle_get_task(
     repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
     Parameters(params): Parameters<GetTaskParams>,
 ) -> Result<CallToolResult, McpError> {
     let task_id_raw = params.task_id.clone();
-    let task: TaskId = task_id_raw
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid task ID: {e}"), None))?;
+    let task = repository
+        .resolve_task_id(&task_id_raw)
+        .await
+        .map_err(|err| map_resolve_error(&err, "task ID"))?;
 
     let snapshot = repository
         .get_snapshot(task)
         .await
         .map_err(|err| map_task_error(&err, &task_id_raw))?;
-    let json_str =
-        serde_json::to_string_pretty(&snapshot).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let rollup = repository
+        .rollup(task)
+        .await
+        .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+    let json_str = serde_json::to_string_pretty(&TaskWithRollup { snapshot, rollup })
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     Ok(CallToolResult::success(vec![Content::text(json_str)]))
 }
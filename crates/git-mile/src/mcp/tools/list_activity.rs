@@ -0,0 +1,143 @@
+//! List activity tool implementation.
+
+use crate::mcp::params::{ActivityEntry, ListActivityParams};
+use git_mile_app::{ActivityFilter, AsyncTaskRepository, parse_time_bound};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::sync::Arc;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+
+fn parse_bound(
+    field: &str,
+    raw: Option<&str>,
+    now: OffsetDateTime,
+) -> Result<Option<OffsetDateTime>, McpError> {
+    raw.map(str::trim)
+        .filter(|raw| !raw.is_empty())
+        .map(|raw| {
+            parse_time_bound(raw, now).ok_or_else(|| {
+                McpError::invalid_params(
+                    format!("Invalid {field}: '{raw}'. Expected RFC3339, YYYY-MM-DD or an age such as 1d"),
+                    None,
+                )
+            })
+        })
+        .transpose()
+}
+
+/// List events from every task, filtered by time range, actor and event type.
+pub async fn handle_list_activity(
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    Parameters(params): Parameters<ListActivityParams>,
+) -> Result<CallToolResult, McpError> {
+    let now = OffsetDateTime::now_utc();
+    let filter = ActivityFilter {
+        since: parse_bound("since", params.since.as_deref(), now)?,
+        until: parse_bound("until", params.until.as_deref(), now)?,
+        actors: params.actors.into_iter().collect(),
+        kinds: params.kinds.into_iter().collect(),
+    };
+    filter
+        .validate()
+        .map_err(|err| McpError::invalid_params(err.to_string(), None))?;
+
+    let feed = repository
+        .activity(&filter)
+        .await
+        .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+    let entries: Vec<ActivityEntry> = feed
+        .events
+        .iter()
+        .map(|event| ActivityEntry {
+            event: event.clone(),
+            task_title: feed.title(event.task).to_owned(),
+        })
+        .collect();
+
+    let json =
+        serde_json::to_string_pretty(&entries).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json)]))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use git_mile_core::event::{Actor, Event, EventKind};
+    use git_mile_core::id::TaskId;
+    use tempfile::tempdir;
+
+    fn created(task: TaskId, actor: &Actor, title: &str) -> Event {
+        Event::new(
+            task,
+            actor,
+            EventKind::TaskCreated {
+                title: title.into(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn filters_events_across_tasks_by_actor() {
+        let dir = tempdir().expect("create temp dir");
+        git2::Repository::init(dir.path()).expect("init repo");
+        let store = GitStore::open(dir.path()).expect("open store");
+        let alice = Actor {
+            name: "alice".into(),
+            email: "alice@example.invalid".into(),
+        };
+        let bob = Actor {
+            name: "bob".into(),
+            email: "bob@example.invalid".into(),
+        };
+        store
+            .append_event(&created(TaskId::new(), &alice, "from alice"))
+            .expect("append");
+        store
+            .append_event(&created(TaskId::new(), &bob, "from bob"))
+            .expect("append");
+
+        let repository = Arc::new(AsyncTaskRepository::new(Arc::new(Mutex::new(store))));
+        let result = handle_list_activity(
+            Arc::clone(&repository),
+            Parameters(ListActivityParams {
+                since: Some("1h".into()),
+                actors: vec!["bob".into()],
+                ..ListActivityParams::default()
+            }),
+        )
+        .await
+        .expect("tool should succeed");
+
+        let content = result
+            .content
+            .first()
+            .and_then(|item| item.as_text().map(|text| text.text.clone()))
+            .expect("text content");
+        let rows: serde_json::Value = serde_json::from_str(&content).expect("parse rows");
+        assert_eq!(rows.as_array().map(Vec::len), Some(1));
+        assert_eq!(rows[0]["task_title"], "from bob");
+        assert_eq!(rows[0]["kind"]["type"], "taskCreated");
+
+        let err = handle_list_activity(
+            repository,
+            Parameters(ListActivityParams {
+                kinds: vec!["bogus".into()],
+                ..ListActivityParams::default()
+            }),
+        )
+        .await
+        .expect_err("unknown kind must fail");
+        assert!(err.message.contains("Unknown event kind"));
+    }
+}
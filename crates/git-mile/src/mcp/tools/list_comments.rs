@@ -1,8 +1,8 @@
 //! List comments tool implementation.
 
 use crate::mcp::params::{ListCommentsParams, TaskCommentEntry};
+use crate::mcp::tools::common::map_resolve_error;
 use git_mile_app::AsyncTaskRepository;
-use git_mile_core::id::TaskId;
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -32,9 +32,10 @@ pub async fn handle_list_comments(
     Parameters(params): Parameters<ListCommentsParams>,
 ) -> Result<CallToolResult, McpError> {
     let task_id_raw = params.task_id.clone();
-    let task: TaskId = task_id_raw
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid task ID: {e}"), None))?;
+    let task = repository
+        .resolve_task_id(&task_id_raw)
+        .await
+        .map_err(|err| map_resolve_error(&err, "task ID"))?;
 
     let view = repository
         .get_view(task)
@@ -51,6 +52,8 @@ pub async fn handle_list_comments(
                 body_md: comment.body.clone(),
                 created_at: format_timestamp(comment.created_at)?,
                 updated_at: comment.updated_at.map(format_timestamp).transpose()?,
+                deleted: comment.deleted,
+                reactions: comment.reactions.clone(),
             })
         })
         .collect::<Result<Vec<_>, McpError>>()?;
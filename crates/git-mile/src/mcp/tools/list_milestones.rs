@@ -0,0 +1,39 @@
+//! List milestones tool implementation.
+
+use crate::mcp::params::ListMilestonesParams;
+use crate::mcp::tools::common::with_store;
+use git_mile_app::{AsyncTaskRepository, MilestoneService, MilestoneView};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// List milestones with the progress of their member tasks.
+pub async fn handle_list_milestones(
+    store: Arc<Mutex<GitStore>>,
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    Parameters(params): Parameters<ListMilestonesParams>,
+) -> Result<CallToolResult, McpError> {
+    let milestones = with_store(store, |store| {
+        MilestoneService::new(store)
+            .list()
+            .map_err(|e| McpError::internal_error(e.to_string(), None))
+    })
+    .await?;
+    let tasks = repository
+        .list_snapshots(None)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let views: Vec<MilestoneView> = milestones
+        .into_iter()
+        .filter(|milestone| params.include_closed || !milestone.closed)
+        .map(|milestone| MilestoneView::new(milestone, &tasks))
+        .collect();
+    let json_str =
+        serde_json::to_string_pretty(&views).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
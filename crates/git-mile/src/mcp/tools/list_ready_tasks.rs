@@ -0,0 +1,23 @@
+//! List ready tasks tool implementation.
+
+use git_mile_app::AsyncTaskRepository;
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolResult, Content};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// List open tasks whose blockers and subtasks are all done, plus any dependency cycles.
+pub async fn handle_list_ready_tasks(
+    repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+) -> Result<CallToolResult, McpError> {
+    let work = repository
+        .ready_work()
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let json_str =
+        serde_json::to_string_pretty(&work).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
@@ -1,9 +1,9 @@
 //! List subtasks tool implementation.
 
-use crate::mcp::params::ListSubtasksParams;
+use crate::mcp::params::{ListSubtasksParams, TaskWithRollup};
+use crate::mcp::tools::common::map_resolve_error;
 use git_mile_app::AsyncTaskRepository;
 use git_mile_core::TaskSnapshot;
-use git_mile_core::id::TaskId;
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -36,9 +36,10 @@ pub async fn handle_list_subtasks(
     Parameters(params): Parameters<ListSubtasksParams>,
 ) -> Result<CallToolResult, McpError> {
     let parent_id_raw = params.parent_task_id.clone();
-    let parent: TaskId = parent_id_raw
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid parent task ID: {e}"), None))?;
+    let parent = repository
+        .resolve_task_id(&parent_id_raw)
+        .await
+        .map_err(|err| map_resolve_error(&err, "parent task ID"))?;
 
     // Ensure parent exists
     repository
@@ -61,9 +62,17 @@ pub async fn handle_list_subtasks(
     }
 
     subtasks.sort_by(compare_snapshots);
+    let mut entries = Vec::with_capacity(subtasks.len());
+    for snapshot in subtasks {
+        let rollup = repository
+            .rollup(snapshot.id)
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+        entries.push(TaskWithRollup { snapshot, rollup });
+    }
 
     let json_str =
-        serde_json::to_string_pretty(&subtasks).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        serde_json::to_string_pretty(&entries).map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     Ok(CallToolResult::success(vec![Content::text(json_str)]))
 }
@@ -1,8 +1,8 @@
 //! List task events tool implementation.
 
 use crate::mcp::params::ListTaskEventsParams;
+use crate::mcp::tools::common::map_resolve_error;
 use git_mile_app::AsyncTaskRepository;
-use git_mile_core::id::TaskId;
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -25,9 +25,10 @@ pub async fn handle_list_task_events(
     Parameters(params): Parameters<ListTaskEventsParams>,
 ) -> Result<CallToolResult, McpError> {
     let task_id_raw = params.task_id.clone();
-    let task: TaskId = task_id_raw
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid task ID: {e}"), None))?;
+    let task = repository
+        .resolve_task_id(&task_id_raw)
+        .await
+        .map_err(|err| map_resolve_error(&err, "task ID"))?;
 
     let events = repository
         .get_log(task)
@@ -1,10 +1,11 @@
 //! List tasks tool implementation.
 
 use crate::mcp::params::ListTasksParams;
+use crate::mcp::tools::common::map_resolve_error;
 use git_mile_app::AsyncTaskRepository;
-use git_mile_app::{FilterBuildError, TaskFilterBuilder};
+use git_mile_app::{FilterBuildError, TaskFilterBuilder, WorkflowConfig, normalize_field_filters};
 use git_mile_core::TaskFilter;
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{MilestoneId, TaskId};
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -14,6 +15,24 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 impl ListTasksParams {
+    /// Whether any selection field is set, ignoring the implicit "hide done" default.
+    pub(crate) fn has_criteria(&self) -> bool {
+        !(self.states.is_empty()
+            && self.labels.is_empty()
+            && self.assignees.is_empty()
+            && self.include_state_kinds.is_empty()
+            && self.exclude_state_kinds.is_empty()
+            && self.parents.is_empty()
+            && self.children.is_empty()
+            && self.updated_since.is_none()
+            && self.updated_until.is_none()
+            && self.fields.is_empty()
+            && self.milestones.is_empty()
+            && !self.open_checklist
+            && self.text.as_deref().is_none_or(|text| text.trim().is_empty())
+            && self.query.as_deref().is_none_or(|query| query.trim().is_empty()))
+    }
+
     pub(crate) fn into_filter(self) -> Result<TaskFilter, McpError> {
         let Self {
             states,
@@ -26,19 +45,37 @@ impl ListTasksParams {
             updated_since,
             updated_until,
             text,
+            query,
+            fields,
+            milestones,
+            open_checklist,
+            include_archived,
         } = self;
 
         let parent_ids = parse_task_ids_for_filter(parents, "parent")?;
         let child_ids = parse_task_ids_for_filter(children, "child")?;
+        let milestone_ids = milestones
+            .into_iter()
+            .map(|value| {
+                MilestoneId::from_str(value.trim())
+                    .map_err(|err| McpError::invalid_params(format!("Invalid milestone id: {err}"), None))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut builder = TaskFilterBuilder::new()
             .with_states(&states)
             .with_labels(&labels)
             .with_assignees(&assignees)
             .with_parents(&parent_ids)
-            .with_children(&child_ids);
+            .with_children(&child_ids)
+            .with_milestones(&milestone_ids);
 
-        if states.is_empty() && include_state_kinds.is_empty() && exclude_state_kinds.is_empty() {
+        // A query owns its own state clauses, so the implicit "hide done" default only applies without one.
+        if states.is_empty()
+            && include_state_kinds.is_empty()
+            && exclude_state_kinds.is_empty()
+            && query.as_deref().is_none_or(|raw| raw.trim().is_empty())
+        {
             exclude_state_kinds.push("done".to_string());
         }
 
@@ -46,9 +83,14 @@ impl ListTasksParams {
             .with_state_kinds(&include_state_kinds, &exclude_state_kinds)
             .map_err(|err| map_filter_error(&err))?;
         builder = builder.with_text(text);
+        builder = builder
+            .with_query(query.as_deref())
+            .map_err(|err| map_filter_error(&err))?;
         builder = builder
             .with_time_range(updated_since, updated_until)
             .map_err(|err| map_filter_error(&err))?;
+        builder = builder.with_fields(fields).with_open_checklist(open_checklist);
+        builder = builder.with_archived(include_archived);
 
         builder.build().map_err(|err| map_filter_error(&err))
     }
@@ -63,28 +105,55 @@ fn parse_task_ids_for_filter(ids: Vec<String>, context: &str) -> Result<Vec<Task
         .collect()
 }
 
+/// Expand abbreviated ids to their full form so [`ListTasksParams::into_filter`] can parse them.
+async fn resolve_filter_ids(
+    repository: &AsyncTaskRepository<Arc<Mutex<GitStore>>>,
+    ids: Vec<String>,
+    context: &str,
+) -> Result<Vec<String>, McpError> {
+    let mut resolved = Vec::with_capacity(ids.len());
+    for raw in ids {
+        let id = repository
+            .resolve_task_id(raw.trim())
+            .await
+            .map_err(|err| map_resolve_error(&err, context))?;
+        resolved.push(id.to_string());
+    }
+    Ok(resolved)
+}
+
 fn map_filter_error(err: &FilterBuildError) -> McpError {
     McpError::invalid_params(err.to_string(), None)
 }
 
+/// Build the filter for `params`, expanding abbreviated parent and child ids and
+/// normalizing custom field values against `workflow` first.
+pub async fn filter_from_params(
+    repository: &AsyncTaskRepository<Arc<Mutex<GitStore>>>,
+    workflow: &WorkflowConfig,
+    mut params: ListTasksParams,
+) -> Result<TaskFilter, McpError> {
+    params.fields = normalize_field_filters(workflow, params.fields)
+        .map_err(|err| map_filter_error(&err))?
+        .into_iter()
+        .collect();
+    params.parents = resolve_filter_ids(repository, params.parents, "parent id").await?;
+    params.children = resolve_filter_ids(repository, params.children, "child id").await?;
+    params.into_filter()
+}
+
 /// List tasks with optional filters.
 pub async fn handle_list_tasks(
     repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
+    workflow: &WorkflowConfig,
     Parameters(params): Parameters<ListTasksParams>,
 ) -> Result<CallToolResult, McpError> {
-    let filter = params.into_filter()?;
+    let filter = filter_from_params(&repository, workflow, params).await?;
 
-    let tasks = if filter.is_empty() {
-        repository
-            .list_snapshots(None)
-            .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?
-    } else {
-        repository
-            .list_snapshots(Some(&filter))
-            .await
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?
-    };
+    let tasks = repository
+        .list_snapshots(Some(&filter))
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let json_str =
         serde_json::to_string_pretty(&tasks).map_err(|e| McpError::internal_error(e.to_string(), None))?;
@@ -140,4 +209,48 @@ mod tests {
         assert!(filter.states.contains("state/todo"));
         assert!(filter.state_kinds.exclude.is_empty());
     }
+
+    #[test]
+    fn hides_archived_tasks_unless_requested() {
+        assert!(!build_filter(ListTasksParams::default()).include_archived);
+
+        let params = ListTasksParams {
+            include_archived: true,
+            ..Default::default()
+        };
+        assert!(build_filter(params).include_archived);
+    }
+
+    #[test]
+    fn query_replaces_default_done_exclusion() {
+        let params = ListTasksParams {
+            query: Some("kind:done label:type/bug".to_string()),
+            ..Default::default()
+        };
+        let filter = build_filter(params);
+        assert!(filter.query.is_some());
+        assert!(filter.state_kinds.is_empty());
+
+        let params = ListTasksParams {
+            query: Some("label:".to_string()),
+            ..Default::default()
+        };
+        assert!(params.into_filter().is_err());
+    }
+
+    #[test]
+    fn criteria_ignore_blank_text_and_archived_flag() {
+        assert!(!ListTasksParams::default().has_criteria());
+        let blank = ListTasksParams {
+            text: Some("  ".into()),
+            include_archived: true,
+            ..Default::default()
+        };
+        assert!(!blank.has_criteria());
+        let labelled = ListTasksParams {
+            labels: vec!["milestone/v1".into()],
+            ..Default::default()
+        };
+        assert!(labelled.has_criteria());
+    }
 }
@@ -0,0 +1,25 @@
+//! List saved views tool implementation.
+
+use std::collections::BTreeMap;
+
+use crate::mcp::params::SavedViewEntry;
+use git_mile_app::SavedView;
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolResult, Content};
+
+/// List saved views configured for this repository.
+#[allow(clippy::unused_async)]
+pub async fn handle_list_views(views: &BTreeMap<String, SavedView>) -> Result<CallToolResult, McpError> {
+    let entries: Vec<_> = views
+        .iter()
+        .map(|(name, view)| SavedViewEntry {
+            name: name.clone(),
+            view: view.clone(),
+        })
+        .collect();
+
+    let json_str =
+        serde_json::to_string_pretty(&entries).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
@@ -20,6 +20,7 @@ pub async fn handle_list_workflow_states(workflow: &WorkflowConfig) -> Result<Ca
                 kind: state.kind(),
             })
             .collect(),
+        fields: workflow.fields().clone(),
     };
 
     let json_str =
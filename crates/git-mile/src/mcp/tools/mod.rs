@@ -1,13 +1,27 @@
 //! MCP tool implementations.
 
 pub mod add_comment;
+pub mod apply_view;
+pub mod archive_task;
+pub mod bulk_update_tasks;
 pub mod common;
+pub mod create_milestone;
 pub mod create_task;
+pub mod delete_comment;
+pub mod get_milestone;
 pub mod get_task;
+pub mod list_activity;
 pub mod list_comments;
+pub mod list_milestones;
+pub mod list_ready_tasks;
 pub mod list_subtasks;
 pub mod list_task_events;
 pub mod list_tasks;
+pub mod list_views;
 pub mod list_workflow_states;
+pub mod react_to_comment;
+pub mod reorder_task;
+pub mod toggle_checklist_item;
 pub mod update_comment;
+pub mod update_milestone;
 pub mod update_task;
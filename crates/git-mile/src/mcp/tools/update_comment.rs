@@ -1,7 +1,7 @@
 //! Update comment tool implementation.
 
 use crate::mcp::params::UpdateCommentParams;
-use crate::mcp::tools::common::with_store;
+use crate::mcp::tools::common::{resolve_task_id, with_store};
 use git_mile_app::AsyncTaskRepository;
 use git_mile_app::actor_from_params_or_default;
 use git_mile_core::event::{Event, EventKind};
@@ -22,10 +22,7 @@ pub async fn handle_update_comment(
     Parameters(params): Parameters<UpdateCommentParams>,
 ) -> Result<CallToolResult, McpError> {
     // Parse task ID
-    let task: TaskId = params
-        .task_id
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid task ID: {e}"), None))?;
+    let task = resolve_task_id(&store, &params.task_id, "task ID").await?;
 
     // Parse comment ID
     let comment_id: EventId = params
@@ -39,7 +36,10 @@ pub async fn handle_update_comment(
         .await
         .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
 
-    let comment_exists = view.comments.iter().any(|comment| comment.id == comment_id);
+    let comment_exists = view
+        .comments
+        .iter()
+        .any(|comment| comment.id == comment_id && !comment.deleted);
 
     if !comment_exists {
         return Err(McpError::invalid_params(
@@ -0,0 +1,50 @@
+//! Update milestone tool implementation.
+
+use crate::mcp::params::UpdateMilestoneParams;
+use crate::mcp::tools::common::{resolve_milestone_id, with_store};
+use git_mile_app::{MilestoneService, MilestoneUpdate, actor_from_params_or_default};
+use git_mile_store_git::GitStore;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, Content};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Update a milestone's title, target date, description, or open/closed state.
+pub async fn handle_update_milestone(
+    store: Arc<Mutex<GitStore>>,
+    base_dir: PathBuf,
+    Parameters(params): Parameters<UpdateMilestoneParams>,
+) -> Result<CallToolResult, McpError> {
+    let UpdateMilestoneParams {
+        milestone_id,
+        title,
+        due,
+        clear_due,
+        description,
+        closed,
+        actor_name,
+        actor_email,
+    } = params;
+    let id = resolve_milestone_id(store.clone(), milestone_id).await?;
+    let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+    let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
+    let update = MilestoneUpdate {
+        title,
+        due: due.map(Some).or_else(|| clear_due.then_some(None)),
+        description: description.map(Some),
+        closed,
+    };
+
+    let milestone = with_store(store, move |store| {
+        MilestoneService::new(store)
+            .update(id, update, &actor)
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))
+    })
+    .await?;
+    let json_str = serde_json::to_string_pretty(&milestone)
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(json_str)]))
+}
@@ -1,10 +1,13 @@
 //! Update task tool implementation.
 
-use crate::mcp::params::UpdateTaskParams;
-use crate::mcp::tools::common::with_store;
+use crate::mcp::params::{RelationParam, UpdateTaskParams};
+use crate::mcp::tools::common::{
+    map_task_write_error, resolve_milestone_id, resolve_task_id, resolve_task_ids, with_store,
+};
 use git_mile_app::actor_from_params_or_default;
 use git_mile_app::{AsyncTaskRepository, WorkflowConfig};
-use git_mile_app::{DescriptionPatch, SetDiff, StatePatch, TaskUpdate, TaskWriteError, TaskWriter};
+use git_mile_app::{DescriptionPatch, MilestonePatch, SetDiff, StatePatch, TaskUpdate, TaskWriter};
+use git_mile_core::RelationKind;
 use git_mile_core::id::TaskId;
 use git_mile_store_git::GitStore;
 use rmcp::ErrorData as McpError;
@@ -14,46 +17,44 @@ use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-fn parse_task_ids(ids: Vec<String>, context: &str) -> Result<Vec<TaskId>, McpError> {
-    ids.into_iter()
-        .map(|value| {
-            value
-                .parse()
-                .map_err(|e| McpError::invalid_params(format!("Invalid {context}: {e}"), None))
+fn parse_relations(relations: Vec<RelationParam>) -> Result<Vec<(RelationKind, String)>, McpError> {
+    relations
+        .into_iter()
+        .map(|RelationParam { kind, task_id }| {
+            let kind = kind
+                .parse::<RelationKind>()
+                .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
+            Ok((kind, task_id))
         })
         .collect()
 }
 
-fn map_task_write_error(err: TaskWriteError) -> McpError {
-    match err {
-        TaskWriteError::InvalidState(state) => {
-            McpError::invalid_params(format!("Invalid workflow state: {state}"), None)
-        }
-        TaskWriteError::MissingParent(parent) => {
-            McpError::invalid_params(format!("Parent task not found: {parent}"), None)
-        }
-        TaskWriteError::MissingTask(task) => {
-            McpError::invalid_params(format!("Task not found: {task}"), None)
-        }
-        TaskWriteError::Store(error) => McpError::internal_error(error.to_string(), None),
-        TaskWriteError::NotImplemented(name) => {
-            McpError::internal_error(format!("{name} not implemented"), None)
-        }
-        TaskWriteError::HookRejected {
-            hook,
-            exit_code,
-            stderr,
-        } => McpError::invalid_params(
-            format!("Hook '{hook}' rejected operation (exit code {exit_code}): {stderr}"),
-            None,
-        ),
-        TaskWriteError::HookFailed { hook, error } => {
-            McpError::internal_error(format!("Hook '{hook}' failed: {error}"), None)
-        }
+async fn resolve_relations(
+    store: &Arc<Mutex<GitStore>>,
+    relations: Vec<RelationParam>,
+) -> Result<Vec<(RelationKind, TaskId)>, McpError> {
+    let mut resolved = Vec::with_capacity(relations.len());
+    for (kind, task_id) in parse_relations(relations)? {
+        resolved.push((kind, resolve_task_id(store, &task_id, "related task ID").await?));
     }
+    Ok(resolved)
 }
 
-/// Update an existing task's title, description, state, labels, assignees, or parent tasks.
+/// Resolve `milestone_id` / `clear_milestone` into a membership change.
+async fn milestone_patch(
+    store: &Arc<Mutex<GitStore>>,
+    milestone_id: Option<String>,
+    clear_milestone: bool,
+) -> Result<Option<MilestonePatch>, McpError> {
+    Ok(match milestone_id {
+        Some(raw) => Some(MilestonePatch::Set {
+            milestone: resolve_milestone_id(Arc::clone(store), raw).await?,
+        }),
+        None => clear_milestone.then_some(MilestonePatch::Clear),
+    })
+}
+
+/// Update an existing task's title, description, state, labels, assignees, parent tasks, or relations.
 pub async fn handle_update_task(
     store: Arc<Mutex<GitStore>>,
     repository: Arc<AsyncTaskRepository<Arc<Mutex<GitStore>>>>,
@@ -74,13 +75,18 @@ pub async fn handle_update_task(
         remove_assignees,
         link_parents,
         unlink_parents,
+        link_relations,
+        unlink_relations,
+        set_fields,
+        clear_fields,
+        milestone_id,
+        clear_milestone,
         actor_name,
         actor_email,
     } = params;
 
-    let task: TaskId = task_id
-        .parse()
-        .map_err(|e| McpError::invalid_params(format!("Invalid task ID: {e}"), None))?;
+    let task = resolve_task_id(&store, &task_id, "task ID").await?;
+    let milestone = milestone_patch(&store, milestone_id, clear_milestone).await?;
     let update = TaskUpdate {
         title,
         description: description.map(|body| DescriptionPatch::Set { description: body }),
@@ -102,13 +108,22 @@ pub async fn handle_update_task(
             added: add_assignees,
             removed: remove_assignees,
         },
+        fields: set_fields
+            .into_iter()
+            .map(|(name, value)| (name, Some(value)))
+            .chain(clear_fields.into_iter().map(|name| (name, None)))
+            .collect(),
+        milestone,
+        rank: None,
     };
 
     let repo_hint = base_dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
     let actor = actor_from_params_or_default(actor_name.as_deref(), actor_email.as_deref(), &repo_hint);
 
-    let link_parent_ids = parse_task_ids(link_parents, "parent task ID")?;
-    let unlink_parent_ids = parse_task_ids(unlink_parents, "parent task ID")?;
+    let link_parent_ids = resolve_task_ids(&store, link_parents, "parent task ID").await?;
+    let unlink_parent_ids = resolve_task_ids(&store, unlink_parents, "parent task ID").await?;
+    let link_relations = resolve_relations(&store, link_relations).await?;
+    let unlink_relations = resolve_relations(&store, unlink_relations).await?;
 
     let workflow_clone = workflow.clone();
     let hooks_clone = hooks_config.clone();
@@ -131,6 +146,18 @@ pub async fn handle_update_task(
                 .unlink_parents(task, &unlink_parent_ids, &actor)
                 .map_err(map_task_write_error)?;
         }
+
+        for (kind, target) in link_relations {
+            writer
+                .link_relation(task, kind, &[target], &actor)
+                .map_err(map_task_write_error)?;
+        }
+
+        for (kind, target) in unlink_relations {
+            writer
+                .unlink_relation(task, kind, &[target], &actor)
+                .map_err(map_task_write_error)?;
+        }
         Ok(())
     })
     .await?;
@@ -144,3 +171,27 @@ pub async fn handle_update_task(
 
     Ok(CallToolResult::success(vec![Content::text(json_str)]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_relations_rejects_unknown_kind() {
+        let target = TaskId::new();
+        let parsed = parse_relations(vec![RelationParam {
+            kind: "blocked_by".into(),
+            task_id: target.to_string(),
+        }])
+        .unwrap_or_else(|e| panic!("relation should parse: {e:?}"));
+        assert_eq!(parsed, vec![(RelationKind::BlockedBy, target.to_string())]);
+
+        let err = parse_relations(vec![RelationParam {
+            kind: "parent".into(),
+            task_id: target.to_string(),
+        }])
+        .err()
+        .unwrap_or_else(|| panic!("unknown kind must be rejected"));
+        assert!(err.message.contains("unknown relation kind"));
+    }
+}
@@ -1,17 +1,20 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 use anyhow::{Context, Error, Result};
-use git_mile_core::TaskSnapshot;
-use git_mile_core::event::Actor;
-use git_mile_core::id::TaskId;
+use git_mile_core::event::{Actor, Event};
+use git_mile_core::id::{EventId, TaskId, TaskIdAbbreviations};
+use git_mile_core::{RelationKind, TaskSnapshot};
 
 use super::task_visibility::TaskVisibility;
 use crate::event_log::{LogEntry, entries_from_events};
 use git_mile_app::TaskRepository;
+use git_mile_app::TaskRollup;
 use git_mile_app::TaskView;
 use git_mile_app::WorkflowConfig;
-use git_mile_app::{CommentRequest, CreateTaskRequest, TaskStore, TaskWriter};
+use git_mile_app::{BlameEntry, blame, latest_event_by};
+use git_mile_app::{BulkUpdateOutcome, StatePatch, TaskUpdate};
+use git_mile_app::{CommentRequest, CreateTaskRequest, QueuePosition, TaskStore, TaskWriter};
 use git_mile_app::{TaskEditData, TaskPatch};
 
 /// Application state shared between the TUI event loop and rendering.
@@ -25,6 +28,8 @@ pub(super) struct App<S: TaskStore> {
     task_index: HashMap<TaskId, usize>,
     parents_index: HashMap<TaskId, Vec<TaskId>>,
     children_index: HashMap<TaskId, Vec<TaskId>>,
+    rollups: HashMap<TaskId, TaskRollup>,
+    id_abbreviations: TaskIdAbbreviations,
 }
 
 impl<S: TaskStore> App<S> {
@@ -46,6 +51,8 @@ impl<S: TaskStore> App<S> {
             task_index: HashMap::new(),
             parents_index: HashMap::new(),
             children_index: HashMap::new(),
+            rollups: HashMap::new(),
+            id_abbreviations: TaskIdAbbreviations::default(),
         };
         app.refresh_tasks()?;
         Ok(app)
@@ -60,6 +67,11 @@ impl<S: TaskStore> App<S> {
         &self.workflow
     }
 
+    /// Shortest unique prefixes of the loaded task ids.
+    pub(super) const fn id_abbreviations(&self) -> &TaskIdAbbreviations {
+        &self.id_abbreviations
+    }
+
     pub(super) const fn visibility(&self) -> &TaskVisibility {
         &self.visibility
     }
@@ -70,10 +82,15 @@ impl<S: TaskStore> App<S> {
     }
 
     pub(super) fn rebuild_visibility(&mut self, preferred: Option<TaskId>) {
-        self.visibility.rebuild(&self.tasks, preferred);
+        self.visibility.rebuild(&self.tasks, &self.workflow, preferred);
     }
 
     /// Get parent tasks of the given task.
+    /// Look up a loaded task by id.
+    pub(super) fn get_task(&self, task_id: TaskId) -> Option<&TaskView> {
+        self.task_index.get(&task_id).and_then(|&idx| self.tasks.get(idx))
+    }
+
     pub(super) fn get_parents(&self, task_id: TaskId) -> Vec<&TaskView> {
         self.parents_index
             .get(&task_id)
@@ -101,6 +118,11 @@ impl<S: TaskStore> App<S> {
             .collect()
     }
 
+    /// Progress rolled up from the task's subtasks, if it has any.
+    pub(super) fn rollup(&self, task_id: TaskId) -> Option<&TaskRollup> {
+        self.rollups.get(&task_id)
+    }
+
     /// Get root (topmost parent) task of the given task.
     /// Returns the task itself if it has no parents.
     pub(super) fn get_root(&self, task_id: TaskId) -> Option<&TaskView> {
@@ -168,13 +190,19 @@ impl<S: TaskStore> App<S> {
         self.task_index = cache.task_index;
         self.parents_index = cache.parents_index;
         self.children_index = cache.children_index;
-        self.visibility.rebuild(&self.tasks, keep_id);
+        self.rollups = cache.rollups;
+        self.id_abbreviations = TaskIdAbbreviations::new(self.task_index.keys().copied());
+        self.visibility.rebuild(&self.tasks, &self.workflow, keep_id);
         Ok(())
     }
 
-    pub(super) fn load_log_entries(&self, task: TaskId) -> Result<Vec<LogEntry>> {
+    /// Log entries of `task` together with the event that last set each of its fields.
+    pub(super) fn load_log(&self, task: TaskId) -> Result<(Vec<LogEntry>, Vec<BlameEntry>)> {
         let events = self.repository.get_log(task)?;
-        Ok(entries_from_events(&events))
+        Ok((
+            entries_from_events(&events, &self.id_abbreviations),
+            blame(&events),
+        ))
     }
 
     /// Append a comment to the given task and refresh the view.
@@ -203,6 +231,7 @@ impl<S: TaskStore> App<S> {
                 assignees: data.assignees,
                 description: data.description,
                 parents,
+                fields: data.fields,
                 actor: actor.clone(),
             })
             .context("タスクの作成に失敗しました")?;
@@ -268,6 +297,14 @@ impl<S: TaskStore> App<S> {
         if snapshot.state.as_deref() == state.as_deref() {
             return Ok(false);
         }
+        if let Some(to) = state.as_deref() {
+            self.workflow.check_transition(
+                snapshot.state.as_deref(),
+                to,
+                &snapshot.labels,
+                &snapshot.assignees,
+            )?;
+        }
 
         self.writer
             .set_state(task, state, actor)
@@ -275,6 +312,143 @@ impl<S: TaskStore> App<S> {
         self.refresh_tasks_with(Some(task))?;
         Ok(true)
     }
+
+    /// Set or clear the workflow state of several tasks, reporting each task's outcome.
+    pub(super) fn bulk_set_state(
+        &mut self,
+        tasks: &[TaskId],
+        state: Option<String>,
+        actor: &Actor,
+    ) -> Result<Vec<BulkUpdateOutcome>> {
+        self.workflow.validate_state(state.as_deref())?;
+        let snapshots: Vec<TaskSnapshot> = tasks
+            .iter()
+            .filter_map(|task| self.get_task(*task))
+            .map(|view| view.snapshot.clone())
+            .collect();
+        let update = TaskUpdate {
+            state: Some(state.map_or(StatePatch::Clear, |state| StatePatch::Set { state })),
+            ..TaskUpdate::default()
+        };
+        let outcomes = self.writer.bulk_update(&snapshots, &update, actor);
+        self.refresh_tasks_with(None)?;
+        Ok(outcomes)
+    }
+
+    /// Flip the archived flag of a task. Returns the new archived state.
+    pub(super) fn toggle_task_archived(&mut self, task: TaskId, actor: &Actor) -> Result<bool> {
+        let archived = if let Some(view) = self.tasks.iter().find(|view| view.snapshot.id == task) {
+            view.snapshot.archived
+        } else {
+            let events = self
+                .writer
+                .store()
+                .load_events(task)
+                .map_err(Self::map_store_error)
+                .context("タスクの読み込みに失敗しました")?;
+            TaskSnapshot::replay(&events).archived
+        };
+
+        self.writer
+            .set_archived(task, !archived, actor)
+            .context("アーカイブイベントの書き込みに失敗しました")?;
+        self.refresh_tasks_with(Some(task))?;
+        Ok(!archived)
+    }
+
+    /// Tick or untick checklist item `index` in the task description.
+    pub(super) fn toggle_checklist_item(&mut self, task: TaskId, index: usize, actor: &Actor) -> Result<()> {
+        self.writer
+            .toggle_checklist_item(task, index, actor)
+            .context("チェックリストの更新に失敗しました")?;
+        self.refresh_tasks_with(Some(task))
+    }
+
+    /// Retract a comment on a task.
+    pub(super) fn delete_comment(&mut self, task: TaskId, comment: EventId, actor: &Actor) -> Result<()> {
+        self.writer
+            .delete_comment(task, comment, actor)
+            .context("コメント削除イベントの書き込みに失敗しました")?;
+        self.refresh_tasks_with(Some(task))
+    }
+
+    /// Add `reaction` to a comment, or withdraw it when `actor` already reacted with it.
+    ///
+    /// Returns `true` when the reaction is now present.
+    pub(super) fn toggle_comment_reaction(
+        &mut self,
+        task: TaskId,
+        comment: EventId,
+        reaction: &str,
+        actor: &Actor,
+    ) -> Result<bool> {
+        let reacted = self
+            .get_task(task)
+            .and_then(|view| view.comments.iter().find(|entry| entry.id == comment))
+            .and_then(|entry| entry.reactions.get(reaction))
+            .is_some_and(|actors| actors.contains(&actor.email));
+        self.writer
+            .react_to_comment(task, comment, reaction, reacted, actor)
+            .context("リアクションの書き込みに失敗しました")?;
+        self.refresh_tasks_with(Some(task))?;
+        Ok(!reacted)
+    }
+
+    /// Move a task within the manual queue.
+    pub(super) fn move_task(&mut self, task: TaskId, position: QueuePosition, actor: &Actor) -> Result<()> {
+        self.writer
+            .move_task(task, position, actor)
+            .context("並べ替えの書き込みに失敗しました")?;
+        self.refresh_tasks_with(Some(task))
+    }
+
+    /// Revert the most recent event `actor` authored in any task.
+    ///
+    /// Returns the reverted event, or `None` when the actor has not changed anything yet.
+    pub(super) fn undo_last(&mut self, actor: &Actor) -> Result<Option<Event>> {
+        let histories = self
+            .writer
+            .store()
+            .load_all_events()
+            .map_err(Self::map_store_error)
+            .context("イベントの読み込みに失敗しました")?;
+        let Some(event) = latest_event_by(&histories, actor).cloned() else {
+            return Ok(None);
+        };
+        self.writer
+            .revert_event(event.task, event.id, actor)
+            .context("取り消しイベントの書き込みに失敗しました")?;
+        self.refresh_tasks_with(Some(event.task))?;
+        Ok(Some(event))
+    }
+
+    /// Link or unlink `target` under `kind`. Returns `true` when the relation now exists.
+    pub(super) fn toggle_task_relation(
+        &mut self,
+        task: TaskId,
+        kind: RelationKind,
+        target: TaskId,
+        actor: &Actor,
+    ) -> Result<bool> {
+        let related = self
+            .tasks
+            .iter()
+            .find(|view| view.snapshot.id == task)
+            .and_then(|view| view.snapshot.relates.get(kind.as_str()))
+            .is_some_and(|targets| targets.contains(&target));
+
+        if related {
+            self.writer
+                .unlink_relation(task, kind, &[target], actor)
+                .context("関連の解除に失敗しました")?;
+        } else {
+            self.writer
+                .link_relation(task, kind, &[target], actor)
+                .context("関連の追加に失敗しました")?;
+        }
+        self.refresh_tasks_with(Some(task))?;
+        Ok(!related)
+    }
 }
 
 /// Input collected from the new task form.
@@ -285,6 +459,7 @@ pub(super) struct NewTaskData {
     pub labels: Vec<String>,
     pub assignees: Vec<String>,
     pub description: Option<String>,
+    pub fields: BTreeMap<String, String>,
     pub parent: Option<TaskId>,
 }
 
@@ -297,5 +472,6 @@ impl From<NewTaskData> for TaskEditData {
             data.assignees,
             data.description,
         )
+        .with_fields(data.fields)
     }
 }
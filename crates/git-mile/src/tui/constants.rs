@@ -16,6 +16,8 @@ pub const TREE_EXPANDED_MARKER: &str = "▼";
 pub const TREE_LEAF_MARKER: &str = "■";
 /// Highlight symbol shown beside selected list entries.
 pub const TASK_LIST_HIGHLIGHT_SYMBOL: &str = "▶ ";
+/// Prefix shown before the titles of tasks marked for bulk changes.
+pub const TASK_LIST_MARKED_SYMBOL: &str = "✓ ";
 /// Width percentage for the state picker popup before clamping.
 pub const STATE_PICKER_WIDTH_PERCENT: u16 = 40;
 /// Height percentage for the state picker popup before clamping.
@@ -24,6 +26,16 @@ pub const STATE_PICKER_HEIGHT_PERCENT: u16 = 60;
 pub const STATE_PICKER_MIN_WIDTH: u16 = 30;
 /// Minimum height for the state picker popup.
 pub const STATE_PICKER_MIN_HEIGHT: u16 = 6;
+/// Width percentage for the relation picker popup before clamping.
+pub const RELATION_PICKER_WIDTH_PERCENT: u16 = 60;
+/// Height percentage for the relation picker popup before clamping.
+pub const RELATION_PICKER_HEIGHT_PERCENT: u16 = 70;
+/// Minimum width for the relation picker popup.
+pub const RELATION_PICKER_MIN_WIDTH: u16 = 40;
+/// Minimum height for the relation picker popup.
+pub const RELATION_PICKER_MIN_HEIGHT: u16 = 8;
+/// Reaction toggled on the selected comment by the comment viewer.
+pub const COMMENT_REACTION: &str = "+1";
 /// Width percentage for the comment viewer popup before clamping.
 pub const COMMENT_VIEWER_WIDTH_PERCENT: u16 = 80;
 /// Height percentage for the comment viewer popup before clamping.
@@ -1,15 +1,14 @@
-use std::collections::BTreeSet;
-use std::str::FromStr;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::Result;
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{TaskId, TaskIdPrefix};
 use git_mile_core::{StateKindFilter, TaskFilter};
 use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 
-use git_mile_app::{TaskFilterBuilder, normalize_timestamp, parse_timestamp};
+use git_mile_app::{FieldDefinition, TaskFilterBuilder, normalize_timestamp, parse_timestamp};
 
 use super::app::NewTaskData;
-use git_mile_app::TaskView;
+use git_mile_app::{TaskEditData, TaskView};
 
 pub(super) fn comment_editor_template(actor: &git_mile_core::event::Actor, task: TaskId) -> String {
     format!(
@@ -32,7 +31,14 @@ pub(super) fn parse_comment_editor_output(raw: &str) -> Option<String> {
     }
 }
 
-pub(super) fn edit_task_editor_template(task: &TaskView, state_hint: Option<&str>) -> String {
+/// Prefix of custom field keys in the task editor templates.
+const FIELD_KEY_PREFIX: &str = "field.";
+
+pub fn edit_task_editor_template(
+    task: &TaskView,
+    state_hint: Option<&str>,
+    fields: &BTreeMap<String, FieldDefinition>,
+) -> String {
     let snapshot = &task.snapshot;
     let state = snapshot.state.as_deref().unwrap_or_default();
     let labels = if snapshot.labels.is_empty() {
@@ -68,6 +74,16 @@ pub(super) fn edit_task_editor_template(task: &TaskView, state_hint: Option<&str
         format!("state: {state}"),
         format!("labels: {labels}"),
         format!("assignees: {assignees}"),
+    ]);
+    let names = fields
+        .keys()
+        .chain(snapshot.fields.keys())
+        .collect::<BTreeSet<_>>();
+    for name in names {
+        let value = snapshot.fields.get(name).map_or("", String::as_str);
+        push_field_lines(&mut lines, name, fields.get(name), value);
+    }
+    lines.extend([
         "---".to_string(),
         "# この下で説明を編集してください。空欄で説明を削除します。".to_string(),
     ]);
@@ -84,6 +100,7 @@ pub(super) fn new_task_editor_template(
     parent: Option<&TaskView>,
     state_hint: Option<&str>,
     default_state: Option<&str>,
+    fields: &BTreeMap<String, FieldDefinition>,
 ) -> String {
     let header = parent.map_or_else(
         || "# 新規タスクを作成します。".to_owned(),
@@ -106,10 +123,11 @@ pub(super) fn new_task_editor_template(
         lines.push(format!("# state 候補: {hint}"));
     }
     let state_line = default_state.map_or_else(|| "state: ".to_string(), |value| format!("state: {value}"));
+    lines.extend([state_line, "labels: ".to_string(), "assignees: ".to_string()]);
+    for (name, definition) in fields {
+        push_field_lines(&mut lines, name, Some(definition), "");
+    }
     lines.extend([
-        state_line,
-        "labels: ".to_string(),
-        "assignees: ".to_string(),
         "---".to_string(),
         "# この下に説明をMarkdown形式で記入してください。不要なら空のままにしてください。".to_string(),
         String::new(),
@@ -117,6 +135,14 @@ pub(super) fn new_task_editor_template(
     lines.join("\n")
 }
 
+/// Append the `field.<name>:` line of a custom field, preceded by its value hint when declared.
+fn push_field_lines(lines: &mut Vec<String>, name: &str, definition: Option<&FieldDefinition>, value: &str) {
+    if let Some(definition) = definition {
+        lines.push(format!("# {FIELD_KEY_PREFIX}{name} 候補: {}", definition.hint()));
+    }
+    lines.push(format!("{FIELD_KEY_PREFIX}{name}: {value}"));
+}
+
 pub(super) fn filter_editor_template(filter: &TaskFilter) -> String {
     let states = filter.states.iter().cloned().collect::<Vec<_>>().join(", ");
     let labels = filter.labels.iter().cloned().collect::<Vec<_>>().join(", ");
@@ -134,6 +160,7 @@ pub(super) fn filter_editor_template(filter: &TaskFilter) -> String {
         .collect::<Vec<_>>()
         .join(", ");
     let text = filter.text.clone().unwrap_or_default();
+    let query = filter.query.as_ref().map(ToString::to_string).unwrap_or_default();
     let updated_since = filter
         .updated
         .as_ref()
@@ -153,6 +180,9 @@ pub(super) fn filter_editor_template(filter: &TaskFilter) -> String {
         "# updated_since / updated_until は RFC3339 (例: 2025-01-01T09:00:00+09:00) 形式。".to_string(),
         "# state_kinds には done/in_progress などの kind を指定し、!done で除外できます。".to_string(),
         format!("# state_kinds の候補: {}", state_kind_options_hint()),
+        "# include_archived を true にするとアーカイブ済みタスクも表示します。".to_string(),
+        "# query は検索式 (例: label:type/bug -state:state/done updated:<7d)。AND/OR/NOT と括弧が使えます。"
+            .to_string(),
         format!("states: {states}"),
         format!(
             "state_kinds: {}",
@@ -163,8 +193,10 @@ pub(super) fn filter_editor_template(filter: &TaskFilter) -> String {
         format!("parents: {parents}"),
         format!("children: {children}"),
         format!("text: {text}"),
+        format!("query: {query}"),
         format!("updated_since: {updated_since}"),
         format!("updated_until: {updated_until}"),
+        format!("include_archived: {}", filter.include_archived),
         String::new(),
     ];
     lines.join("\n")
@@ -210,11 +242,17 @@ pub(super) fn state_kind_summary_tokens(filter: &StateKindFilter) -> Vec<String>
     tokens
 }
 
+/// Parse the output of [`edit_task_editor_template`] into the fields to write back.
+pub fn parse_edit_task_editor_output(raw: &str) -> Result<Option<TaskEditData>, String> {
+    parse_new_task_editor_output(raw).map(|data| data.map(Into::into))
+}
+
 pub(super) fn parse_new_task_editor_output(raw: &str) -> Result<Option<NewTaskData>, String> {
     let mut title: Option<&str> = None;
     let mut state: Option<&str> = None;
     let mut labels: Option<&str> = None;
     let mut assignees: Option<&str> = None;
+    let mut fields = BTreeMap::new();
     let mut description_lines = Vec::new();
     let mut in_description = false;
 
@@ -242,6 +280,15 @@ pub(super) fn parse_new_task_editor_output(raw: &str) -> Result<Option<NewTaskDa
                 "state" => state = Some(value),
                 "labels" => labels = Some(value),
                 "assignees" => assignees = Some(value),
+                key if key.starts_with(FIELD_KEY_PREFIX) => {
+                    let name = key[FIELD_KEY_PREFIX.len()..].trim();
+                    if name.is_empty() {
+                        return Err(format!("フィールド名がありません: {key}"));
+                    }
+                    if !value.is_empty() {
+                        fields.insert(name.to_owned(), value.to_owned());
+                    }
+                }
                 unknown => {
                     return Err(format!("未知のフィールドです: {unknown}"));
                 }
@@ -261,6 +308,7 @@ pub(super) fn parse_new_task_editor_output(raw: &str) -> Result<Option<NewTaskDa
         && state.is_empty()
         && labels.is_empty()
         && assignees.is_empty()
+        && fields.is_empty()
         && description.trim().is_empty();
     if is_all_empty {
         return Ok(None);
@@ -289,21 +337,25 @@ pub(super) fn parse_new_task_editor_output(raw: &str) -> Result<Option<NewTaskDa
         labels,
         assignees,
         description,
+        fields,
         parent: None,
     }))
 }
 
-pub(super) fn parse_filter_editor_output(raw: &str) -> Result<TaskFilter, String> {
+/// Parse the filter editor buffer, resolving abbreviated task ids against `known`.
+pub(super) fn parse_filter_editor_output(raw: &str, known: &[TaskId]) -> Result<TaskFilter, String> {
     let mut states = BTreeSet::new();
     let mut labels = BTreeSet::new();
     let mut assignees = BTreeSet::new();
     let mut parents = BTreeSet::new();
     let mut children = BTreeSet::new();
     let mut text: Option<String> = None;
+    let mut query: Option<String> = None;
     let mut updated_since: Option<OffsetDateTime> = None;
     let mut updated_until: Option<OffsetDateTime> = None;
     let mut include_state_kind_tokens: Vec<String> = Vec::new();
     let mut exclude_state_kind_tokens: Vec<String> = Vec::new();
+    let mut include_archived = false;
 
     for line in raw.lines() {
         let trimmed = line.trim();
@@ -326,10 +378,10 @@ pub(super) fn parse_filter_editor_output(raw: &str) -> Result<TaskFilter, String
                 assignees = parse_list(value).into_iter().collect();
             }
             "parents" => {
-                parents = parse_task_id_list(value)?;
+                parents = parse_task_id_list(value, known)?;
             }
             "children" => {
-                children = parse_task_id_list(value)?;
+                children = parse_task_id_list(value, known)?;
             }
             "text" => {
                 if value.is_empty() {
@@ -338,6 +390,9 @@ pub(super) fn parse_filter_editor_output(raw: &str) -> Result<TaskFilter, String
                     text = Some(value.to_owned());
                 }
             }
+            "query" => {
+                query = (!value.is_empty()).then(|| value.to_owned());
+            }
             "updated_since" => {
                 updated_since = parse_optional_timestamp(value)?;
             }
@@ -347,6 +402,9 @@ pub(super) fn parse_filter_editor_output(raw: &str) -> Result<TaskFilter, String
             "state_kinds" => {
                 (include_state_kind_tokens, exclude_state_kind_tokens) = split_state_kind_tokens(value);
             }
+            "include_archived" => {
+                include_archived = parse_bool_flag(value)?;
+            }
             unknown => return Err(format!("未知のフィールドです: {unknown}")),
         }
     }
@@ -369,15 +427,25 @@ pub(super) fn parse_filter_editor_output(raw: &str) -> Result<TaskFilter, String
     builder = builder
         .with_state_kinds(&include_state_kind_tokens, &exclude_state_kind_tokens)
         .map_err(|err| err.describe_user_facing())?;
-    builder = builder.with_time_range_values(updated_since, updated_until);
+    builder = builder
+        .with_query(query.as_deref())
+        .map_err(|err| err.describe_user_facing())?;
+    builder = builder
+        .with_time_range_values(updated_since, updated_until)
+        .with_archived(include_archived);
 
-    builder.build().map_err(|err| err.describe_user_facing())
+    let filter = builder.build().map_err(|err| err.describe_user_facing())?;
+    filter
+        .resolve_task_ids(known)
+        .map_err(|err| format!("クエリの TaskId を解決できません: {err}"))
 }
 
-fn parse_task_id_list(input: &str) -> Result<BTreeSet<TaskId>, String> {
+fn parse_task_id_list(input: &str, known: &[TaskId]) -> Result<BTreeSet<TaskId>, String> {
     let mut ids = BTreeSet::new();
     for value in parse_list(input) {
-        let id = TaskId::from_str(&value).map_err(|_| format!("TaskId の形式が正しくありません: {value}"))?;
+        let id = TaskIdPrefix::parse(&value)
+            .and_then(|prefix| prefix.resolve(known.iter().copied()))
+            .map_err(|err| format!("TaskId を解決できません ({value}): {err}"))?;
         ids.insert(id);
     }
     Ok(ids)
@@ -393,6 +461,14 @@ fn parse_optional_timestamp(input: &str) -> Result<Option<OffsetDateTime>, Strin
         .map_err(|err| format!("時刻の形式が正しくありません ({input}): {err}"))
 }
 
+fn parse_bool_flag(input: &str) -> Result<bool, String> {
+    match input.to_ascii_lowercase().as_str() {
+        "" | "false" | "no" | "0" => Ok(false),
+        "true" | "yes" | "1" => Ok(true),
+        other => Err(format!("true/false のいずれかを指定してください: {other}")),
+    }
+}
+
 fn split_state_kind_tokens(input: &str) -> (Vec<String>, Vec<String>) {
     let mut include = Vec::new();
     let mut exclude = Vec::new();
@@ -447,6 +523,9 @@ pub(super) fn summarize_task_filter(filter: &TaskFilter) -> String {
     }) {
         parts.push(format!("text=\"{text}\""));
     }
+    if let Some(query) = &filter.query {
+        parts.push(format!("query=\"{query}\""));
+    }
     if let Some(updated) = &filter.updated {
         if let Some(since) = updated.since {
             parts.push(format!("since={}", format_timestamp(since)));
@@ -456,6 +535,9 @@ pub(super) fn summarize_task_filter(filter: &TaskFilter) -> String {
         }
     }
     parts.extend(state_kind_summary_tokens(&filter.state_kinds));
+    if filter.include_archived {
+        parts.push("archived=含む".to_string());
+    }
     if parts.is_empty() {
         "未設定".into()
     } else {
@@ -32,7 +32,9 @@ pub(super) fn handle_ui_action<S: TaskStore>(
                 .tasks
                 .iter()
                 .find(|view| view.snapshot.id == task)
-                .map(|view| edit_task_editor_template(view, state_hint.as_deref()))
+                .map(|view| {
+                    edit_task_editor_template(view, state_hint.as_deref(), ui.app.workflow().fields())
+                })
             else {
                 ui.error("編集対象のタスクが見つかりません");
                 return Ok(());
@@ -43,7 +45,8 @@ pub(super) fn handle_ui_action<S: TaskStore>(
         UiAction::CreateTask => {
             let hint = ui.app.workflow().state_hint();
             let default_state = ui.app.workflow().default_state();
-            let template = new_task_editor_template(None, hint.as_deref(), default_state);
+            let template =
+                new_task_editor_template(None, hint.as_deref(), default_state, ui.app.workflow().fields());
             let raw = with_terminal_suspended(terminal, || launch_editor(&template))?;
             ui.apply_new_task_input(&raw)?;
         }
@@ -51,7 +54,12 @@ pub(super) fn handle_ui_action<S: TaskStore>(
             let parent_view = ui.app.tasks.iter().find(|view| view.snapshot.id == parent);
             let hint = ui.app.workflow().state_hint();
             let default_state = ui.app.workflow().default_state();
-            let template = new_task_editor_template(parent_view, hint.as_deref(), default_state);
+            let template = new_task_editor_template(
+                parent_view,
+                hint.as_deref(),
+                default_state,
+                ui.app.workflow().fields(),
+            );
             let raw = with_terminal_suspended(terminal, || launch_editor(&template))?;
             ui.apply_new_subtask_input(parent, &raw)?;
         }
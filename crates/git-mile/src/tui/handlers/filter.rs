@@ -5,12 +5,14 @@ use super::super::view::Ui;
 
 impl<S: TaskStore> Ui<S> {
     pub(in crate::tui) fn apply_filter_editor_output(&mut self, raw: &str) {
-        match parse_filter_editor_output(raw) {
+        let known: Vec<_> = self.app.tasks.iter().map(|view| view.snapshot.id).collect();
+        match parse_filter_editor_output(raw, &known) {
             Ok(filter) => {
                 if &filter == self.app.visibility().filter() {
                     self.info("フィルタに変更はありません");
                 } else {
-                    self.update_filter(filter.clone());
+                    self.active_view = None;
+                    self.apply_view(filter.clone(), None);
                     let summary = summarize_task_filter(&filter);
                     if self.app.visibility().has_visible_tasks() {
                         self.info(format!("フィルタを更新しました: {summary}"));
@@ -22,4 +24,30 @@ impl<S: TaskStore> Ui<S> {
             Err(err) => self.error(format!("フィルタの解析に失敗しました: {err}")),
         }
     }
+
+    /// Apply the next saved view. Cycling past the last view returns to the default filter.
+    pub(in crate::tui) fn cycle_view(&mut self) {
+        if self.views.is_empty() {
+            self.info("保存済みビューがありません (.git-mile/config.toml の [views] で定義できます)");
+            return;
+        }
+
+        let next = self.active_view.map_or(0, |idx| idx + 1);
+        if let Some((name, view)) = self.views.get(next).cloned() {
+            // Step past a view that cannot be applied so the next cycle moves on.
+            self.active_view = Some(next);
+            let known: Vec<_> = self.app.tasks.iter().map(|view| view.snapshot.id).collect();
+            match view.filter.resolve_task_ids(&known) {
+                Ok(filter) => {
+                    self.apply_view(filter, Some(view.sort));
+                    self.info(format!("ビュー「{name}」を適用しました"));
+                }
+                Err(err) => self.error(format!("ビュー「{name}」を適用できません: {err}")),
+            }
+        } else {
+            self.active_view = None;
+            self.apply_view(Self::default_filter(), None);
+            self.info("ビューを解除しました");
+        }
+    }
 }
@@ -10,6 +10,7 @@ use super::view::{Ui, UiAction};
 pub(super) mod edit;
 pub(super) mod filter;
 pub(super) mod navigation;
+pub(super) mod relation_picker;
 pub(super) mod state_picker;
 
 pub(super) fn handle_ui_action<S: TaskStore>(
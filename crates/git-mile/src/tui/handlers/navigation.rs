@@ -1,11 +1,14 @@
 use anyhow::Result;
 use crossterm::event::{KeyEvent, KeyEventKind};
-use git_mile_app::TaskStore;
-use git_mile_core::id::TaskId;
+use git_mile_app::{QueuePosition, TaskComment, TaskStore};
+use git_mile_core::checklist;
+use git_mile_core::id::{EventId, TaskId};
 
+use super::super::constants::COMMENT_REACTION;
 use super::super::view::{
     CommentViewerState, DescriptionViewerState, DetailFocus, LogViewerState, Ui, UiAction,
 };
+use super::super::widgets::util::log_entry_lines;
 use crate::config::{Action, ViewType};
 
 impl<S: TaskStore> Ui<S> {
@@ -22,6 +25,7 @@ impl<S: TaskStore> Ui<S> {
             DetailFocus::None => self.handle_task_list_key(key),
             DetailFocus::TreeView => Ok(self.handle_tree_view_key(key)),
             DetailFocus::StatePicker => Ok(self.handle_state_picker_key(key)),
+            DetailFocus::RelationPicker => Ok(self.handle_relation_picker_key(key)),
             DetailFocus::CommentViewer => Ok(self.handle_comment_viewer_key(key)),
             DetailFocus::DescriptionViewer => Ok(self.handle_description_viewer_key(key)),
             DetailFocus::LogViewer => Ok(self.handle_log_viewer_key(key)),
@@ -53,6 +57,14 @@ impl<S: TaskStore> Ui<S> {
             return Ok(None);
         }
 
+        if self
+            .keybindings
+            .matches(ViewType::TaskList, Action::ToggleMark, &key)
+        {
+            self.toggle_mark();
+            return Ok(None);
+        }
+
         if self
             .keybindings
             .matches(ViewType::TaskList, Action::JumpToParent, &key)
@@ -157,6 +169,51 @@ impl<S: TaskStore> Ui<S> {
             return Ok(None);
         }
 
+        if self
+            .keybindings
+            .matches(ViewType::TaskList, Action::ToggleArchive, &key)
+        {
+            self.toggle_selected_archive();
+            return Ok(None);
+        }
+
+        if self.keybindings.matches(ViewType::TaskList, Action::Undo, &key) {
+            self.undo_last_change();
+            return Ok(None);
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::TaskList, Action::MoveTaskUp, &key)
+        {
+            self.move_selected_task(true);
+            return Ok(None);
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::TaskList, Action::MoveTaskDown, &key)
+        {
+            self.move_selected_task(false);
+            return Ok(None);
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::TaskList, Action::OpenRelationPicker, &key)
+        {
+            self.open_relation_picker();
+            return Ok(None);
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::TaskList, Action::CycleView, &key)
+        {
+            self.cycle_view();
+            return Ok(None);
+        }
+
         if self
             .keybindings
             .matches(ViewType::TaskList, Action::EditFilter, &key)
@@ -205,6 +262,43 @@ impl<S: TaskStore> Ui<S> {
     }
 
     fn handle_comment_viewer_key(&mut self, key: KeyEvent) -> Option<UiAction> {
+        let delete_key = self
+            .keybindings
+            .matches(ViewType::CommentViewer, Action::DeleteComment, &key);
+        if let Some(comment) = self
+            .comment_viewer
+            .as_mut()
+            .and_then(|viewer| viewer.pending_delete.take())
+        {
+            if delete_key {
+                self.delete_comment(comment);
+            } else {
+                self.info("コメントの削除を中止しました");
+            }
+            return None;
+        }
+
+        if delete_key {
+            self.request_comment_deletion();
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::CommentViewer, Action::NextComment, &key)
+        {
+            self.comment_viewer_next_comment();
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::CommentViewer, Action::ReactToComment, &key)
+        {
+            self.toggle_selected_comment_reaction();
+            return None;
+        }
+
         if self
             .keybindings
             .matches(ViewType::CommentViewer, Action::Close, &key)
@@ -289,6 +383,22 @@ impl<S: TaskStore> Ui<S> {
             return None;
         }
 
+        if self
+            .keybindings
+            .matches(ViewType::DescriptionViewer, Action::NextChecklistItem, &key)
+        {
+            self.description_viewer_next_item();
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::DescriptionViewer, Action::ToggleChecklistItem, &key)
+        {
+            self.toggle_selected_checklist_item();
+            return None;
+        }
+
         None
     }
 
@@ -330,6 +440,14 @@ impl<S: TaskStore> Ui<S> {
             return None;
         }
 
+        if self
+            .keybindings
+            .matches(ViewType::LogViewer, Action::NextField, &key)
+        {
+            self.log_viewer_next_field();
+            return None;
+        }
+
         None
     }
 
@@ -355,6 +473,23 @@ impl<S: TaskStore> Ui<S> {
         }
     }
 
+    /// Mark or unmark the selected task and move to the next one.
+    pub(in crate::tui) fn toggle_mark(&mut self) {
+        let Some(task) = self.selected_task_id() else {
+            self.error("選択するタスクがありません");
+            return;
+        };
+        if !self.marked.remove(&task) {
+            self.marked.insert(task);
+        }
+        self.app.visibility_mut().select_next();
+        if self.marked.is_empty() {
+            self.info("複数選択を解除しました");
+        } else {
+            self.info(format!("{}件選択中", self.marked.len()));
+        }
+    }
+
     pub(in crate::tui) fn copy_selected_task_id(&mut self) {
         let Some(task) = self.selected_task() else {
             self.error("コピー対象のタスクが選択されていません");
@@ -377,10 +512,93 @@ impl<S: TaskStore> Ui<S> {
         self.comment_viewer = Some(CommentViewerState {
             task_id: task.snapshot.id,
             scroll_offset: 0,
+            selected_comment: 0,
+            pending_delete: None,
         });
         self.detail_focus = DetailFocus::CommentViewer;
     }
 
+    /// Comment selected in the comment viewer, with the task it belongs to.
+    fn selected_comment(&self) -> Option<(TaskId, &TaskComment)> {
+        let viewer = self.comment_viewer.as_ref()?;
+        let comment = self
+            .app
+            .get_task(viewer.task_id)?
+            .comments
+            .get(viewer.selected_comment)?;
+        Some((viewer.task_id, comment))
+    }
+
+    fn comment_viewer_next_comment(&mut self) {
+        let Some(viewer) = &self.comment_viewer else {
+            return;
+        };
+        let total = self
+            .app
+            .get_task(viewer.task_id)
+            .map_or(0, |view| view.comments.len());
+        if total == 0 {
+            self.error("コメントがありません");
+            return;
+        }
+        if let Some(viewer) = &mut self.comment_viewer {
+            viewer.selected_comment = (viewer.selected_comment + 1) % total;
+        }
+    }
+
+    fn request_comment_deletion(&mut self) {
+        let Some((_, comment)) = self.selected_comment() else {
+            self.error("コメントがありません");
+            return;
+        };
+        if comment.deleted {
+            self.error("このコメントは削除済みです");
+            return;
+        }
+        let comment_id = comment.id;
+        let key = self
+            .keybindings
+            .comment_viewer
+            .delete_comment
+            .first()
+            .map_or("?", String::as_str);
+        let message = format!("もう一度 {key} を押すとコメントを削除します (他のキーで中止)");
+        if let Some(viewer) = &mut self.comment_viewer {
+            viewer.pending_delete = Some(comment_id);
+        }
+        self.info(message);
+    }
+
+    fn delete_comment(&mut self, comment: EventId) {
+        let Some(task_id) = self.comment_viewer.as_ref().map(|viewer| viewer.task_id) else {
+            return;
+        };
+        match self.app.delete_comment(task_id, comment, &self.actor) {
+            Ok(()) => self.info("コメントを削除しました"),
+            Err(err) => self.error(format!("コメントの削除に失敗しました: {err}")),
+        }
+    }
+
+    fn toggle_selected_comment_reaction(&mut self) {
+        let Some((task_id, comment)) = self.selected_comment() else {
+            self.error("コメントがありません");
+            return;
+        };
+        if comment.deleted {
+            self.error("削除済みのコメントにはリアクションできません");
+            return;
+        }
+        let comment_id = comment.id;
+        match self
+            .app
+            .toggle_comment_reaction(task_id, comment_id, COMMENT_REACTION, &self.actor)
+        {
+            Ok(true) => self.info(format!("{COMMENT_REACTION} を付けました")),
+            Ok(false) => self.info(format!("{COMMENT_REACTION} を取り消しました")),
+            Err(err) => self.error(format!("リアクションに失敗しました: {err}")),
+        }
+    }
+
     const fn close_comment_viewer(&mut self) {
         self.comment_viewer = None;
         self.detail_focus = DetailFocus::None;
@@ -406,6 +624,7 @@ impl<S: TaskStore> Ui<S> {
         self.description_viewer = Some(DescriptionViewerState {
             task_id: task.snapshot.id,
             scroll_offset: 0,
+            selected_item: 0,
         });
         self.detail_focus = DetailFocus::DescriptionViewer;
     }
@@ -427,19 +646,123 @@ impl<S: TaskStore> Ui<S> {
         }
     }
 
+    fn description_viewer_next_item(&mut self) {
+        let Some(viewer) = &self.description_viewer else {
+            return;
+        };
+        let total = self
+            .app
+            .tasks
+            .iter()
+            .find(|view| view.snapshot.id == viewer.task_id)
+            .map_or(0, |view| view.snapshot.checklist.total);
+        if total == 0 {
+            self.error("チェックリストがありません");
+            return;
+        }
+        if let Some(viewer) = &mut self.description_viewer {
+            viewer.selected_item = (viewer.selected_item + 1) % total;
+        }
+    }
+
+    fn toggle_selected_checklist_item(&mut self) {
+        let Some(viewer) = &self.description_viewer else {
+            return;
+        };
+        let (task_id, index) = (viewer.task_id, viewer.selected_item);
+        let Some(item) = self
+            .app
+            .tasks
+            .iter()
+            .find(|view| view.snapshot.id == task_id)
+            .and_then(|view| {
+                checklist::parse(&view.snapshot.description)
+                    .into_iter()
+                    .nth(index)
+            })
+        else {
+            self.error("チェックリストがありません");
+            return;
+        };
+
+        match self.app.toggle_checklist_item(task_id, index, &self.actor) {
+            Ok(()) if item.checked => self.info(format!("チェックを外しました: {}", item.text)),
+            Ok(()) => self.info(format!("チェックを付けました: {}", item.text)),
+            Err(err) => self.error(format!("チェックリストの更新に失敗しました: {err}")),
+        }
+    }
+
+    fn toggle_selected_archive(&mut self) {
+        let Some(task_id) = self.selected_task().map(|task| task.snapshot.id) else {
+            self.error("アーカイブするタスクが選択されていません");
+            return;
+        };
+
+        match self.app.toggle_task_archived(task_id, &self.actor) {
+            Ok(true) => self.info("タスクをアーカイブしました"),
+            Ok(false) => self.info("タスクのアーカイブを解除しました"),
+            Err(err) => self.error(format!("アーカイブに失敗しました: {err}")),
+        }
+    }
+
+    fn undo_last_change(&mut self) {
+        match self.app.undo_last(&self.actor) {
+            Ok(Some(event)) => {
+                let title = self
+                    .app
+                    .tasks
+                    .iter()
+                    .find(|view| view.snapshot.id == event.task)
+                    .map_or_else(|| event.task.to_string(), |view| view.snapshot.title.clone());
+                self.info(format!("「{title}」の直前の変更を取り消しました"));
+                self.refresh_log_viewer_for(event.task);
+            }
+            Ok(None) => self.error("取り消せる変更がありません"),
+            Err(err) => self.error(format!("取り消しに失敗しました: {err}")),
+        }
+    }
+
+    /// Swap the selected task with its visible neighbour by re-ranking it around that neighbour.
+    fn move_selected_task(&mut self, up: bool) {
+        let visibility = self.app.visibility();
+        let index = visibility.selected_index();
+        let visible: Vec<TaskId> = visibility
+            .visible_tasks(&self.app.tasks)
+            .map(|view| view.snapshot.id)
+            .collect();
+        let Some(&task) = visible.get(index) else {
+            self.error("並べ替えるタスクが選択されていません");
+            return;
+        };
+        let neighbour = if up {
+            index.checked_sub(1)
+        } else {
+            index.checked_add(1)
+        };
+        let Some(&anchor) = neighbour.and_then(|neighbour| visible.get(neighbour)) else {
+            return;
+        };
+        let position = if up {
+            QueuePosition::Before(anchor)
+        } else {
+            QueuePosition::After(anchor)
+        };
+
+        match self.app.move_task(task, position, &self.actor) {
+            Ok(()) => self.info("タスクの順番を変更しました"),
+            Err(err) => self.error(format!("並べ替えに失敗しました: {err}")),
+        }
+    }
+
     fn open_log_viewer(&mut self) {
         let Some(task) = self.selected_task() else {
             self.error("ログを表示するタスクが選択されていません");
             return;
         };
 
-        match self.app.load_log_entries(task.snapshot.id) {
-            Ok(entries) => {
-                self.log_viewer = Some(LogViewerState {
-                    task_id: task.snapshot.id,
-                    entries,
-                    scroll_offset: 0,
-                });
+        match self.app.load_log(task.snapshot.id) {
+            Ok((entries, blame)) => {
+                self.log_viewer = Some(LogViewerState::new(task.snapshot.id, entries, blame));
                 self.detail_focus = DetailFocus::LogViewer;
             }
             Err(err) => self.error(format!("ログの読み込みに失敗しました: {err}")),
@@ -465,15 +788,33 @@ impl<S: TaskStore> Ui<S> {
         }
     }
 
+    /// Highlight the event behind the next field, wrapping back to no highlight.
+    fn log_viewer_next_field(&mut self) {
+        let Some(viewer) = &mut self.log_viewer else {
+            return;
+        };
+        viewer.field = match viewer.field {
+            None if !viewer.blame.is_empty() => Some(0),
+            Some(idx) if idx + 1 < viewer.blame.len() => Some(idx + 1),
+            _ => None,
+        };
+        if let Some(event) = viewer.highlighted().map(|entry| entry.event_id) {
+            let offset: usize = viewer
+                .entries
+                .iter()
+                .take_while(|entry| entry.id != event)
+                .map(|entry| log_entry_lines(entry, None).len())
+                .sum();
+            viewer.scroll_offset = u16::try_from(offset).unwrap_or(u16::MAX);
+        }
+    }
+
     pub(in crate::tui) fn refresh_log_viewer_for(&mut self, task_id: TaskId) {
         if let Some(viewer) = &mut self.log_viewer
             && viewer.task_id == task_id
         {
-            match self.app.load_log_entries(task_id) {
-                Ok(entries) => {
-                    viewer.entries = entries;
-                    viewer.scroll_offset = 0;
-                }
+            match self.app.load_log(task_id) {
+                Ok((entries, blame)) => *viewer = LogViewerState::new(task_id, entries, blame),
                 Err(err) => self.error(format!("ログの再読込に失敗しました: {err}")),
             }
         }
@@ -489,15 +830,17 @@ impl<S: TaskStore> Ui<S> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::event_log::entries_from_events;
     use crate::tui::app::App;
     use anyhow::Error;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     use git_mile_app::TaskRepository;
     use git_mile_app::TaskView;
     use git_mile_app::WorkflowConfig;
+    use git_mile_app::blame;
     use git_mile_core::TaskSnapshot;
-    use git_mile_core::event::{Actor, Event};
-    use git_mile_core::id::TaskId;
+    use git_mile_core::event::{Actor, Event, EventKind};
+    use git_mile_core::id::{TaskId, TaskIdAbbreviations};
     use git2::Oid;
     use std::sync::Arc;
 
@@ -612,4 +955,51 @@ mod tests {
         assert!(ui.description_viewer.is_none());
         assert_eq!(ui.detail_focus, DetailFocus::None);
     }
+
+    #[test]
+    fn log_viewer_tab_highlights_event_behind_each_field() {
+        let mut ui = test_ui();
+        let task = seed_task(&mut ui);
+        let mut created = Event::new(
+            task,
+            &ui.actor,
+            EventKind::TaskCreated {
+                title: "draft".into(),
+                labels: vec![],
+                assignees: vec![],
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        );
+        created.lamport = 1;
+        let mut retitled = Event::new(task, &ui.actor, EventKind::TaskTitleSet { title: "task".into() });
+        retitled.lamport = 2;
+        let events = vec![created, retitled.clone()];
+        ui.log_viewer = Some(LogViewerState::new(
+            task,
+            entries_from_events(&events, &TaskIdAbbreviations::default()),
+            blame(&events),
+        ));
+        ui.detail_focus = DetailFocus::LogViewer;
+
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        ui.handle_log_viewer_key(tab);
+        let viewer = ui
+            .log_viewer
+            .as_ref()
+            .unwrap_or_else(|| panic!("log viewer closed"));
+        assert_eq!(
+            viewer.highlighted().map(|entry| entry.event_id),
+            Some(retitled.id)
+        );
+        assert!(viewer.scroll_offset > 0);
+
+        ui.handle_log_viewer_key(tab);
+        let viewer = ui
+            .log_viewer
+            .as_ref()
+            .unwrap_or_else(|| panic!("log viewer closed"));
+        assert!(viewer.highlighted().is_none());
+    }
 }
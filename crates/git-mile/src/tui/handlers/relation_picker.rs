@@ -0,0 +1,118 @@
+use crossterm::event::KeyEvent;
+
+use git_mile_app::TaskStore;
+use git_mile_core::RelationKind;
+
+use super::super::view::{DetailFocus, RelationPickerState, Ui, UiAction};
+use crate::config::{Action, ViewType};
+
+impl<S: TaskStore> Ui<S> {
+    pub(in crate::tui) fn handle_relation_picker_key(&mut self, key: KeyEvent) -> Option<UiAction> {
+        if self
+            .keybindings
+            .matches(ViewType::RelationPicker, Action::Close, &key)
+        {
+            self.close_relation_picker();
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::RelationPicker, Action::Down, &key)
+        {
+            if let Some(picker) = &mut self.relation_picker {
+                let max_index = picker.candidates.len().saturating_sub(1);
+                picker.selected = (picker.selected + 1).min(max_index);
+            }
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::RelationPicker, Action::Up, &key)
+        {
+            if let Some(picker) = &mut self.relation_picker {
+                picker.selected = picker.selected.saturating_sub(1);
+            }
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::RelationPicker, Action::NextRelationKind, &key)
+        {
+            if let Some(picker) = &mut self.relation_picker {
+                picker.kind = next_relation_kind(picker.kind);
+            }
+            return None;
+        }
+
+        if self
+            .keybindings
+            .matches(ViewType::RelationPicker, Action::Select, &key)
+        {
+            self.toggle_relation_picker_selection();
+            return None;
+        }
+
+        None
+    }
+
+    pub(in crate::tui) fn open_relation_picker(&mut self) {
+        let Some(task_id) = self.selected_task_id() else {
+            self.error("関連付けるタスクが選択されていません");
+            return;
+        };
+
+        let candidates: Vec<_> = self
+            .app
+            .tasks
+            .iter()
+            .map(|view| view.snapshot.id)
+            .filter(|id| *id != task_id)
+            .collect();
+        if candidates.is_empty() {
+            self.error("関連付けできるタスクがありません");
+            return;
+        }
+
+        self.relation_picker = Some(RelationPickerState {
+            task_id,
+            kind: RelationKind::Blocks,
+            candidates,
+            selected: 0,
+        });
+        self.detail_focus = DetailFocus::RelationPicker;
+    }
+
+    /// Link or unlink the highlighted task. The picker stays open so several tasks can be toggled.
+    pub(in crate::tui) fn toggle_relation_picker_selection(&mut self) {
+        let Some(picker) = &self.relation_picker else {
+            return;
+        };
+        let Some(target) = picker.candidates.get(picker.selected).copied() else {
+            self.error("関連候補が見つかりません");
+            return;
+        };
+        let (task_id, kind) = (picker.task_id, picker.kind);
+
+        match self.app.toggle_task_relation(task_id, kind, target, &self.actor) {
+            Ok(true) => self.info(format!("関連 ({kind}) を追加しました")),
+            Ok(false) => self.info(format!("関連 ({kind}) を解除しました")),
+            Err(err) => self.error(format!("関連の更新に失敗しました: {err}")),
+        }
+    }
+
+    fn close_relation_picker(&mut self) {
+        self.relation_picker = None;
+        self.detail_focus = DetailFocus::None;
+    }
+}
+
+fn next_relation_kind(kind: RelationKind) -> RelationKind {
+    let position = RelationKind::ALL
+        .iter()
+        .position(|candidate| *candidate == kind)
+        .unwrap_or(0);
+    RelationKind::ALL[(position + 1) % RelationKind::ALL.len()]
+}
@@ -2,7 +2,8 @@ use std::collections::BTreeSet;
 
 use crossterm::event::KeyEvent;
 
-use git_mile_app::TaskStore;
+use git_mile_app::{BulkUpdateOutcome, TaskStore};
+use git_mile_core::id::TaskId;
 
 use super::super::view::{DetailFocus, StatePickerOption, StatePickerState, Ui, UiAction};
 use crate::config::{Action, ViewType};
@@ -47,17 +48,24 @@ impl<S: TaskStore> Ui<S> {
             return;
         };
 
-        let options = self.state_picker_options(task.snapshot.state.as_deref());
+        let marked: Vec<TaskId> = self.marked.iter().copied().collect();
+        let current_state = if marked.is_empty() {
+            task.snapshot.state.as_deref()
+        } else {
+            None
+        };
+        let options = self.state_picker_options(current_state);
         if options.is_empty() {
             self.error("ステータス候補が見つかりません");
             return;
         }
         let selected = options
             .iter()
-            .position(|option| option.matches(task.snapshot.state.as_deref()))
+            .position(|option| option.matches(current_state))
             .unwrap_or(0);
         self.state_picker = Some(StatePickerState {
             task_id: task.snapshot.id,
+            marked,
             options,
             selected,
         });
@@ -124,6 +132,10 @@ impl<S: TaskStore> Ui<S> {
             return;
         };
         let desired_state = option.value.clone();
+        if !picker.marked.is_empty() {
+            self.apply_bulk_state(&picker.marked, desired_state);
+            return;
+        }
         match self
             .app
             .set_task_state(picker.task_id, desired_state, &self.actor)
@@ -134,6 +146,42 @@ impl<S: TaskStore> Ui<S> {
         }
     }
 
+    fn apply_bulk_state(&mut self, tasks: &[TaskId], state: Option<String>) {
+        let outcomes = match self.app.bulk_set_state(tasks, state, &self.actor) {
+            Ok(outcomes) => outcomes,
+            Err(err) => {
+                self.error(format!("ステータス更新に失敗しました: {err}"));
+                return;
+            }
+        };
+        self.marked.clear();
+        let updated = outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome, BulkUpdateOutcome::Updated { .. }))
+            .count();
+        let failures: Vec<String> = outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                BulkUpdateOutcome::Failed { task, error } => Some(format!(
+                    "{}: {error}",
+                    self.app.id_abbreviations().abbreviate(*task)
+                )),
+                _ => None,
+            })
+            .collect();
+        if let Some(first) = failures.first() {
+            self.error(format!(
+                "{updated}件を更新し、{}件は失敗しました ({first})",
+                failures.len()
+            ));
+        } else {
+            let unchanged = outcomes.len() - updated;
+            self.info(format!(
+                "{updated}件のステータスを更新しました (変更なし{unchanged}件)"
+            ));
+        }
+    }
+
     fn close_state_picker(&mut self) {
         self.state_picker = None;
         self.detail_focus = DetailFocus::None;
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::io::{self, Stdout};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -15,15 +16,15 @@ use tracing::subscriber::NoSubscriber;
 
 use crate::config::keybindings::{KeyBindingsConfig, load_config, validate_config_struct};
 use git_mile_app::TaskRepository;
-use git_mile_app::{WorkflowConfig, default_actor};
+use git_mile_app::{SavedView, WorkflowConfig, default_actor};
 
 mod app;
 mod clipboard;
 pub mod constants;
-mod editor;
+pub mod editor;
 mod handlers;
 mod task_visibility;
-mod terminal;
+pub mod terminal;
 mod tree_view;
 mod view;
 mod widgets;
@@ -38,6 +39,7 @@ pub fn run(
     store: GitStore,
     workflow: WorkflowConfig,
     hooks_config: git_mile_app::HooksConfig,
+    views: BTreeMap<String, SavedView>,
     base_dir: std::path::PathBuf,
 ) -> Result<()> {
     enable_raw_mode().context("failed to enable raw mode")?;
@@ -48,7 +50,7 @@ pub fn run(
     terminal.hide_cursor()?;
 
     let result = tracing::subscriber::with_default(NoSubscriber::default(), || {
-        run_event_loop(&mut terminal, store, workflow, hooks_config, base_dir)
+        run_event_loop(&mut terminal, store, workflow, hooks_config, views, base_dir)
     });
 
     disable_raw_mode().ok();
@@ -64,6 +66,7 @@ fn run_event_loop(
     store: GitStore,
     workflow: WorkflowConfig,
     hooks_config: git_mile_app::HooksConfig,
+    views: BTreeMap<String, SavedView>,
     base_dir: std::path::PathBuf,
 ) -> Result<()> {
     let store_arc = Arc::new(store);
@@ -87,6 +90,7 @@ fn run_event_loop(
     };
 
     let mut ui = Ui::new(app, actor, keybindings);
+    ui.set_views(views);
 
     let mut last_tick = Instant::now();
     let tick_rate = Duration::from_millis(TUI_TICK_RATE_MS);
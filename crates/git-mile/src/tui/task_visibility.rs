@@ -3,12 +3,14 @@ use std::collections::HashMap;
 use git_mile_core::TaskFilter;
 use git_mile_core::id::TaskId;
 
-use git_mile_app::TaskView;
+use git_mile_app::{TaskView, ViewSort, WorkflowConfig};
 
 /// Manages task visibility, filters, and selection independent of IO.
 #[derive(Debug, Default)]
 pub(super) struct TaskVisibility {
     filter: TaskFilter,
+    /// Ordering override from a saved view; `None` keeps the cache order.
+    sort: Option<ViewSort>,
     visible: Vec<usize>,
     visible_index: HashMap<TaskId, usize>,
     selected: usize,
@@ -24,7 +26,16 @@ impl TaskVisibility {
         self.filter = filter;
     }
 
-    pub(super) fn rebuild(&mut self, tasks: &[TaskView], preferred: Option<TaskId>) {
+    pub(super) fn set_sort(&mut self, sort: Option<ViewSort>) {
+        self.sort = sort;
+    }
+
+    pub(super) fn rebuild(
+        &mut self,
+        tasks: &[TaskView],
+        workflow: &WorkflowConfig,
+        preferred: Option<TaskId>,
+    ) {
         self.visible.clear();
         self.visible_index.clear();
 
@@ -33,20 +44,19 @@ impl TaskVisibility {
             return;
         }
 
-        if self.filter.is_empty() {
-            for (idx, view) in tasks.iter().enumerate() {
-                let pos = self.visible.len();
-                self.visible.push(idx);
-                self.visible_index.insert(view.snapshot.id, pos);
-            }
-        } else {
-            for (idx, view) in tasks.iter().enumerate() {
-                if self.filter.matches(&view.snapshot) {
-                    let pos = self.visible.len();
-                    self.visible.push(idx);
-                    self.visible_index.insert(view.snapshot.id, pos);
-                }
-            }
+        self.visible.extend(
+            tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, view)| self.filter.matches(&view.snapshot))
+                .map(|(idx, _)| idx),
+        );
+        if let Some(sort) = &self.sort {
+            self.visible
+                .sort_by(|&a, &b| sort.compare(workflow, &tasks[a].snapshot, &tasks[b].snapshot));
+        }
+        for (pos, &idx) in self.visible.iter().enumerate() {
+            self.visible_index.insert(tasks[idx].snapshot.id, pos);
         }
 
         self.selected = self.resolve_selection(preferred);
@@ -145,7 +155,7 @@ mod tests {
             view("00000000-0000-0000-0000-000000000002", "two"),
         ];
         let mut visibility = TaskVisibility::default();
-        visibility.rebuild(&tasks, None);
+        visibility.rebuild(&tasks, &WorkflowConfig::unrestricted(), None);
 
         assert_eq!(visibility.visible_indexes(), &[0, 1]);
         assert_eq!(visibility.selected_index(), 0);
@@ -166,7 +176,7 @@ mod tests {
             ..TaskFilter::default()
         });
         let preferred = Some(tasks[2].snapshot.id);
-        visibility.rebuild(&tasks, preferred);
+        visibility.rebuild(&tasks, &WorkflowConfig::unrestricted(), preferred);
 
         assert_eq!(visibility.visible_indexes(), &[1, 2]);
         assert_eq!(visibility.selected_task_id(&tasks), Some(tasks[2].snapshot.id));
@@ -179,7 +189,7 @@ mod tests {
             view("00000000-0000-0000-0000-000000000021", "second"),
         ];
         let mut visibility = TaskVisibility::default();
-        visibility.rebuild(&tasks, None);
+        visibility.rebuild(&tasks, &WorkflowConfig::unrestricted(), None);
         visibility.select_next();
         assert_eq!(visibility.selected_task_id(&tasks), Some(tasks[1].snapshot.id));
         visibility.select_next();
@@ -197,7 +207,7 @@ mod tests {
             view("00000000-0000-0000-0000-000000000031", "second"),
         ];
         let mut visibility = TaskVisibility::default();
-        visibility.rebuild(&tasks, None);
+        visibility.rebuild(&tasks, &WorkflowConfig::unrestricted(), None);
 
         visibility.jump_to_task(tasks[1].snapshot.id);
         assert_eq!(visibility.selected_task_id(&tasks), Some(tasks[1].snapshot.id));
@@ -218,7 +228,7 @@ mod tests {
             text: Some("Grand".into()),
             ..TaskFilter::default()
         });
-        visibility.rebuild(&tasks, None);
+        visibility.rebuild(&tasks, &WorkflowConfig::unrestricted(), None);
 
         let titles: Vec<&str> = visibility
             .visible_tasks(&tasks)
@@ -239,7 +249,7 @@ mod tests {
             text: Some("Root".into()),
             ..TaskFilter::default()
         });
-        visibility.rebuild(&tasks, None);
+        visibility.rebuild(&tasks, &WorkflowConfig::unrestricted(), None);
 
         let titles: Vec<&str> = visibility
             .visible_tasks(&tasks)
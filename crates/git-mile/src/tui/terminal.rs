@@ -47,7 +47,7 @@ pub(super) fn resolve_editor_command() -> String {
         .unwrap_or_else(|_| "vi".into())
 }
 
-pub(super) fn launch_editor(initial: &str) -> Result<String> {
+pub fn launch_editor(initial: &str) -> Result<String> {
     let mut tempfile = NamedTempFile::new().context("一時ファイルの作成に失敗しました")?;
     tempfile
         .write_all(initial.as_bytes())
@@ -5,18 +5,19 @@ use super::view::*;
 use super::widgets::truncate_with_ellipsis;
 use crate::config::keybindings::KeyBindingsConfig;
 use anyhow::{Result, anyhow};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use git_mile_app::TaskRepository;
 use git_mile_app::TaskStore;
 use git_mile_app::TaskView;
-use git_mile_app::{StateKind, WorkflowConfig, WorkflowState};
+use git_mile_app::{SavedView, StateKind, ViewSort, WorkflowConfig, WorkflowState, WorkflowTransition};
 use git_mile_core::event::{Actor, Event, EventKind};
 use git_mile_core::id::{EventId, TaskId};
-use git_mile_core::{TaskFilter, TaskSnapshot};
+use git_mile_core::{RelationKind, TaskFilter, TaskSnapshot};
 use git2::Oid;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 use std::rc::Rc;
 use std::result::Result as StdResult;
@@ -686,6 +687,7 @@ fn create_task_registers_and_selects_new_entry() -> Result<()> {
         labels: vec!["type/docs".into()],
         assignees: Vec::new(),
         description: Some("Write documentation".into()),
+        fields: BTreeMap::new(),
         parent: None,
     };
 
@@ -714,6 +716,7 @@ fn create_task_rejects_unknown_state() -> Result<()> {
         labels: Vec::new(),
         assignees: Vec::new(),
         description: None,
+        fields: BTreeMap::new(),
         parent: None,
     };
 
@@ -735,6 +738,7 @@ fn create_task_applies_default_state() -> Result<()> {
         labels: Vec::new(),
         assignees: Vec::new(),
         description: None,
+        fields: BTreeMap::new(),
         parent: None,
     };
 
@@ -807,10 +811,38 @@ assignees:
 
 #[test]
 fn new_task_editor_template_prefills_default_state() {
-    let template = new_task_editor_template(None, None, Some("state/todo"));
+    let template = new_task_editor_template(None, None, Some("state/todo"), &BTreeMap::new());
     assert!(template.contains("state: state/todo"));
 }
 
+#[test]
+fn task_editor_round_trips_custom_fields() {
+    let fields = BTreeMap::from([("due".to_owned(), git_mile_app::FieldDefinition::Date)]);
+    let template = new_task_editor_template(None, None, None, &fields);
+    assert!(template.contains("# field.due 候補: a date like 2025-01-31\nfield.due: \n"));
+
+    let raw = "\
+title: Ship
+field.due: 2025-03-01
+field.estimate:
+---
+";
+    let data = expect_some(
+        expect_ok(parse_new_task_editor_output(raw), "parse succeeds"),
+        "data present",
+    );
+    assert_eq!(
+        data.fields,
+        BTreeMap::from([("due".to_owned(), "2025-03-01".to_owned())])
+    );
+
+    let err = expect_err(
+        parse_new_task_editor_output("title: x\nfield.: 1\n"),
+        "should error",
+    );
+    assert!(err.contains("field."));
+}
+
 #[test]
 fn filter_editor_output_parses_all_fields() {
     let parent = fixed_task_id(1);
@@ -828,7 +860,7 @@ updated_since: 2025-01-01T00:00:00Z
 updated_until: 2025-01-02T00:00:00Z
 "
     );
-    let filter = expect_ok(parse_filter_editor_output(&raw), "parse succeeds");
+    let filter = expect_ok(parse_filter_editor_output(&raw, &[]), "parse succeeds");
     assert!(filter.states.contains("state/todo"));
     assert!(filter.labels.contains("type/bug"));
     assert!(filter.assignees.contains("alice"));
@@ -852,12 +884,71 @@ updated_until: 2025-01-02T00:00:00Z
 #[test]
 fn filter_editor_output_rejects_invalid_timestamp() {
     let err = expect_err(
-        parse_filter_editor_output("updated_since: invalid"),
+        parse_filter_editor_output("updated_since: invalid", &[]),
         "should error",
     );
     assert!(err.contains("時刻"));
 }
 
+#[test]
+fn filter_editor_output_parses_include_archived() {
+    let filter = expect_ok(
+        parse_filter_editor_output("include_archived: true", &[]),
+        "parse succeeds",
+    );
+    assert!(filter.include_archived);
+    assert!(summarize_task_filter(&filter).contains("archived="));
+
+    let err = expect_err(
+        parse_filter_editor_output("include_archived: maybe", &[]),
+        "should error",
+    );
+    assert!(err.contains("maybe"));
+}
+
+#[test]
+fn filter_editor_output_parses_query() {
+    let filter = expect_ok(
+        parse_filter_editor_output("query: label:type/bug OR \"crash on start\"", &[]),
+        "parse succeeds",
+    );
+    let query = expect_some(filter.query.as_ref(), "query");
+    assert_eq!(query.as_str(), "label:type/bug OR \"crash on start\"");
+    assert!(summarize_task_filter(&filter).contains("query="));
+    assert!(filter_editor_template(&filter).contains("query: label:type/bug OR"));
+
+    let err = expect_err(parse_filter_editor_output("query: (label:a", &[]), "should error");
+    assert!(err.contains("クエリ"));
+}
+
+#[test]
+fn filter_editor_output_resolves_short_task_ids() {
+    let parent = expect_ok(TaskId::from_str("0192f3a1-7b00-7000-8000-000000000001"), "id");
+    let child = expect_ok(TaskId::from_str("0192f3a1-7c00-7000-8000-000000000002"), "id");
+    let known = [parent, child];
+
+    let filter = expect_ok(
+        parse_filter_editor_output("parents: 0192f3a17b\nquery: child:0192f3a1-7c", &known),
+        "parse succeeds",
+    );
+    assert_eq!(filter.parents.iter().copied().collect::<Vec<_>>(), vec![parent]);
+    let mut snapshot = TaskSnapshot::default();
+    snapshot.children.insert(child);
+    let query = expect_some(filter.query.as_ref(), "query");
+    assert!(query.matches(&snapshot));
+
+    let err = expect_err(
+        parse_filter_editor_output("parents: 0192f3a1", &known),
+        "ambiguous prefix",
+    );
+    assert!(err.contains("ambiguous"), "unexpected error: {err}");
+    let err = expect_err(
+        parse_filter_editor_output("query: parent:0192f3a1", &known),
+        "ambiguous prefix",
+    );
+    assert!(err.contains("ambiguous"), "unexpected error: {err}");
+}
+
 #[test]
 fn summarize_task_filter_lists_active_fields() {
     let mut filter = TaskFilter::default();
@@ -936,6 +1027,7 @@ fn update_task_applies_field_changes() -> Result<()> {
             labels: vec!["type/docs".into()],
             assignees: vec!["bob".into()],
             description: Some("new description".into()),
+            fields: BTreeMap::new(),
             parent: None,
         },
         &actor(),
@@ -1031,6 +1123,7 @@ fn update_task_returns_false_when_no_diff() -> Result<()> {
             } else {
                 Some(snapshot.description)
             },
+            fields: snapshot.fields,
             parent: None,
         },
         &actor(),
@@ -1085,6 +1178,41 @@ fn set_task_state_applies_new_value() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn toggle_task_archived_hides_task_from_default_view() -> Result<()> {
+    let task = TaskId::new();
+    let created = Event::new(
+        task,
+        &actor(),
+        EventKind::TaskCreated {
+            title: "Junk".into(),
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            description: None,
+            state: None,
+            state_kind: None,
+        },
+    );
+    let store = MockStore::new().with_task(task, vec![created]);
+    let mut app = create_test_app(store, WorkflowConfig::unrestricted())?;
+    assert!(app.visibility().contains(task));
+
+    assert!(app.toggle_task_archived(task, &actor())?);
+    assert!(!app.visibility().contains(task));
+
+    apply_app_filter(
+        &mut app,
+        TaskFilter {
+            include_archived: true,
+            ..TaskFilter::default()
+        },
+    );
+    assert!(app.visibility().contains(task));
+
+    assert!(!app.toggle_task_archived(task, &actor())?);
+    Ok(())
+}
+
 #[test]
 fn set_task_state_returns_false_when_unchanged() -> Result<()> {
     let task = TaskId::new();
@@ -1113,6 +1241,43 @@ fn set_task_state_returns_false_when_unchanged() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn set_task_state_rejects_disallowed_transition() -> Result<()> {
+    let task = TaskId::new();
+    let created = Event::new(
+        task,
+        &actor(),
+        EventKind::TaskCreated {
+            title: "Initial".into(),
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            description: None,
+            state: Some("state/todo".into()),
+            state_kind: Some(StateKind::Todo),
+        },
+    );
+    let workflow = WorkflowConfig::from_states(vec![
+        WorkflowState::new("state/todo"),
+        WorkflowState::new("state/in-review"),
+        WorkflowState::new("state/done"),
+    ])
+    .with_transitions(vec![
+        WorkflowTransition::new(Some("state/todo"), "state/in-review"),
+        WorkflowTransition::new(Some("state/in-review"), "state/done"),
+    ]);
+    let store = MockStore::new().with_task(task, vec![created]);
+    let mut app = create_test_app(store, workflow)?;
+
+    let err = expect_err(
+        app.set_task_state(task, Some("state/done".into()), &actor()),
+        "todo -> done should be refused",
+    );
+    assert!(err.to_string().contains("not allowed"));
+    assert!(app.set_task_state(task, Some("state/in-review".into()), &actor())?);
+    assert!(app.set_task_state(task, Some("state/done".into()), &actor())?);
+    Ok(())
+}
+
 #[test]
 fn open_state_picker_prefills_current_state() -> Result<()> {
     let task = TaskId::new();
@@ -1183,3 +1348,281 @@ fn apply_state_picker_selection_updates_state() -> Result<()> {
     assert_eq!(ui.detail_focus, DetailFocus::None);
     Ok(())
 }
+
+#[test]
+fn state_picker_applies_to_every_marked_task() -> Result<()> {
+    let created = |task: TaskId, title: &str| {
+        Event::new(
+            task,
+            &actor(),
+            EventKind::TaskCreated {
+                title: title.into(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                description: None,
+                state: Some("state/todo".into()),
+                state_kind: Some(StateKind::Todo),
+            },
+        )
+    };
+    let first = TaskId::new();
+    let second = TaskId::new();
+    let workflow = WorkflowConfig::from_states(vec![
+        WorkflowState::new("state/todo"),
+        WorkflowState::new("state/done"),
+    ]);
+    let store = MockStore::new()
+        .with_task(first, vec![created(first, "First")])
+        .with_task(second, vec![created(second, "Second")]);
+    let app = create_test_app(store, workflow)?;
+    let mut ui = ui_with_clipboard(app, Box::new(NoopClipboard));
+    apply_app_filter(&mut ui.app, TaskFilter::default());
+
+    ui.toggle_mark();
+    ui.toggle_mark();
+    assert_eq!(ui.marked.len(), 2);
+
+    ui.open_state_picker();
+    let picker = expect_some(ui.state_picker.as_ref(), "state picker");
+    assert_eq!(picker.marked.len(), 2);
+    assert_eq!(picker.options[picker.selected].value, None);
+    ui.state_picker_down();
+    ui.state_picker_down();
+    ui.apply_state_picker_selection();
+
+    for task in [first, second] {
+        let view = expect_some(ui.app.get_task(task), "task exists");
+        assert_eq!(view.snapshot.state.as_deref(), Some("state/done"));
+    }
+    assert!(ui.marked.is_empty());
+    Ok(())
+}
+
+#[test]
+fn relation_picker_toggles_relation_and_inverse() -> Result<()> {
+    let created = |task: TaskId, title: &str| {
+        Event::new(
+            task,
+            &actor(),
+            EventKind::TaskCreated {
+                title: title.into(),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                description: None,
+                state: None,
+                state_kind: None,
+            },
+        )
+    };
+    let first = TaskId::new();
+    let second = TaskId::new();
+    let store = MockStore::new()
+        .with_task(first, vec![created(first, "First")])
+        .with_task(second, vec![created(second, "Second")]);
+    let app = create_test_app(store, WorkflowConfig::unrestricted())?;
+    let mut ui = ui_with_clipboard(app, Box::new(NoopClipboard));
+    let source = expect_some(ui.selected_task_id(), "selected task");
+    let target = if source == first { second } else { first };
+
+    ui.open_relation_picker();
+    assert_eq!(ui.detail_focus, DetailFocus::RelationPicker);
+    ui.handle_relation_picker_key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+    let picker = expect_some(ui.relation_picker.as_ref(), "relation picker");
+    assert_eq!(picker.kind, RelationKind::BlockedBy);
+    assert_eq!(picker.candidates, vec![target]);
+
+    ui.toggle_relation_picker_selection();
+    let related = |ui: &Ui<Arc<MockStore>>, task: TaskId, kind: RelationKind, other: TaskId| {
+        ui.app
+            .get_task(task)
+            .and_then(|view| view.snapshot.relates.get(kind.as_str()))
+            .is_some_and(|targets| targets.contains(&other))
+    };
+    assert!(related(&ui, source, RelationKind::BlockedBy, target));
+    assert!(related(&ui, target, RelationKind::Blocks, source));
+
+    ui.toggle_relation_picker_selection();
+    assert!(!related(&ui, source, RelationKind::BlockedBy, target));
+    assert!(!related(&ui, target, RelationKind::Blocks, source));
+    Ok(())
+}
+
+#[test]
+fn description_viewer_toggles_selected_checklist_item() -> Result<()> {
+    let task = TaskId::new();
+    let created = Event::new(
+        task,
+        &actor(),
+        EventKind::TaskCreated {
+            title: "Release".into(),
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            description: Some("- [ ] tag\n- [ ] publish".into()),
+            state: None,
+            state_kind: None,
+        },
+    );
+    let store = MockStore::new().with_task(task, vec![created]);
+    let app = create_test_app(store, WorkflowConfig::unrestricted())?;
+    let mut ui = ui_with_clipboard(app, Box::new(NoopClipboard));
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+    ui.open_description_viewer();
+    ui.handle_key(press(KeyCode::Tab))?;
+    ui.handle_key(press(KeyCode::Char(' ')))?;
+    let description = |ui: &Ui<Arc<MockStore>>| {
+        expect_some(ui.app.get_task(task), "task")
+            .snapshot
+            .description
+            .clone()
+    };
+    assert_eq!(description(&ui), "- [ ] tag\n- [x] publish");
+    let message = expect_some(ui.message.take(), "info message must be set");
+    assert!(message.text.contains("publish"));
+
+    ui.handle_key(press(KeyCode::Tab))?;
+    ui.handle_key(press(KeyCode::Char('x')))?;
+    assert_eq!(description(&ui), "- [x] tag\n- [x] publish");
+    let checklist = expect_some(ui.app.get_task(task), "task").snapshot.checklist;
+    assert_eq!((checklist.done, checklist.total), (2, 2));
+    assert_eq!(ui.detail_focus, DetailFocus::DescriptionViewer);
+    Ok(())
+}
+
+#[test]
+fn comment_viewer_reacts_and_deletes_after_confirmation() -> Result<()> {
+    let task = fixed_task_id(1);
+    let [first, second] = [EventId::new(), EventId::new()];
+    let comment = |secs, comment_id, body: &str| {
+        event(
+            task,
+            ts(secs),
+            EventKind::CommentAdded {
+                comment_id,
+                body_md: body.into(),
+            },
+        )
+    };
+    let store = MockStore::new().with_task(
+        task,
+        vec![
+            created(task, 0, "Discussed"),
+            comment(10, first, "first"),
+            comment(20, second, "second"),
+        ],
+    );
+    let app = create_test_app(store, WorkflowConfig::unrestricted())?;
+    let mut ui = ui_with_clipboard(app, Box::new(NoopClipboard));
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let comments = |ui: &Ui<Arc<MockStore>>| expect_some(ui.app.get_task(task), "task").comments.clone();
+
+    ui.open_comment_viewer();
+    ui.handle_key(press(KeyCode::Tab))?;
+    ui.handle_key(press(KeyCode::Char('r')))?;
+    let reactions = &comments(&ui)[1].reactions;
+    assert!(
+        reactions
+            .get("+1")
+            .is_some_and(|actors| actors.contains(&actor().email))
+    );
+    ui.handle_key(press(KeyCode::Char('r')))?;
+    assert!(comments(&ui)[1].reactions.is_empty());
+
+    ui.handle_key(press(KeyCode::Char('d')))?;
+    assert!(
+        !comments(&ui)[1].deleted,
+        "first press only asks for confirmation"
+    );
+    ui.handle_key(press(KeyCode::Char('j')))?;
+    ui.handle_key(press(KeyCode::Char('d')))?;
+    assert!(!comments(&ui)[1].deleted, "another key cancels the deletion");
+
+    ui.handle_key(press(KeyCode::Char('d')))?;
+    let message = expect_some(ui.message.take(), "info message must be set");
+    assert!(message.text.contains("削除しました"));
+    let comments = comments(&ui);
+    assert!(comments[1].deleted);
+    assert!(!comments[0].deleted);
+    assert_eq!(ui.detail_focus, DetailFocus::CommentViewer);
+    Ok(())
+}
+
+#[test]
+fn move_keys_reorder_selected_task_within_the_list() -> Result<()> {
+    let [alpha, beta, gamma] = [1, 2, 3].map(fixed_task_id);
+    let store = MockStore::new()
+        .with_task(alpha, vec![created(alpha, 30, "alpha")])
+        .with_task(beta, vec![created(beta, 20, "beta")])
+        .with_task(gamma, vec![created(gamma, 10, "gamma")]);
+    let app = create_test_app(store, WorkflowConfig::unrestricted())?;
+    let mut ui = ui_with_clipboard(app, Box::new(NoopClipboard));
+    let order = |ui: &Ui<Arc<MockStore>>| -> Vec<TaskId> {
+        ui.app
+            .visibility()
+            .visible_tasks(&ui.app.tasks)
+            .map(|view| view.snapshot.id)
+            .collect()
+    };
+
+    app_select_next(&mut ui.app);
+    app_select_next(&mut ui.app);
+    ui.handle_key(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::ALT))?;
+    assert_eq!(order(&ui), vec![alpha, gamma, beta]);
+    assert_eq!(app_selected_task_id(&ui.app), Some(gamma));
+
+    ui.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT))?;
+    assert_eq!(order(&ui), vec![gamma, alpha, beta]);
+    ui.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT))?;
+    assert_eq!(order(&ui), vec![gamma, alpha, beta]);
+
+    ui.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::ALT))?;
+    assert_eq!(order(&ui), vec![alpha, gamma, beta]);
+    let message = expect_some(ui.message.take(), "info message must be set");
+    assert!(message.text.contains("順番"));
+    Ok(())
+}
+
+#[test]
+fn cycle_view_applies_saved_views_then_resets() -> Result<()> {
+    let [alpha, beta, gamma] = [1, 2, 3].map(fixed_task_id);
+    let store = MockStore::new()
+        .with_task(alpha, vec![created(alpha, 30, "alpha")])
+        .with_task(beta, vec![created(beta, 20, "Beta")])
+        .with_task(gamma, vec![created(gamma, 10, "gamma done")]);
+    let app = create_test_app(store, WorkflowConfig::unrestricted())?;
+    let mut ui = ui_with_clipboard(app, Box::new(NoopClipboard));
+
+    let by_title = SavedView {
+        sort: ViewSort::Title,
+        ..SavedView::default()
+    };
+    let mut only_gamma = SavedView::default();
+    only_gamma.filter.text = Some("done".into());
+    ui.set_views(BTreeMap::from([
+        ("a-by-title".to_owned(), by_title),
+        ("b-gamma".to_owned(), only_gamma),
+    ]));
+
+    let titles = |ui: &Ui<Arc<MockStore>>| -> Vec<String> {
+        ui.app
+            .visibility()
+            .visible_tasks(&ui.app.tasks)
+            .map(|view| view.snapshot.title.clone())
+            .collect()
+    };
+
+    ui.cycle_view();
+    assert_eq!(ui.active_view, Some(0));
+    assert_eq!(titles(&ui), ["alpha", "Beta", "gamma done"]);
+
+    ui.cycle_view();
+    assert_eq!(titles(&ui), ["gamma done"]);
+
+    ui.cycle_view();
+    assert_eq!(ui.active_view, None);
+    assert_eq!(
+        ui.app.visibility().filter(),
+        &Ui::<Arc<MockStore>>::default_filter()
+    );
+    Ok(())
+}
@@ -1,18 +1,19 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::thread;
 use std::time::{Duration, Instant};
 
-use git_mile_core::TaskFilter;
 use git_mile_core::event::Actor;
-use git_mile_core::id::TaskId;
+use git_mile_core::id::{EventId, TaskId};
+use git_mile_core::{RelationKind, TaskFilter};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
 };
 
-use git_mile_app::StateKind;
 use git_mile_app::TaskStore;
 use git_mile_app::TaskView;
+use git_mile_app::{BlameEntry, SavedView, StateKind, ViewSort};
 
 use super::app::App;
 use super::clipboard::{ClipboardSink, default_clipboard};
@@ -44,27 +45,63 @@ impl StatePickerOption {
 pub(super) struct CommentViewerState {
     pub(super) task_id: TaskId,
     pub(super) scroll_offset: u16,
+    /// Comment that the delete and react keys act on.
+    pub(super) selected_comment: usize,
+    /// Comment awaiting a second press of the delete key.
+    pub(super) pending_delete: Option<EventId>,
 }
 
 #[derive(Debug, Clone)]
 pub(super) struct DescriptionViewerState {
     pub(super) task_id: TaskId,
     pub(super) scroll_offset: u16,
+    /// Checklist item that the toggle key acts on.
+    pub(super) selected_item: usize,
 }
 
 #[derive(Debug, Clone)]
 pub(super) struct LogViewerState {
     pub(super) task_id: TaskId,
     pub(super) entries: Vec<LogEntry>,
+    /// Last writer of each current field, cycled through to highlight entries.
+    pub(super) blame: Vec<BlameEntry>,
+    /// Index into `blame` of the highlighted field.
+    pub(super) field: Option<usize>,
     pub(super) scroll_offset: u16,
 }
 
+impl LogViewerState {
+    pub(super) const fn new(task_id: TaskId, entries: Vec<LogEntry>, blame: Vec<BlameEntry>) -> Self {
+        Self {
+            task_id,
+            entries,
+            blame,
+            field: None,
+            scroll_offset: 0,
+        }
+    }
+
+    /// Blame entry of the highlighted field, if any.
+    pub(super) fn highlighted(&self) -> Option<&BlameEntry> {
+        self.field.and_then(|idx| self.blame.get(idx))
+    }
+}
+
 pub(super) struct StatePickerState {
     pub(super) task_id: TaskId,
+    /// Marked tasks the choice applies to instead of `task_id`; empty for a single task.
+    pub(super) marked: Vec<TaskId>,
     pub(super) options: Vec<StatePickerOption>,
     pub(super) selected: usize,
 }
 
+pub(super) struct RelationPickerState {
+    pub(super) task_id: TaskId,
+    pub(super) kind: RelationKind,
+    pub(super) candidates: Vec<TaskId>,
+    pub(super) selected: usize,
+}
+
 /// Focus state for detail view components.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum DetailFocus {
@@ -74,6 +111,8 @@ pub(super) enum DetailFocus {
     TreeView,
     /// Focus on state picker popup.
     StatePicker,
+    /// Focus on relation picker popup.
+    RelationPicker,
     /// Focus on comment viewer popup.
     CommentViewer,
     /// Focus on description viewer popup.
@@ -93,6 +132,8 @@ pub(super) struct Ui<S: TaskStore> {
     pub(super) tree_state: TreeViewState,
     /// State picker popup state.
     pub(super) state_picker: Option<StatePickerState>,
+    /// Relation picker popup state.
+    pub(super) relation_picker: Option<RelationPickerState>,
     /// Comment viewer popup state.
     pub(super) comment_viewer: Option<CommentViewerState>,
     /// Description viewer popup state.
@@ -102,6 +143,12 @@ pub(super) struct Ui<S: TaskStore> {
     pub(super) clipboard: Box<dyn ClipboardSink>,
     /// Keybindings configuration.
     pub(super) keybindings: KeyBindingsConfig,
+    /// Saved views from the project configuration, in name order.
+    pub(super) views: Vec<(String, SavedView)>,
+    /// Index into `views` of the view currently applied.
+    pub(super) active_view: Option<usize>,
+    /// Tasks marked for bulk changes.
+    pub(super) marked: BTreeSet<TaskId>,
 }
 
 impl<S: TaskStore> Ui<S> {
@@ -132,24 +179,48 @@ impl<S: TaskStore> Ui<S> {
             detail_focus: DetailFocus::None,
             tree_state: TreeViewState::new(),
             state_picker: None,
+            relation_picker: None,
             comment_viewer: None,
             description_viewer: None,
             log_viewer: None,
             clipboard,
             keybindings,
+            views: Vec::new(),
+            active_view: None,
+            marked: BTreeSet::new(),
         };
         ui.apply_default_filter();
         ui
     }
 
+    pub(super) fn set_views(&mut self, views: BTreeMap<String, SavedView>) {
+        self.views = views.into_iter().collect();
+        self.active_view = None;
+    }
+
     fn apply_default_filter(&mut self) {
         if self.app.visibility().filter().is_empty() {
-            let mut filter = TaskFilter::default();
-            filter.state_kinds.exclude.insert(StateKind::Done);
-            self.update_filter(filter);
+            self.update_filter(Self::default_filter());
         }
     }
 
+    pub(super) fn default_filter() -> TaskFilter {
+        let mut filter = TaskFilter::default();
+        filter.state_kinds.exclude.insert(StateKind::Done);
+        filter
+    }
+
+    /// Replace both the filter and the sort order, rebuilding the list once.
+    pub(super) fn apply_view(&mut self, filter: TaskFilter, sort: Option<ViewSort>) {
+        let keep_id = self.app.visibility().selected_task_id(&self.app.tasks);
+        {
+            let visibility = self.app.visibility_mut();
+            visibility.set_filter(filter);
+            visibility.set_sort(sort);
+        }
+        self.app.rebuild_visibility(keep_id);
+    }
+
     pub(super) fn update_filter(&mut self, filter: TaskFilter) {
         if self.app.visibility().filter() == &filter {
             return;
@@ -187,6 +258,7 @@ impl<S: TaskStore> Ui<S> {
         match self.detail_focus {
             DetailFocus::TreeView => self.draw_tree_view_popup(f),
             DetailFocus::StatePicker => self.draw_state_picker_popup(f),
+            DetailFocus::RelationPicker => self.draw_relation_picker_popup(f),
             DetailFocus::CommentViewer => self.draw_comment_viewer_popup(f),
             DetailFocus::DescriptionViewer => self.draw_description_viewer_popup(f),
             DetailFocus::LogViewer => self.draw_log_viewer_popup(f),
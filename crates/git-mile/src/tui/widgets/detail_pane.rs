@@ -1,5 +1,7 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
+use git_mile_core::RelationKind;
 use git_mile_core::id::TaskId;
 use ratatui::{
     Frame,
@@ -9,6 +11,7 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
 };
 
+use git_mile_app::TaskRollup;
 use git_mile_app::TaskStore;
 use git_mile_app::TaskView;
 
@@ -18,7 +21,9 @@ use super::super::constants::{
     DETAIL_SECTION_MIN_HEIGHT,
 };
 use super::super::view::Ui;
-use super::util::{state_kind_marker, truncate_with_ellipsis};
+use super::util::comment_lines;
+use super::util::{relation_label, truncate_with_ellipsis};
+use crate::event_log::state_kind_marker;
 
 impl<S: TaskStore> Ui<S> {
     pub(in crate::tui) fn draw_task_details(&self, f: &mut Frame<'_>, area: Rect) {
@@ -150,6 +155,8 @@ impl<S: TaskStore> Ui<S> {
             lines.push(Line::from(format!("子タスク: {child_count} 件")));
         }
 
+        lines.extend(self.relation_lines(task));
+
         if let Some(updated) = task.last_updated {
             lines.push(Line::from(format!("更新: {updated}")));
         }
@@ -174,7 +181,41 @@ impl<S: TaskStore> Ui<S> {
         f.render_widget(paragraph, inner);
     }
 
-    fn draw_subtasks(&self, f: &mut Frame<'_>, area: Rect, _task_id: TaskId, children: &[&TaskView]) {
+    /// One line per relation kind, known kinds first in their canonical order.
+    fn relation_lines(&self, task: &TaskView) -> Vec<Line<'static>> {
+        let relates = &task.snapshot.relates;
+        let known = RelationKind::ALL.iter().map(|kind| kind.as_str());
+        let custom = relates
+            .keys()
+            .map(String::as_str)
+            .filter(|key| key.parse::<RelationKind>().is_err());
+
+        known
+            .chain(custom)
+            .filter_map(|key| {
+                let targets = relates.get(key).filter(|targets| !targets.is_empty())?;
+                let titles = targets
+                    .iter()
+                    .map(|target| {
+                        self.app.get_task(*target).map_or_else(
+                            || target.to_string(),
+                            |view| {
+                                truncate_with_ellipsis(
+                                    view.snapshot.title.as_str(),
+                                    DETAIL_PARENT_TITLE_MAX_CHARS,
+                                )
+                                .into_owned()
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some(Line::from(format!("{}: {titles}", relation_label(key))))
+            })
+            .collect()
+    }
+
+    fn draw_subtasks(&self, f: &mut Frame<'_>, area: Rect, task_id: TaskId, children: &[&TaskView]) {
         let workflow = self.app.workflow();
         let items: Vec<ListItem<'_>> = children
             .iter()
@@ -192,7 +233,10 @@ impl<S: TaskStore> Ui<S> {
             })
             .collect();
 
-        let title = format!("子タスク ({})", children.len());
+        let mut title = format!("子タスク ({})", children.len());
+        if let Some(rollup) = self.app.rollup(task_id) {
+            title.push_str(&rollup_summary(rollup));
+        }
         let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
 
         f.render_widget(list, area);
@@ -211,18 +255,7 @@ impl<S: TaskStore> Ui<S> {
             } else {
                 let mut lines = Vec::new();
                 for comment in &task.comments {
-                    let header = format!(
-                        "{} <{}> [{}]",
-                        comment.actor.name, comment.actor.email, comment.created_at
-                    );
-                    lines.push(Line::from(Span::styled(
-                        header,
-                        Style::default().fg(Color::Yellow),
-                    )));
-                    for body_line in comment.body.lines() {
-                        lines.push(Line::from(body_line.to_owned()));
-                    }
-                    lines.push(Line::from(""));
+                    lines.extend(comment_lines(comment));
                 }
                 let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
                 f.render_widget(paragraph, inner);
@@ -234,3 +267,19 @@ impl<S: TaskStore> Ui<S> {
         }
     }
 }
+
+/// Subtask progress appended to the subtask list title, e.g. ` · 完了 2/5 (40%) · 見積 8`.
+fn rollup_summary(rollup: &TaskRollup) -> String {
+    let mut summary = format!(
+        " · 完了 {}/{} ({}%)",
+        rollup.children.done, rollup.children.total, rollup.percent_done
+    );
+    if let Some(estimate) = rollup.estimate {
+        let _ = write!(summary, " · 見積 {estimate}");
+    }
+    if let Some(blocked) = &rollup.deepest_blocked {
+        let title = truncate_with_ellipsis(&blocked.title, DETAIL_PARENT_TITLE_MAX_CHARS);
+        let _ = write!(summary, " · ブロック中: {title}");
+    }
+    summary
+}
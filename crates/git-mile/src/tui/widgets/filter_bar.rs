@@ -49,6 +49,7 @@ impl<S: TaskStore> Ui<S> {
             DetailFocus::None => ViewType::TaskList,
             DetailFocus::TreeView => ViewType::TreeView,
             DetailFocus::StatePicker => ViewType::StatePicker,
+            DetailFocus::RelationPicker => ViewType::RelationPicker,
             DetailFocus::CommentViewer => ViewType::CommentViewer,
             DetailFocus::DescriptionViewer => ViewType::DescriptionViewer,
             DetailFocus::LogViewer => ViewType::LogViewer,
@@ -64,7 +65,11 @@ impl<S: TaskStore> Ui<S> {
     }
 
     fn filter_summary_text(&self) -> String {
-        summarize_task_filter(self.app.visibility().filter())
+        let summary = summarize_task_filter(self.app.visibility().filter());
+        match self.active_view.and_then(|idx| self.views.get(idx)) {
+            Some((name, _)) => format!("view={name} / {summary}"),
+            None => summary,
+        }
     }
 
     fn status_text(&self) -> Cow<'_, str> {
@@ -1,3 +1,4 @@
+use git_mile_core::checklist;
 use git_mile_core::id::TaskId;
 use ratatui::{
     Frame,
@@ -13,14 +14,15 @@ use git_mile_app::WorkflowState;
 use super::super::constants::{
     COMMENT_VIEWER_HEIGHT_PERCENT, COMMENT_VIEWER_MIN_HEIGHT, COMMENT_VIEWER_MIN_WIDTH,
     COMMENT_VIEWER_WIDTH_PERCENT, LOG_VIEWER_HEIGHT_PERCENT, LOG_VIEWER_MIN_HEIGHT, LOG_VIEWER_MIN_WIDTH,
-    LOG_VIEWER_WIDTH_PERCENT, STATE_PICKER_HEIGHT_PERCENT, STATE_PICKER_MIN_HEIGHT, STATE_PICKER_MIN_WIDTH,
-    STATE_PICKER_WIDTH_PERCENT, TASK_LIST_HIGHLIGHT_SYMBOL, TREE_COLLAPSED_MARKER, TREE_EXPANDED_MARKER,
-    TREE_INDENT_UNIT, TREE_LEAF_MARKER, TREE_POPUP_PERCENT,
+    LOG_VIEWER_WIDTH_PERCENT, RELATION_PICKER_HEIGHT_PERCENT, RELATION_PICKER_MIN_HEIGHT,
+    RELATION_PICKER_MIN_WIDTH, RELATION_PICKER_WIDTH_PERCENT, STATE_PICKER_HEIGHT_PERCENT,
+    STATE_PICKER_MIN_HEIGHT, STATE_PICKER_MIN_WIDTH, STATE_PICKER_WIDTH_PERCENT, TASK_LIST_HIGHLIGHT_SYMBOL,
+    TREE_COLLAPSED_MARKER, TREE_EXPANDED_MARKER, TREE_INDENT_UNIT, TREE_LEAF_MARKER, TREE_POPUP_PERCENT,
 };
 use super::super::tree_view::TreeNode;
 use super::super::view::Ui;
-use super::util::state_kind_marker;
-use crate::event_log::{format_actor, format_timestamp};
+use super::util::{comment_lines, log_entry_lines};
+use crate::event_log::state_kind_marker;
 
 impl<S: TaskStore> Ui<S> {
     pub(in crate::tui) fn draw_tree_view_popup(&self, f: &mut Frame<'_>) {
@@ -121,15 +123,24 @@ impl<S: TaskStore> Ui<S> {
             height: popup_height,
         };
 
-        let task_title = self
+        // Transition hints only make sense for a single task.
+        let task = self
             .app
             .tasks
             .iter()
-            .find(|view| view.snapshot.id == picker.task_id)
-            .map_or("不明", |view| view.snapshot.title.as_str());
+            .find(|view| picker.marked.is_empty() && view.snapshot.id == picker.task_id)
+            .map(|view| &view.snapshot);
+        let title = if picker.marked.is_empty() {
+            format!(
+                "ステータス選択: {}",
+                task.map_or("不明", |snapshot| snapshot.title.as_str())
+            )
+        } else {
+            format!("ステータス選択: {}件のタスク", picker.marked.len())
+        };
 
         let block = Block::default()
-            .title(format!("ステータス選択: {task_title}"))
+            .title(title)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan));
         f.render_widget(Clear, popup_area);
@@ -147,9 +158,20 @@ impl<S: TaskStore> Ui<S> {
                     .and_then(|state_value| workflow.find_state(state_value))
                     .and_then(WorkflowState::kind)
                     .map_or("", |kind| state_kind_marker(Some(kind)));
+                let blocked = task.zip(value).is_some_and(|(snapshot, to)| {
+                    workflow
+                        .check_transition(
+                            snapshot.state.as_deref(),
+                            to,
+                            &snapshot.labels,
+                            &snapshot.assignees,
+                        )
+                        .is_err()
+                });
+                let suffix = if blocked { " [遷移不可]" } else { "" };
                 let text = value.map_or_else(
                     || "未設定 (stateなし)".to_string(),
-                    |value| format!("{label}{marker} ({value})"),
+                    |value| format!("{label}{marker} ({value}){suffix}"),
                 );
                 ListItem::new(Line::from(text))
             })
@@ -171,6 +193,73 @@ impl<S: TaskStore> Ui<S> {
         f.render_stateful_widget(list, inner, &mut list_state);
     }
 
+    pub(in crate::tui) fn draw_relation_picker_popup(&self, f: &mut Frame<'_>) {
+        let Some(picker) = &self.relation_picker else {
+            return;
+        };
+        let area = f.area();
+
+        let mut popup_width = (area.width * RELATION_PICKER_WIDTH_PERCENT) / 100;
+        popup_width = popup_width.max(RELATION_PICKER_MIN_WIDTH).min(area.width);
+        let mut popup_height = (area.height * RELATION_PICKER_HEIGHT_PERCENT) / 100;
+        popup_height = popup_height.max(RELATION_PICKER_MIN_HEIGHT).min(area.height);
+        let popup_x = area.width.saturating_sub(popup_width) / 2;
+        let popup_y = area.height.saturating_sub(popup_height) / 2;
+        let popup_area = Rect {
+            x: popup_x,
+            y: popup_y,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        let source = self
+            .app
+            .tasks
+            .iter()
+            .find(|view| view.snapshot.id == picker.task_id);
+        let task_title = source.map_or("不明", |view| view.snapshot.title.as_str());
+        let related = source.and_then(|view| view.snapshot.relates.get(picker.kind.as_str()));
+
+        let block = Block::default()
+            .title(format!("関連 [{}]: {task_title}", picker.kind))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        f.render_widget(Clear, popup_area);
+        f.render_widget(block.clone(), popup_area);
+        let inner = block.inner(popup_area);
+
+        let items: Vec<ListItem<'_>> = picker
+            .candidates
+            .iter()
+            .map(|candidate| {
+                let checked = related.is_some_and(|targets| targets.contains(candidate));
+                let title = self
+                    .app
+                    .tasks
+                    .iter()
+                    .find(|view| view.snapshot.id == *candidate)
+                    .map_or("不明", |view| view.snapshot.title.as_str());
+                let marker = if checked { "[x]" } else { "[ ]" };
+                ListItem::new(Line::from(format!("{marker} {title}")))
+            })
+            .collect();
+
+        let mut list_state = ListState::default();
+        if picker.selected < picker.candidates.len() {
+            list_state.select(Some(picker.selected));
+        }
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Yellow)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(TASK_LIST_HIGHLIGHT_SYMBOL);
+
+        f.render_stateful_widget(list, inner, &mut list_state);
+    }
+
     pub(in crate::tui) fn draw_comment_viewer_popup(&self, f: &mut Frame<'_>) {
         let Some(viewer) = &self.comment_viewer else {
             return;
@@ -217,19 +306,24 @@ impl<S: TaskStore> Ui<S> {
                 f.render_widget(paragraph, inner);
             } else {
                 let mut lines = Vec::new();
-                for comment in &task.comments {
-                    let header = format!(
-                        "{} <{}> [{}]",
-                        comment.actor.name, comment.actor.email, comment.created_at
-                    );
-                    lines.push(Line::from(Span::styled(
-                        header,
-                        Style::default().fg(Color::Yellow),
-                    )));
-                    for body_line in comment.body.lines() {
-                        lines.push(Line::from(body_line.to_owned()));
+                for (index, comment) in task.comments.iter().enumerate() {
+                    let mut entry = comment_lines(comment);
+                    if index == viewer.selected_comment
+                        && let Some(header) = entry.first_mut()
+                    {
+                        let marked = if viewer.pending_delete == Some(comment.id) {
+                            Color::Red
+                        } else {
+                            Color::Yellow
+                        };
+                        for span in &mut header.spans {
+                            span.style = Style::default()
+                                .fg(Color::Black)
+                                .bg(marked)
+                                .add_modifier(Modifier::BOLD);
+                        }
                     }
-                    lines.push(Line::from(""));
+                    lines.extend(entry);
                 }
                 let paragraph = Paragraph::new(lines)
                     .wrap(Wrap { trim: false })
@@ -258,35 +352,46 @@ impl<S: TaskStore> Ui<S> {
             height: popup_height,
         };
 
-        let task_title = self
+        let task = self
             .app
             .tasks
             .iter()
-            .find(|view| view.snapshot.id == viewer.task_id)
-            .map_or("不明", |view| view.snapshot.title.as_str());
+            .find(|view| view.snapshot.id == viewer.task_id);
+        let task_title = task.map_or("不明", |view| view.snapshot.title.as_str());
+        let title = match task.map(|view| view.snapshot.checklist) {
+            Some(counts) if !counts.is_empty() => {
+                format!(
+                    "説明: {task_title} (チェックリスト {}/{})",
+                    counts.done, counts.total
+                )
+            }
+            _ => format!("説明: {task_title}"),
+        };
 
         let block = Block::default()
-            .title(format!("説明: {task_title}"))
+            .title(title)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan));
         f.render_widget(Clear, popup_area);
         f.render_widget(block.clone(), popup_area);
         let inner = block.inner(popup_area);
 
-        if let Some(task) = self
-            .app
-            .tasks
-            .iter()
-            .find(|view| view.snapshot.id == viewer.task_id)
-        {
+        if let Some(task) = task {
             if task.snapshot.description.is_empty() {
                 let paragraph =
                     Paragraph::new("説明はまだありません。").style(Style::default().fg(Color::DarkGray));
                 f.render_widget(paragraph, inner);
             } else {
+                let selected_line = checklist::parse(&task.snapshot.description)
+                    .get(viewer.selected_item)
+                    .map(|item| item.line);
                 let mut lines = Vec::new();
-                for line in task.snapshot.description.lines() {
-                    lines.push(Line::from(line.to_owned()));
+                for (number, line) in task.snapshot.description.lines().enumerate() {
+                    let mut line = Line::from(line.to_owned());
+                    if selected_line == Some(number) {
+                        line = line.style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+                    }
+                    lines.push(line);
                 }
                 let paragraph = Paragraph::new(lines)
                     .wrap(Wrap { trim: false })
@@ -322,8 +427,12 @@ impl<S: TaskStore> Ui<S> {
             .find(|view| view.snapshot.id == viewer.task_id)
             .map_or("不明", |view| view.snapshot.title.as_str());
 
+        let title = viewer.highlighted().map_or_else(
+            || format!("ログ: {task_title}"),
+            |entry| format!("ログ: {task_title} ({} の変更元)", entry.field),
+        );
         let block = Block::default()
-            .title(format!("ログ: {task_title}"))
+            .title(title)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan));
         f.render_widget(Clear, popup_area);
@@ -337,40 +446,20 @@ impl<S: TaskStore> Ui<S> {
             return;
         }
 
-        let mut lines = Vec::new();
-        for entry in &viewer.entries {
-            let header = format!("[{}] {} ({})", format_timestamp(entry.ts), entry.action, entry.id);
-            lines.push(Line::from(Span::styled(
-                header,
-                Style::default().fg(Color::Yellow),
-            )));
-
-            let actor_line = format!("by {}", format_actor(&entry.actor));
-            lines.push(Line::from(Span::styled(
-                actor_line,
-                Style::default().fg(Color::Gray),
-            )));
-
-            if let Some(detail) = &entry.detail {
-                let should_skip = entry.description_body.as_ref() == Some(detail);
-                if !should_skip {
-                    for detail_line in detail.lines() {
-                        lines.push(Line::from(detail_line.to_owned()));
-                    }
-                }
-            }
-
-            if let Some(description) = &entry.description_body {
-                lines.push(Line::from(Span::styled(
-                    "description:",
-                    Style::default().fg(Color::Green),
-                )));
-                for detail_line in description.lines() {
-                    lines.push(Line::from(detail_line.to_owned()));
-                }
-            }
-            lines.push(Line::from(""));
-        }
+        let highlight = viewer
+            .highlighted()
+            .map(|entry| (entry.event_id, entry.field.to_string()));
+        let lines: Vec<Line<'_>> = viewer
+            .entries
+            .iter()
+            .flat_map(|entry| {
+                let label = highlight
+                    .as_ref()
+                    .filter(|(event, _)| *event == entry.id)
+                    .map(|(_, field)| field.as_str());
+                log_entry_lines(entry, label)
+            })
+            .collect();
 
         let paragraph = Paragraph::new(lines)
             .wrap(Wrap { trim: false })
@@ -6,9 +6,11 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
+use std::fmt::Write;
+
 use git_mile_app::TaskStore;
 
-use super::super::constants::TASK_LIST_HIGHLIGHT_SYMBOL;
+use super::super::constants::{TASK_LIST_HIGHLIGHT_SYMBOL, TASK_LIST_MARKED_SYMBOL};
 use super::super::view::Ui;
 
 impl<S: TaskStore> Ui<S> {
@@ -19,13 +21,29 @@ impl<S: TaskStore> Ui<S> {
             visibility
                 .visible_tasks(&self.app.tasks)
                 .map(|view| {
+                    let mark = if self.marked.contains(&view.snapshot.id) {
+                        TASK_LIST_MARKED_SYMBOL
+                    } else {
+                        ""
+                    };
                     let title = Span::styled(
-                        &view.snapshot.title,
+                        format!("{mark}{}", view.snapshot.title),
                         Style::default().add_modifier(Modifier::BOLD),
                     );
                     let state_value = view.snapshot.state.as_deref();
                     let state_label = workflow.display_label(state_value);
-                    let meta = format!("{} | {}", view.snapshot.id, state_label);
+                    let id = self.app.id_abbreviations().abbreviate(view.snapshot.id);
+                    let mut meta = format!("{id} | {state_label}");
+                    if let Some(rollup) = self.app.rollup(view.snapshot.id) {
+                        let _ = write!(
+                            meta,
+                            " | 子 {}/{} ({}%)",
+                            rollup.children.done, rollup.children.total, rollup.percent_done
+                        );
+                    }
+                    if view.snapshot.archived {
+                        meta.push_str(" | アーカイブ済み");
+                    }
                     let meta_span = Span::styled(meta, Style::default().fg(Color::DarkGray));
                     ListItem::new(vec![Line::from(vec![title]), Line::from(vec![meta_span])])
                 })
@@ -39,8 +57,13 @@ impl<S: TaskStore> Ui<S> {
             vec![ListItem::new(Line::from(message))]
         };
 
+        let title = if self.marked.is_empty() {
+            "タスクリスト".to_owned()
+        } else {
+            format!("タスクリスト ({}件選択中)", self.marked.len())
+        };
         let list = List::new(items)
-            .block(Block::default().title("タスクリスト").borders(Borders::ALL))
+            .block(Block::default().title(title).borders(Borders::ALL))
             .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
             .highlight_symbol(TASK_LIST_HIGHLIGHT_SYMBOL);
         let mut state = ListState::default();
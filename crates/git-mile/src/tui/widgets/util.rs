@@ -1,8 +1,15 @@
 use std::borrow::Cow;
 
-use git_mile_app::StateKind;
+use git_mile_app::TaskComment;
+use git_mile_core::RelationKind;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::event_log::{LogEntry, format_actor, format_timestamp};
+
 pub(super) fn truncate_with_ellipsis(input: &str, max_graphemes: usize) -> Cow<'_, str> {
     const ELLIPSIS: &str = "...";
     const ELLIPSIS_GRAPHEMES: usize = 3;
@@ -29,13 +36,94 @@ pub(super) fn truncate_with_ellipsis(input: &str, max_graphemes: usize) -> Cow<'
     Cow::Owned(truncated)
 }
 
-pub(super) const fn state_kind_marker(kind: Option<StateKind>) -> &'static str {
-    match kind {
-        Some(StateKind::Done) => " ✓",
-        Some(StateKind::InProgress) => " →",
-        Some(StateKind::Blocked) => " ⊗",
-        Some(StateKind::Todo) => " □",
-        Some(StateKind::Backlog) => " ◇",
-        None => "",
+/// Japanese label for a relation key, falling back to the raw key for unknown kinds.
+pub(super) fn relation_label(kind: &str) -> &str {
+    match kind.parse::<RelationKind>() {
+        Ok(RelationKind::Blocks) => "ブロック先",
+        Ok(RelationKind::BlockedBy) => "ブロック元",
+        Ok(RelationKind::Duplicates) => "重複",
+        Ok(RelationKind::RelatesTo) => "関連",
+        Err(_) => kind,
+    }
+}
+
+/// Render a comment as header, body and reaction lines followed by a blank separator.
+pub(super) fn comment_lines(comment: &TaskComment) -> Vec<Line<'static>> {
+    let header = format!(
+        "{} <{}> [{}]",
+        comment.actor.name, comment.actor.email, comment.created_at
+    );
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::Yellow),
+    ))];
+    if comment.deleted {
+        lines.push(Line::from(Span::styled(
+            "(削除されたコメント)",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )));
+    } else {
+        for body_line in comment.body.lines() {
+            lines.push(Line::from(body_line.to_owned()));
+        }
+    }
+    if !comment.reactions.is_empty() {
+        let summary = comment
+            .reactions
+            .iter()
+            .map(|(reaction, actors)| format!("{reaction} {}", actors.len()))
+            .collect::<Vec<_>>()
+            .join("  ");
+        lines.push(Line::from(Span::styled(
+            summary,
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
+/// Render a log entry as header, actor and detail lines followed by a blank separator.
+///
+/// `highlight` names the field this entry last set; the header is then emphasised.
+pub(in crate::tui) fn log_entry_lines(entry: &LogEntry, highlight: Option<&str>) -> Vec<Line<'static>> {
+    let header = format!("[{}] {} ({})", format_timestamp(entry.ts), entry.action, entry.id);
+    let header = match highlight {
+        Some(field) => Line::from(vec![
+            Span::styled(
+                header,
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" ◀ {field}"), Style::default().fg(Color::Yellow)),
+        ]),
+        None => Line::from(Span::styled(header, Style::default().fg(Color::Yellow))),
+    };
+    let mut lines = vec![
+        header,
+        Line::from(Span::styled(
+            format!("by {}", format_actor(&entry.actor)),
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+
+    if let Some(detail) = &entry.detail
+        && entry.description_body.as_ref() != Some(detail)
+    {
+        lines.extend(detail.lines().map(|line| Line::from(line.to_owned())));
+    }
+
+    if let Some(description) = &entry.description_body {
+        lines.push(Line::from(Span::styled(
+            "description:",
+            Style::default().fg(Color::Green),
+        )));
+        lines.extend(description.lines().map(|line| Line::from(line.to_owned())));
     }
+    lines.push(Line::from(""));
+    lines
 }